@@ -0,0 +1,62 @@
+//! Felt and integer conversions on the hint hot paths, small values taking the fast paths and
+//! large ones the BigUint fallbacks.
+//!
+//! `cargo bench --bench conversions`
+
+use std::hint::black_box;
+
+use cairo_vm::Felt252;
+use cairo_vm_base::types::{
+    bounded_uint::Uint64, felt::Felt, fixed_bytes::Bytes32, int::Int64, uint256::Uint256,
+    FromAnyStr,
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+use num_bigint::BigUint;
+
+fn bounded_ints(c: &mut Criterion) {
+    let small = Felt252::from(123_456u64);
+    let negative = -Felt252::from(123_456u64);
+    c.bench_function("Uint64::from_felt", |b| {
+        b.iter(|| Uint64::from_felt(black_box(&small)))
+    });
+    c.bench_function("Int64::from_felt negative", |b| {
+        b.iter(|| Int64::from_felt(black_box(&negative)))
+    });
+}
+
+fn uint256(c: &mut Criterion) {
+    let small = Uint256::new(BigUint::from(42u32));
+    let large = Uint256::new((BigUint::from(1u32) << 255u32) - 1u32);
+    c.bench_function("Uint256::to_limbs small", |b| {
+        b.iter(|| black_box(&small).to_limbs().collect::<Vec<_>>())
+    });
+    c.bench_function("Uint256::to_limbs large", |b| {
+        b.iter(|| black_box(&large).to_limbs().collect::<Vec<_>>())
+    });
+    c.bench_function("Bytes32::from(Uint256)", |b| {
+        b.iter(|| Bytes32::from(black_box(large.clone())))
+    });
+    c.bench_function("Uint256::to_biguint", |b| {
+        b.iter(|| black_box(&large).to_biguint())
+    });
+}
+
+fn felts(c: &mut Criterion) {
+    let bytes = Bytes32::from(Felt(Felt252::from(u128::MAX)));
+    c.bench_function("Felt::try_from(Bytes32)", |b| {
+        b.iter(|| Felt::try_from(black_box(bytes)))
+    });
+    c.bench_function("Felt::from_any_str decimal", |b| {
+        b.iter(|| Felt::from_any_str(black_box("1234567890123456789")))
+    });
+    let (x, y) = (
+        Felt(Felt252::from(1u64 << 40)),
+        Felt(Felt252::from(1u64 << 20)),
+    );
+    c.bench_function("Felt::checked_mul small", |b| {
+        b.iter(|| black_box(&x).checked_mul(black_box(&y)))
+    });
+}
+
+criterion_group!(benches, bounded_ints, uint256, felts);
+criterion_main!(benches);
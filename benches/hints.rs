@@ -0,0 +1,72 @@
+//! Default hints run through `HintTester`, each on a fresh frame.
+//!
+//! `cargo bench --bench hints`
+
+use std::hint::black_box;
+
+use cairo_vm::Felt252;
+use cairo_vm_base::{
+    default_hints::sha256::{
+        hint_sha256_finalize, hint_sha256_main_constant_input_length, HINT_SHA256_FINALIZE,
+        HINT_SHA256_MAIN_CONSTANT_INPUT_LENGTH,
+    },
+    testing::HintTester,
+    types::sha256_bytes::Sha256Bytes,
+};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+const SHA256_UTILS: &str = "starkware.cairo.common.cairo_sha256.sha256_utils";
+
+fn sha256_tester(ids: &[&str]) -> HintTester {
+    HintTester::new(ids)
+        .with_constant(
+            &format!("{SHA256_UTILS}.SHA256_INPUT_CHUNK_SIZE_FELTS"),
+            16u64,
+        )
+        .with_constant(&format!("{SHA256_UTILS}.SHA256_STATE_SIZE_FELTS"), 8u64)
+        .with_constant(&format!("{SHA256_UTILS}.BATCH_SIZE"), 7u64)
+}
+
+fn sha256(c: &mut Criterion) {
+    c.bench_function("sha256_finalize", |b| {
+        b.iter_batched(
+            || {
+                let mut tester = sha256_tester(&["n", "sha256_ptr_end"]);
+                let end = tester.add_segment(&[]).unwrap();
+                tester.write_id_value("n", Felt252::ONE).unwrap();
+                tester.write_id_value("sha256_ptr_end", end).unwrap();
+                tester
+            },
+            |mut tester| tester.run(HINT_SHA256_FINALIZE, black_box(hint_sha256_finalize)),
+            BatchSize::SmallInput,
+        )
+    });
+
+    let block: Vec<_> = Sha256Bytes(b"abc".to_vec())
+        .padded_words()
+        .into_iter()
+        .map(|word| Felt252::from(word).into())
+        .collect();
+    c.bench_function("sha256_main", |b| {
+        b.iter_batched(
+            || {
+                let mut tester = sha256_tester(&["sha256_start", "output"]);
+                let start = tester.add_segment(&block).unwrap();
+                let output = tester.add_segment(&[]).unwrap();
+                tester.write_id_value("sha256_start", start).unwrap();
+                tester.write_id_value("output", output).unwrap();
+                tester
+            },
+            |mut tester| {
+                tester.run(
+                    HINT_SHA256_MAIN_CONSTANT_INPUT_LENGTH,
+                    black_box(hint_sha256_main_constant_input_length),
+                )
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(benches, sha256);
+criterion_main!(benches);
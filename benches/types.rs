@@ -0,0 +1,103 @@
+//! Parsing, JSON serialization, `to_limbs` and memory reads and writes of each input type.
+//!
+//! `cargo bench --bench types`
+
+use std::hint::black_box;
+
+use cairo_vm::{types::relocatable::Relocatable, vm::vm_core::VirtualMachine};
+use cairo_vm_base::{
+    cairo_type::{CairoType, CairoWritable},
+    types::{
+        bounded_uint::Uint64, byte_array::ByteArray, felt::Felt, fixed_bytes::Bytes32, int::Int128,
+        keccak_bytes::KeccakBytes, sha256_bytes::Sha256Bytes, short_string::ShortString,
+        uint256::Uint256, uint256_32::Uint256Bits32, uint384::UInt384, FromAnyStr,
+    },
+};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+const HEX_256: &str = "0x7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+const HEX_384: &str = "0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab";
+const HEX_BYTES: &str = "0x68656c6c6f20776f726c642c20746869732069732061206c6f6e676572206d6573736167652074686174207370616e73206d616e79206c696d6273";
+
+// A fresh VM for each write, so repeated writes never hit already set cells
+fn vm_with_segment() -> (VirtualMachine, Relocatable) {
+    let mut vm = VirtualMachine::new(false, false);
+    let address = vm.add_memory_segment();
+    (vm, address)
+}
+
+// parse, serialize, deserialize and to_memory for any type, from_memory for `CairoType`s
+macro_rules! bench_type {
+    ($c:expr, $name:literal, $ty:ty, $input:expr) => {{
+        let value = <$ty>::from_any_str($input).unwrap();
+        let json = serde_json::to_string(&value).unwrap();
+        let mut group = $c.benchmark_group($name);
+        group.bench_function("parse", |b| {
+            b.iter(|| <$ty>::from_any_str(black_box($input)))
+        });
+        group.bench_function("serialize", |b| {
+            b.iter(|| serde_json::to_string(black_box(&value)))
+        });
+        group.bench_function("deserialize", |b| {
+            b.iter(|| serde_json::from_str::<$ty>(black_box(&json)))
+        });
+        group.bench_function("to_memory", |b| {
+            b.iter_batched(
+                vm_with_segment,
+                |(mut vm, address)| value.to_memory(&mut vm, address),
+                BatchSize::SmallInput,
+            )
+        });
+        group.finish();
+        value
+    }};
+    ($c:expr, $name:literal, $ty:ty, $input:expr, from_memory) => {{
+        let value = bench_type!($c, $name, $ty, $input);
+        let (mut vm, address) = vm_with_segment();
+        value.to_memory(&mut vm, address).unwrap();
+        $c.bench_function(concat!($name, "/from_memory"), |b| {
+            b.iter(|| <$ty as CairoType>::from_memory(black_box(&vm), address))
+        });
+    }};
+}
+
+fn felts(c: &mut Criterion) {
+    bench_type!(c, "Felt", Felt, HEX_256, from_memory);
+    bench_type!(c, "ShortString", ShortString, "hello world", from_memory);
+    bench_type!(c, "Uint64", Uint64, "18446744073709551615", from_memory);
+    bench_type!(
+        c,
+        "Int128",
+        Int128,
+        "-170141183460469231731687303715884105728",
+        from_memory
+    );
+}
+
+fn limbs(c: &mut Criterion) {
+    let uint256 = Uint256::from_any_str(HEX_256).unwrap();
+    let uint384 = UInt384::from_any_str(HEX_384).unwrap();
+    let uint256_32 = Uint256Bits32::from_any_str(HEX_256).unwrap();
+    bench_type!(c, "Uint256", Uint256, HEX_256, from_memory);
+    bench_type!(c, "UInt384", UInt384, HEX_384, from_memory);
+    bench_type!(c, "Uint256Bits32", Uint256Bits32, HEX_256, from_memory);
+    bench_type!(c, "Bytes32", Bytes32, HEX_256, from_memory);
+    c.bench_function("Uint256/to_limbs", |b| {
+        b.iter(|| black_box(&uint256).to_limbs().collect::<Vec<_>>())
+    });
+    c.bench_function("UInt384/to_limbs", |b| {
+        b.iter(|| black_box(&uint384).to_limbs().collect::<Vec<_>>())
+    });
+    c.bench_function("Uint256Bits32/to_limbs", |b| {
+        b.iter(|| black_box(&uint256_32).to_limbs().collect::<Vec<_>>())
+    });
+}
+
+fn bytes(c: &mut Criterion) {
+    bench_type!(c, "KeccakBytes", KeccakBytes, HEX_BYTES, from_memory);
+    bench_type!(c, "ByteArray", ByteArray, HEX_BYTES, from_memory);
+    bench_type!(c, "Sha256Bytes", Sha256Bytes, HEX_BYTES);
+}
+
+criterion_group!(benches, felts, limbs, bytes);
+criterion_main!(benches);
@@ -0,0 +1,225 @@
+use cairo_vm::{
+    types::{
+        builtin_name::BuiltinName,
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+/// One instance of a builtin, decoded from its cells. Output cells are deduced by the VM when
+/// they are read, so they are `None` if the program never accessed them.
+pub trait BuiltinInstance: Sized {
+    const BUILTIN: BuiltinName;
+    const CELLS: usize;
+
+    fn from_cells(cells: &[Option<Felt252>]) -> Result<Self, HintError>;
+}
+
+fn input(cells: &[Option<Felt252>], i: usize, builtin: BuiltinName) -> Result<Felt252, HintError> {
+    cells[i].ok_or_else(|| {
+        HintError::CustomHint(
+            format!("{} instance is missing input cell {i}", builtin.to_str()).into_boxed_str(),
+        )
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeCheckInstance {
+    pub value: Felt252,
+}
+
+impl BuiltinInstance for RangeCheckInstance {
+    const BUILTIN: BuiltinName = BuiltinName::range_check;
+    const CELLS: usize = 1;
+
+    fn from_cells(cells: &[Option<Felt252>]) -> Result<Self, HintError> {
+        Ok(Self {
+            value: input(cells, 0, Self::BUILTIN)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitwiseInstance {
+    pub x: Felt252,
+    pub y: Felt252,
+    pub x_and_y: Option<Felt252>,
+    pub x_xor_y: Option<Felt252>,
+    pub x_or_y: Option<Felt252>,
+}
+
+impl BuiltinInstance for BitwiseInstance {
+    const BUILTIN: BuiltinName = BuiltinName::bitwise;
+    const CELLS: usize = 5;
+
+    fn from_cells(cells: &[Option<Felt252>]) -> Result<Self, HintError> {
+        Ok(Self {
+            x: input(cells, 0, Self::BUILTIN)?,
+            y: input(cells, 1, Self::BUILTIN)?,
+            x_and_y: cells[2],
+            x_xor_y: cells[3],
+            x_or_y: cells[4],
+        })
+    }
+}
+
+// The 1600-bit keccak state as 8 limbs of 200 bits, before and after the permutation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeccakInstance {
+    pub input: [Felt252; 8],
+    pub output: [Option<Felt252>; 8],
+}
+
+impl BuiltinInstance for KeccakInstance {
+    const BUILTIN: BuiltinName = BuiltinName::keccak;
+    const CELLS: usize = 16;
+
+    fn from_cells(cells: &[Option<Felt252>]) -> Result<Self, HintError> {
+        let mut input_limbs = [Felt252::ZERO; 8];
+        for (i, limb) in input_limbs.iter_mut().enumerate() {
+            *limb = input(cells, i, Self::BUILTIN)?;
+        }
+        let mut output = [None; 8];
+        output.copy_from_slice(&cells[8..16]);
+        Ok(Self {
+            input: input_limbs,
+            output,
+        })
+    }
+}
+
+// Hades permutation of a 3 element state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoseidonInstance {
+    pub input: [Felt252; 3],
+    pub output: [Option<Felt252>; 3],
+}
+
+impl BuiltinInstance for PoseidonInstance {
+    const BUILTIN: BuiltinName = BuiltinName::poseidon;
+    const CELLS: usize = 6;
+
+    fn from_cells(cells: &[Option<Felt252>]) -> Result<Self, HintError> {
+        Ok(Self {
+            input: [
+                input(cells, 0, Self::BUILTIN)?,
+                input(cells, 1, Self::BUILTIN)?,
+                input(cells, 2, Self::BUILTIN)?,
+            ],
+            output: [cells[3], cells[4], cells[5]],
+        })
+    }
+}
+
+// Computes r = p + m * q on the STARK curve
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EcOpInstance {
+    pub p: (Felt252, Felt252),
+    pub q: (Felt252, Felt252),
+    pub m: Felt252,
+    pub r: Option<(Felt252, Felt252)>,
+}
+
+impl BuiltinInstance for EcOpInstance {
+    const BUILTIN: BuiltinName = BuiltinName::ec_op;
+    const CELLS: usize = 7;
+
+    fn from_cells(cells: &[Option<Felt252>]) -> Result<Self, HintError> {
+        Ok(Self {
+            p: (
+                input(cells, 0, Self::BUILTIN)?,
+                input(cells, 1, Self::BUILTIN)?,
+            ),
+            q: (
+                input(cells, 2, Self::BUILTIN)?,
+                input(cells, 3, Self::BUILTIN)?,
+            ),
+            m: input(cells, 4, Self::BUILTIN)?,
+            r: cells[5].zip(cells[6]),
+        })
+    }
+}
+
+/// Base of the segment of a builtin, if the program uses it.
+pub fn builtin_segment_base(vm: &VirtualMachine, builtin: BuiltinName) -> Option<Relocatable> {
+    vm.get_builtin_runners()
+        .iter()
+        .find(|runner| runner.name() == builtin)
+        .map(|runner| Relocatable::from((runner.base() as isize, 0)))
+}
+
+/// Decodes every used instance of a builtin segment. Segment sizes are only known once the
+/// run has ended, i.e. after `CairoRunner::end_run`.
+pub fn read_builtin_instances<T: BuiltinInstance>(
+    vm: &VirtualMachine,
+) -> Result<Vec<T>, HintError> {
+    let Some(base) = builtin_segment_base(vm, T::BUILTIN) else {
+        return Ok(vec![]);
+    };
+    let used = vm
+        .get_segment_used_size(base.segment_index as usize)
+        .ok_or_else(|| {
+            HintError::CustomHint(
+                format!("{} segment size is not computed yet", T::BUILTIN.to_str())
+                    .into_boxed_str(),
+            )
+        })?;
+
+    (0..used.div_ceil(T::CELLS))
+        .map(|instance| {
+            let cells = (0..T::CELLS)
+                .map(|cell| {
+                    let address = (base + (instance * T::CELLS + cell))?;
+                    match vm.get_maybe(&address) {
+                        Some(MaybeRelocatable::Int(value)) => Ok(Some(value)),
+                        Some(MaybeRelocatable::RelocatableValue(_)) => Err(HintError::CustomHint(
+                            format!("unexpected pointer at {address}").into_boxed_str(),
+                        )),
+                        None => Ok(None),
+                    }
+                })
+                .collect::<Result<Vec<_>, HintError>>()?;
+            T::from_cells(&cells)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::builtin_segments::{
+        read_builtin_instances, BitwiseInstance, BuiltinInstance, EcOpInstance, PoseidonInstance,
+    };
+    use cairo_vm::{vm::vm_core::VirtualMachine, Felt252};
+
+    #[test]
+    fn test_decode_builtin_instances() {
+        let felt = |x: u64| Some(Felt252::from(x));
+        let bitwise =
+            BitwiseInstance::from_cells(&[felt(12), felt(10), felt(8), None, None]).unwrap();
+        assert_eq!(bitwise.x, Felt252::from(12));
+        assert_eq!(bitwise.x_and_y, felt(8));
+        assert_eq!(bitwise.x_or_y, None);
+
+        let poseidon =
+            PoseidonInstance::from_cells(&[felt(1), felt(2), felt(3), felt(4), felt(5), felt(6)])
+                .unwrap();
+        assert_eq!(poseidon.output, [felt(4), felt(5), felt(6)]);
+
+        let ec_op =
+            EcOpInstance::from_cells(&[felt(1), felt(2), felt(3), felt(4), felt(5), felt(6), None])
+                .unwrap();
+        assert_eq!(ec_op.r, None);
+
+        // Missing input cells are an error
+        assert!(BitwiseInstance::from_cells(&[felt(1), None, None, None, None]).is_err());
+    }
+
+    #[test]
+    fn test_read_unused_builtin() {
+        let vm = VirtualMachine::new(false, false);
+        assert!(read_builtin_instances::<BitwiseInstance>(&vm)
+            .unwrap()
+            .is_empty());
+    }
+}
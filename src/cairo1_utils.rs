@@ -0,0 +1,365 @@
+use crate::cairo_type::{CairoType, CairoWritable, Layout};
+use crate::default_hints::utils::{felt_to_u64, felt_to_usize};
+use cairo_vm::{
+    types::relocatable::{MaybeRelocatable, Relocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+// Size of the (infos, n_segments, n_finalized) header preceding the segment arena builtin base
+pub const SEGMENT_ARENA_HEADER_SIZE: usize = 3;
+
+/// Initializes a segment arena the way Cairo 1 programs expect it: a fresh infos segment and an
+/// arena segment starting with the header `[infos, 0, 0]`. Returns the arena pointer, i.e. the
+/// address right after the header, which is what gets passed as the `SegmentArena` builtin.
+pub fn init_segment_arena(vm: &mut VirtualMachine) -> Result<Relocatable, HintError> {
+    let infos = vm.add_memory_segment();
+    let arena = vm.add_memory_segment();
+    SegmentArena {
+        infos,
+        n_segments: 0,
+        n_finalized: 0,
+    }
+    .to_memory(vm, arena)
+}
+
+/// The last `(infos, n_segments, n_finalized)` triple written to a segment arena.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SegmentArena {
+    pub infos: Relocatable,
+    pub n_segments: usize,
+    pub n_finalized: usize,
+}
+
+impl SegmentArena {
+    // Reads the state of the arena given the arena pointer (not the header address)
+    pub fn from_arena_ptr(vm: &VirtualMachine, arena_ptr: Relocatable) -> Result<Self, HintError> {
+        Self::from_memory(vm, (arena_ptr - SEGMENT_ARENA_HEADER_SIZE)?)
+    }
+
+    pub fn segment_infos(&self, vm: &VirtualMachine) -> Result<Vec<SegmentInfo>, HintError> {
+        SegmentInfo::from_memory_range(vm, self.infos, self.n_segments)
+    }
+}
+
+impl CairoType for SegmentArena {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        let infos = vm.get_relocatable(address)?;
+        let n_segments = felt_to_usize(&*vm.get_integer((address + 1)?)?, "n_segments")?;
+        let n_finalized = felt_to_usize(&*vm.get_integer((address + 2)?)?, "n_finalized")?;
+        Ok(Self {
+            infos,
+            n_segments,
+            n_finalized,
+        })
+    }
+}
+
+impl CairoWritable for SegmentArena {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        vm.insert_value((address + 0)?, self.infos)?;
+        vm.insert_value((address + 1)?, Felt252::from(self.n_segments))?;
+        vm.insert_value((address + 2)?, Felt252::from(self.n_finalized))?;
+        Ok((address + 3)?)
+    }
+
+    fn n_fields() -> usize {
+        SEGMENT_ARENA_HEADER_SIZE
+    }
+
+    fn layout() -> Layout {
+        Layout::new("SegmentArena")
+            .pointer("infos")
+            .felt("n_segments")
+            .felt("n_finalized")
+    }
+}
+
+/// An entry of the segment arena infos segment: `(start, end, finalization_index)`.
+/// `end` and `finalization_index` are only set once the segment has been finalized.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SegmentInfo {
+    pub start: Relocatable,
+    pub end: Option<Relocatable>,
+    pub finalization_index: Option<usize>,
+}
+
+impl CairoType for SegmentInfo {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        let start = vm.get_relocatable(address)?;
+        let end_ptr: Relocatable = (address + 1)?;
+        let index_ptr: Relocatable = (address + 2)?;
+        let end = match vm.get_maybe(&end_ptr) {
+            Some(MaybeRelocatable::RelocatableValue(end)) => Some(end),
+            _ => None,
+        };
+        let finalization_index = match vm.get_maybe(&index_ptr) {
+            Some(MaybeRelocatable::Int(index)) => {
+                Some(felt_to_usize(&index, "finalization_index")?)
+            }
+            _ => None,
+        };
+        Ok(Self {
+            start,
+            end,
+            finalization_index,
+        })
+    }
+}
+
+impl CairoWritable for SegmentInfo {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        vm.insert_value((address + 0)?, self.start)?;
+        if let Some(end) = self.end {
+            vm.insert_value((address + 1)?, end)?;
+        }
+        if let Some(index) = self.finalization_index {
+            vm.insert_value((address + 2)?, Felt252::from(index))?;
+        }
+        Ok((address + 3)?)
+    }
+
+    fn n_fields() -> usize {
+        3
+    }
+
+    fn layout() -> Layout {
+        Layout::new("SegmentInfo")
+            .pointer("start")
+            .pointer("end")
+            .felt("finalization_index")
+    }
+}
+
+/// The gas counter implicit argument of Cairo 1 functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasCounter(pub u64);
+
+impl CairoType for GasCounter {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        Ok(Self(felt_to_u64(
+            &*vm.get_integer(address)?,
+            "gas counter",
+        )?))
+    }
+}
+
+impl CairoWritable for GasCounter {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        vm.insert_value(address, Felt252::from(self.0))?;
+        Ok((address + 1)?)
+    }
+
+    fn n_fields() -> usize {
+        1
+    }
+
+    fn layout() -> Layout {
+        Layout::new("GasCounter").felt("value")
+    }
+}
+
+/// The builtin costs table read by `get_builtin_costs`, in the order used by the Cairo 1 compiler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuiltinCosts {
+    pub pedersen: u64,
+    pub bitwise: u64,
+    pub ec_op: u64,
+    pub poseidon: u64,
+    pub add_mod: u64,
+    pub mul_mod: u64,
+}
+
+impl Default for BuiltinCosts {
+    fn default() -> Self {
+        Self {
+            pedersen: 4050,
+            bitwise: 583,
+            ec_op: 4085,
+            poseidon: 491,
+            add_mod: 230,
+            mul_mod: 604,
+        }
+    }
+}
+
+impl BuiltinCosts {
+    // Writes the table into a new segment and returns a pointer to it
+    pub fn to_segment(&self, vm: &mut VirtualMachine) -> Result<Relocatable, HintError> {
+        let segment = vm.add_memory_segment();
+        self.to_memory(vm, segment)?;
+        Ok(segment)
+    }
+
+    fn costs(&self) -> [u64; 6] {
+        [
+            self.pedersen,
+            self.bitwise,
+            self.ec_op,
+            self.poseidon,
+            self.add_mod,
+            self.mul_mod,
+        ]
+    }
+}
+
+impl CairoType for BuiltinCosts {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        let cost = |i: usize| -> Result<u64, HintError> {
+            felt_to_u64(&*vm.get_integer((address + i)?)?, "builtin cost")
+        };
+        Ok(Self {
+            pedersen: cost(0)?,
+            bitwise: cost(1)?,
+            ec_op: cost(2)?,
+            poseidon: cost(3)?,
+            add_mod: cost(4)?,
+            mul_mod: cost(5)?,
+        })
+    }
+}
+
+impl CairoWritable for BuiltinCosts {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        for (i, cost) in self.costs().iter().enumerate() {
+            vm.insert_value((address + i)?, Felt252::from(*cost))?;
+        }
+        Ok((address + Self::n_fields())?)
+    }
+
+    fn n_fields() -> usize {
+        6
+    }
+
+    fn layout() -> Layout {
+        [
+            "pedersen", "bitwise", "ec_op", "poseidon", "add_mod", "mul_mod",
+        ]
+        .into_iter()
+        .fold(Layout::new("BuiltinCosts"), Layout::felt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cairo1_utils::{
+        init_segment_arena, BuiltinCosts, GasCounter, SegmentArena, SegmentInfo,
+        SEGMENT_ARENA_HEADER_SIZE,
+    };
+    use crate::cairo_type::{CairoType, CairoWritable};
+    use cairo_vm::{types::relocatable::Relocatable, vm::vm_core::VirtualMachine, Felt252};
+
+    #[test]
+    fn test_init_segment_arena() {
+        let mut vm = VirtualMachine::new(false, false);
+        let arena_ptr = init_segment_arena(&mut vm).unwrap();
+        assert_eq!(arena_ptr, Relocatable::from((1, SEGMENT_ARENA_HEADER_SIZE)));
+
+        let arena = SegmentArena::from_arena_ptr(&vm, arena_ptr).unwrap();
+        assert_eq!(
+            arena,
+            SegmentArena {
+                infos: Relocatable::from((0, 0)),
+                n_segments: 0,
+                n_finalized: 0,
+            }
+        );
+        assert!(arena.segment_infos(&vm).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_segment_infos() {
+        let mut vm = VirtualMachine::new(false, false);
+        let arena_ptr = init_segment_arena(&mut vm).unwrap();
+        let infos = SegmentArena::from_arena_ptr(&vm, arena_ptr).unwrap().infos;
+
+        // Two segments allocated, the first one finalized, as a Cairo 1 run leaves them
+        let first = vm.add_memory_segment();
+        let second = vm.add_memory_segment();
+        let finalized = SegmentInfo {
+            start: first,
+            end: Some((first + 4usize).unwrap()),
+            finalization_index: Some(0),
+        };
+        let pending = SegmentInfo {
+            start: second,
+            end: None,
+            finalization_index: None,
+        };
+        let next = finalized.to_memory(&mut vm, infos).unwrap();
+        pending.to_memory(&mut vm, next).unwrap();
+        let arena = SegmentArena {
+            infos,
+            n_segments: 2,
+            n_finalized: 1,
+        };
+        let arena_ptr = arena.to_memory(&mut vm, arena_ptr).unwrap();
+
+        let read = SegmentArena::from_arena_ptr(&vm, arena_ptr).unwrap();
+        assert_eq!(read, arena);
+        assert_eq!(read.segment_infos(&vm).unwrap(), vec![finalized, pending]);
+
+        // The header is read from right before the arena pointer
+        assert!(SegmentArena::from_arena_ptr(&vm, Relocatable::from((1, 2))).is_err());
+    }
+
+    #[test]
+    fn test_segment_arena_overflow() {
+        let mut vm = VirtualMachine::new(false, false);
+        let arena_ptr = init_segment_arena(&mut vm).unwrap();
+        let infos = vm.add_memory_segment();
+        vm.insert_value(arena_ptr, infos).unwrap();
+        vm.insert_value((arena_ptr + 1usize).unwrap(), Felt252::MAX)
+            .unwrap();
+        vm.insert_value((arena_ptr + 2usize).unwrap(), Felt252::ZERO)
+            .unwrap();
+        let err =
+            SegmentArena::from_arena_ptr(&vm, (arena_ptr + SEGMENT_ARENA_HEADER_SIZE).unwrap())
+                .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("n_segments does not fit in a usize"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_builtin_costs_to_segment() {
+        let mut vm = VirtualMachine::new(false, false);
+        let costs = BuiltinCosts {
+            poseidon: 500,
+            ..Default::default()
+        };
+        let segment = costs.to_segment(&mut vm).unwrap();
+        assert_eq!(segment, Relocatable::from((0, 0)));
+        assert_eq!(*vm.get_integer(segment).unwrap(), Felt252::from(4050));
+        assert_eq!(
+            *vm.get_integer((segment + 3usize).unwrap()).unwrap(),
+            Felt252::from(500)
+        );
+        assert_eq!(BuiltinCosts::from_memory(&vm, segment).unwrap(), costs);
+
+        let gas = vm.add_memory_segment();
+        GasCounter(u64::MAX).to_memory(&mut vm, gas).unwrap();
+        assert_eq!(
+            GasCounter::from_memory(&vm, gas).unwrap(),
+            GasCounter(u64::MAX)
+        );
+    }
+}
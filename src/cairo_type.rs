@@ -1,7 +1,9 @@
+use crate::stdlib::prelude::*;
 use crate::types::FromAnyStr;
 use cairo_vm::{
-    types::relocatable::Relocatable,
+    types::relocatable::{MaybeRelocatable, Relocatable},
     vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
 };
 
 pub trait BaseCairoType: FromAnyStr + Sized + CairoType {
@@ -9,21 +11,508 @@ pub trait BaseCairoType: FromAnyStr + Sized + CairoType {
     fn bytes_len() -> usize;
 }
 
-pub trait CairoType: Sized {
+/// A `CairoWritable` that can also be read back from memory.
+pub trait CairoType: CairoWritable + Sized {
     fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError>;
-    fn to_memory(
-        &self,
-        vm: &mut VirtualMachine,
+
+    /// `count` values laid out one after the other from `address`. Types made of felts only
+    /// override it to read the whole range at once with `read_felt_chunks`.
+    fn from_memory_range(
+        vm: &VirtualMachine,
         address: Relocatable,
-    ) -> Result<Relocatable, HintError>;
-    fn n_fields() -> usize;
+        count: usize,
+    ) -> Result<Vec<Self>, HintError> {
+        (0..count)
+            .map(|i| Self::from_memory(vm, (address + i * Self::n_fields())?))
+            .collect()
+    }
 }
 
-pub trait CairoWritable: Sized {
+// Object safe so heterogeneous inputs can be collected as `&dyn CairoWritable`
+pub trait CairoWritable {
     fn to_memory(
         &self,
         vm: &mut VirtualMachine,
         address: Relocatable,
     ) -> Result<Relocatable, HintError>;
-    fn n_fields() -> usize;
+    fn n_fields() -> usize
+    where
+        Self: Sized;
+
+    /// Names, offsets and widths of the `n_fields()` felts, unnamed felts by default.
+    fn layout() -> Layout
+    where
+        Self: Sized,
+    {
+        Layout::felts(core::any::type_name::<Self>(), Self::n_fields())
+    }
+}
+
+// Tuples laid out element after element, e.g. `(Uint256, Felt)` as three felts, for ad hoc
+// composite reads without a nominal struct. Members are named by index in the layout.
+macro_rules! impl_cairo_type_for_tuple {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name: CairoType),+> CairoType for ($($name,)+) {
+            #[allow(unused_assignments)]
+            fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+                let mut next = address;
+                Ok(($({
+                    let value = <$name as CairoType>::from_memory(vm, next)?;
+                    next = (next + <$name as CairoWritable>::n_fields())?;
+                    value
+                },)+))
+            }
+        }
+
+        impl<$($name: CairoType),+> CairoWritable for ($($name,)+) {
+            fn to_memory(
+                &self,
+                vm: &mut VirtualMachine,
+                address: Relocatable,
+            ) -> Result<Relocatable, HintError> {
+                let next = address;
+                $(let next = self.$index.to_memory(vm, next)?;)+
+                Ok(next)
+            }
+
+            fn n_fields() -> usize {
+                0 $(+ <$name as CairoWritable>::n_fields())+
+            }
+
+            // Named after the members, e.g. `(UintBits<256, 128>, Felt)`
+            fn layout() -> Layout {
+                let members = [$((stringify!($index), <$name as CairoWritable>::layout())),+];
+                let names = members
+                    .iter()
+                    .map(|(_, layout)| layout.name.as_str())
+                    .collect::<Vec<_>>();
+                let name = format!("({})", names.join(", "));
+                members
+                    .into_iter()
+                    .fold(Layout::new(name), |layout, (index, member)| layout.nested(index, member))
+            }
+        }
+    };
+}
+
+impl_cairo_type_for_tuple!(A 0, B 1);
+impl_cairo_type_for_tuple!(A 0, B 1, C 2);
+impl_cairo_type_for_tuple!(A 0, B 1, C 2, D 3);
+impl_cairo_type_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_cairo_type_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_cairo_type_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_cairo_type_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// What a member of a `Layout` holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldKind {
+    Felt,
+    // A relocatable, e.g. to an array in its own segment
+    Pointer,
+    Struct(Layout),
+}
+
+/// Member `name` of a `Layout`, `offset` felts after the start of the struct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutField {
+    pub name: String,
+    pub offset: usize,
+    pub kind: FieldKind,
+}
+
+impl LayoutField {
+    pub fn width(&self) -> usize {
+        match &self.kind {
+            FieldKind::Felt | FieldKind::Pointer => 1,
+            FieldKind::Struct(layout) => layout.size(),
+        }
+    }
+}
+
+/// Memory layout of a type as written by `to_memory`, the structured counterpart of
+/// `n_fields()` for tooling such as memory dump formatters, validators and codegen.
+///
+/// ```ignore
+/// let layout = Layout::new("G1Point").member::<UInt384>("x").member::<UInt384>("y");
+/// assert_eq!(layout.cells()[4].name, "y.d0");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    pub name: String,
+    pub fields: Vec<LayoutField>,
+}
+
+impl Layout {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// `n` felts named by their index, for types without member names.
+    pub fn felts(name: impl Into<String>, n: usize) -> Self {
+        (0..n).fold(Self::new(name), |layout, i| layout.felt(format!("{i}")))
+    }
+
+    pub fn felt(self, name: impl Into<String>) -> Self {
+        self.push(name, FieldKind::Felt)
+    }
+
+    pub fn pointer(self, name: impl Into<String>) -> Self {
+        self.push(name, FieldKind::Pointer)
+    }
+
+    pub fn nested(self, name: impl Into<String>, layout: Layout) -> Self {
+        self.push(name, FieldKind::Struct(layout))
+    }
+
+    /// A member of type `T`, nested as a struct.
+    pub fn member<T: CairoWritable>(self, name: impl Into<String>) -> Self {
+        self.nested(name, T::layout())
+    }
+
+    /// The same members under another type name, for newtypes.
+    pub fn renamed(self, name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..self
+        }
+    }
+
+    fn push(mut self, name: impl Into<String>, kind: FieldKind) -> Self {
+        let offset = self.size();
+        self.fields.push(LayoutField {
+            name: name.into(),
+            offset,
+            kind,
+        });
+        self
+    }
+
+    pub fn size(&self) -> usize {
+        self.fields.iter().map(LayoutField::width).sum()
+    }
+
+    /// The felt and pointer cells in memory order, nested members flattened to dotted paths
+    /// such as `x.d0` with offsets from the start of the outer struct.
+    pub fn cells(&self) -> Vec<LayoutField> {
+        let mut cells = Vec::with_capacity(self.size());
+        for field in &self.fields {
+            match &field.kind {
+                FieldKind::Struct(layout) => {
+                    cells.extend(layout.cells().into_iter().map(|cell| LayoutField {
+                        name: format!("{}.{}", field.name, cell.name),
+                        offset: field.offset + cell.offset,
+                        kind: cell.kind,
+                    }))
+                }
+                kind => cells.push(LayoutField {
+                    name: field.name.clone(),
+                    offset: field.offset,
+                    kind: kind.clone(),
+                }),
+            }
+        }
+        cells
+    }
+}
+
+/// Writes `items` contiguously starting at `address`, returning the address each item was written to.
+pub fn apply_to_memory(
+    vm: &mut VirtualMachine,
+    address: Relocatable,
+    items: &[&dyn CairoWritable],
+) -> Result<Vec<Relocatable>, HintError> {
+    let mut ptrs = Vec::with_capacity(items.len());
+    let mut next = address;
+    for item in items {
+        ptrs.push(next);
+        next = item.to_memory(vm, next)?;
+    }
+    Ok(ptrs)
+}
+
+/// Reads `count` values of `width` felts from `address` with a single `get_integer_range`,
+/// decoding each from its felts.
+pub fn read_felt_chunks<T>(
+    vm: &VirtualMachine,
+    address: Relocatable,
+    count: usize,
+    width: usize,
+    mut decode: impl FnMut(&[Felt252]) -> Result<T, String>,
+) -> Result<Vec<T>, HintError> {
+    let felts = vm.get_integer_range(address, count * width)?;
+    let mut chunk = Vec::with_capacity(width);
+    let mut values = Vec::with_capacity(count);
+    for felts in felts.chunks(width.max(1)) {
+        chunk.clear();
+        chunk.extend(felts.iter().map(|felt| **felt));
+        values.push(decode(&chunk).map_err(|e| HintError::CustomHint(e.into_boxed_str()))?);
+    }
+    Ok(values)
+}
+
+/// Buffers consecutive cells and writes them with a single `load_data` call, which is much
+/// cheaper than one `insert_value` per cell for large arrays.
+#[derive(Debug, Clone)]
+pub struct MemoryWriter {
+    start: Relocatable,
+    cells: Vec<MaybeRelocatable>,
+}
+
+impl MemoryWriter {
+    pub fn new(start: Relocatable) -> Self {
+        Self::with_capacity(start, 0)
+    }
+
+    pub fn with_capacity(start: Relocatable, capacity: usize) -> Self {
+        Self {
+            start,
+            cells: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn push(&mut self, value: impl Into<MaybeRelocatable>) {
+        self.cells.push(value.into());
+    }
+
+    pub fn extend<T: Into<MaybeRelocatable>>(&mut self, values: impl IntoIterator<Item = T>) {
+        self.cells.extend(values.into_iter().map(Into::into));
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Writes the buffered cells and returns the address right after them.
+    pub fn flush(self, vm: &mut VirtualMachine) -> Result<Relocatable, HintError> {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(start = %self.start, cells = self.cells.len(), "batch memory write");
+        Ok(vm.load_data(self.start, &self.cells)?)
+    }
+}
+
+/// Writes `felts` contiguously at `address` in one batch, returning the address after them.
+pub fn write_felts(
+    vm: &mut VirtualMachine,
+    address: Relocatable,
+    felts: impl IntoIterator<Item = Felt252>,
+) -> Result<Relocatable, HintError> {
+    let mut writer = MemoryWriter::new(address);
+    writer.extend(felts);
+    writer.flush(vm)
+}
+
+#[derive(Debug)]
+pub enum BoundedWriteError {
+    // A write of `size` felts at `offset` doesn't fit in the `capacity` felts allocated
+    CapacityExceeded {
+        start: Relocatable,
+        capacity: usize,
+        offset: usize,
+        size: usize,
+    },
+    Hint(HintError),
+}
+
+impl core::fmt::Display for BoundedWriteError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BoundedWriteError::CapacityExceeded {
+                start,
+                capacity,
+                offset,
+                size,
+            } => write!(
+                f,
+                "writing {size} felts at {start} + {offset} exceeds the capacity of {capacity} felts"
+            ),
+            BoundedWriteError::Hint(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl core::error::Error for BoundedWriteError {}
+
+impl From<HintError> for BoundedWriteError {
+    fn from(e: HintError) -> Self {
+        BoundedWriteError::Hint(e)
+    }
+}
+
+impl From<BoundedWriteError> for HintError {
+    fn from(e: BoundedWriteError) -> Self {
+        match e {
+            BoundedWriteError::Hint(e) => e,
+            e => HintError::CustomHint(e.to_string().into_boxed_str()),
+        }
+    }
+}
+
+/// Sequential writer over a region of `capacity` felts, e.g. a struct allocated on the Cairo side.
+/// Writes that would go past the end of the region fail before touching memory.
+#[derive(Debug, Clone)]
+pub struct BoundedMemoryWriter {
+    start: Relocatable,
+    capacity: usize,
+    offset: usize,
+}
+
+impl BoundedMemoryWriter {
+    pub fn new(start: Relocatable, capacity: usize) -> Self {
+        Self {
+            start,
+            capacity,
+            offset: 0,
+        }
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.capacity - self.offset
+    }
+
+    fn reserve(&self, size: usize) -> Result<Relocatable, BoundedWriteError> {
+        if size > self.remaining() {
+            return Err(BoundedWriteError::CapacityExceeded {
+                start: self.start,
+                capacity: self.capacity,
+                offset: self.offset,
+                size,
+            });
+        }
+        Ok((self.start + self.offset).map_err(HintError::from)?)
+    }
+
+    /// Writes `item` at the current offset and returns the address it was written to.
+    pub fn write<T: CairoWritable>(
+        &mut self,
+        vm: &mut VirtualMachine,
+        item: &T,
+    ) -> Result<Relocatable, BoundedWriteError> {
+        let size = T::n_fields();
+        let address = self.reserve(size)?;
+        item.to_memory(vm, address)?;
+        self.offset += size;
+        Ok(address)
+    }
+
+    pub fn write_felts(
+        &mut self,
+        vm: &mut VirtualMachine,
+        felts: &[Felt252],
+    ) -> Result<Relocatable, BoundedWriteError> {
+        let address = self.reserve(felts.len())?;
+        write_felts(vm, address, felts.iter().copied())?;
+        self.offset += felts.len();
+        Ok(address)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{apply_to_memory, CairoType, CairoWritable};
+    use crate::types::{felt, keccak_bytes, uint256};
+    use cairo_vm::{vm::vm_core::VirtualMachine, Felt252};
+    use num_bigint::BigUint;
+
+    #[test]
+    fn test_memory_writer_batch() {
+        use crate::cairo_type::{write_felts, MemoryWriter};
+        use cairo_vm::types::relocatable::MaybeRelocatable;
+
+        let mut vm = VirtualMachine::new(false, false);
+        let base = vm.add_memory_segment();
+        let other = vm.add_memory_segment();
+
+        let mut writer = MemoryWriter::with_capacity(base, 3);
+        writer.push(Felt252::from(1));
+        writer.extend([Felt252::from(2)]);
+        writer.push(other);
+        assert_eq!(writer.len(), 3);
+        let end = writer.flush(&mut vm).unwrap();
+        assert_eq!(end, (base + 3usize).unwrap());
+        assert_eq!(
+            *vm.get_integer((base + 1usize).unwrap()).unwrap(),
+            Felt252::from(2)
+        );
+        assert_eq!(
+            vm.get_maybe(&(base + 2usize).unwrap()),
+            Some(MaybeRelocatable::from(other))
+        );
+
+        let end = write_felts(&mut vm, other, (0..100u64).map(Felt252::from)).unwrap();
+        assert_eq!(end, (other + 100usize).unwrap());
+        assert_eq!(
+            *vm.get_integer((other + 99usize).unwrap()).unwrap(),
+            Felt252::from(99)
+        );
+
+        // Cells are still write-once
+        assert!(write_felts(&mut vm, base, [Felt252::from(5)]).is_err());
+    }
+
+    #[test]
+    fn test_bounded_memory_writer() {
+        use crate::cairo_type::{BoundedMemoryWriter, BoundedWriteError};
+
+        let mut vm = VirtualMachine::new(false, false);
+        let base = vm.add_memory_segment();
+        let mut writer = BoundedMemoryWriter::new(base, 3);
+
+        let uint = uint256::Uint256::new(BigUint::from(5u32));
+        assert_eq!(writer.write(&mut vm, &uint).unwrap(), base);
+        assert_eq!(writer.remaining(), 1);
+
+        // A second Uint256 needs 2 felts, nothing gets written
+        let err = writer.write(&mut vm, &uint).unwrap_err();
+        assert!(matches!(
+            err,
+            BoundedWriteError::CapacityExceeded {
+                capacity: 3,
+                offset: 2,
+                size: 2,
+                ..
+            }
+        ));
+        assert!(vm.get_maybe(&(base + 2usize).unwrap()).is_none());
+
+        let felt = felt::Felt(Felt252::from(9u64));
+        writer.write(&mut vm, &felt).unwrap();
+        assert!(writer.write_felts(&mut vm, &[Felt252::ONE]).is_err());
+        assert_eq!(writer.offset(), 3);
+    }
+
+    #[test]
+    fn test_apply_to_memory_heterogeneous_items() {
+        let mut vm = VirtualMachine::new(false, false);
+        let base = vm.add_memory_segment();
+
+        let felt = felt::Felt(Felt252::from(7u64));
+        let uint = uint256::Uint256::new(BigUint::from(1u32) << 128 | BigUint::from(5u32));
+        let bytes = keccak_bytes::KeccakBytes(vec![0x01, 0x02]);
+        let items: Vec<&dyn CairoWritable> = vec![&felt, &uint, &bytes];
+
+        let ptrs = apply_to_memory(&mut vm, base, &items).unwrap();
+        assert_eq!(
+            ptrs,
+            vec![base, (base + 1usize).unwrap(), (base + 3usize).unwrap()]
+        );
+
+        assert_eq!(felt::Felt::from_memory(&vm, ptrs[0]).unwrap(), felt);
+        assert_eq!(uint256::Uint256::from_memory(&vm, ptrs[1]).unwrap(), uint);
+        let limbs_ptr = vm.get_relocatable(ptrs[2]).unwrap();
+        assert_eq!(
+            *vm.get_integer(limbs_ptr).unwrap(),
+            Felt252::from(0x0201u64)
+        );
+    }
 }
@@ -0,0 +1,209 @@
+//! Streams large byte inputs, such as calldata blobs of hundreds of megabytes, from an
+//! `io::Read` source into VM memory as words, without holding the bytes or their felts in
+//! memory all at once.
+
+use std::io::{ErrorKind, Read};
+use std::marker::PhantomData;
+
+use cairo_vm::{
+    types::relocatable::{MaybeRelocatable, Relocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+use crate::cairo_type::{CairoWritable, Layout};
+use crate::types::chunked_bytes::Endian;
+
+// 1 MiB of 8-byte words per batch by default
+const DEFAULT_BATCH_WORDS: usize = 1 << 17;
+
+/// Where `ChunkedWriter::write_from` put the words of a stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamedBytes {
+    pub words: Relocatable,
+    pub n_bytes: usize,
+    pub n_words: usize,
+}
+
+/// Laid out as `(words, n_bytes)`, like `KeccakBytes` for 64-bit little-endian words.
+impl CairoWritable for StreamedBytes {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        vm.insert_value(address, self.words)?;
+        vm.insert_value((address + 1)?, Felt252::from(self.n_bytes))?;
+        Ok((address + 2)?)
+    }
+
+    fn n_fields() -> usize {
+        2
+    }
+
+    fn layout() -> Layout {
+        Layout::new("StreamedBytes")
+            .pointer("words")
+            .felt("n_bytes")
+    }
+}
+
+/// Reads a stream in batches and writes each batch as `WORD`-byte words in `E` byte order with
+/// a single `load_data` call, the same words `ChunkedBytes<WORD, E>` writes. Peak memory is a
+/// batch rather than the whole input.
+///
+/// ```ignore
+/// let file = File::open("calldata.bin")?;
+/// let streamed = ChunkedWriter::<8, LittleEndian>::new().write_from(vm, file)?;
+/// write_typed_to_var_name("data", &streamed, vm, hint_data)?;
+/// ```
+pub struct ChunkedWriter<const WORD: usize, E> {
+    batch_words: usize,
+    _endian: PhantomData<E>,
+}
+
+impl<const WORD: usize, E: Endian> ChunkedWriter<WORD, E> {
+    const VALID: () = assert!(
+        WORD > 0 && WORD <= 8,
+        "ChunkedWriter words are 1 to 8 bytes"
+    );
+
+    pub fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+        Self {
+            batch_words: DEFAULT_BATCH_WORDS,
+            _endian: PhantomData,
+        }
+    }
+
+    /// Words read and written per batch.
+    pub fn with_batch_words(mut self, batch_words: usize) -> Self {
+        self.batch_words = batch_words.max(1);
+        self
+    }
+
+    /// Writes the words of `reader` to a new segment.
+    pub fn write_from(
+        &self,
+        vm: &mut VirtualMachine,
+        reader: impl Read,
+    ) -> Result<StreamedBytes, HintError> {
+        let words = vm.add_memory_segment();
+        self.write_from_at(vm, words, reader)
+    }
+
+    /// Writes the words of `reader` from `address` on.
+    pub fn write_from_at(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+        mut reader: impl Read,
+    ) -> Result<StreamedBytes, HintError> {
+        let mut buffer = vec![0u8; self.batch_words * WORD];
+        let mut next = address;
+        let mut n_bytes = 0;
+        loop {
+            let filled = read_full(&mut reader, &mut buffer).map_err(|e| {
+                HintError::CustomHint(
+                    format!("failed to read input after {n_bytes} bytes: {e}").into_boxed_str(),
+                )
+            })?;
+            if filled == 0 {
+                break;
+            }
+            let batch: Vec<MaybeRelocatable> = buffer[..filled]
+                .chunks(WORD)
+                .map(|chunk| Felt252::from(E::word(chunk)).into())
+                .collect();
+            next = vm.load_data(next, &batch)?;
+            n_bytes += filled;
+            if filled < buffer.len() {
+                break;
+            }
+        }
+        Ok(StreamedBytes {
+            words: address,
+            n_bytes,
+            n_words: n_bytes.div_ceil(WORD),
+        })
+    }
+}
+
+impl<const WORD: usize, E: Endian> Default for ChunkedWriter<WORD, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Fills `buffer` unless the stream ends first, so that only the last batch has a partial word
+fn read_full(reader: &mut impl Read, buffer: &mut [u8]) -> std::io::Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cairo_type::{CairoType, CairoWritable};
+    use cairo_vm::{vm::vm_core::VirtualMachine, Felt252};
+
+    #[test]
+    fn test_chunked_writer() {
+        use crate::chunked_writer::ChunkedWriter;
+        use crate::types::chunked_bytes::{BigEndian, ChunkedBytes, LittleEndian};
+        use crate::types::keccak_bytes::KeccakBytes;
+
+        // Short reads, so words straddle both reads and batches
+        struct Trickle<'a>(&'a [u8]);
+        impl std::io::Read for Trickle<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                let n = buf.len().min(3).min(self.0.len());
+                buf[..n].copy_from_slice(&self.0[..n]);
+                self.0 = &self.0[n..];
+                Ok(n)
+            }
+        }
+
+        let message: Vec<u8> = (1..=21).collect();
+        let mut vm = VirtualMachine::new(false, false);
+        let streamed = ChunkedWriter::<8, LittleEndian>::new()
+            .with_batch_words(2)
+            .write_from(&mut vm, Trickle(&message))
+            .unwrap();
+        assert_eq!((streamed.n_bytes, streamed.n_words), (21, 3));
+        let words = vm.get_integer_range(streamed.words, 3).unwrap();
+        let words: Vec<Felt252> = words.into_iter().map(|word| *word).collect();
+        assert_eq!(words, KeccakBytes(message.clone()).to_limbs());
+
+        // Laid out as KeccakBytes
+        let address = vm.add_memory_segment();
+        streamed.to_memory(&mut vm, address).unwrap();
+        assert_eq!(
+            KeccakBytes::from_memory(&vm, address).unwrap(),
+            KeccakBytes(message.clone())
+        );
+
+        let streamed = ChunkedWriter::<4, BigEndian>::new()
+            .write_from(&mut vm, &message[..])
+            .unwrap();
+        let words = vm.get_integer_range(streamed.words, 6).unwrap();
+        let words: Vec<Felt252> = words.into_iter().map(|word| *word).collect();
+        assert_eq!(
+            words,
+            ChunkedBytes::<4, BigEndian>::new(message).to_chunks()
+        );
+
+        let streamed = ChunkedWriter::<8, LittleEndian>::new()
+            .write_from(&mut vm, std::io::empty())
+            .unwrap();
+        assert_eq!((streamed.n_bytes, streamed.n_words), (0, 0));
+    }
+}
@@ -0,0 +1,640 @@
+//! Generates Rust structs with `CairoType` impls from Cairo 0 struct definitions, either from
+//! the `identifiers` of a compiled program or from source, so the Rust side of program inputs
+//! and outputs follows the Cairo layout instead of being kept in sync by hand.
+//!
+//! Meant to run from a `build.rs`, the output being included in a module of its own:
+//!
+//! ```ignore
+//! let program = std::fs::read_to_string("build/program.json").unwrap();
+//! let code = CodegenOptions::new().generate_from_program(&program, "__main__").unwrap();
+//! let out = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("structs.rs");
+//! std::fs::write(out, code).unwrap();
+//! // then `mod cairo_structs { include!(concat!(env!("OUT_DIR"), "/structs.rs")); }`
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+use serde_json::Value;
+
+/// A Cairo struct member, `offset` felts after the start of the struct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CairoMember {
+    pub name: String,
+    pub cairo_type: String,
+    pub offset: usize,
+}
+
+/// A Cairo struct definition, `name` being its full name, e.g. `__main__.Point`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CairoStruct {
+    pub name: String,
+    pub members: Vec<CairoMember>,
+    pub size: usize,
+}
+
+impl CairoStruct {
+    // Name of the generated Rust struct
+    pub fn short_name(&self) -> &str {
+        short_name(&self.name)
+    }
+}
+
+fn short_name(name: &str) -> &str {
+    name.rsplit('.').next().unwrap_or(name)
+}
+
+// Structs the compiler generates for function signatures
+const SIGNATURE_STRUCTS: [&str; 3] = ["Args", "ImplicitArgs", "Return"];
+
+/// Struct definitions under `module` (e.g. `__main__`) in the `identifiers` of a compiled Cairo 0
+/// program, skipping the argument and return structs of functions.
+pub fn structs_from_identifiers(
+    program_json: &str,
+    module: &str,
+) -> Result<Vec<CairoStruct>, String> {
+    let program: Value =
+        serde_json::from_str(program_json).map_err(|e| format!("invalid program JSON: {e}"))?;
+    let identifiers = program
+        .get("identifiers")
+        .and_then(Value::as_object)
+        .ok_or("program has no identifiers")?;
+    let prefix = format!("{module}.");
+    let mut structs = Vec::new();
+    for (name, identifier) in identifiers {
+        if identifier.get("type").and_then(Value::as_str) != Some("struct")
+            || !name.starts_with(&prefix)
+            || SIGNATURE_STRUCTS.contains(&short_name(name))
+        {
+            continue;
+        }
+        let size = identifier
+            .get("size")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| format!("struct {name} has no size"))? as usize;
+        let members = identifier
+            .get("members")
+            .and_then(Value::as_object)
+            .ok_or_else(|| format!("struct {name} has no members"))?;
+        let mut members = members
+            .iter()
+            .map(|(member, value)| {
+                let cairo_type = value.get("cairo_type").and_then(Value::as_str);
+                let offset = value.get("offset").and_then(Value::as_u64);
+                match (cairo_type, offset) {
+                    (Some(cairo_type), Some(offset)) => Ok(CairoMember {
+                        name: member.clone(),
+                        cairo_type: cairo_type.to_string(),
+                        offset: offset as usize,
+                    }),
+                    _ => Err(format!("invalid member {member} of struct {name}")),
+                }
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        members.sort_by_key(|member| member.offset);
+        structs.push(CairoStruct {
+            name: name.clone(),
+            members,
+            size,
+        });
+    }
+    Ok(structs)
+}
+
+/// Struct definitions of Cairo 0 source, in the `struct Point { x: felt, y: felt, }` syntax.
+/// Member offsets are computed from the sizes of their types, which are either felts,
+/// pointers, structs of the same source or the types known to `options`.
+pub fn parse_cairo_structs(
+    source: &str,
+    options: &CodegenOptions,
+) -> Result<Vec<CairoStruct>, String> {
+    let source: String = source
+        .lines()
+        .map(|line| line.split("//").next().unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut definitions = Vec::new();
+    let mut rest = source.as_str();
+    while let Some(start) = find_keyword(rest, "struct") {
+        let after = &rest[start + "struct".len()..];
+        let open = after.find('{').ok_or("missing `{` after struct name")?;
+        let close = after[open..]
+            .find('}')
+            .map(|close| open + close)
+            .ok_or("missing `}` closing struct")?;
+        let name = after[..open].trim();
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(format!("invalid struct name {name:?}"));
+        }
+        let body = &after[open + 1..close];
+        // Members are split on commas, which tuple types also contain
+        if body.contains('(') {
+            return Err(format!("tuple members of struct {name} are not supported"));
+        }
+        let members = body
+            .split(',')
+            .map(str::trim)
+            .filter(|member| !member.is_empty())
+            .map(|member| {
+                let (member, cairo_type) = member
+                    .split_once(':')
+                    .ok_or_else(|| format!("invalid member {member:?} of struct {name}"))?;
+                Ok((member.trim().to_string(), cairo_type.trim().to_string()))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        definitions.push((name.to_string(), members));
+        rest = &after[close + 1..];
+    }
+
+    let mut sizes = HashMap::new();
+    for (name, _) in &definitions {
+        struct_size(name, &definitions, options, &mut sizes, &mut Vec::new())?;
+    }
+    definitions
+        .iter()
+        .map(|(name, members)| {
+            let mut offset = 0;
+            let members = members
+                .iter()
+                .map(|(member, cairo_type)| {
+                    let member = CairoMember {
+                        name: member.clone(),
+                        cairo_type: cairo_type.clone(),
+                        offset,
+                    };
+                    offset += member_size(
+                        cairo_type,
+                        &definitions,
+                        options,
+                        &mut sizes,
+                        &mut Vec::new(),
+                    )?;
+                    Ok(member)
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+            Ok(CairoStruct {
+                name: name.clone(),
+                members,
+                size: offset,
+            })
+        })
+        .collect()
+}
+
+// Start of `keyword` as a whole word
+fn find_keyword(source: &str, keyword: &str) -> Option<usize> {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    source.match_indices(keyword).map(|(i, _)| i).find(|&i| {
+        let before = source[..i].chars().next_back();
+        let after = source[i + keyword.len()..].chars().next();
+        !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+    })
+}
+
+type Definitions = [(String, Vec<(String, String)>)];
+
+fn member_size(
+    cairo_type: &str,
+    definitions: &Definitions,
+    options: &CodegenOptions,
+    sizes: &mut HashMap<String, usize>,
+    visiting: &mut Vec<String>,
+) -> Result<usize, String> {
+    if cairo_type == "felt" || cairo_type.ends_with('*') {
+        return Ok(1);
+    }
+    if definitions.iter().any(|(name, _)| name == cairo_type) {
+        return struct_size(cairo_type, definitions, options, sizes, visiting);
+    }
+    options
+        .known_type(cairo_type)
+        .map(|known| known.size)
+        .ok_or_else(|| format!("unknown Cairo type {cairo_type}"))
+}
+
+fn struct_size(
+    name: &str,
+    definitions: &Definitions,
+    options: &CodegenOptions,
+    sizes: &mut HashMap<String, usize>,
+    visiting: &mut Vec<String>,
+) -> Result<usize, String> {
+    if let Some(size) = sizes.get(name) {
+        return Ok(*size);
+    }
+    if visiting.iter().any(|visited| visited == name) {
+        return Err(format!("struct {name} contains itself"));
+    }
+    visiting.push(name.to_string());
+    let (_, members) = definitions
+        .iter()
+        .find(|(definition, _)| definition == name)
+        .ok_or_else(|| format!("unknown struct {name}"))?;
+    let mut size = 0;
+    for (_, cairo_type) in members {
+        size += member_size(cairo_type, definitions, options, sizes, visiting)?;
+    }
+    visiting.pop();
+    sizes.insert(name.to_string(), size);
+    Ok(size)
+}
+
+/// A Cairo type with an existing Rust `CairoType`, used as is by the generated structs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownType {
+    pub rust_path: String,
+    pub size: usize,
+}
+
+/// Where the generated code finds this crate and which Cairo types map to existing Rust types.
+#[derive(Debug, Clone)]
+pub struct CodegenOptions {
+    crate_path: String,
+    known_types: BTreeMap<String, KnownType>,
+    derives: Vec<String>,
+}
+
+impl Default for CodegenOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CodegenOptions {
+    /// Maps `Uint256` and `UInt384` to the crate's types, felts being `Felt` and pointers
+    /// `Relocatable`s.
+    pub fn new() -> Self {
+        Self {
+            crate_path: "cairo_vm_base".to_string(),
+            known_types: BTreeMap::new(),
+            derives: vec![
+                "Debug".to_string(),
+                "Clone".to_string(),
+                "PartialEq".to_string(),
+            ],
+        }
+        .with_type("Uint256", "{crate}::types::uint256::Uint256", 2)
+        .with_type("UInt384", "{crate}::types::uint384::UInt384", 4)
+    }
+
+    /// Path of this crate in the generated code, `crate` when generating inside it.
+    pub fn with_crate_path(mut self, path: impl Into<String>) -> Self {
+        self.crate_path = path.into();
+        self
+    }
+
+    /// Uses `rust_path` for the Cairo type `cairo_name`, either a full name such as
+    /// `starkware.cairo.common.uint256.Uint256` or a short one matching any module. `{crate}`
+    /// in the path stands for the crate path.
+    pub fn with_type(
+        mut self,
+        cairo_name: impl Into<String>,
+        rust_path: impl Into<String>,
+        size: usize,
+    ) -> Self {
+        self.known_types.insert(
+            cairo_name.into(),
+            KnownType {
+                rust_path: rust_path.into(),
+                size,
+            },
+        );
+        self
+    }
+
+    pub fn with_derive(mut self, derive: impl Into<String>) -> Self {
+        self.derives.push(derive.into());
+        self
+    }
+
+    fn known_type(&self, cairo_type: &str) -> Option<&KnownType> {
+        self.known_types
+            .get(cairo_type)
+            .or_else(|| self.known_types.get(short_name(cairo_type)))
+    }
+
+    fn path(&self, path: &str) -> String {
+        path.replace("{crate}", &self.crate_path)
+    }
+
+    pub fn generate_from_program(
+        &self,
+        program_json: &str,
+        module: &str,
+    ) -> Result<String, String> {
+        self.generate(&structs_from_identifiers(program_json, module)?)
+    }
+
+    pub fn generate_from_source(&self, source: &str) -> Result<String, String> {
+        self.generate(&parse_cairo_structs(source, self)?)
+    }
+
+    /// Rust source of a struct and its `CairoType` impl for each of `structs`, failing on
+    /// unknown member types and on members that don't tile the struct.
+    pub fn generate(&self, structs: &[CairoStruct]) -> Result<String, String> {
+        let mut names = HashMap::new();
+        for cairo_struct in structs {
+            if let Some(other) = names.insert(cairo_struct.short_name(), &cairo_struct.name) {
+                return Err(format!(
+                    "structs {other} and {} would both be named {}",
+                    cairo_struct.name,
+                    cairo_struct.short_name()
+                ));
+            }
+        }
+
+        let mut code = format!(
+            "// Generated by {crate}::codegen, do not edit\n\n\
+             use {crate}::cairo_type::{{CairoType, CairoWritable, Layout}};\n\
+             use cairo_vm::types::relocatable::Relocatable;\n\
+             use cairo_vm::vm::{{errors::hint_errors::HintError, vm_core::VirtualMachine}};\n",
+            crate = self.crate_path
+        );
+        for cairo_struct in structs {
+            let members = self.resolve_members(cairo_struct, structs)?;
+            code.push('\n');
+            self.write_struct(&mut code, cairo_struct, &members)
+                .map_err(|e| e.to_string())?;
+        }
+        Ok(code)
+    }
+
+    fn resolve_members(
+        &self,
+        cairo_struct: &CairoStruct,
+        structs: &[CairoStruct],
+    ) -> Result<Vec<Member>, String> {
+        let mut offset = 0;
+        let mut members = Vec::with_capacity(cairo_struct.members.len());
+        for member in &cairo_struct.members {
+            if member.offset != offset {
+                return Err(format!(
+                    "member {} of struct {} is at offset {}, expected {offset}",
+                    member.name, cairo_struct.name, member.offset
+                ));
+            }
+            let cairo_type = member.cairo_type.as_str();
+            let (kind, size) = if cairo_type.ends_with('*') {
+                (MemberKind::Pointer, 1)
+            } else if cairo_type == "felt" {
+                (
+                    MemberKind::Value(self.path("{crate}::types::felt::Felt")),
+                    1,
+                )
+            } else if let Some(nested) = structs
+                .iter()
+                .find(|s| s.name == cairo_type || s.short_name() == cairo_type)
+            {
+                (
+                    MemberKind::Value(nested.short_name().to_string()),
+                    nested.size,
+                )
+            } else if let Some(known) = self.known_type(cairo_type) {
+                (MemberKind::Value(self.path(&known.rust_path)), known.size)
+            } else {
+                return Err(format!(
+                    "unknown type {cairo_type} of member {} of struct {}",
+                    member.name, cairo_struct.name
+                ));
+            };
+            members.push(Member {
+                name: member.name.clone(),
+                field: rust_field_name(&member.name),
+                offset,
+                kind,
+            });
+            offset += size;
+        }
+        if offset != cairo_struct.size {
+            return Err(format!(
+                "members of struct {} take {offset} felts, expected {}",
+                cairo_struct.name, cairo_struct.size
+            ));
+        }
+        Ok(members)
+    }
+
+    fn write_struct(
+        &self,
+        code: &mut String,
+        cairo_struct: &CairoStruct,
+        members: &[Member],
+    ) -> std::fmt::Result {
+        let name = cairo_struct.short_name();
+        writeln!(code, "// {}", cairo_struct.name)?;
+        writeln!(code, "#[derive({})]", self.derives.join(", "))?;
+        writeln!(code, "pub struct {name} {{")?;
+        for member in members {
+            let rust_type = match &member.kind {
+                MemberKind::Pointer => "Relocatable",
+                MemberKind::Value(path) => path.as_str(),
+            };
+            writeln!(code, "    pub {}: {rust_type},", member.field)?;
+        }
+        writeln!(code, "}}\n")?;
+
+        writeln!(code, "impl CairoType for {name} {{")?;
+        writeln!(
+            code,
+            "    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {{"
+        )?;
+        writeln!(code, "        Ok(Self {{")?;
+        for member in members {
+            let read = match &member.kind {
+                MemberKind::Pointer => "vm.get_relocatable",
+                MemberKind::Value(_) => "CairoType::from_memory",
+            };
+            let args = match &member.kind {
+                MemberKind::Pointer => format!("(address + {}usize)?", member.offset),
+                MemberKind::Value(_) => format!("vm, (address + {}usize)?", member.offset),
+            };
+            writeln!(code, "            {}: {read}({args})?,", member.field)?;
+        }
+        writeln!(code, "        }})")?;
+        writeln!(code, "    }}")?;
+        writeln!(code, "}}\n")?;
+
+        writeln!(code, "impl CairoWritable for {name} {{")?;
+        writeln!(code, "    fn to_memory(")?;
+        writeln!(code, "        &self,")?;
+        writeln!(code, "        vm: &mut VirtualMachine,")?;
+        writeln!(code, "        address: Relocatable,")?;
+        writeln!(code, "    ) -> Result<Relocatable, HintError> {{")?;
+        for member in members {
+            match &member.kind {
+                MemberKind::Pointer => writeln!(
+                    code,
+                    "        vm.insert_value((address + {}usize)?, self.{})?;",
+                    member.offset, member.field
+                )?,
+                MemberKind::Value(_) => writeln!(
+                    code,
+                    "        self.{}.to_memory(vm, (address + {}usize)?)?;",
+                    member.field, member.offset
+                )?,
+            }
+        }
+        writeln!(code, "        Ok((address + {}usize)?)", cairo_struct.size)?;
+        writeln!(code, "    }}\n")?;
+
+        writeln!(code, "    fn n_fields() -> usize {{")?;
+        writeln!(code, "        {}", cairo_struct.size)?;
+        writeln!(code, "    }}\n")?;
+
+        writeln!(code, "    fn layout() -> Layout {{")?;
+        write!(code, "        Layout::new({name:?})")?;
+        for member in members {
+            match &member.kind {
+                MemberKind::Pointer => write!(code, "\n            .pointer({:?})", member.name)?,
+                MemberKind::Value(path) => write!(
+                    code,
+                    "\n            .nested({:?}, <{path} as CairoWritable>::layout())",
+                    member.name
+                )?,
+            }
+        }
+        writeln!(code, "\n    }}")?;
+        writeln!(code, "}}")
+    }
+}
+
+enum MemberKind {
+    // Stored as a `Relocatable`
+    Pointer,
+    // A `CairoType` at this Rust path
+    Value(String),
+}
+
+struct Member {
+    name: String,
+    field: String,
+    offset: usize,
+    kind: MemberKind,
+}
+
+// Strict and reserved Rust keywords, any of which can be a Cairo member name
+const RUST_KEYWORDS: [&str; 52] = [
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+fn rust_field_name(name: &str) -> String {
+    match name {
+        // Not allowed as raw identifiers
+        "self" | "Self" | "crate" | "super" => format!("{name}_"),
+        name if RUST_KEYWORDS.contains(&name) => format!("r#{name}"),
+        name => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::codegen::{parse_cairo_structs, structs_from_identifiers, CodegenOptions};
+
+    const SOURCE: &str = "
+        from starkware.cairo.common.uint256 import Uint256
+
+        // A header committed to by the program
+        struct Header {
+            number: felt,
+            hash: Uint256,
+            parent: Header*,
+        }
+
+        struct Output {
+            header: Header,
+            type: felt,  // not a Rust field name
+        }
+
+        func main{output_ptr: felt*}() {
+            return ();
+        }
+    ";
+
+    #[test]
+    fn test_parse_cairo_structs() {
+        let structs = parse_cairo_structs(SOURCE, &CodegenOptions::new()).unwrap();
+        assert_eq!(structs.len(), 2);
+        assert_eq!(structs[0].name, "Header");
+        assert_eq!(structs[0].size, 4);
+        assert_eq!(structs[0].members[2].offset, 3);
+        assert_eq!(structs[1].members[1].offset, 4);
+        assert_eq!(structs[1].size, 5);
+
+        let err = parse_cairo_structs("struct A { b: B, }", &CodegenOptions::new()).unwrap_err();
+        assert_eq!(err, "unknown Cairo type B");
+        let err = parse_cairo_structs("struct A { a: A, }", &CodegenOptions::new()).unwrap_err();
+        assert_eq!(err, "struct A contains itself");
+        // A `}` before the `{` doesn't close the struct
+        let err = parse_cairo_structs("struct X } {", &CodegenOptions::new()).unwrap_err();
+        assert_eq!(err, "missing `}` closing struct");
+        let err = parse_cairo_structs("struct P { xy: (felt, felt), }", &CodegenOptions::new())
+            .unwrap_err();
+        assert_eq!(err, "tuple members of struct P are not supported");
+    }
+
+    #[test]
+    fn test_keyword_member_names() {
+        let code = CodegenOptions::new()
+            .with_crate_path("crate")
+            .generate_from_source("struct Range { in: felt, fn: felt, Self: felt, }")
+            .unwrap();
+        assert!(code.contains("    pub r#in: crate::types::felt::Felt,"));
+        assert!(code.contains("    pub r#fn: crate::types::felt::Felt,"));
+        assert!(code.contains("    pub Self_: crate::types::felt::Felt,"));
+        assert!(code.contains("        self.r#in.to_memory(vm, (address + 0usize)?)?;"));
+    }
+
+    #[test]
+    fn test_generate_from_source() {
+        let code = CodegenOptions::new()
+            .with_crate_path("crate")
+            .generate_from_source(SOURCE)
+            .unwrap();
+        assert!(code.contains("pub struct Header {\n    pub number: crate::types::felt::Felt,\n    pub hash: crate::types::uint256::Uint256,\n    pub parent: Relocatable,\n}"));
+        assert!(code.contains("            parent: vm.get_relocatable((address + 3usize)?)?,"));
+        assert!(code.contains("impl CairoWritable for Header {\n    fn to_memory("));
+        assert!(code.contains("        self.header.to_memory(vm, (address + 0usize)?)?;"));
+        assert!(code.contains("    pub r#type: crate::types::felt::Felt,"));
+        assert!(code.contains("        Ok((address + 5usize)?)"));
+        assert!(code.contains(
+            "            .nested(\"type\", <crate::types::felt::Felt as CairoWritable>::layout())"
+        ));
+    }
+
+    #[test]
+    fn test_generate_from_program_identifiers() {
+        let program = r#"{"identifiers": {
+            "__main__.Point": {"type": "struct", "full_name": "__main__.Point", "size": 2,
+                "members": {"y": {"cairo_type": "felt", "offset": 1}, "x": {"cairo_type": "felt", "offset": 0}}},
+            "__main__.main.Args": {"type": "struct", "full_name": "__main__.main.Args", "size": 0, "members": {}},
+            "__main__.Segment": {"type": "struct", "full_name": "__main__.Segment", "size": 5,
+                "members": {"start": {"cairo_type": "__main__.Point", "offset": 0},
+                    "value": {"cairo_type": "starkware.cairo.common.uint256.Uint256", "offset": 2},
+                    "next": {"cairo_type": "__main__.Segment*", "offset": 4}}},
+            "starkware.cairo.common.uint256.Uint256": {"type": "struct", "size": 2, "members": {}},
+            "__main__.main": {"type": "function", "pc": 0}
+        }}"#;
+        let structs = structs_from_identifiers(program, "__main__").unwrap();
+        let names: Vec<_> = structs.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["__main__.Point", "__main__.Segment"]);
+        assert_eq!(structs[0].members[0].name, "x");
+
+        let code = CodegenOptions::new().generate(&structs).unwrap();
+        assert!(code.starts_with("// Generated by cairo_vm_base::codegen"));
+        assert!(code.contains("    pub start: Point,"));
+        assert!(code.contains("    pub value: cairo_vm_base::types::uint256::Uint256,"));
+
+        // Members must tile the struct
+        let mut gap = structs.clone();
+        gap[0].members[1].offset = 2;
+        assert_eq!(
+            CodegenOptions::new().generate(&gap).unwrap_err(),
+            "member y of struct __main__.Point is at offset 2, expected 1"
+        );
+    }
+}
@@ -0,0 +1,254 @@
+//! Hash chain commitments over program outputs, computed on the host the same way the Cairo
+//! programs fold them in the VM, so that expected commitments can be precomputed or checked
+//! without running the program. A chain starts from a seed, zero unless set with `with_seed`,
+//! and absorbs one felt at a time as `acc = H(acc, value)`. The byte hashes (keccak, blake2s)
+//! take `acc || value` as 64 bytes, the felt as a 32-byte big-endian word.
+
+use blake2::Blake2s256;
+use cairo_vm::Felt252;
+use sha3::{Digest, Keccak256};
+use starknet_types_core::hash::{Poseidon, StarkHash};
+
+use crate::types::fixed_bytes::{Bytes32, FixedBytes};
+
+/// A hash chain absorbing felts one at a time.
+pub trait HashChain {
+    type Digest;
+
+    fn absorb(&mut self, value: &Felt252);
+
+    fn digest(&self) -> Self::Digest;
+
+    fn absorb_all<'a>(&mut self, values: impl IntoIterator<Item = &'a Felt252>) {
+        for value in values {
+            self.absorb(value);
+        }
+    }
+}
+
+/// `acc = poseidon_hash(acc, value)`, as computed with `poseidon_hash` in Cairo.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoseidonChain(Felt252);
+
+impl PoseidonChain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_seed(seed: Felt252) -> Self {
+        Self(seed)
+    }
+}
+
+impl HashChain for PoseidonChain {
+    type Digest = Felt252;
+
+    fn absorb(&mut self, value: &Felt252) {
+        self.0 = Poseidon::hash(&self.0, value);
+    }
+
+    fn digest(&self) -> Felt252 {
+        self.0
+    }
+}
+
+/// `acc = keccak256(acc || value)`, the digest converting to the `Uint256` Cairo's keccak
+/// returns with `Uint256::from`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KeccakChain([u8; 32]);
+
+impl KeccakChain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_seed(seed: Bytes32) -> Self {
+        Self(seed.0)
+    }
+}
+
+impl HashChain for KeccakChain {
+    type Digest = Bytes32;
+
+    fn absorb(&mut self, value: &Felt252) {
+        self.0 = keccak256(&chain_block(&self.0, value));
+    }
+
+    fn digest(&self) -> Bytes32 {
+        FixedBytes(self.0)
+    }
+}
+
+/// `acc = blake2s256(acc || value)`, with the digest bytes as output by BLAKE2s.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Blake2sChain([u8; 32]);
+
+impl Blake2sChain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_seed(seed: Bytes32) -> Self {
+        Self(seed.0)
+    }
+}
+
+impl HashChain for Blake2sChain {
+    type Digest = Bytes32;
+
+    fn absorb(&mut self, value: &Felt252) {
+        self.0 = blake2s256(&chain_block(&self.0, value));
+    }
+
+    fn digest(&self) -> Bytes32 {
+        FixedBytes(self.0)
+    }
+}
+
+fn chain_block(acc: &[u8; 32], value: &Felt252) -> [u8; 64] {
+    let mut block = [0u8; 64];
+    block[..32].copy_from_slice(acc);
+    block[32..].copy_from_slice(&value.to_bytes_be());
+    block
+}
+
+pub fn poseidon_chain<'a>(values: impl IntoIterator<Item = &'a Felt252>) -> Felt252 {
+    chain(PoseidonChain::new(), values)
+}
+
+pub fn keccak_chain<'a>(values: impl IntoIterator<Item = &'a Felt252>) -> Bytes32 {
+    chain(KeccakChain::new(), values)
+}
+
+pub fn blake2s_chain<'a>(values: impl IntoIterator<Item = &'a Felt252>) -> Bytes32 {
+    chain(Blake2sChain::new(), values)
+}
+
+fn chain<'a, C: HashChain>(
+    mut chain: C,
+    values: impl IntoIterator<Item = &'a Felt252>,
+) -> C::Digest {
+    chain.absorb_all(values);
+    chain.digest()
+}
+
+/// Checks that `chain` over `outputs`, e.g. `RunOutput::output`, gives `expected`.
+pub fn verify_commitment<'a, C>(
+    chain: C,
+    outputs: impl IntoIterator<Item = &'a Felt252>,
+    expected: &C::Digest,
+) -> Result<(), String>
+where
+    C: HashChain,
+    C::Digest: PartialEq + std::fmt::Debug,
+{
+    let digest = self::chain(chain, outputs);
+    if digest != *expected {
+        return Err(format!(
+            "commitment mismatch: expected {expected:?}, computed {digest:?}"
+        ));
+    }
+    Ok(())
+}
+
+/// Keccak-256 as used by Ethereum, with the original `0x01` padding rather than SHA-3's.
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+/// BLAKE2s with a 32-byte digest and no key, as computed by `cairo_blake2s`.
+pub fn blake2s256(data: &[u8]) -> [u8; 32] {
+    Blake2s256::digest(data).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commitments::{
+        blake2s256, blake2s_chain, keccak256, keccak_chain, poseidon_chain, verify_commitment,
+        Blake2sChain, HashChain, KeccakChain, PoseidonChain,
+    };
+    use crate::default_hints::poseidon::poseidon_hash;
+    use crate::types::fixed_bytes::Bytes32;
+    use crate::types::FromAnyStr;
+    use cairo_vm::Felt252;
+
+    fn outputs() -> Vec<Felt252> {
+        (1..=3u64).map(Felt252::from).collect()
+    }
+
+    #[test]
+    fn test_keccak256() {
+        assert_eq!(
+            hex::encode(keccak256(b"")),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            hex::encode(keccak256(b"abc")),
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+        );
+    }
+
+    #[test]
+    fn test_blake2s256() {
+        assert_eq!(
+            hex::encode(blake2s256(b"abc")),
+            "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982"
+        );
+        assert_eq!(
+            hex::encode(blake2s256(b"")),
+            "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9"
+        );
+        assert_eq!(
+            hex::encode(blake2s256(&[0; 64])),
+            "ae09db7cd54f42b490ef09b6bc541af688e4959bb8c53f359a6f56e38ab454a3"
+        );
+        assert_ne!(blake2s256(&[0; 64]), blake2s256(&[0; 65]));
+    }
+
+    #[test]
+    fn test_hash_chains() {
+        let outputs = outputs();
+        assert_eq!(
+            keccak_chain(&outputs),
+            Bytes32::from_any_str(
+                "0xa732dab0e90325f680f60605209acab91df17a2a018eecc4b4bfc091b76a8d7f"
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            blake2s_chain(&outputs),
+            Bytes32::from_any_str(
+                "0x1a41d8abbbe5c3f58b52df9c5f4a3cdc6f67cc5f253b32325ca60a8bde53a982"
+            )
+            .unwrap()
+        );
+        let mut expected = Felt252::ZERO;
+        for value in &outputs {
+            expected = poseidon_hash(&expected, value);
+        }
+        assert_eq!(poseidon_chain(&outputs), expected);
+        assert_eq!(keccak_chain(&[]).0, [0; 32]);
+
+        // Absorbing from the digest of a prefix continues the same chain
+        let mut chain = KeccakChain::with_seed(keccak_chain(&outputs[..1]));
+        chain.absorb_all(&outputs[1..]);
+        assert_eq!(chain.digest(), keccak_chain(&outputs));
+        let mut first = [0u8; 64];
+        first[63] = 1;
+        assert_eq!(keccak_chain(&outputs[..1]).0, keccak256(&first));
+    }
+
+    #[test]
+    fn test_verify_commitment() {
+        let outputs = outputs();
+        verify_commitment(PoseidonChain::new(), &outputs, &poseidon_chain(&outputs)).unwrap();
+        verify_commitment(Blake2sChain::new(), &outputs, &blake2s_chain(&outputs)).unwrap();
+        let err = verify_commitment(
+            PoseidonChain::with_seed(Felt252::ONE),
+            &outputs,
+            &poseidon_chain(&outputs),
+        )
+        .unwrap_err();
+        assert!(err.starts_with("commitment mismatch"), "{err}");
+    }
+}
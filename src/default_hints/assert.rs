@@ -0,0 +1,215 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData,
+        hint_utils::get_maybe_relocatable_from_var_name,
+    },
+    types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+use crate::types::{felt::Felt, FromAnyStr};
+
+// Registered by prefix, so the operands, an optional label and a trailing `# file:line` comment
+// can be picked freely, e.g. `assert_le(ids.amount, ids.balance, "withdrawal")  # wallet.cairo:42`
+pub const ASSERT_EQ_PREFIX: &str = "assert_eq(";
+pub const ASSERT_LE_PREFIX: &str = "assert_le(";
+pub const HINT_ASSERT_EQ: &str = "assert_eq(ids.a, ids.b)";
+pub const HINT_ASSERT_LE: &str = "assert_le(ids.a, ids.b)";
+
+/// An `assert_*(lhs, rhs[, "label"])  # location` hint. Operands are `ids.<name>` or felt
+/// literals, the location is whatever follows the `#`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assertion {
+    pub name: String,
+    pub lhs: String,
+    pub rhs: String,
+    pub label: Option<String>,
+    pub location: Option<String>,
+}
+
+impl Assertion {
+    /// Parses the first non-empty line of the hint code.
+    pub fn parse(code: &str) -> Option<Self> {
+        let line = code.lines().map(str::trim).find(|line| !line.is_empty())?;
+        let (name, rest) = line.split_once('(')?;
+        let mut args = vec![];
+        let mut current = String::new();
+        let mut quote = None;
+        let mut chars = rest.char_indices();
+        let end = loop {
+            let (i, c) = chars.next()?;
+            match (c, quote) {
+                ('"' | '\'', None) => quote = Some(c),
+                (c, Some(q)) if c == q => quote = None,
+                (',', None) => {
+                    args.push(std::mem::take(&mut current));
+                    continue;
+                }
+                (')', None) => {
+                    args.push(std::mem::take(&mut current));
+                    break i;
+                }
+                _ => {}
+            }
+            current.push(c);
+        };
+        let location = match rest[end + 1..].trim() {
+            "" => None,
+            comment => Some(comment.strip_prefix('#')?.trim().to_string()),
+        };
+
+        let mut args = args.iter().map(|arg| arg.trim());
+        let lhs = args.next()?.to_string();
+        let rhs = args.next()?.to_string();
+        let label = match args.next() {
+            Some(label) => Some(unquote(label)?.to_string()),
+            None => None,
+        };
+        if args.next().is_some() || lhs.is_empty() || rhs.is_empty() {
+            return None;
+        }
+        Some(Self {
+            name: name.trim().to_string(),
+            lhs,
+            rhs,
+            label,
+            location: location.filter(|location| !location.is_empty()),
+        })
+    }
+
+    fn from_hint(hint_data: &HintProcessorData) -> Result<Self, HintError> {
+        Self::parse(&hint_data.code).ok_or_else(|| {
+            HintError::CustomHint(format!("invalid assertion hint: {}", hint_data.code).into())
+        })
+    }
+
+    // Values of both operands
+    fn operands(
+        &self,
+        vm: &VirtualMachine,
+        hint_data: &HintProcessorData,
+    ) -> Result<(MaybeRelocatable, MaybeRelocatable), HintError> {
+        Ok((
+            operand_value(&self.lhs, vm, hint_data)?,
+            operand_value(&self.rhs, vm, hint_data)?,
+        ))
+    }
+
+    // `assert_le failed (label) at file:line`, label and location only when given
+    fn context(&self) -> String {
+        let mut context = format!("{} failed", self.name);
+        if let Some(label) = &self.label {
+            context.push_str(&format!(" ({label})"));
+        }
+        if let Some(location) = &self.location {
+            context.push_str(&format!(" at {location}"));
+        }
+        context
+    }
+
+    fn failure(&self, lhs: &MaybeRelocatable, op: &str, rhs: &MaybeRelocatable) -> HintError {
+        HintError::CustomHint(
+            format!(
+                "{}: {} = {} {op} {} = {}",
+                self.context(),
+                self.lhs,
+                format_value(lhs),
+                self.rhs,
+                format_value(rhs)
+            )
+            .into(),
+        )
+    }
+}
+
+fn unquote(s: &str) -> Option<&str> {
+    s.strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .or_else(|| s.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')))
+}
+
+fn operand_value(
+    operand: &str,
+    vm: &VirtualMachine,
+    hint_data: &HintProcessorData,
+) -> Result<MaybeRelocatable, HintError> {
+    if let Some(name) = operand.strip_prefix("ids.") {
+        return get_maybe_relocatable_from_var_name(
+            name,
+            vm,
+            &hint_data.ids_data,
+            &hint_data.ap_tracking,
+        );
+    }
+    let literal = match operand.starts_with('-') {
+        true => Felt::from_signed_str(operand),
+        false => Felt::from_any_str(operand),
+    };
+    literal
+        .map(|felt| MaybeRelocatable::Int(felt.0))
+        .map_err(|e| {
+            HintError::CustomHint(format!("invalid assertion operand {operand}: {e}").into())
+        })
+}
+
+/// Felts in hex and decimal, e.g. `0x2a (42)`, pointers as `segment:offset`.
+pub fn format_value(value: &MaybeRelocatable) -> String {
+    match value {
+        MaybeRelocatable::Int(value) => format!("{} ({value})", value.to_hex_string()),
+        MaybeRelocatable::RelocatableValue(ptr) => ptr.to_string(),
+    }
+}
+
+pub fn hint_assert_eq(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let assertion = Assertion::from_hint(hint_data)?;
+    let (lhs, rhs) = assertion.operands(vm, hint_data)?;
+    match lhs == rhs {
+        true => Ok(()),
+        false => Err(assertion.failure(&lhs, "!=", &rhs)),
+    }
+}
+
+// Felts compare as integers in [0, P), pointers only within the same segment
+pub fn hint_assert_le(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let assertion = Assertion::from_hint(hint_data)?;
+    let (lhs, rhs) = assertion.operands(vm, hint_data)?;
+    let ordering = match (&lhs, &rhs) {
+        (MaybeRelocatable::Int(a), MaybeRelocatable::Int(b)) => a.cmp(b),
+        (MaybeRelocatable::RelocatableValue(a), MaybeRelocatable::RelocatableValue(b))
+            if a.segment_index == b.segment_index =>
+        {
+            a.offset.cmp(&b.offset)
+        }
+        _ => {
+            return Err(HintError::CustomHint(
+                format!(
+                    "{}: can't compare {} = {} and {} = {}",
+                    assertion.context(),
+                    assertion.lhs,
+                    format_value(&lhs),
+                    assertion.rhs,
+                    format_value(&rhs)
+                )
+                .into(),
+            ))
+        }
+    };
+    match ordering {
+        Ordering::Greater => Err(assertion.failure(&lhs, ">", &rhs)),
+        _ => Ok(()),
+    }
+}
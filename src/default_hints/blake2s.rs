@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        blake2s_utils::{
+            blake2s_add_uint256, blake2s_add_uint256_bigend, compute_blake2s, finalize_blake2s,
+            finalize_blake2s_v3,
+        },
+        builtin_hint_processor_definition::HintProcessorData,
+        hint_code,
+    },
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+pub const HINT_BLAKE2S_COMPUTE: &str = hint_code::BLAKE2S_COMPUTE;
+pub const HINT_BLAKE2S_FINALIZE: &str = hint_code::BLAKE2S_FINALIZE;
+// Same as HINT_BLAKE2S_FINALIZE, with the constant name used by newer cairo-lang versions
+pub const HINT_BLAKE2S_FINALIZE_V2: &str = hint_code::BLAKE2S_FINALIZE_V2;
+// The message comes before the state in the padding instances
+pub const HINT_BLAKE2S_FINALIZE_V3: &str = hint_code::BLAKE2S_FINALIZE_V3;
+pub const HINT_BLAKE2S_ADD_UINT256: &str = hint_code::BLAKE2S_ADD_UINT256;
+pub const HINT_BLAKE2S_ADD_UINT256_BIGEND: &str = hint_code::BLAKE2S_ADD_UINT256_BIGEND;
+
+pub fn hint_blake2s_compute(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    compute_blake2s(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_blake2s_finalize(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    finalize_blake2s(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_blake2s_finalize_v3(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    finalize_blake2s_v3(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_blake2s_add_uint256(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    blake2s_add_uint256(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_blake2s_add_uint256_bigend(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    blake2s_add_uint256_bigend(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
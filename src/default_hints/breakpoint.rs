@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{BufRead, Write};
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData,
+        hint_utils::get_maybe_relocatable_from_var_name,
+    },
+    types::{
+        exec_scope::ExecutionScopes,
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+use super::debug::format_tracked_memory_range;
+use super::logger::print_line;
+use crate::scopes::{insert_typed, remove_typed, BREAKPOINT_CONFIG_KEY};
+
+pub const HINT_BREAKPOINT: &str = "breakpoint()";
+
+// Exec scope variable holding the `BreakpointConfig`, stored in the root scope
+pub const BREAKPOINT_CONFIG: &str = "__breakpoint_config";
+
+/// Registers and ids in scope when a `breakpoint` hint is hit. Ids that can't be read, e.g.
+/// locals not yet assigned, have no value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BreakpointState {
+    pub pc: Relocatable,
+    pub ap: Relocatable,
+    pub fp: Relocatable,
+    pub ids: Vec<(String, Option<MaybeRelocatable>)>,
+}
+
+impl fmt::Display for BreakpointState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Breakpoint: pc={} ap={} fp={}",
+            self.pc, self.ap, self.fp
+        )?;
+        for (name, value) in &self.ids {
+            match value {
+                Some(MaybeRelocatable::Int(value)) => {
+                    write!(f, "\n  ids.{name} = {}", value.to_hex_string())?
+                }
+                Some(MaybeRelocatable::RelocatableValue(ptr)) => {
+                    write!(f, "\n  ids.{name} = {ptr}")?
+                }
+                None => write!(f, "\n  ids.{name} = <unset>")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Host side receiver of the `breakpoint` hint, execution resumes when it returns. Returning
+/// an error aborts the run. The exec scopes give access to the injected `OutputSink`.
+pub trait BreakpointHandler {
+    fn on_breakpoint(
+        &mut self,
+        vm: &VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        state: &BreakpointState,
+    ) -> Result<(), HintError>;
+}
+
+impl<F> BreakpointHandler for F
+where
+    F: FnMut(&VirtualMachine, &mut ExecutionScopes, &BreakpointState) -> Result<(), HintError>,
+{
+    fn on_breakpoint(
+        &mut self,
+        vm: &VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        state: &BreakpointState,
+    ) -> Result<(), HintError> {
+        self(vm, exec_scopes, state)
+    }
+}
+
+/// Prints the state as print output, to the `OutputSink`, and resumes right away.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PrintHandler;
+
+impl BreakpointHandler for PrintHandler {
+    fn on_breakpoint(
+        &mut self,
+        _vm: &VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        state: &BreakpointState,
+    ) -> Result<(), HintError> {
+        for line in state.to_string().lines() {
+            print_line(exec_scopes, line);
+        }
+        Ok(())
+    }
+}
+
+const PROMPT_HELP: &str = "commands: c(ontinue), s(tate), m(em) <segment>:<offset> [len], abort";
+
+/// Prints the state and reads commands until told to continue:
+/// - `c` / `continue`, an empty line or end of input resumes execution
+/// - `s` / `state` prints the state again
+/// - `m` / `mem <segment>:<offset> [len]` dumps `len` cells (default 1)
+/// - `abort` fails the hint, stopping the run
+pub struct PromptHandler<R, W> {
+    input: R,
+    output: W,
+}
+
+impl<R: BufRead, W: Write> PromptHandler<R, W> {
+    pub fn new(input: R, output: W) -> Self {
+        Self { input, output }
+    }
+
+    fn write(&mut self, line: &str) -> Result<(), HintError> {
+        writeln!(self.output, "{line}").map_err(prompt_error)
+    }
+}
+
+impl PromptHandler<std::io::StdinLock<'static>, std::io::Stdout> {
+    pub fn stdio() -> Self {
+        Self::new(std::io::stdin().lock(), std::io::stdout())
+    }
+}
+
+fn prompt_error(e: std::io::Error) -> HintError {
+    HintError::CustomHint(format!("breakpoint prompt failed: {e}").into_boxed_str())
+}
+
+pub(crate) fn parse_relocatable(s: &str) -> Option<Relocatable> {
+    let (segment, offset) = s.split_once(':')?;
+    Some(Relocatable::from((
+        segment.parse().ok()?,
+        offset.parse().ok()?,
+    )))
+}
+
+impl<R: BufRead, W: Write> BreakpointHandler for PromptHandler<R, W> {
+    fn on_breakpoint(
+        &mut self,
+        vm: &VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        state: &BreakpointState,
+    ) -> Result<(), HintError> {
+        self.write(&state.to_string())?;
+        loop {
+            write!(self.output, "> ").map_err(prompt_error)?;
+            self.output.flush().map_err(prompt_error)?;
+            let mut line = String::new();
+            if self.input.read_line(&mut line).map_err(prompt_error)? == 0 {
+                return Ok(());
+            }
+            let mut args = line.split_whitespace();
+            match args.next() {
+                None | Some("c" | "continue") => return Ok(()),
+                Some("s" | "state") => self.write(&state.to_string())?,
+                Some("m" | "mem") => {
+                    let address = args.next().and_then(parse_relocatable);
+                    let len = args.next().map_or(Some(1), |len| len.parse().ok());
+                    match (address, len) {
+                        (Some(address), Some(len)) => {
+                            for line in format_tracked_memory_range(vm, exec_scopes, address, len)?
+                            {
+                                self.write(&line)?;
+                            }
+                        }
+                        _ => self.write("usage: mem <segment>:<offset> [len]")?,
+                    }
+                }
+                Some("abort") => {
+                    return Err(HintError::CustomHint(
+                        format!("aborted at breakpoint, pc={}", state.pc).into_boxed_str(),
+                    ))
+                }
+                Some(_) => self.write(PROMPT_HELP)?,
+            }
+        }
+    }
+}
+
+/// Enables the `breakpoint` hint. `ids` selects the ids reported, all of them when `None`.
+pub struct BreakpointConfig {
+    pub ids: Option<Vec<String>>,
+    handler: Box<dyn BreakpointHandler>,
+}
+
+impl BreakpointConfig {
+    pub fn new(handler: impl BreakpointHandler + 'static) -> Self {
+        Self {
+            ids: None,
+            handler: Box::new(handler),
+        }
+    }
+
+    pub fn print() -> Self {
+        Self::new(PrintHandler)
+    }
+
+    pub fn prompt() -> Self {
+        Self::new(PromptHandler::stdio())
+    }
+
+    pub fn with_ids<S: Into<String>>(mut self, ids: impl IntoIterator<Item = S>) -> Self {
+        self.ids = Some(ids.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+/// Enables breakpoints. Without a config, the `breakpoint` hint is a no-op.
+pub fn enable_breakpoints(exec_scopes: &mut ExecutionScopes, config: BreakpointConfig) {
+    insert_typed(exec_scopes, &BREAKPOINT_CONFIG_KEY, config);
+}
+
+pub fn hint_breakpoint(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    // Taken out for the handler to get the exec scopes, and put back afterwards
+    let Some(mut config) = remove_typed(exec_scopes, &BREAKPOINT_CONFIG_KEY) else {
+        return Ok(());
+    };
+
+    let mut names: Vec<&String> = match &config.ids {
+        Some(ids) => ids.iter().collect(),
+        None => hint_data.ids_data.keys().collect(),
+    };
+    names.sort();
+    let ids = names
+        .into_iter()
+        .map(|name| {
+            let value = get_maybe_relocatable_from_var_name(
+                name,
+                vm,
+                &hint_data.ids_data,
+                &hint_data.ap_tracking,
+            )
+            .ok();
+            (name.clone(), value)
+        })
+        .collect();
+    let state = BreakpointState {
+        pc: vm.get_pc(),
+        ap: vm.get_ap(),
+        fp: vm.get_fp(),
+        ids,
+    };
+    let result = config.handler.on_breakpoint(vm, exec_scopes, &state);
+    insert_typed(exec_scopes, &BREAKPOINT_CONFIG_KEY, config);
+    result
+}
@@ -0,0 +1,113 @@
+use std::collections::{BTreeMap, HashMap};
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData,
+    types::{exec_scope::ExecutionScopes, relocatable::Relocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+use super::profiling::Profiler;
+use crate::scopes::{get_typed_ref, insert_typed, PROFILER_KEY, STEP_BUDGET_KEY};
+
+// Exec scope variable holding the `StepBudget`, stored in the root scope
+pub const STEP_BUDGET: &str = "__step_budget";
+
+// For loops that run no other hint, the budget being checked before every hint otherwise
+pub const HINT_CHECK_STEP_BUDGET: &str = "check_step_budget()";
+
+/// Maximum number of steps of the whole run and of the sections delimited by the
+/// `start_timer` / `stop_timer` hints, checked before each hint run by `ExtendedHintProcessor`
+/// and by `check_step_budget()`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StepBudget {
+    pub max_steps: Option<usize>,
+    pub sections: BTreeMap<String, usize>,
+}
+
+impl StepBudget {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = Some(max_steps);
+        self
+    }
+
+    /// Budget of each run of the section `label`, counted from its `start_timer`.
+    pub fn with_section(mut self, label: impl Into<String>, max_steps: usize) -> Self {
+        self.sections.insert(label.into(), max_steps);
+        self
+    }
+
+    /// Fails if the run or a running section is over its budget at the current step.
+    pub fn check(
+        &self,
+        vm: &VirtualMachine,
+        exec_scopes: &ExecutionScopes,
+    ) -> Result<(), HintError> {
+        let profiler = get_typed_ref(exec_scopes, &PROFILER_KEY).ok();
+        self.check_step(vm.get_current_step(), vm.get_pc(), profiler)
+    }
+
+    /// Fails if the run or one of the sections running in `profiler` is over its budget at
+    /// `step`, `pc` being reported in the error.
+    pub fn check_step(
+        &self,
+        step: usize,
+        pc: Relocatable,
+        profiler: Option<&Profiler>,
+    ) -> Result<(), HintError> {
+        if let Some(max_steps) = self.max_steps.filter(|max_steps| step > *max_steps) {
+            return Err(budget_error(format!(
+                "step budget exceeded at pc {pc}: {step} steps, budget {max_steps}"
+            )));
+        }
+        let Some(profiler) = profiler else {
+            return Ok(());
+        };
+        for (label, max_steps) in &self.sections {
+            let Some(start) = profiler.start_step(label) else {
+                continue;
+            };
+            let steps = step.saturating_sub(start);
+            if steps > *max_steps {
+                return Err(budget_error(format!(
+                    "step budget of section {label} exceeded at pc {pc}: {steps} steps since \
+                     start_timer at step {start}, budget {max_steps}"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn budget_error(message: String) -> HintError {
+    HintError::CustomHint(message.into_boxed_str())
+}
+
+/// Sets the budget checked for the rest of the run, replacing any previous one.
+pub fn set_step_budget(exec_scopes: &mut ExecutionScopes, budget: StepBudget) {
+    insert_typed(exec_scopes, &STEP_BUDGET_KEY, budget);
+}
+
+/// Checks the budget set with `set_step_budget`, a no-op without one.
+pub fn check_step_budget(
+    vm: &VirtualMachine,
+    exec_scopes: &ExecutionScopes,
+) -> Result<(), HintError> {
+    match get_typed_ref(exec_scopes, &STEP_BUDGET_KEY) {
+        Ok(budget) => budget.check(vm, exec_scopes),
+        Err(_) => Ok(()),
+    }
+}
+
+pub fn hint_check_step_budget(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    _hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    check_step_budget(vm, exec_scopes)
+}
@@ -1,49 +1,85 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::path::{Path, PathBuf};
 
 use cairo_vm::{
     hint_processor::builtin_hint_processor::{
         builtin_hint_processor_definition::HintProcessorData,
-        hint_utils::{get_address_from_var_name, get_integer_from_var_name},
+        hint_utils::{
+            get_integer_from_var_name, get_maybe_relocatable_from_var_name, get_ptr_from_var_name,
+        },
+    },
+    types::{
+        exec_scope::ExecutionScopes,
+        relocatable::{MaybeRelocatable, Relocatable},
     },
-    types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
     vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
     Felt252,
 };
 
+use num_traits::ToPrimitive;
+
+use super::logger::{
+    log, log_enabled, print_line, LogLevel, TARGET_FELT, TARGET_STRING, TARGET_UINT256,
+    TARGET_UINT384,
+};
+use super::utils::get_typed_from_var_name;
+use crate::provenance::{Provenance, ProvenanceTracker};
+use crate::types::{felt::Felt, uint256::Uint256, uint384::UInt384};
+
+// The print hints always print, to the `OutputSink` injected with `logger::set_output_sink`
 pub const PRINT_FELT_HEX: &str = "print(f\"{hex(ids.value)}\")";
 pub const PRINT_FELT: &str = "print(f\"{ids.value}\")";
 pub const PRINT_STRING: &str = "print(f\"String: {ids.value}\")";
+pub const PRINT_FELT_SIGNED: &str =
+    "from starkware.cairo.common.math_utils import as_int\nprint(f\"{as_int(ids.value, PRIME)}\")";
 pub const PRINT_UINT256: &str = "print(f\"{hex(ids.value.high * 2 ** 128 + ids.value.low)}\")";
 pub const PRINT_UINT384: &str =
     "print(f\"{hex(ids.value.d3 * 2 ** 144 + ids.value.d2 * 2 ** 96 + ids.value.d1 * 2 ** 48 + ids.value.d0)}\")";
 
 pub fn print_felt_hex(
     vm: &mut VirtualMachine,
-    _exec_scopes: &mut ExecutionScopes,
+    exec_scopes: &mut ExecutionScopes,
     hint_data: &HintProcessorData,
     _constants: &HashMap<String, Felt252>,
 ) -> Result<(), HintError> {
     let value =
         get_integer_from_var_name("value", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
-    println!("Value: {}", value.to_hex_string());
+    print_line(exec_scopes, &format!("Value: {}", value.to_hex_string()));
     Ok(())
 }
 
 pub fn print_felt(
     vm: &mut VirtualMachine,
-    _exec_scopes: &mut ExecutionScopes,
+    exec_scopes: &mut ExecutionScopes,
     hint_data: &HintProcessorData,
     _constants: &HashMap<String, Felt252>,
 ) -> Result<(), HintError> {
     let value =
         get_integer_from_var_name("value", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
-    println!("Value: {value}");
+    print_line(exec_scopes, &format!("Value: {value}"));
+    Ok(())
+}
+
+// Felts above P / 2 printed as negative, e.g. `Value: -1` rather than the 76 digits of P - 1
+pub fn print_felt_signed(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let value =
+        get_integer_from_var_name("value", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    print_line(
+        exec_scopes,
+        &format!("Value: {}", Felt(value).to_signed_string()),
+    );
     Ok(())
 }
 
 pub fn print_string(
     vm: &mut VirtualMachine,
-    _exec_scopes: &mut ExecutionScopes,
+    exec_scopes: &mut ExecutionScopes,
     hint_data: &HintProcessorData,
     _constants: &HashMap<String, Felt252>,
 ) -> Result<(), HintError> {
@@ -51,58 +87,209 @@ pub fn print_string(
         get_integer_from_var_name("value", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
     let bytes = value.to_bytes_be();
     let ascii = String::from_utf8_lossy(&bytes);
-    println!("String: {ascii}");
+    print_line(exec_scopes, &format!("String: {ascii}"));
     Ok(())
 }
 
 pub fn print_uint256(
     vm: &mut VirtualMachine,
-    _exec_scopes: &mut ExecutionScopes,
+    exec_scopes: &mut ExecutionScopes,
     hint_data: &HintProcessorData,
     _constants: &HashMap<String, Felt252>,
 ) -> Result<(), HintError> {
-    let ptr: MaybeRelocatable =
-        get_address_from_var_name("value", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
-    if let MaybeRelocatable::RelocatableValue(ptr) = ptr {
-        let low = vm.get_integer((ptr + 0)?)?;
-        let high = vm.get_integer((ptr + 1)?)?;
-
-        let low_bytes = low.to_bytes_be();
-        let high_bytes = high.to_bytes_be();
-
-        let low_128 = &low_bytes[low_bytes.len().saturating_sub(16)..];
-        let high_128 = &high_bytes[high_bytes.len().saturating_sub(16)..];
-
-        let mut bytes = Vec::new();
-        bytes.extend_from_slice(high_128);
-        bytes.extend_from_slice(low_128);
-        println!("Value: 0x{}", hex::encode(bytes));
-        return Ok(());
-    }
-    Err(HintError::UnknownHint(
-        hint_data.code.to_string().into_boxed_str(),
-    ))
+    let bytes = uint256_bytes(&get_typed_from_var_name("value", vm, hint_data)?);
+    print_line(exec_scopes, &format!("Value: 0x{}", hex::encode(bytes)));
+    Ok(())
 }
 
 pub fn print_uint384(
     vm: &mut VirtualMachine,
-    _exec_scopes: &mut ExecutionScopes,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let bytes = uint384_bytes(&get_typed_from_var_name("value", vm, hint_data)?);
+    print_line(exec_scopes, &format!("Value: 0x{}", hex::encode(bytes)));
+    Ok(())
+}
+
+// Big-endian bytes of `high * 2**128 + low`
+fn uint256_bytes(value: &Uint256) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (chunk, limb) in bytes.rchunks_exact_mut(16).zip(value.to_limbs()) {
+        chunk.copy_from_slice(&limb.to_bytes_be()[16..]);
+    }
+    bytes
+}
+
+// The four limbs as consecutive 32-byte big-endian felts, most significant limb first
+fn uint384_bytes(value: &UInt384) -> [u8; 128] {
+    let mut bytes = [0u8; 128];
+    for (chunk, limb) in bytes.rchunks_exact_mut(32).zip(value.to_limbs()) {
+        chunk.copy_from_slice(&limb.to_bytes_be());
+    }
+    bytes
+}
+
+pub const PRINT_ARRAY: &str =
+    "for i in range(ids.len):\n    print(f\"{ids.ptr + i}: {hex(memory[ids.ptr + i])}\")";
+pub const PRINT_MEMORY_RANGE: &str =
+    "for addr in range(ids.start, ids.end):\n    print(f\"{addr}: {memory.get(addr)}\")";
+
+/// Formats `len` cells from `start` as `segment:offset: value`, felts in hex and unset cells as
+/// `<unset>`, so gaps in a segment show up instead of failing the dump.
+pub fn format_memory_range(
+    vm: &VirtualMachine,
+    start: Relocatable,
+    len: usize,
+) -> Result<Vec<String>, HintError> {
+    format_range(vm, start, len, None)
+}
+
+/// Same as `format_memory_range`, each cell followed by where it came from when a
+/// `ProvenanceTracker` is enabled, e.g. `2:0: 0x2a <- program_input (Felt.0)`.
+pub fn format_tracked_memory_range(
+    vm: &VirtualMachine,
+    exec_scopes: &ExecutionScopes,
+    start: Relocatable,
+    len: usize,
+) -> Result<Vec<String>, HintError> {
+    format_range(vm, start, len, ProvenanceTracker::in_scopes(exec_scopes))
+}
+
+fn format_range(
+    vm: &VirtualMachine,
+    start: Relocatable,
+    len: usize,
+    tracker: Option<&ProvenanceTracker>,
+) -> Result<Vec<String>, HintError> {
+    (0..len)
+        .map(|i| {
+            let address = (start + i)?;
+            let line = format!("{address}: {}", format_cell(vm.get_maybe(&address)));
+            Ok(match tracker.and_then(|tracker| tracker.get(address)) {
+                Some(provenance) => format!("{line} <- {provenance}"),
+                None => line,
+            })
+        })
+        .collect()
+}
+
+// Felts in hex, pointers as `segment:offset`
+fn format_cell(value: Option<MaybeRelocatable>) -> String {
+    match value {
+        Some(MaybeRelocatable::Int(value)) => value.to_hex_string(),
+        Some(MaybeRelocatable::RelocatableValue(ptr)) => ptr.to_string(),
+        None => "<unset>".to_string(),
+    }
+}
+
+// Prints the `ids.len` felts of the array at `ids.ptr`
+pub fn print_array(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let ptr = get_ptr_from_var_name("ptr", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let len = get_integer_from_var_name("len", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let len = len
+        .to_usize()
+        .ok_or_else(|| HintError::CustomHint(format!("invalid array length {len}").into()))?;
+    for line in format_tracked_memory_range(vm, exec_scopes, ptr, len)? {
+        print_line(exec_scopes, &line);
+    }
+    Ok(())
+}
+
+// Prints the cells in `[ids.start, ids.end)`, both pointers being in the same segment
+pub fn print_memory_range(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let start = get_ptr_from_var_name("start", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let end = get_ptr_from_var_name("end", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let len = (end - start)?;
+    for line in format_tracked_memory_range(vm, exec_scopes, start, len)? {
+        print_line(exec_scopes, &line);
+    }
+    Ok(())
+}
+
+pub const PRINT_RELOCATABLE: &str = "print_relocatable(ids.ptr)";
+pub const PRINT_SEGMENT: &str = "print_segment(ids.ptr, ids.len)";
+
+// Prints `ids.ptr` as `segment:offset`, or in hex when it holds a felt
+pub fn print_relocatable(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let value = get_maybe_relocatable_from_var_name(
+        "ptr",
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    let line = match value {
+        MaybeRelocatable::RelocatableValue(ptr) => format!("Pointer: {ptr}"),
+        value => format!("Value: {}", format_cell(Some(value))),
+    };
+    print_line(exec_scopes, &line);
+    Ok(())
+}
+
+/// Formats `len` cells from `start` as a table of `address | value` rows under a line naming
+/// the segment, the address column padded to its widest entry.
+pub fn format_segment_table(
+    vm: &VirtualMachine,
+    start: Relocatable,
+    len: usize,
+) -> Result<Vec<String>, HintError> {
+    let rows = (0..len)
+        .map(|i| {
+            let address = (start + i)?;
+            Ok((address.to_string(), format_cell(vm.get_maybe(&address))))
+        })
+        .collect::<Result<Vec<_>, HintError>>()?;
+    let width = rows
+        .iter()
+        .map(|(address, _)| address.len())
+        .chain(["address".len()])
+        .max()
+        .unwrap_or_default();
+
+    let mut lines = Vec::with_capacity(len + 3);
+    lines.push(format!(
+        "Segment {} from offset {}, {len} cells:",
+        start.segment_index, start.offset
+    ));
+    lines.push(format!("{:<width$} | value", "address"));
+    lines.push(format!("{}-+-{}", "-".repeat(width), "-".repeat(5)));
+    lines.extend(
+        rows.into_iter()
+            .map(|(address, value)| format!("{address:<width$} | {value}")),
+    );
+    Ok(lines)
+}
+
+// Prints the `ids.len` cells from `ids.ptr` as a table, pointers and unset cells included
+pub fn print_segment(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
     hint_data: &HintProcessorData,
     _constants: &HashMap<String, Felt252>,
 ) -> Result<(), HintError> {
-    let ptr: MaybeRelocatable =
-        get_address_from_var_name("value", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
-    if let MaybeRelocatable::RelocatableValue(ptr) = ptr {
-        let d0 = vm.get_integer((ptr + 0)?)?;
-        let d1 = vm.get_integer((ptr + 1)?)?;
-        let d2 = vm.get_integer((ptr + 2)?)?;
-        let d3 = vm.get_integer((ptr + 3)?)?;
-        let mut bytes = Vec::new();
-        bytes.extend_from_slice(&d3.to_bytes_be());
-        bytes.extend_from_slice(&d2.to_bytes_be());
-        bytes.extend_from_slice(&d1.to_bytes_be());
-        bytes.extend_from_slice(&d0.to_bytes_be());
-        println!("Value: 0x{}", hex::encode(bytes));
+    let ptr = get_ptr_from_var_name("ptr", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let len = get_integer_from_var_name("len", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let len = len
+        .to_usize()
+        .ok_or_else(|| HintError::CustomHint(format!("invalid segment length {len}").into()))?;
+    for line in format_segment_table(vm, ptr, len)? {
+        print_line(exec_scopes, &line);
     }
     Ok(())
 }
@@ -125,11 +312,15 @@ pub fn info_felt(
     hint_data: &HintProcessorData,
     _constants: &HashMap<String, Felt252>,
 ) -> Result<(), HintError> {
-    let log_level = exec_scopes.get::<&str>("LOG_LEVEL_CAIRO").unwrap_or("info");
-    if log_level == "info" || log_level == "debug" {
+    if log_enabled(exec_scopes, LogLevel::Info, TARGET_FELT) {
         let value =
             get_integer_from_var_name("value", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
-        println!("Info: {value}");
+        log(
+            exec_scopes,
+            LogLevel::Info,
+            TARGET_FELT,
+            &format!("{value}"),
+        );
     }
     Ok(())
 }
@@ -140,11 +331,15 @@ pub fn info_felt_hex(
     hint_data: &HintProcessorData,
     _constants: &HashMap<String, Felt252>,
 ) -> Result<(), HintError> {
-    let log_level = exec_scopes.get::<&str>("LOG_LEVEL_CAIRO").unwrap_or("info");
-    if log_level == "info" || log_level == "debug" {
+    if log_enabled(exec_scopes, LogLevel::Info, TARGET_FELT) {
         let value =
             get_integer_from_var_name("value", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
-        println!("Info: {}", value.to_hex_string());
+        log(
+            exec_scopes,
+            LogLevel::Info,
+            TARGET_FELT,
+            &value.to_hex_string(),
+        );
     }
     Ok(())
 }
@@ -155,13 +350,12 @@ pub fn info_string(
     hint_data: &HintProcessorData,
     _constants: &HashMap<String, Felt252>,
 ) -> Result<(), HintError> {
-    let log_level = exec_scopes.get::<&str>("LOG_LEVEL_CAIRO").unwrap_or("info");
-    if log_level == "info" || log_level == "debug" {
+    if log_enabled(exec_scopes, LogLevel::Info, TARGET_STRING) {
         let value =
             get_integer_from_var_name("value", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
         let bytes = value.to_bytes_be();
         let ascii = String::from_utf8_lossy(&bytes);
-        println!("Info: {ascii}");
+        log(exec_scopes, LogLevel::Info, TARGET_STRING, &ascii);
     }
     Ok(())
 }
@@ -172,29 +366,14 @@ pub fn info_uint256(
     hint_data: &HintProcessorData,
     _constants: &HashMap<String, Felt252>,
 ) -> Result<(), HintError> {
-    let log_level = exec_scopes.get::<&str>("LOG_LEVEL_CAIRO").unwrap_or("info");
-    if log_level == "info" || log_level == "debug" {
-        let ptr: MaybeRelocatable =
-            get_address_from_var_name("value", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
-        if let MaybeRelocatable::RelocatableValue(ptr) = ptr {
-            let low = vm.get_integer((ptr + 0)?)?;
-            let high = vm.get_integer((ptr + 1)?)?;
-
-            let low_bytes = low.to_bytes_be();
-            let high_bytes = high.to_bytes_be();
-
-            let low_128 = &low_bytes[low_bytes.len().saturating_sub(16)..];
-            let high_128 = &high_bytes[high_bytes.len().saturating_sub(16)..];
-
-            let mut bytes = Vec::new();
-            bytes.extend_from_slice(high_128);
-            bytes.extend_from_slice(low_128);
-            println!("Info: 0x{}", hex::encode(bytes));
-            return Ok(());
-        }
-        return Err(HintError::UnknownHint(
-            hint_data.code.to_string().into_boxed_str(),
-        ));
+    if log_enabled(exec_scopes, LogLevel::Info, TARGET_UINT256) {
+        let bytes = uint256_bytes(&get_typed_from_var_name("value", vm, hint_data)?);
+        log(
+            exec_scopes,
+            LogLevel::Info,
+            TARGET_UINT256,
+            &format!("0x{}", hex::encode(bytes)),
+        );
     }
     Ok(())
 }
@@ -205,22 +384,14 @@ pub fn info_uint384(
     hint_data: &HintProcessorData,
     _constants: &HashMap<String, Felt252>,
 ) -> Result<(), HintError> {
-    let log_level = exec_scopes.get::<&str>("LOG_LEVEL_CAIRO").unwrap_or("info");
-    if log_level == "info" || log_level == "debug" {
-        let ptr: MaybeRelocatable =
-            get_address_from_var_name("value", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
-        if let MaybeRelocatable::RelocatableValue(ptr) = ptr {
-            let d0 = vm.get_integer((ptr + 0)?)?;
-            let d1 = vm.get_integer((ptr + 1)?)?;
-            let d2 = vm.get_integer((ptr + 2)?)?;
-            let d3 = vm.get_integer((ptr + 3)?)?;
-            let mut bytes = Vec::new();
-            bytes.extend_from_slice(&d3.to_bytes_be());
-            bytes.extend_from_slice(&d2.to_bytes_be());
-            bytes.extend_from_slice(&d1.to_bytes_be());
-            bytes.extend_from_slice(&d0.to_bytes_be());
-            println!("Info: 0x{}", hex::encode(bytes));
-        }
+    if log_enabled(exec_scopes, LogLevel::Info, TARGET_UINT384) {
+        let bytes = uint384_bytes(&get_typed_from_var_name("value", vm, hint_data)?);
+        log(
+            exec_scopes,
+            LogLevel::Info,
+            TARGET_UINT384,
+            &format!("0x{}", hex::encode(bytes)),
+        );
     }
     Ok(())
 }
@@ -231,11 +402,15 @@ pub fn debug_felt(
     hint_data: &HintProcessorData,
     _constants: &HashMap<String, Felt252>,
 ) -> Result<(), HintError> {
-    let log_level = exec_scopes.get::<&str>("LOG_LEVEL_CAIRO").unwrap_or("info");
-    if log_level == "debug" {
+    if log_enabled(exec_scopes, LogLevel::Debug, TARGET_FELT) {
         let value =
             get_integer_from_var_name("value", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
-        println!("Debug: {value}");
+        log(
+            exec_scopes,
+            LogLevel::Debug,
+            TARGET_FELT,
+            &format!("{value}"),
+        );
     }
     Ok(())
 }
@@ -246,11 +421,15 @@ pub fn debug_felt_hex(
     hint_data: &HintProcessorData,
     _constants: &HashMap<String, Felt252>,
 ) -> Result<(), HintError> {
-    let log_level = exec_scopes.get::<&str>("LOG_LEVEL_CAIRO").unwrap_or("info");
-    if log_level == "debug" {
+    if log_enabled(exec_scopes, LogLevel::Debug, TARGET_FELT) {
         let value =
             get_integer_from_var_name("value", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
-        println!("Debug: {}", value.to_hex_string());
+        log(
+            exec_scopes,
+            LogLevel::Debug,
+            TARGET_FELT,
+            &value.to_hex_string(),
+        );
     }
     Ok(())
 }
@@ -261,13 +440,12 @@ pub fn debug_string(
     hint_data: &HintProcessorData,
     _constants: &HashMap<String, Felt252>,
 ) -> Result<(), HintError> {
-    let log_level = exec_scopes.get::<&str>("LOG_LEVEL_CAIRO").unwrap_or("info");
-    if log_level == "debug" {
+    if log_enabled(exec_scopes, LogLevel::Debug, TARGET_STRING) {
         let value =
             get_integer_from_var_name("value", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
         let bytes = value.to_bytes_be();
         let ascii = String::from_utf8_lossy(&bytes);
-        println!("Debug: {ascii}");
+        log(exec_scopes, LogLevel::Debug, TARGET_STRING, &ascii);
     }
     Ok(())
 }
@@ -278,29 +456,14 @@ pub fn debug_uint256(
     hint_data: &HintProcessorData,
     _constants: &HashMap<String, Felt252>,
 ) -> Result<(), HintError> {
-    let log_level = exec_scopes.get::<&str>("LOG_LEVEL_CAIRO").unwrap_or("info");
-    if log_level == "debug" {
-        let ptr: MaybeRelocatable =
-            get_address_from_var_name("value", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
-        if let MaybeRelocatable::RelocatableValue(ptr) = ptr {
-            let low = vm.get_integer((ptr + 0)?)?;
-            let high = vm.get_integer((ptr + 1)?)?;
-
-            let low_bytes = low.to_bytes_be();
-            let high_bytes = high.to_bytes_be();
-
-            let low_128 = &low_bytes[low_bytes.len().saturating_sub(16)..];
-            let high_128 = &high_bytes[high_bytes.len().saturating_sub(16)..];
-
-            let mut bytes = Vec::new();
-            bytes.extend_from_slice(high_128);
-            bytes.extend_from_slice(low_128);
-            println!("Debug: 0x{}", hex::encode(bytes));
-            return Ok(());
-        }
-        return Err(HintError::UnknownHint(
-            hint_data.code.to_string().into_boxed_str(),
-        ));
+    if log_enabled(exec_scopes, LogLevel::Debug, TARGET_UINT256) {
+        let bytes = uint256_bytes(&get_typed_from_var_name("value", vm, hint_data)?);
+        log(
+            exec_scopes,
+            LogLevel::Debug,
+            TARGET_UINT256,
+            &format!("0x{}", hex::encode(bytes)),
+        );
     }
     Ok(())
 }
@@ -311,22 +474,241 @@ pub fn debug_uint384(
     hint_data: &HintProcessorData,
     _constants: &HashMap<String, Felt252>,
 ) -> Result<(), HintError> {
-    let log_level = exec_scopes.get::<&str>("LOG_LEVEL_CAIRO").unwrap_or("info");
-    if log_level == "debug" {
-        let ptr: MaybeRelocatable =
-            get_address_from_var_name("value", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
-        if let MaybeRelocatable::RelocatableValue(ptr) = ptr {
-            let d0 = vm.get_integer((ptr + 0)?)?;
-            let d1 = vm.get_integer((ptr + 1)?)?;
-            let d2 = vm.get_integer((ptr + 2)?)?;
-            let d3 = vm.get_integer((ptr + 3)?)?;
-            let mut bytes = Vec::new();
-            bytes.extend_from_slice(&d3.to_bytes_be());
-            bytes.extend_from_slice(&d2.to_bytes_be());
-            bytes.extend_from_slice(&d1.to_bytes_be());
-            bytes.extend_from_slice(&d0.to_bytes_be());
-            println!("Debug: 0x{}", hex::encode(bytes));
-        }
+    if log_enabled(exec_scopes, LogLevel::Debug, TARGET_UINT384) {
+        let bytes = uint384_bytes(&get_typed_from_var_name("value", vm, hint_data)?);
+        log(
+            exec_scopes,
+            LogLevel::Debug,
+            TARGET_UINT384,
+            &format!("0x{}", hex::encode(bytes)),
+        );
     }
     Ok(())
 }
+
+pub const HINT_DUMP_MEMORY_SNAPSHOT: &str = "dump_memory_snapshot()";
+
+// Exec scope variable holding the directory the `dump_memory_snapshot` hint writes to, stored in
+// the root scope
+pub const MEMORY_SNAPSHOT_DIR: &str = "__memory_snapshot_dir";
+
+fn cell_json(value: Option<&MaybeRelocatable>) -> serde_json::Value {
+    value.map_or(serde_json::Value::Null, |value| {
+        format_cell(Some(value.clone())).into()
+    })
+}
+
+/// Cell whose value differs between two snapshots, `None` standing for an unset cell. The
+/// provenance is the one the later snapshot holds for the cell, see `with_provenance`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryChange {
+    pub address: Relocatable,
+    pub old: Option<MaybeRelocatable>,
+    pub new: Option<MaybeRelocatable>,
+    pub provenance: Option<Provenance>,
+}
+
+impl MemoryChange {
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut json = serde_json::json!({
+            "address": self.address.to_string(),
+            "old": cell_json(self.old.as_ref()),
+            "new": cell_json(self.new.as_ref()),
+        });
+        if let Some(provenance) = &self.provenance {
+            json["source"] = provenance.to_string().into();
+        }
+        json
+    }
+}
+
+impl fmt::Display for MemoryChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.address,
+            format_cell(self.old.clone()),
+            format_cell(self.new.clone())
+        )?;
+        match &self.provenance {
+            Some(provenance) => write!(f, " <- {provenance}"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Set cells of VM memory at some point of a run, to diff against a later snapshot and find
+/// which hint or program section wrote a cell.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemorySnapshot {
+    cells: BTreeMap<Relocatable, MaybeRelocatable>,
+    provenance: BTreeMap<Relocatable, Provenance>,
+}
+
+impl MemorySnapshot {
+    /// Captures every set cell of every segment. Temporary segments, whose sizes cairo-vm
+    /// doesn't expose, are left out, `capture_range` reads them.
+    pub fn capture(vm: &mut VirtualMachine) -> Self {
+        // The sizes are cached once computed, which the end of the run relies on being final, so
+        // the cache is put back as it was
+        let cached = vm.segments.segment_used_sizes.take();
+        let sizes = vm.segments.compute_effective_sizes().clone();
+        vm.segments.segment_used_sizes = cached;
+
+        let mut cells = BTreeMap::new();
+        for (segment, size) in sizes.into_iter().enumerate() {
+            for offset in 0..size {
+                let address = Relocatable::from((segment as isize, offset));
+                if let Some(value) = vm.get_maybe(&address) {
+                    cells.insert(address, value);
+                }
+            }
+        }
+        Self {
+            cells,
+            provenance: BTreeMap::new(),
+        }
+    }
+
+    /// Captures `len` cells from `start`, when only a region such as a hint output matters.
+    pub fn capture_range(
+        vm: &VirtualMachine,
+        start: Relocatable,
+        len: usize,
+    ) -> Result<Self, HintError> {
+        let mut cells = BTreeMap::new();
+        for i in 0..len {
+            let address = (start + i)?;
+            if let Some(value) = vm.get_maybe(&address) {
+                cells.insert(address, value);
+            }
+        }
+        Ok(Self {
+            cells,
+            provenance: BTreeMap::new(),
+        })
+    }
+
+    /// Attaches where the captured cells came from, for `diff` and `to_json` to report.
+    pub fn with_provenance(mut self, tracker: &ProvenanceTracker) -> Self {
+        self.provenance = self
+            .cells
+            .keys()
+            .filter_map(|address| Some((*address, tracker.get(*address)?.clone())))
+            .collect();
+        self
+    }
+
+    pub fn provenance(&self, address: Relocatable) -> Option<&Provenance> {
+        self.provenance.get(&address)
+    }
+
+    pub fn get(&self, address: Relocatable) -> Option<&MaybeRelocatable> {
+        self.cells.get(&address)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Relocatable, &MaybeRelocatable)> {
+        self.cells.iter()
+    }
+
+    /// Cells set, changed or unset between `self` and `after`, by address. Cairo memory being
+    /// write-once, a changed or unset cell points at a relocation or a VM that was tampered with.
+    pub fn diff(&self, after: &MemorySnapshot) -> Vec<MemoryChange> {
+        let mut addresses: Vec<&Relocatable> =
+            self.cells.keys().chain(after.cells.keys()).collect();
+        addresses.sort();
+        addresses.dedup();
+        addresses
+            .into_iter()
+            .filter_map(|address| {
+                let old = self.cells.get(address);
+                let new = after.cells.get(address);
+                (old != new).then(|| MemoryChange {
+                    address: *address,
+                    old: old.cloned(),
+                    new: new.cloned(),
+                    provenance: after.provenance.get(address).cloned(),
+                })
+            })
+            .collect()
+    }
+
+    /// `[{"address": "1:0", "value": "0x2a"}, ..]` by address, pointers as `segment:offset`,
+    /// with the `source` of the cells with a provenance.
+    pub fn to_json(&self) -> serde_json::Value {
+        self.cells
+            .iter()
+            .map(|(address, value)| {
+                let mut json = serde_json::json!({
+                    "address": address.to_string(),
+                    "value": format_cell(Some(value.clone())),
+                });
+                if let Some(provenance) = self.provenance.get(address) {
+                    json["source"] = provenance.to_string().into();
+                }
+                json
+            })
+            .collect()
+    }
+
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(&self.to_json())?;
+        std::fs::write(path, json)
+    }
+}
+
+/// Points the `dump_memory_snapshot` hint at `dir`, where it writes `memory_snapshot_<n>.json`
+/// for its n-th call instead of printing the snapshot.
+pub fn set_memory_snapshot_dir(exec_scopes: &mut ExecutionScopes, dir: impl Into<PathBuf>) {
+    let dir = SnapshotDir {
+        path: dir.into(),
+        count: 0,
+    };
+    exec_scopes.data[0].insert(MEMORY_SNAPSHOT_DIR.to_string(), Box::new(dir));
+}
+
+struct SnapshotDir {
+    path: PathBuf,
+    count: usize,
+}
+
+// Dumps all of memory as JSON, to the `MEMORY_SNAPSHOT_DIR` if one is set, else to the print sink.
+// Cells get their source when a `ProvenanceTracker` is enabled
+pub fn dump_memory_snapshot(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    _hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let mut snapshot = MemorySnapshot::capture(vm);
+    if let Some(tracker) = ProvenanceTracker::in_scopes(exec_scopes) {
+        snapshot = snapshot.with_provenance(tracker);
+    }
+    let dir = exec_scopes.data[0]
+        .get_mut(MEMORY_SNAPSHOT_DIR)
+        .and_then(|dir| dir.downcast_mut::<SnapshotDir>());
+    match dir {
+        Some(dir) => {
+            let path = dir.path.join(format!("memory_snapshot_{}.json", dir.count));
+            dir.count += 1;
+            snapshot.write_to_file(&path).map_err(|e| {
+                HintError::CustomHint(
+                    format!("failed to write {}: {e}", path.display()).into_boxed_str(),
+                )
+            })
+        }
+        None => {
+            print_line(exec_scopes, &snapshot.to_json().to_string());
+            Ok(())
+        }
+    }
+}
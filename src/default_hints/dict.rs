@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData,
+        dict_hint_utils::{
+            default_dict_new, dict_new, dict_read, dict_squash_copy_dict, dict_squash_update_ptr,
+            dict_update, dict_write,
+        },
+        hint_code,
+        squash_dict_utils::{
+            squash_dict, squash_dict_inner_assert_len_keys, squash_dict_inner_check_access_index,
+            squash_dict_inner_continue_loop, squash_dict_inner_first_iteration,
+            squash_dict_inner_len_assert, squash_dict_inner_next_key, squash_dict_inner_skip_loop,
+            squash_dict_inner_used_accesses_assert,
+        },
+    },
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+// Expects `initial_dict` in the current scope, see `CairoDict::insert_initial_dict`
+pub const HINT_DICT_NEW: &str = hint_code::DICT_NEW;
+pub const HINT_DEFAULT_DICT_NEW: &str = hint_code::DEFAULT_DICT_NEW;
+pub const HINT_DICT_READ: &str = hint_code::DICT_READ;
+pub const HINT_DICT_WRITE: &str = hint_code::DICT_WRITE;
+pub const HINT_DICT_UPDATE: &str = hint_code::DICT_UPDATE;
+
+// Start of `starkware.cairo.common.squash_dict`, the inner loop hints then walk `keys` and
+// `access_indices`
+pub const HINT_SQUASH_DICT: &str = hint_code::SQUASH_DICT;
+
+// The `squash_dict_inner` loop over the keys and access indices set up by `squash_dict`
+pub const HINT_SQUASH_DICT_INNER_FIRST_ITERATION: &str =
+    hint_code::SQUASH_DICT_INNER_FIRST_ITERATION;
+pub const HINT_SQUASH_DICT_INNER_SKIP_LOOP: &str = hint_code::SQUASH_DICT_INNER_SKIP_LOOP;
+pub const HINT_SQUASH_DICT_INNER_CHECK_ACCESS_INDEX: &str =
+    hint_code::SQUASH_DICT_INNER_CHECK_ACCESS_INDEX;
+pub const HINT_SQUASH_DICT_INNER_CONTINUE_LOOP: &str = hint_code::SQUASH_DICT_INNER_CONTINUE_LOOP;
+pub const HINT_SQUASH_DICT_INNER_LEN_ASSERT: &str = hint_code::SQUASH_DICT_INNER_LEN_ASSERT;
+pub const HINT_SQUASH_DICT_INNER_USED_ACCESSES_ASSERT: &str =
+    hint_code::SQUASH_DICT_INNER_USED_ACCESSES_ASSERT;
+pub const HINT_SQUASH_DICT_INNER_ASSERT_LEN_KEYS: &str =
+    hint_code::SQUASH_DICT_INNER_ASSERT_LEN_KEYS;
+pub const HINT_SQUASH_DICT_INNER_NEXT_KEY: &str = hint_code::SQUASH_DICT_INNER_NEXT_KEY;
+
+pub const HINT_DICT_SQUASH_COPY_DICT: &str = hint_code::DICT_SQUASH_COPY_DICT;
+pub const HINT_DICT_SQUASH_UPDATE_PTR: &str = hint_code::DICT_SQUASH_UPDATE_PTR;
+
+pub fn hint_dict_new(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    _hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    dict_new(vm, exec_scopes)
+}
+
+pub fn hint_default_dict_new(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    default_dict_new(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_dict_read(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    dict_read(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_dict_write(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    dict_write(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+// Fails unless `ids.prev_value` is the current value of `ids.key`
+pub fn hint_dict_update(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    dict_update(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_squash_dict(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    squash_dict(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_squash_dict_inner_first_iteration(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    squash_dict_inner_first_iteration(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_squash_dict_inner_skip_loop(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    squash_dict_inner_skip_loop(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_squash_dict_inner_check_access_index(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    squash_dict_inner_check_access_index(
+        vm,
+        exec_scopes,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )
+}
+
+pub fn hint_squash_dict_inner_continue_loop(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    squash_dict_inner_continue_loop(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_squash_dict_inner_len_assert(
+    _vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    _hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    squash_dict_inner_len_assert(exec_scopes)
+}
+
+pub fn hint_squash_dict_inner_used_accesses_assert(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    squash_dict_inner_used_accesses_assert(
+        vm,
+        exec_scopes,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )
+}
+
+pub fn hint_squash_dict_inner_assert_len_keys(
+    _vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    _hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    squash_dict_inner_assert_len_keys(exec_scopes)
+}
+
+pub fn hint_squash_dict_inner_next_key(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    squash_dict_inner_next_key(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_dict_squash_copy_dict(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    dict_squash_copy_dict(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_dict_squash_update_ptr(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    dict_squash_update_ptr(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
@@ -0,0 +1,100 @@
+//! Read access to the dicts created by the `dict` hints. They are tracked by cairo-vm's
+//! `DictManager`, stored in the `dict_manager` exec scope variable, so that custom hints and the
+//! host can look up the current content of a dict from a pointer into it.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use cairo_vm::{
+    types::{
+        exec_scope::ExecutionScopes,
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::errors::hint_errors::HintError,
+    Felt252,
+};
+
+pub use cairo_vm::hint_processor::builtin_hint_processor::dict_manager::{
+    DictManager, DictTracker, Dictionary,
+};
+
+/// Copy of a tracked dict: its segment, the end of its accesses so far and its entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackedDict {
+    pub segment_index: isize,
+    pub current_ptr: Relocatable,
+    pub entries: HashMap<MaybeRelocatable, MaybeRelocatable>,
+    // Value of the missing keys, for dicts created by `default_dict_new`
+    pub default_value: Option<MaybeRelocatable>,
+}
+
+impl TrackedDict {
+    fn new(segment_index: isize, tracker: &DictTracker) -> Self {
+        let (entries, default_value) = match &tracker.data {
+            Dictionary::SimpleDictionary(entries) => (entries.clone(), None),
+            Dictionary::DefaultDictionary {
+                dict,
+                default_value,
+            } => (dict.clone(), Some(default_value.clone())),
+        };
+        Self {
+            segment_index,
+            current_ptr: tracker.current_ptr,
+            entries,
+            default_value,
+        }
+    }
+
+    /// The value of `key`, or the default value of a default dict.
+    pub fn get(&self, key: Felt252) -> Option<&MaybeRelocatable> {
+        self.entries
+            .get(&MaybeRelocatable::Int(key))
+            .or(self.default_value.as_ref())
+    }
+}
+
+/// The manager of the run, `None` before the first `dict_new` or `default_dict_new`.
+pub fn dict_manager(exec_scopes: &ExecutionScopes) -> Option<Rc<RefCell<DictManager>>> {
+    exec_scopes.get_dict_manager().ok()
+}
+
+/// The dict whose accesses segment `dict_ptr` points into, at any offset.
+pub fn get_dict(
+    exec_scopes: &ExecutionScopes,
+    dict_ptr: Relocatable,
+) -> Result<TrackedDict, HintError> {
+    let manager = dict_manager(exec_scopes)
+        .ok_or_else(|| HintError::CustomHint("no dict was created".into()))?;
+    let manager = manager.borrow();
+    manager
+        .trackers
+        .get(&dict_ptr.segment_index)
+        .map(|tracker| TrackedDict::new(dict_ptr.segment_index, tracker))
+        .ok_or_else(|| HintError::CustomHint(format!("no dict tracked at {dict_ptr}").into()))
+}
+
+/// Value of `key` in the dict at `dict_ptr`, `None` if it was never written and the dict has no
+/// default value.
+pub fn get_dict_value(
+    exec_scopes: &ExecutionScopes,
+    dict_ptr: Relocatable,
+    key: Felt252,
+) -> Result<Option<MaybeRelocatable>, HintError> {
+    Ok(get_dict(exec_scopes, dict_ptr)?.get(key).cloned())
+}
+
+/// All the dicts created so far, ordered by segment.
+pub fn tracked_dicts(exec_scopes: &ExecutionScopes) -> Vec<TrackedDict> {
+    let Some(manager) = dict_manager(exec_scopes) else {
+        return vec![];
+    };
+    let manager = manager.borrow();
+    let mut dicts = manager
+        .trackers
+        .iter()
+        .map(|(segment_index, tracker)| TrackedDict::new(*segment_index, tracker))
+        .collect::<Vec<_>>();
+    dicts.sort_by_key(|dict| dict.segment_index);
+    dicts
+}
@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData,
+        hint_code,
+        hint_utils::get_relocatable_from_var_name,
+        secp::{ec_utils::ec_negate_embedded_secp_p, field_utils::verify_zero},
+        vrf::{
+            fq::{inv_mod_p_uint256, uint512_unsigned_div_rem},
+            inv_mod_p_uint512::inv_mod_p_uint512,
+            pack::{ed25519_is_zero_assign_scope_vars, ed25519_is_zero_pack, ed25519_reduce},
+        },
+    },
+    math_utils::sqrt_prime_power,
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::{BigInt, BigUint};
+use num_traits::One;
+
+use super::secp::split_bigint3;
+use crate::cairo_type::write_felts;
+
+// Writes the point encoded by the Uint256 `ids.compressed` (32 bytes read as a little-endian
+// integer) at `ids.point`, as an (x, y) pair of BigInt3
+pub const HINT_ED25519_DECOMPRESS: &str = r#"from starkware.cairo.common.cairo_secp.secp_utils import split
+from starkware.python.math_utils import sqrt
+
+P = 2**255 - 19
+D = -121665 * pow(121666, -1, P) % P
+compressed = ids.compressed.low + (ids.compressed.high << 128)
+y = compressed & ((1 << 255) - 1)
+sign = compressed >> 255
+assert y < P, "ed25519: y is not reduced"
+x = sqrt((y * y - 1) * pow(D * y * y + 1, -1, P) % P, P)
+assert x != 0 or sign == 0, "ed25519: invalid sign of x = 0"
+if x % 2 != sign:
+    x = P - x
+segments.write_arg(ids.point.x.address_, split(x))
+segments.write_arg(ids.point.y.address_, split(y))"#;
+
+// Scalar reduction mod L: `ids.x` (Uint512, e.g. a sha512 digest) divided by `ids.div` (Uint256)
+pub const HINT_UINT512_UNSIGNED_DIV_REM: &str = hint_code::UINT512_UNSIGNED_DIV_REM;
+pub const HINT_INV_MOD_P_UINT256: &str = hint_code::INV_MOD_P_UINT256;
+pub const HINT_INV_MOD_P_UINT512: &str = hint_code::INV_MOD_P_UINT512;
+
+// BigInt3 field arithmetic mod 2**255 - 19
+pub const HINT_REDUCE: &str = hint_code::REDUCE_ED25519;
+pub const HINT_VERIFY_ZERO: &str = hint_code::VERIFY_ZERO_V3;
+pub const HINT_IS_ZERO_PACK: &str = hint_code::IS_ZERO_PACK_ED25519;
+pub const HINT_IS_ZERO_ASSIGN_SCOPE_VARS: &str = hint_code::IS_ZERO_ASSIGN_SCOPE_VARS_ED25519;
+pub const HINT_EC_NEGATE: &str = hint_code::EC_NEGATE_EMBEDDED_SECP;
+
+pub fn p() -> BigUint {
+    (BigUint::one() << 255u32) - 19u32
+}
+
+// Order of the prime subgroup, 2**252 + 27742317777372353535851937790883648493
+pub fn l() -> BigUint {
+    (BigUint::one() << 252u32)
+        + BigUint::parse_bytes(b"27742317777372353535851937790883648493", 10).unwrap()
+}
+
+/// Decodes a compressed Edwards point (RFC 8032 section 5.1.3) into its affine coordinates.
+pub fn decompress(bytes: &[u8; 32]) -> Result<(BigUint, BigUint), String> {
+    let p = p();
+    let encoded = BigUint::from_bytes_le(bytes);
+    let sign = encoded.bit(255);
+    let y = encoded & ((BigUint::one() << 255u32) - 1u32);
+    if y >= p {
+        return Err("ed25519: y is not reduced".to_string());
+    }
+
+    // d = -121665 / 121666
+    let inverse = |value: &BigUint| value.modpow(&(&p - 2u32), &p);
+    let d = (&p - BigUint::from(121665u32)) * inverse(&BigUint::from(121666u32)) % &p;
+    let y2 = &y * &y % &p;
+    let u = (&y2 + &p - 1u32) % &p;
+    let v = (d * &y2 + 1u32) % &p;
+    let x2 = u * inverse(&v) % &p;
+    let mut x = sqrt_prime_power(&x2, &p)
+        .ok_or_else(|| "ed25519: point is not on the curve".to_string())?;
+    if x == BigUint::ZERO && sign {
+        return Err("ed25519: invalid sign of x = 0".to_string());
+    }
+    if x.bit(0) != sign {
+        x = &p - x;
+    }
+    Ok((x, y))
+}
+
+pub fn hint_ed25519_decompress(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let compressed = get_relocatable_from_var_name(
+        "compressed",
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    let low = vm.get_integer(compressed)?.to_biguint();
+    let high = vm.get_integer((compressed + 1)?)?.to_biguint();
+    let mut bytes = [0u8; 32];
+    let encoded = ((high << 128u32) + low).to_bytes_le();
+    if encoded.len() > 32 {
+        return Err(HintError::CustomHint(
+            "ed25519: compressed point does not fit in 32 bytes".into(),
+        ));
+    }
+    bytes[..encoded.len()].copy_from_slice(&encoded);
+
+    let (x, y) = decompress(&bytes).map_err(|e| HintError::CustomHint(e.into_boxed_str()))?;
+    let point =
+        get_relocatable_from_var_name("point", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    write_felts(
+        vm,
+        point,
+        split_bigint3(&x).into_iter().chain(split_bigint3(&y)),
+    )?;
+    Ok(())
+}
+
+pub fn hint_uint512_unsigned_div_rem(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    uint512_unsigned_div_rem(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_inv_mod_p_uint256(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    inv_mod_p_uint256(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_inv_mod_p_uint512(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    inv_mod_p_uint512(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_reduce(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    ed25519_reduce(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_verify_zero(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    verify_zero(
+        vm,
+        exec_scopes,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+        &BigInt::from(p()),
+    )
+}
+
+pub fn hint_is_zero_pack(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    ed25519_is_zero_pack(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_is_zero_assign_scope_vars(
+    _vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    _hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    ed25519_is_zero_assign_scope_vars(exec_scopes)
+}
+
+pub fn hint_ec_negate(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    ec_negate_embedded_secp_p(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
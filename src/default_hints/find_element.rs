@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData,
+        find_element_hint::{find_element, search_sorted_lower},
+        hint_code,
+        set::set_add,
+    },
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+// Exec scope variables standing for `__find_element_index` and `__find_element_max_size`,
+// read from the current scope
+pub const FIND_ELEMENT_INDEX: &str = "find_element_index";
+pub const FIND_ELEMENT_MAX_SIZE: &str = "find_element_max_size";
+
+// Uses the index set with `set_find_element_index` when there is one, consuming it
+pub const HINT_FIND_ELEMENT: &str = hint_code::FIND_ELEMENT;
+pub const HINT_SEARCH_SORTED_LOWER: &str = hint_code::SEARCH_SORTED_LOWER;
+pub const HINT_SET_ADD: &str = hint_code::SET_ADD;
+
+/// Gives the index the next `find_element` hint should use instead of searching the array,
+/// as `__find_element_index` does in Python. The hint still checks the key at that index.
+pub fn set_find_element_index(exec_scopes: &mut ExecutionScopes, index: usize) {
+    exec_scopes.insert_value(FIND_ELEMENT_INDEX, Felt252::from(index));
+}
+
+/// Bounds the number of elements `find_element` and `search_sorted_lower` may scan.
+pub fn set_find_element_max_size(exec_scopes: &mut ExecutionScopes, max_size: usize) {
+    exec_scopes.insert_value(FIND_ELEMENT_MAX_SIZE, Felt252::from(max_size));
+}
+
+pub fn hint_find_element(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    find_element(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_search_sorted_lower(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    search_sorted_lower(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_set_add(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    set_add(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData,
+        hint_code,
+        hint_utils::{
+            get_constant_from_var_name, get_integer_from_var_name, get_ptr_from_var_name,
+            insert_value_from_var_name,
+        },
+    },
+    types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+
+use crate::fixture::read_input;
+use crate::types::keccak_bytes::KeccakBytes;
+
+const KECCAK_STATE_SIZE: usize = 25;
+
+// Block sizes below 1000, the cairo_keccak finalize hint of recent cairo-lang versions
+pub const HINT_KECCAK_FINALIZE: &str = hint_code::CAIRO_KECCAK_FINALIZE_V2;
+pub const HINT_BLOCK_PERMUTATION: &str = hint_code::BLOCK_PERMUTATION_WHITELIST_V1;
+pub const HINT_KECCAK_WRITE_ARGS: &str = hint_code::KECCAK_WRITE_ARGS;
+
+// Loads the `KeccakBytes` stored in the exec scopes under `keccak_bytes` as little-endian u64 words
+pub const HINT_LOAD_KECCAK_BYTES: &str = r#"ids.inputs = segments.gen_arg(keccak_bytes.to_limbs())
+ids.n_bytes = len(keccak_bytes)"#;
+
+fn keccak_state_size(constants: &HashMap<String, Felt252>) -> Result<usize, HintError> {
+    let size = get_constant_from_var_name("KECCAK_STATE_SIZE_FELTS", constants)?;
+    match size.to_usize() {
+        Some(KECCAK_STATE_SIZE) => Ok(KECCAK_STATE_SIZE),
+        _ => Err(HintError::InvalidKeccakStateSizeFelt252s(Box::new(*size))),
+    }
+}
+
+fn permute(state: [u64; KECCAK_STATE_SIZE]) -> Vec<MaybeRelocatable> {
+    let mut state = state;
+    keccak::f1600(&mut state);
+    state
+        .iter()
+        .map(|word| MaybeRelocatable::from(Felt252::from(*word)))
+        .collect()
+}
+
+pub fn hint_keccak_finalize(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let state_size = keccak_state_size(constants)?;
+    let block_size = get_constant_from_var_name("BLOCK_SIZE", constants)?;
+    let block_size = match block_size.to_usize() {
+        Some(size) if size < 1000 => size,
+        _ => return Err(HintError::InvalidBlockSize(Box::new(*block_size))),
+    };
+
+    let mut dummy_pair = vec![MaybeRelocatable::from(Felt252::ZERO); state_size];
+    dummy_pair.extend(permute([0u64; KECCAK_STATE_SIZE]));
+    let padding = vec![dummy_pair; block_size].concat();
+
+    let keccak_ptr_end = get_ptr_from_var_name(
+        "keccak_ptr_end",
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    vm.load_data(keccak_ptr_end, &padding)?;
+    Ok(())
+}
+
+pub fn hint_block_permutation(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let state_size = keccak_state_size(constants)?;
+    let keccak_ptr = get_ptr_from_var_name(
+        "keccak_ptr",
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+
+    let mut state = [0u64; KECCAK_STATE_SIZE];
+    let input = vm.get_integer_range((keccak_ptr - state_size)?, state_size)?;
+    for (word, value) in state.iter_mut().zip(input) {
+        *word = value.to_u64().ok_or_else(|| {
+            HintError::CustomHint(
+                format!("keccak state word does not fit in 64 bits: {value}").into_boxed_str(),
+            )
+        })?;
+    }
+
+    vm.load_data(keccak_ptr, &permute(state))?;
+    Ok(())
+}
+
+pub fn hint_keccak_write_args(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let inputs = get_ptr_from_var_name("inputs", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let low = get_integer_from_var_name("low", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let high = get_integer_from_var_name("high", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+
+    let mask = BigUint::from(u64::MAX);
+    let words = [low, high]
+        .iter()
+        .flat_map(|value| {
+            let value = value.to_biguint();
+            [&value & &mask, value >> 64]
+        })
+        .map(|word| MaybeRelocatable::from(Felt252::from(word)))
+        .collect::<Vec<_>>();
+
+    vm.load_data(inputs, &words)?;
+    Ok(())
+}
+
+pub fn hint_load_keccak_bytes(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let keccak_bytes = read_input::<KeccakBytes>(exec_scopes, "keccak_bytes")?;
+    let inputs = keccak_bytes.write_limbs(vm)?;
+    insert_value_from_var_name(
+        "inputs",
+        inputs,
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    insert_value_from_var_name(
+        "n_bytes",
+        Felt252::from(keccak_bytes.0.len()),
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    Ok(())
+}
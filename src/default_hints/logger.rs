@@ -0,0 +1,518 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::io::Write;
+use std::rc::Rc;
+
+use cairo_vm::types::exec_scope::ExecutionScopes;
+
+use crate::scopes::{
+    find_typed_mut, get_typed, insert_typed, remove_typed, LOG_LEVEL_KEY, OUTPUT_SINK_KEY,
+};
+
+// Exec scope variable holding the `OutputSink` all hint output goes to, stored in the root scope
+pub const OUTPUT_SINK: &str = "__output_sink";
+
+// Exec scope variable read when no logger is injected, either "info" or "debug"
+pub const LOG_LEVEL_CAIRO: &str = "LOG_LEVEL_CAIRO";
+
+// Targets of the info_* and debug_* hints, by the kind of value they log
+pub const TARGET_FELT: &str = "cairo::felt";
+pub const TARGET_STRING: &str = "cairo::string";
+pub const TARGET_UINT256: &str = "cairo::uint256";
+pub const TARGET_UINT384: &str = "cairo::uint384";
+// Target of the lines of the print_* hints in an `OutputSink`
+pub const TARGET_PRINT: &str = "cairo::print";
+
+/// Verbosity of a log record, `Debug` being the most verbose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Info,
+    Debug,
+}
+
+impl LogLevel {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.to_ascii_lowercase().as_str() {
+            "info" => Ok(LogLevel::Info),
+            "debug" => Ok(LogLevel::Debug),
+            _ => Err(format!("invalid log level {s}")),
+        }
+    }
+}
+
+/// Maximum level per target, the longest matching target prefix winning. `None` turns
+/// logging off.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LevelFilter {
+    default: Option<LogLevel>,
+    targets: Vec<(String, Option<LogLevel>)>,
+}
+
+impl Default for LevelFilter {
+    fn default() -> Self {
+        Self::new(Some(LogLevel::Info))
+    }
+}
+
+impl LevelFilter {
+    pub fn new(default: Option<LogLevel>) -> Self {
+        Self {
+            default,
+            targets: vec![],
+        }
+    }
+
+    pub fn with_target(mut self, target: &str, level: Option<LogLevel>) -> Self {
+        self.targets.push((target.to_string(), level));
+        self
+    }
+
+    /// Parses `env_logger` style directives, e.g. `info,cairo::uint256=debug,cairo::string=off`.
+    pub fn parse(directives: &str) -> Result<Self, String> {
+        let parse_level = |s: &str| match s {
+            "off" => Ok(None),
+            s => LogLevel::parse(s).map(Some),
+        };
+        let mut filter = Self::new(Some(LogLevel::Info));
+        for directive in directives
+            .split(',')
+            .map(str::trim)
+            .filter(|d| !d.is_empty())
+        {
+            match directive.split_once('=') {
+                Some((target, level)) => {
+                    filter = filter.with_target(target.trim(), parse_level(level.trim())?)
+                }
+                None => filter.default = parse_level(directive)?,
+            }
+        }
+        Ok(filter)
+    }
+
+    pub fn enabled(&self, level: LogLevel, target: &str) -> bool {
+        let max_level = self
+            .targets
+            .iter()
+            .filter(|(prefix, _)| target.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.default, |(_, level)| *level);
+        max_level.is_some_and(|max_level| level <= max_level)
+    }
+}
+
+/// Receiver of the info_* and debug_* records, plugged into the hint output as an `OutputSink`
+/// with `set_logger`: it filters and takes the records, prints go on to the previous sink.
+pub trait CairoLogger {
+    fn enabled(&self, level: LogLevel, target: &str) -> bool;
+    fn log(&mut self, level: LogLevel, target: &str, message: &str);
+}
+
+/// Receiver of the lines of the print_* hints, plugged into the hint output as an `OutputSink`
+/// with `set_print_sink`: log records go on to the previous sink. Closures taking the line are
+/// sinks, e.g. to forward it to `console.log` in a browser.
+pub trait PrintSink {
+    fn print(&mut self, line: &str);
+}
+
+impl<F: FnMut(&str)> PrintSink for F {
+    fn print(&mut self, line: &str) {
+        self(line)
+    }
+}
+
+/// Which hint produced a line of output: a print_* hint, or an info_* / debug_* record that
+/// passed the level filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutputKind {
+    Print,
+    Log(LogLevel),
+}
+
+/// Destination of every line of hint output, prints and log records alike, set with
+/// `set_output_sink`. Without one, the hints write to the `DefaultSink`.
+pub trait OutputSink {
+    fn write(&mut self, kind: OutputKind, target: &str, message: &str);
+
+    /// Whether an info_* / debug_* record is written, per `filter`, the `LOG_LEVEL_CAIRO` level,
+    /// by default.
+    fn enabled(&self, level: LogLevel, target: &str, filter: &LevelFilter) -> bool {
+        filter.enabled(level, target)
+    }
+}
+
+// Lines as printed to stdout, prefixed with `Info: ` / `Debug: ` for log records
+fn render_line(kind: OutputKind, message: &str) -> String {
+    match kind {
+        OutputKind::Print => message.to_string(),
+        OutputKind::Log(LogLevel::Info) => format!("Info: {message}"),
+        OutputKind::Log(LogLevel::Debug) => format!("Debug: {message}"),
+    }
+}
+
+/// Prints lines to stdout, log records prefixed with `Info: ` / `Debug: `.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StdoutSink;
+
+impl OutputSink for StdoutSink {
+    fn write(&mut self, kind: OutputKind, _target: &str, message: &str) {
+        println!("{}", render_line(kind, message));
+    }
+}
+
+/// The output without an injected sink: stdout, with log records emitted as `tracing` events
+/// instead with the `tracing` feature. The `wasm` feature has no stdout to print to, only the
+/// `tracing` events are kept.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultSink;
+
+impl OutputSink for DefaultSink {
+    fn write(&mut self, kind: OutputKind, target: &str, message: &str) {
+        #[cfg(feature = "tracing")]
+        if let OutputKind::Log(level) = kind {
+            return trace_record(level, target, message);
+        }
+        if !cfg!(feature = "wasm") {
+            StdoutSink.write(kind, target, message);
+        }
+    }
+
+    // Records that would be dropped aren't even read
+    fn enabled(&self, level: LogLevel, target: &str, filter: &LevelFilter) -> bool {
+        !cfg!(all(feature = "wasm", not(feature = "tracing"))) && filter.enabled(level, target)
+    }
+}
+
+/// A line received by a `BufferSink`, displayed as it would be printed to stdout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputLine {
+    pub kind: OutputKind,
+    pub target: String,
+    pub message: String,
+}
+
+impl fmt::Display for OutputLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&render_line(self.kind, &self.message))
+    }
+}
+
+/// Captures the output of the hints in memory, to assert on what a program printed in tests.
+/// Clones share the same buffer, so keep one to read the lines after injecting the sink.
+#[derive(Debug, Clone, Default)]
+pub struct BufferSink {
+    lines: Rc<RefCell<Vec<OutputLine>>>,
+}
+
+impl BufferSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn lines(&self) -> Vec<OutputLine> {
+        self.lines.borrow().clone()
+    }
+
+    /// The lines as printed to stdout, e.g. `Value: 0x2a` or `Info: 42`.
+    pub fn rendered(&self) -> Vec<String> {
+        self.lines
+            .borrow()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    /// Whether a line, as printed to stdout, contains `text`.
+    pub fn contains(&self, text: &str) -> bool {
+        self.lines
+            .borrow()
+            .iter()
+            .any(|line| line.to_string().contains(text))
+    }
+
+    pub fn clear(&self) {
+        self.lines.borrow_mut().clear();
+    }
+}
+
+impl OutputSink for BufferSink {
+    fn write(&mut self, kind: OutputKind, target: &str, message: &str) {
+        self.lines.borrow_mut().push(OutputLine {
+            kind,
+            target: target.to_string(),
+            message: message.to_string(),
+        });
+    }
+}
+
+/// Writes one JSON object per line, e.g.
+/// `{"kind":"log","level":"info","message":"42","target":"cairo::felt"}`, for log collectors.
+/// Prints have `"kind":"print"` and a `null` level.
+#[derive(Debug)]
+pub struct JsonLinesSink<W> {
+    writer: W,
+}
+
+impl<W: Write> JsonLinesSink<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> OutputSink for JsonLinesSink<W> {
+    fn write(&mut self, kind: OutputKind, target: &str, message: &str) {
+        let (kind, level) = match kind {
+            OutputKind::Print => ("print", None),
+            OutputKind::Log(LogLevel::Info) => ("log", Some("info")),
+            OutputKind::Log(LogLevel::Debug) => ("log", Some("debug")),
+        };
+        let line = serde_json::json!({
+            "kind": kind,
+            "level": level,
+            "target": target,
+            "message": message,
+        });
+        // Output is best effort, a failing writer doesn't fail the hint
+        let _ = writeln!(self.writer, "{line}");
+    }
+}
+
+/// Prints records as `Info: ..` / `Debug: ..`, the historical output of the hints.
+#[derive(Debug, Clone, Default)]
+pub struct StdoutLogger {
+    pub filter: LevelFilter,
+}
+
+impl StdoutLogger {
+    pub fn new(filter: LevelFilter) -> Self {
+        Self { filter }
+    }
+}
+
+impl CairoLogger for StdoutLogger {
+    fn enabled(&self, level: LogLevel, target: &str) -> bool {
+        self.filter.enabled(level, target)
+    }
+
+    fn log(&mut self, level: LogLevel, target: &str, message: &str) {
+        StdoutSink.write(OutputKind::Log(level), target, message);
+    }
+}
+
+/// Discards every record, without even reading the logged values.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SinkLogger;
+
+impl CairoLogger for SinkLogger {
+    fn enabled(&self, _level: LogLevel, _target: &str) -> bool {
+        false
+    }
+
+    fn log(&mut self, _level: LogLevel, _target: &str, _message: &str) {}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogRecord {
+    pub level: LogLevel,
+    pub target: String,
+    pub message: String,
+}
+
+/// Captures records in memory, e.g. to assert on the output of a program in tests. Clones
+/// share the same buffer, so keep one to read the records after injecting the logger.
+#[derive(Debug, Clone, Default)]
+pub struct BufferLogger {
+    pub filter: LevelFilter,
+    records: Rc<RefCell<Vec<LogRecord>>>,
+}
+
+impl BufferLogger {
+    pub fn new(filter: LevelFilter) -> Self {
+        Self {
+            filter,
+            records: Rc::default(),
+        }
+    }
+
+    pub fn records(&self) -> Vec<LogRecord> {
+        self.records.borrow().clone()
+    }
+
+    pub fn messages(&self) -> Vec<String> {
+        self.records
+            .borrow()
+            .iter()
+            .map(|record| record.message.clone())
+            .collect()
+    }
+}
+
+impl CairoLogger for BufferLogger {
+    fn enabled(&self, level: LogLevel, target: &str) -> bool {
+        self.filter.enabled(level, target)
+    }
+
+    fn log(&mut self, level: LogLevel, target: &str, message: &str) {
+        self.records.borrow_mut().push(LogRecord {
+            level,
+            target: target.to_string(),
+            message: message.to_string(),
+        });
+    }
+}
+
+/// Emits records as `tracing` events with target `cairo`, the hint target being a field.
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone, Default)]
+pub struct TracingLogger {
+    pub filter: LevelFilter,
+}
+
+#[cfg(feature = "tracing")]
+impl TracingLogger {
+    pub fn new(filter: LevelFilter) -> Self {
+        Self { filter }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl CairoLogger for TracingLogger {
+    fn enabled(&self, level: LogLevel, target: &str) -> bool {
+        self.filter.enabled(level, target)
+    }
+
+    fn log(&mut self, level: LogLevel, target: &str, message: &str) {
+        trace_record(level, target, message);
+    }
+}
+
+#[cfg(feature = "tracing")]
+fn trace_record(level: LogLevel, target: &str, message: &str) {
+    match level {
+        LogLevel::Info => tracing::info!(target: "cairo", hint_target = target, "{message}"),
+        LogLevel::Debug => tracing::debug!(target: "cairo", hint_target = target, "{message}"),
+    }
+}
+
+// A `CairoLogger` taking the records, prints going to the sink it was set over
+struct LoggerSink {
+    logger: Box<dyn CairoLogger>,
+    inner: Box<dyn OutputSink>,
+}
+
+impl OutputSink for LoggerSink {
+    fn write(&mut self, kind: OutputKind, target: &str, message: &str) {
+        match kind {
+            OutputKind::Log(level) => self.logger.log(level, target, message),
+            OutputKind::Print => self.inner.write(kind, target, message),
+        }
+    }
+
+    fn enabled(&self, level: LogLevel, target: &str, _filter: &LevelFilter) -> bool {
+        self.logger.enabled(level, target)
+    }
+}
+
+// A `PrintSink` taking the prints, records going to the sink it was set over
+struct PrintSinkAdapter {
+    sink: Box<dyn PrintSink>,
+    inner: Box<dyn OutputSink>,
+}
+
+impl OutputSink for PrintSinkAdapter {
+    fn write(&mut self, kind: OutputKind, target: &str, message: &str) {
+        match kind {
+            OutputKind::Print => self.sink.print(message),
+            OutputKind::Log(_) => self.inner.write(kind, target, message),
+        }
+    }
+
+    fn enabled(&self, level: LogLevel, target: &str, filter: &LevelFilter) -> bool {
+        self.inner.enabled(level, target, filter)
+    }
+}
+
+/// Injects the sink of all hint output, replacing stdout and any sink, logger or print sink set
+/// before.
+pub fn set_output_sink(exec_scopes: &mut ExecutionScopes, sink: impl OutputSink + 'static) {
+    set_boxed_output_sink(exec_scopes, Box::new(sink));
+}
+
+pub fn set_boxed_output_sink(exec_scopes: &mut ExecutionScopes, sink: Box<dyn OutputSink>) {
+    insert_typed(exec_scopes, &OUTPUT_SINK_KEY, sink);
+}
+
+// Wraps the current sink, the `DefaultSink` if none was set
+fn wrap_output_sink(
+    exec_scopes: &mut ExecutionScopes,
+    wrap: impl FnOnce(Box<dyn OutputSink>) -> Box<dyn OutputSink>,
+) {
+    let inner =
+        remove_typed(exec_scopes, &OUTPUT_SINK_KEY).unwrap_or_else(|| Box::new(DefaultSink));
+    set_boxed_output_sink(exec_scopes, wrap(inner));
+}
+
+/// Sends the info_* and debug_* records to `logger`, replacing `LOG_LEVEL_CAIRO`, over the
+/// current output sink.
+pub fn set_logger(exec_scopes: &mut ExecutionScopes, logger: impl CairoLogger + 'static) {
+    set_boxed_logger(exec_scopes, Box::new(logger));
+}
+
+pub fn set_boxed_logger(exec_scopes: &mut ExecutionScopes, logger: Box<dyn CairoLogger>) {
+    wrap_output_sink(exec_scopes, |inner| Box::new(LoggerSink { logger, inner }));
+}
+
+/// Sends the lines of the print_* hints to `sink`, over the current output sink.
+pub fn set_print_sink(exec_scopes: &mut ExecutionScopes, sink: impl PrintSink + 'static) {
+    set_boxed_print_sink(exec_scopes, Box::new(sink));
+}
+
+pub fn set_boxed_print_sink(exec_scopes: &mut ExecutionScopes, sink: Box<dyn PrintSink>) {
+    wrap_output_sink(exec_scopes, |inner| {
+        Box::new(PrintSinkAdapter { sink, inner })
+    });
+}
+
+// Without an injected logger, the level is the `LOG_LEVEL_CAIRO` string, defaulting to info
+fn default_filter(exec_scopes: &ExecutionScopes) -> LevelFilter {
+    let level = get_typed(exec_scopes, &LOG_LEVEL_KEY)
+        .ok()
+        .and_then(|level| LogLevel::parse(level).ok())
+        .unwrap_or(LogLevel::Info);
+    LevelFilter::new(Some(level))
+}
+
+fn with_output_sink<R>(
+    exec_scopes: &mut ExecutionScopes,
+    f: impl FnOnce(&mut dyn OutputSink, &LevelFilter) -> R,
+) -> R {
+    let filter = default_filter(exec_scopes);
+    match find_typed_mut(exec_scopes, &OUTPUT_SINK_KEY) {
+        Ok(Some(sink)) => f(sink.as_mut(), &filter),
+        _ => f(&mut DefaultSink, &filter),
+    }
+}
+
+/// Writes a line of print output, as the print_* hints do.
+pub fn print_line(exec_scopes: &mut ExecutionScopes, line: &str) {
+    with_output_sink(exec_scopes, |sink, _| {
+        sink.write(OutputKind::Print, TARGET_PRINT, line)
+    })
+}
+
+pub fn log_enabled(exec_scopes: &mut ExecutionScopes, level: LogLevel, target: &str) -> bool {
+    with_output_sink(exec_scopes, |sink, filter| {
+        sink.enabled(level, target, filter)
+    })
+}
+
+pub fn log(exec_scopes: &mut ExecutionScopes, level: LogLevel, target: &str, message: &str) {
+    with_output_sink(exec_scopes, |sink, filter| {
+        if sink.enabled(level, target, filter) {
+            sink.write(OutputKind::Log(level), target, message)
+        }
+    })
+}
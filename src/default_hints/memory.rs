@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData,
+        hint_code,
+        memcpy_hint_utils::{add_segment, enter_scope, exit_scope, memcpy_enter_scope},
+        memset_utils::{memset_enter_scope, memset_step_loop},
+        segments::{relocate_segment, temporary_array},
+    },
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+pub const HINT_ADD_SEGMENT: &str = hint_code::ADD_SEGMENT;
+pub const HINT_TEMPORARY_ARRAY: &str = hint_code::TEMPORARY_ARRAY;
+pub const HINT_RELOCATE_SEGMENT: &str = hint_code::RELOCATE_SEGMENT;
+pub const HINT_VM_ENTER_SCOPE: &str = hint_code::VM_ENTER_SCOPE;
+pub const HINT_VM_EXIT_SCOPE: &str = hint_code::VM_EXIT_SCOPE;
+
+// Loops of `memcpy` and `memset`, counting down `n` in the scope entered for the loop
+pub const HINT_MEMCPY_ENTER_SCOPE: &str = hint_code::MEMCPY_ENTER_SCOPE;
+pub const HINT_MEMCPY_CONTINUE_COPYING: &str = hint_code::MEMCPY_CONTINUE_COPYING;
+pub const HINT_MEMSET_ENTER_SCOPE: &str = hint_code::MEMSET_ENTER_SCOPE;
+pub const HINT_MEMSET_CONTINUE_LOOP: &str = hint_code::MEMSET_CONTINUE_LOOP;
+
+pub fn hint_add_segment(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    _hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    add_segment(vm)
+}
+
+pub fn hint_temporary_array(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    temporary_array(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_relocate_segment(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    relocate_segment(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_vm_enter_scope(
+    _vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    _hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    enter_scope(exec_scopes)
+}
+
+pub fn hint_vm_exit_scope(
+    _vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    _hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    exit_scope(exec_scopes)
+}
+
+pub fn hint_memcpy_enter_scope(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    memcpy_enter_scope(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_memcpy_continue_copying(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    memset_step_loop(
+        vm,
+        exec_scopes,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+        "continue_copying",
+    )
+}
+
+pub fn hint_memset_enter_scope(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    memset_enter_scope(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_memset_continue_loop(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    memset_step_loop(
+        vm,
+        exec_scopes,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+        "continue_loop",
+    )
+}
@@ -6,9 +6,38 @@ use cairo_vm::{
 };
 use std::collections::HashMap;
 
+pub mod assert;
+pub mod blake2s;
+pub mod breakpoint;
+pub mod budget;
 pub mod debug;
+pub mod dict;
+pub mod dict_manager;
+pub mod ed25519;
+pub mod find_element;
+pub mod keccak;
+pub mod logger;
+pub mod memory;
+pub mod poseidon;
+pub mod profiling;
+pub mod program_input;
+pub mod progress;
+pub mod proof;
+pub mod random;
+pub mod registry;
+pub mod rlp;
+pub mod secp;
 pub mod sha256;
+pub mod sha512;
+pub mod uint256;
+pub mod usort;
 pub mod utils;
+pub mod witness_file;
+
+#[cfg(test)]
+mod tests;
+
+pub use registry::{HintCall, HintMiddleware, HintRegistry};
 
 pub type HintImpl = fn(
     &mut VirtualMachine,
@@ -17,30 +46,308 @@ pub type HintImpl = fn(
     &HashMap<String, Felt252>,
 ) -> Result<(), HintError>;
 
-pub fn default_hint_mapping() -> HashMap<String, HintImpl> {
-    let mut hints = HashMap::<String, HintImpl>::new();
-    hints.insert(
-        sha256::HINT_SHA256_FINALIZE.into(),
-        sha256::hint_sha256_finalize,
-    );
-    hints.insert(debug::PRINT_FELT_HEX.into(), debug::print_felt_hex);
-    hints.insert(debug::PRINT_FELT.into(), debug::print_felt);
-    hints.insert(debug::PRINT_STRING.into(), debug::print_string);
-    hints.insert(debug::PRINT_UINT256.into(), debug::print_uint256);
-    hints.insert(debug::PRINT_UINT384.into(), debug::print_uint384);
-    hints.insert(utils::HINT_BIT_LENGTH.into(), utils::hint_bit_length);
-
-    hints.insert(debug::INFO_FELT.into(), debug::info_felt);
-    hints.insert(debug::INFO_FELT_HEX.into(), debug::info_felt_hex);
-    hints.insert(debug::INFO_STRING.into(), debug::info_string);
-    hints.insert(debug::INFO_UINT256.into(), debug::info_uint256);
-    hints.insert(debug::INFO_UINT384.into(), debug::info_uint384);
-
-    hints.insert(debug::DEBUG_FELT.into(), debug::debug_felt);
-    hints.insert(debug::DEBUG_FELT_HEX.into(), debug::debug_felt_hex);
-    hints.insert(debug::DEBUG_STRING.into(), debug::debug_string);
-    hints.insert(debug::DEBUG_UINT256.into(), debug::debug_uint256);
-    hints.insert(debug::DEBUG_UINT384.into(), debug::debug_uint384);
+pub fn default_hint_mapping() -> HintRegistry {
+    let default_hints: &[(&str, HintImpl)] = &[
+        (sha256::HINT_SHA256_INPUT, sha256::hint_sha256_input),
+        (
+            sha256::HINT_SHA256_MAIN_CONSTANT_INPUT_LENGTH,
+            sha256::hint_sha256_main_constant_input_length,
+        ),
+        (
+            sha256::HINT_SHA256_MAIN_ARBITRARY_INPUT_LENGTH,
+            sha256::hint_sha256_main_arbitrary_input_length,
+        ),
+        (sha256::HINT_SHA256_FINALIZE, sha256::hint_sha256_finalize),
+        (sha512::HINT_SHA512_INPUT, sha512::hint_sha512_input),
+        (
+            sha512::HINT_SHA512_MESSAGE_SCHEDULE,
+            sha512::hint_sha512_message_schedule,
+        ),
+        (sha512::HINT_SHA512_MAIN, sha512::hint_sha512_main),
+        (sha512::HINT_SHA512_FINALIZE, sha512::hint_sha512_finalize),
+        (blake2s::HINT_BLAKE2S_COMPUTE, blake2s::hint_blake2s_compute),
+        (
+            blake2s::HINT_BLAKE2S_FINALIZE,
+            blake2s::hint_blake2s_finalize,
+        ),
+        (
+            blake2s::HINT_BLAKE2S_FINALIZE_V2,
+            blake2s::hint_blake2s_finalize,
+        ),
+        (
+            blake2s::HINT_BLAKE2S_FINALIZE_V3,
+            blake2s::hint_blake2s_finalize_v3,
+        ),
+        (
+            blake2s::HINT_BLAKE2S_ADD_UINT256,
+            blake2s::hint_blake2s_add_uint256,
+        ),
+        (
+            blake2s::HINT_BLAKE2S_ADD_UINT256_BIGEND,
+            blake2s::hint_blake2s_add_uint256_bigend,
+        ),
+        (keccak::HINT_KECCAK_FINALIZE, keccak::hint_keccak_finalize),
+        (
+            keccak::HINT_BLOCK_PERMUTATION,
+            keccak::hint_block_permutation,
+        ),
+        (
+            keccak::HINT_KECCAK_WRITE_ARGS,
+            keccak::hint_keccak_write_args,
+        ),
+        (
+            keccak::HINT_LOAD_KECCAK_BYTES,
+            keccak::hint_load_keccak_bytes,
+        ),
+        (poseidon::HINT_POSEIDON_HASH, poseidon::hint_poseidon_hash),
+        (
+            poseidon::HINT_POSEIDON_HASH_MANY,
+            poseidon::hint_poseidon_hash_many,
+        ),
+        (
+            poseidon::HINT_HADES_PERMUTATION,
+            poseidon::hint_hades_permutation,
+        ),
+        (debug::PRINT_FELT_HEX, debug::print_felt_hex),
+        (debug::PRINT_FELT, debug::print_felt),
+        (debug::PRINT_FELT_SIGNED, debug::print_felt_signed),
+        (debug::PRINT_STRING, debug::print_string),
+        (debug::PRINT_UINT256, debug::print_uint256),
+        (debug::PRINT_UINT384, debug::print_uint384),
+        (debug::PRINT_ARRAY, debug::print_array),
+        (debug::PRINT_MEMORY_RANGE, debug::print_memory_range),
+        (debug::PRINT_RELOCATABLE, debug::print_relocatable),
+        (debug::PRINT_SEGMENT, debug::print_segment),
+        (
+            debug::HINT_DUMP_MEMORY_SNAPSHOT,
+            debug::dump_memory_snapshot,
+        ),
+        (uint256::HINT_UINT256_ADD, uint256::hint_uint256_add),
+        (uint256::HINT_UINT256_ADD_LOW, uint256::hint_uint256_add_low),
+        (uint256::HINT_UINT128_ADD, uint256::hint_uint128_add),
+        (uint256::HINT_UINT256_SUB, uint256::hint_uint256_sub),
+        (uint256::HINT_SPLIT_64, uint256::hint_split_64),
+        (uint256::HINT_UINT256_SQRT, uint256::hint_uint256_sqrt),
+        (
+            uint256::HINT_UINT256_SQRT_FELT,
+            uint256::hint_uint256_sqrt_felt,
+        ),
+        (
+            uint256::HINT_UINT256_SIGNED_NN,
+            uint256::hint_uint256_signed_nn,
+        ),
+        (
+            uint256::HINT_UINT256_UNSIGNED_DIV_REM,
+            uint256::hint_uint256_unsigned_div_rem,
+        ),
+        (
+            uint256::HINT_UINT256_EXPANDED_UNSIGNED_DIV_REM,
+            uint256::hint_uint256_expanded_unsigned_div_rem,
+        ),
+        (
+            uint256::HINT_UINT256_MUL_DIV_MOD,
+            uint256::hint_uint256_mul_div_mod,
+        ),
+        (utils::HINT_BIT_LENGTH, utils::hint_bit_length),
+        (utils::HINT_INV_MOD, utils::hint_inv_mod),
+        (utils::HINT_DIV_MOD, utils::hint_div_mod),
+        (utils::HINT_SQRT_MOD, utils::hint_sqrt_mod),
+        (memory::HINT_ADD_SEGMENT, memory::hint_add_segment),
+        (memory::HINT_TEMPORARY_ARRAY, memory::hint_temporary_array),
+        (memory::HINT_RELOCATE_SEGMENT, memory::hint_relocate_segment),
+        (memory::HINT_VM_ENTER_SCOPE, memory::hint_vm_enter_scope),
+        (memory::HINT_VM_EXIT_SCOPE, memory::hint_vm_exit_scope),
+        (
+            memory::HINT_MEMCPY_ENTER_SCOPE,
+            memory::hint_memcpy_enter_scope,
+        ),
+        (
+            memory::HINT_MEMCPY_CONTINUE_COPYING,
+            memory::hint_memcpy_continue_copying,
+        ),
+        (
+            memory::HINT_MEMSET_ENTER_SCOPE,
+            memory::hint_memset_enter_scope,
+        ),
+        (
+            memory::HINT_MEMSET_CONTINUE_LOOP,
+            memory::hint_memset_continue_loop,
+        ),
+        (dict::HINT_DICT_NEW, dict::hint_dict_new),
+        (dict::HINT_DEFAULT_DICT_NEW, dict::hint_default_dict_new),
+        (dict::HINT_DICT_READ, dict::hint_dict_read),
+        (dict::HINT_DICT_WRITE, dict::hint_dict_write),
+        (dict::HINT_DICT_UPDATE, dict::hint_dict_update),
+        (dict::HINT_SQUASH_DICT, dict::hint_squash_dict),
+        (
+            dict::HINT_SQUASH_DICT_INNER_FIRST_ITERATION,
+            dict::hint_squash_dict_inner_first_iteration,
+        ),
+        (
+            dict::HINT_SQUASH_DICT_INNER_SKIP_LOOP,
+            dict::hint_squash_dict_inner_skip_loop,
+        ),
+        (
+            dict::HINT_SQUASH_DICT_INNER_CHECK_ACCESS_INDEX,
+            dict::hint_squash_dict_inner_check_access_index,
+        ),
+        (
+            dict::HINT_SQUASH_DICT_INNER_CONTINUE_LOOP,
+            dict::hint_squash_dict_inner_continue_loop,
+        ),
+        (
+            dict::HINT_SQUASH_DICT_INNER_LEN_ASSERT,
+            dict::hint_squash_dict_inner_len_assert,
+        ),
+        (
+            dict::HINT_SQUASH_DICT_INNER_USED_ACCESSES_ASSERT,
+            dict::hint_squash_dict_inner_used_accesses_assert,
+        ),
+        (
+            dict::HINT_SQUASH_DICT_INNER_ASSERT_LEN_KEYS,
+            dict::hint_squash_dict_inner_assert_len_keys,
+        ),
+        (
+            dict::HINT_SQUASH_DICT_INNER_NEXT_KEY,
+            dict::hint_squash_dict_inner_next_key,
+        ),
+        (usort::HINT_USORT_ENTER_SCOPE, usort::hint_usort_enter_scope),
+        (usort::HINT_USORT_BODY, usort::hint_usort_body),
+        (usort::HINT_USORT_VERIFY, usort::hint_usort_verify),
+        (
+            usort::HINT_USORT_VERIFY_MULTIPLICITY_ASSERT,
+            usort::hint_usort_verify_multiplicity_assert,
+        ),
+        (
+            usort::HINT_USORT_VERIFY_MULTIPLICITY_BODY,
+            usort::hint_usort_verify_multiplicity_body,
+        ),
+        (
+            dict::HINT_DICT_SQUASH_COPY_DICT,
+            dict::hint_dict_squash_copy_dict,
+        ),
+        (
+            dict::HINT_DICT_SQUASH_UPDATE_PTR,
+            dict::hint_dict_squash_update_ptr,
+        ),
+        (
+            find_element::HINT_FIND_ELEMENT,
+            find_element::hint_find_element,
+        ),
+        (
+            find_element::HINT_SEARCH_SORTED_LOWER,
+            find_element::hint_search_sorted_lower,
+        ),
+        (find_element::HINT_SET_ADD, find_element::hint_set_add),
+        (secp::HINT_DIV_MOD_N, secp::hint_div_mod_n),
+        (secp::HINT_DIV_MOD_N_SAFE_DIV, secp::hint_div_mod_n_safe_div),
+        (secp::HINT_GET_POINT_FROM_X, secp::hint_get_point_from_x),
+        (secp::HINT_EC_NEGATE, secp::hint_ec_negate),
+        (secp::HINT_EC_DOUBLE_SLOPE, secp::hint_ec_double_slope),
+        (secp::HINT_COMPUTE_SLOPE, secp::hint_compute_slope),
+        (
+            secp::HINT_EC_DOUBLE_ASSIGN_NEW_X,
+            secp::hint_ec_double_assign_new_x,
+        ),
+        (
+            secp::HINT_EC_DOUBLE_ASSIGN_NEW_Y,
+            secp::hint_ec_double_assign_new_y,
+        ),
+        (
+            secp::HINT_FAST_EC_ADD_ASSIGN_NEW_X,
+            secp::hint_fast_ec_add_assign_new_x,
+        ),
+        (
+            secp::HINT_FAST_EC_ADD_ASSIGN_NEW_Y,
+            secp::hint_fast_ec_add_assign_new_y,
+        ),
+        (secp::HINT_NONDET_BIGINT3, secp::hint_nondet_bigint3),
+        (secp::HINT_VERIFY_ZERO, secp::hint_verify_zero),
+        (secp::HINT_REDUCE, secp::hint_reduce),
+        (secp::HINT_IS_ZERO_PACK, secp::hint_is_zero_pack),
+        (secp::HINT_IS_ZERO_NONDET, secp::hint_is_zero_nondet),
+        (
+            secp::HINT_IS_ZERO_ASSIGN_SCOPE_VARS,
+            secp::hint_is_zero_assign_scope_vars,
+        ),
+        (
+            ed25519::HINT_ED25519_DECOMPRESS,
+            ed25519::hint_ed25519_decompress,
+        ),
+        (
+            ed25519::HINT_UINT512_UNSIGNED_DIV_REM,
+            ed25519::hint_uint512_unsigned_div_rem,
+        ),
+        (
+            ed25519::HINT_INV_MOD_P_UINT256,
+            ed25519::hint_inv_mod_p_uint256,
+        ),
+        (
+            ed25519::HINT_INV_MOD_P_UINT512,
+            ed25519::hint_inv_mod_p_uint512,
+        ),
+        (ed25519::HINT_REDUCE, ed25519::hint_reduce),
+        (ed25519::HINT_VERIFY_ZERO, ed25519::hint_verify_zero),
+        (ed25519::HINT_IS_ZERO_PACK, ed25519::hint_is_zero_pack),
+        (
+            ed25519::HINT_IS_ZERO_ASSIGN_SCOPE_VARS,
+            ed25519::hint_is_zero_assign_scope_vars,
+        ),
+        (ed25519::HINT_EC_NEGATE, ed25519::hint_ec_negate),
+        (progress::HINT_PROGRESS, progress::hint_progress),
+        (breakpoint::HINT_BREAKPOINT, breakpoint::hint_breakpoint),
+        (
+            budget::HINT_CHECK_STEP_BUDGET,
+            budget::hint_check_step_budget,
+        ),
+        (
+            program_input::HINT_WRITE_PROGRAM_INPUT,
+            program_input::hint_write_program_input,
+        ),
+        (proof::HINT_LOAD_MPT_PROOF, proof::hint_load_mpt_proof),
+        (proof::HINT_MPT_NODE_TYPE, proof::hint_mpt_node_type),
+        (proof::HINT_MPT_KEY_NIBBLE, proof::hint_mpt_key_nibble),
+        (proof::HINT_MERKLE_PATH_BIT, proof::hint_merkle_path_bit),
+        (random::HINT_SEED_RANDOM, random::hint_seed_random),
+        (random::HINT_RANDOM_FELT, random::hint_random_felt),
+        (random::HINT_RANDOM_UINT256, random::hint_random_uint256),
+        (rlp::HINT_LOAD_RLP_BYTES, rlp::hint_load_rlp_bytes),
+        (rlp::HINT_RLP_ITEM_HEADER, rlp::hint_rlp_item_header),
+        (rlp::HINT_RLP_LIST_LEN, rlp::hint_rlp_list_len),
+        (
+            witness_file::HINT_LOAD_WITNESS_FILE,
+            witness_file::hint_load_witness_file,
+        ),
+        (
+            witness_file::HINT_LOAD_WITNESS_FELTS,
+            witness_file::hint_load_witness_felts,
+        ),
+        // INFO_FELT and DEBUG_FELT share their code with the string variants, which were the
+        // ones effectively registered when the hints lived in a plain HashMap
+        (debug::INFO_FELT_HEX, debug::info_felt_hex),
+        (debug::INFO_STRING, debug::info_string),
+        (debug::INFO_UINT256, debug::info_uint256),
+        (debug::INFO_UINT384, debug::info_uint384),
+        (debug::DEBUG_FELT_HEX, debug::debug_felt_hex),
+        (debug::DEBUG_STRING, debug::debug_string),
+        (debug::DEBUG_UINT256, debug::debug_uint256),
+        (debug::DEBUG_UINT384, debug::debug_uint384),
+    ];
 
+    let mut hints = HintRegistry::new(registry::DEFAULT_NAMESPACE);
+    hints
+        .register_all(default_hints.iter().copied())
+        .expect("default hints have unique codes");
+    let prefix_hints: &[(&str, HintImpl)] = &[
+        (assert::ASSERT_EQ_PREFIX, assert::hint_assert_eq),
+        (assert::ASSERT_LE_PREFIX, assert::hint_assert_le),
+        (profiling::START_TIMER_PREFIX, profiling::hint_start_timer),
+        (profiling::STOP_TIMER_PREFIX, profiling::hint_stop_timer),
+    ];
+    for (prefix, hint) in prefix_hints {
+        hints
+            .register_prefix(*prefix, *hint)
+            .expect("default hints have unique prefixes");
+    }
     hints
 }
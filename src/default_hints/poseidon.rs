@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData,
+        hint_utils::{
+            get_integer_from_var_name, get_ptr_from_var_name, insert_value_from_var_name,
+        },
+    },
+    types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_traits::ToPrimitive;
+use starknet_types_core::hash::{Poseidon, StarkHash};
+
+pub const HINT_POSEIDON_HASH: &str = r#"from starkware.cairo.common.poseidon_hash import poseidon_hash
+ids.res = poseidon_hash(ids.x, ids.y)"#;
+
+pub const HINT_POSEIDON_HASH_MANY: &str = r#"from starkware.cairo.common.poseidon_hash import poseidon_hash_many
+ids.res = poseidon_hash_many(memory.get_range(ids.elements, ids.n))"#;
+
+pub const HINT_HADES_PERMUTATION: &str = r#"from starkware.cairo.common.poseidon_utils import hades_permutation
+segments.write_arg(ids.output, hades_permutation(memory.get_range(ids.input, 3)))"#;
+
+/// Poseidon hash of two elements, matching `poseidon_hash(x, y)` in Cairo.
+pub fn poseidon_hash(x: &Felt252, y: &Felt252) -> Felt252 {
+    Poseidon::hash(x, y)
+}
+
+/// Sponge based Poseidon hash of an array, matching `poseidon_hash_many` in Cairo.
+pub fn poseidon_hash_many(elements: &[Felt252]) -> Felt252 {
+    Poseidon::hash_array(elements)
+}
+
+pub fn hint_poseidon_hash(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let x = get_integer_from_var_name("x", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let y = get_integer_from_var_name("y", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    insert_value_from_var_name(
+        "res",
+        poseidon_hash(&x, &y),
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    Ok(())
+}
+
+pub fn hint_poseidon_hash_many(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let elements =
+        get_ptr_from_var_name("elements", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let n = get_integer_from_var_name("n", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let n = n.to_usize().ok_or_else(|| {
+        HintError::CustomHint(format!("n does not fit in a usize: {n}").into_boxed_str())
+    })?;
+
+    let values = vm
+        .get_integer_range(elements, n)?
+        .into_iter()
+        .map(|value| value.into_owned())
+        .collect::<Vec<_>>();
+    insert_value_from_var_name(
+        "res",
+        poseidon_hash_many(&values),
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    Ok(())
+}
+
+pub fn hint_hades_permutation(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let input = get_ptr_from_var_name("input", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let output = get_ptr_from_var_name("output", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+
+    let mut state = [Felt252::ZERO; 3];
+    for (word, value) in state.iter_mut().zip(vm.get_integer_range(input, 3)?) {
+        *word = value.into_owned();
+    }
+    Poseidon::hades_permutation(&mut state);
+
+    let state = state.iter().map(MaybeRelocatable::from).collect::<Vec<_>>();
+    vm.load_data(output, &state)?;
+    Ok(())
+}
@@ -0,0 +1,180 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData, hint_utils::get_integer_from_var_name,
+    },
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use serde::{Deserialize, Serialize};
+
+use super::budget::check_step_budget;
+use crate::hint_processor::timer_start;
+use crate::scopes::{find_typed_mut, get_typed_mut, get_typed_ref, insert_typed, PROFILER_KEY};
+use crate::types::short_string::ShortString;
+
+// Exec scope variable holding the `Profiler`, stored in the root scope
+pub const PROFILER: &str = "__profiler";
+
+// Registered by prefix, the label being either a short string id, e.g.
+// `let label = 'hash blocks';` and `start_timer(ids.label)`, or a quoted literal as in
+// `start_timer("hash blocks")`
+pub const START_TIMER_PREFIX: &str = "start_timer(";
+pub const STOP_TIMER_PREFIX: &str = "stop_timer(";
+pub const HINT_START_TIMER: &str = "start_timer(ids.label)";
+pub const HINT_STOP_TIMER: &str = "stop_timer(ids.label)";
+
+/// Time and steps spent in a section over all its `start_timer` / `stop_timer` pairs. Time
+/// isn't measured on wasm32-unknown-unknown, where only steps are counted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SectionProfile {
+    pub count: u64,
+    pub total_nanos: u64,
+    pub steps: u64,
+}
+
+impl SectionProfile {
+    pub fn total(&self) -> Duration {
+        Duration::from_nanos(self.total_nanos)
+    }
+}
+
+/// Sections timed during a run, keyed by label.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfilingReport {
+    pub sections: BTreeMap<String, SectionProfile>,
+}
+
+impl ProfilingReport {
+    pub fn get(&self, label: &str) -> Option<&SectionProfile> {
+        self.sections.get(label)
+    }
+}
+
+// One line per section, most steps first
+impl fmt::Display for ProfilingReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sections = self.sections.iter().collect::<Vec<_>>();
+        sections.sort_by(|a, b| b.1.steps.cmp(&a.1.steps));
+        for (label, section) in sections {
+            writeln!(
+                f,
+                "{label}: {} steps, {:?} over {} runs",
+                section.steps,
+                section.total(),
+                section.count
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The running timers and the report of the stopped ones.
+#[derive(Debug, Clone, Default)]
+pub struct Profiler {
+    running: HashMap<String, (Option<Instant>, usize)>,
+    report: ProfilingReport,
+}
+
+impl Profiler {
+    pub fn start(&mut self, label: &str, step: usize) -> Result<(), HintError> {
+        if self.running.contains_key(label) {
+            return Err(timer_error(format!("timer {label} is already running")));
+        }
+        self.running
+            .insert(label.to_string(), (timer_start(), step));
+        Ok(())
+    }
+
+    pub fn stop(&mut self, label: &str, step: usize) -> Result<(), HintError> {
+        let (started, start_step) = self
+            .running
+            .remove(label)
+            .ok_or_else(|| timer_error(format!("timer {label} was not started")))?;
+        let section = self.report.sections.entry(label.to_string()).or_default();
+        section.count += 1;
+        let elapsed = started.map(|started| started.elapsed()).unwrap_or_default();
+        section.total_nanos = section
+            .total_nanos
+            .saturating_add(elapsed.as_nanos().try_into().unwrap_or(u64::MAX));
+        section.steps += step.saturating_sub(start_step) as u64;
+        Ok(())
+    }
+
+    /// Labels of the timers started but not stopped yet.
+    pub fn running(&self) -> impl Iterator<Item = &str> {
+        self.running.keys().map(String::as_str)
+    }
+
+    /// Step at which the running timer `label` was started.
+    pub fn start_step(&self, label: &str) -> Option<usize> {
+        self.running.get(label).map(|(_, step)| *step)
+    }
+
+    pub fn report(&self) -> &ProfilingReport {
+        &self.report
+    }
+}
+
+fn timer_error(message: String) -> HintError {
+    HintError::CustomHint(message.into_boxed_str())
+}
+
+/// The report of the sections timed so far, `None` if no timer was ever started.
+pub fn profiling_report(exec_scopes: &ExecutionScopes) -> Option<ProfilingReport> {
+    get_typed_ref(exec_scopes, &PROFILER_KEY)
+        .ok()
+        .map(|profiler| profiler.report().clone())
+}
+
+// The quoted literal, or the short string held by the id
+fn timer_label(vm: &VirtualMachine, hint_data: &HintProcessorData) -> Result<String, HintError> {
+    let invalid = || timer_error(format!("invalid timer hint: {}", hint_data.code));
+    let code = hint_data.code.trim();
+    let arg = code
+        .split_once('(')
+        .and_then(|(_, rest)| rest.strip_suffix(')'))
+        .ok_or_else(invalid)?
+        .trim();
+    if let Some(name) = arg.strip_prefix("ids.") {
+        let label =
+            get_integer_from_var_name(name, vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+        return Ok(ShortString(label).to_string());
+    }
+    arg.strip_prefix('"')
+        .and_then(|arg| arg.strip_suffix('"'))
+        .map(String::from)
+        .ok_or_else(invalid)
+}
+
+pub fn hint_start_timer(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let label = timer_label(vm, hint_data)?;
+    if find_typed_mut(exec_scopes, &PROFILER_KEY)?.is_none() {
+        insert_typed(exec_scopes, &PROFILER_KEY, Profiler::default());
+    }
+    get_typed_mut(exec_scopes, &PROFILER_KEY)?.start(&label, vm.get_current_step())
+}
+
+pub fn hint_stop_timer(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let label = timer_label(vm, hint_data)?;
+    // Catches a section over its budget even when no other hint ran in between
+    check_step_budget(vm, exec_scopes)?;
+    match find_typed_mut(exec_scopes, &PROFILER_KEY)? {
+        Some(profiler) => profiler.stop(&label, vm.get_current_step()),
+        None => Err(timer_error(format!("timer {label} was not started"))),
+    }
+}
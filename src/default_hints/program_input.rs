@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData,
+        hint_utils::{get_ptr_from_var_name, insert_value_from_var_name},
+    },
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::{HintImpl, HintRegistry};
+use crate::cairo_type::CairoWritable;
+use crate::fixture::record_input;
+use crate::provenance::write_tracked;
+use crate::scopes::{
+    get_typed, get_typed_ref, insert_typed, PROGRAM_INPUT_KEY, PROGRAM_INPUT_WRITABLE_KEY,
+};
+
+// Exec scope variables set by `runner::run_program`
+pub const PROGRAM_INPUT: &str = "program_input";
+pub const PROGRAM_INPUT_WRITABLE: &str = "program_input_writable";
+
+// Writes the run inputs to a new segment, `ids.input` being a pointer to the input struct
+pub const HINT_WRITE_PROGRAM_INPUT: &str = "ids.input = segments.gen_arg(program_input)";
+
+// Lays out the JSON `program_input` at `ids.input_ptr`, see `register_load_input`
+pub const HINT_LOAD_INPUT: &str = "segments.write_arg(ids.input_ptr, program_input)";
+
+/// JSON program input, stored in the exec scopes under `program_input` as cairo-lang does.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ProgramInput(pub serde_json::Value);
+
+impl ProgramInput {
+    pub fn from_json_str(s: &str) -> Result<Self, String> {
+        serde_json::from_str(s)
+            .map(Self)
+            .map_err(|e| format!("invalid program input: {e}"))
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        Self::from_json_str(&json)
+    }
+
+    pub fn parse<T: DeserializeOwned>(&self) -> Result<T, String> {
+        serde_json::from_value(self.0.clone()).map_err(|e| format!("invalid program input: {e}"))
+    }
+
+    pub fn insert_into(self, exec_scopes: &mut ExecutionScopes) {
+        insert_typed(exec_scopes, &PROGRAM_INPUT_KEY, self);
+    }
+}
+
+pub fn hint_write_program_input(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let input = get_typed(exec_scopes, &PROGRAM_INPUT_WRITABLE_KEY)?;
+    // The JSON form is only there for inputs representable as JSON
+    let program_input = get_typed_ref(exec_scopes, &PROGRAM_INPUT_KEY).ok().cloned();
+    if let Some(program_input) = program_input {
+        record_input(exec_scopes, PROGRAM_INPUT, &program_input)?;
+    }
+    let segment = vm.add_memory_segment();
+    write_tracked(vm, exec_scopes, segment, PROGRAM_INPUT, input.as_ref())?;
+    insert_value_from_var_name(
+        "input",
+        segment,
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    Ok(())
+}
+
+/// Parses the `program_input` of the exec scopes as `T` and writes it at `ids.input_ptr`.
+pub fn hint_load_input<T: DeserializeOwned + CairoWritable>(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let program_input = get_typed_ref(exec_scopes, &PROGRAM_INPUT_KEY)?.clone();
+    record_input(exec_scopes, PROGRAM_INPUT, &program_input)?;
+    let input = program_input
+        .parse::<T>()
+        .map_err(|e| HintError::CustomHint(e.into_boxed_str()))?;
+    let input_ptr =
+        get_ptr_from_var_name("input_ptr", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    write_tracked(vm, exec_scopes, input_ptr, PROGRAM_INPUT, &input)?;
+    Ok(())
+}
+
+/// Registers `HINT_LOAD_INPUT` for the program input type `T`.
+pub fn register_load_input<T: DeserializeOwned + CairoWritable>(
+    registry: &mut HintRegistry,
+) -> Result<(), String> {
+    registry.register(HINT_LOAD_INPUT, hint_load_input::<T> as HintImpl)
+}
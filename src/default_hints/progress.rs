@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData, hint_utils::get_integer_from_var_name,
+    },
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+use super::utils::felt_to_u64;
+use crate::scopes::{find_typed_mut, insert_typed, PROGRESS_REPORTER_KEY};
+
+// `label` is a Cairo short string, e.g. `let label = 'aggregating blocks';`
+pub const HINT_PROGRESS: &str = "progress(ids.current, ids.total, ids.label)";
+
+// Exec scope variable holding the `ProgressReporter`, stored in the root scope
+pub const PROGRESS_REPORTER: &str = "__progress_reporter";
+
+/// Host side receiver of the `progress` hint.
+pub trait ProgressReporter {
+    fn report(&mut self, current: u64, total: u64, label: &str);
+}
+
+/// Injects the reporter the `progress` hint reports to. Without one, the hint is a no-op.
+pub fn set_progress_reporter(
+    exec_scopes: &mut ExecutionScopes,
+    reporter: impl ProgressReporter + 'static,
+) {
+    let reporter: Box<dyn ProgressReporter> = Box::new(reporter);
+    insert_typed(exec_scopes, &PROGRESS_REPORTER_KEY, reporter);
+}
+
+pub fn hint_progress(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let Ok(Some(reporter)) = find_typed_mut(exec_scopes, &PROGRESS_REPORTER_KEY) else {
+        return Ok(());
+    };
+
+    let current =
+        get_integer_from_var_name("current", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let total =
+        get_integer_from_var_name("total", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let label =
+        get_integer_from_var_name("label", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+
+    let bytes = label.to_bytes_be();
+    let start = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    let label = String::from_utf8_lossy(&bytes[start..]);
+
+    reporter.report(
+        felt_to_u64(&current, "current")?,
+        felt_to_u64(&total, "total")?,
+        &label,
+    );
+    Ok(())
+}
+
+/// Reports progress as terminal progress bars, one per label.
+#[cfg(feature = "indicatif")]
+#[derive(Default)]
+pub struct IndicatifReporter {
+    multi: indicatif::MultiProgress,
+    bars: HashMap<String, indicatif::ProgressBar>,
+}
+
+#[cfg(feature = "indicatif")]
+impl IndicatifReporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(feature = "indicatif")]
+impl ProgressReporter for IndicatifReporter {
+    fn report(&mut self, current: u64, total: u64, label: &str) {
+        let multi = &self.multi;
+        let bar = self.bars.entry(label.to_string()).or_insert_with(|| {
+            let bar = multi.add(indicatif::ProgressBar::new(total));
+            bar.set_message(label.to_string());
+            if let Ok(style) = indicatif::ProgressStyle::with_template(
+                "{msg} [{elapsed_precise}] {wide_bar} {pos}/{len} ({eta})",
+            ) {
+                bar.set_style(style);
+            }
+            bar
+        });
+        bar.set_length(total);
+        bar.set_position(current);
+        if current >= total {
+            bar.finish();
+        }
+    }
+}
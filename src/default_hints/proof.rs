@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData,
+        hint_utils::{
+            get_integer_from_var_name, get_ptr_from_var_name, insert_value_from_var_name,
+        },
+    },
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_traits::ToPrimitive;
+
+use super::utils::{get_typed_from_var_name, write_typed_to_var_name};
+use crate::fixture::read_input;
+use crate::types::{proof::MptNode, proof::MptProof, uint256::Uint256};
+
+// Exec scope variable read by `load_mpt_proof`
+pub const MPT_PROOF: &str = "mpt_proof";
+
+// Writes the `MptProof` stored in the exec scopes under `mpt_proof` to the
+// `(n_nodes, nodes_bytes_len, nodes)` struct at `ids.proof`
+pub const HINT_LOAD_MPT_PROOF: &str =
+    r#"segments.write_arg(ids.proof.address_, mpt_proof.to_cairo())"#;
+
+// 0 for a branch, 1 for an extension and 2 for a leaf node
+pub const HINT_MPT_NODE_TYPE: &str =
+    r#"ids.node_type = mpt_node_type(ids.node, ids.node_bytes_len)"#;
+
+// Nibble of a 32-byte trie key, the most significant one first
+pub const HINT_MPT_KEY_NIBBLE: &str = r#"key = ids.key.high * 2 ** 128 + ids.key.low
+ids.nibble = (key >> (4 * (63 - ids.nibble_index))) & 0xf"#;
+
+// Bit of the leaf index at the current level of a Merkle proof, and the index one level up
+pub const HINT_MERKLE_PATH_BIT: &str = r#"ids.bit = ids.index % 2
+ids.next_index = ids.index // 2"#;
+
+fn custom_error(message: String) -> HintError {
+    HintError::CustomHint(message.into_boxed_str())
+}
+
+pub fn hint_load_mpt_proof(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let proof = read_input::<MptProof>(exec_scopes, MPT_PROOF)?;
+    write_typed_to_var_name("proof", &proof, vm, hint_data)
+}
+
+pub fn hint_mpt_node_type(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let node = get_ptr_from_var_name("node", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let n_bytes = get_integer_from_var_name(
+        "node_bytes_len",
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    let n_bytes = n_bytes
+        .to_usize()
+        .ok_or_else(|| custom_error(format!("invalid MPT node length {n_bytes}")))?;
+    let limbs: Vec<Felt252> = vm
+        .get_integer_range(node, n_bytes.div_ceil(8))?
+        .into_iter()
+        .map(|limb| *limb)
+        .collect();
+    let kind = MptNode::from_limbs(&limbs, n_bytes)
+        .and_then(|node| node.kind())
+        .map_err(custom_error)?;
+    insert_value_from_var_name(
+        "node_type",
+        kind.to_felt(),
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    Ok(())
+}
+
+pub fn hint_mpt_key_nibble(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let key: Uint256 = get_typed_from_var_name("key", vm, hint_data)?;
+    let index = get_integer_from_var_name(
+        "nibble_index",
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    let index = match index.to_usize() {
+        Some(index) if index < 64 => index,
+        _ => return Err(custom_error(format!("nibble index {index} out of range"))),
+    };
+    let nibble = (key.to_biguint() >> (4 * (63 - index))) & num_bigint::BigUint::from(0xfu8);
+    insert_value_from_var_name(
+        "nibble",
+        Felt252::from(nibble),
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    Ok(())
+}
+
+pub fn hint_merkle_path_bit(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let index =
+        get_integer_from_var_name("index", vm, &hint_data.ids_data, &hint_data.ap_tracking)?
+            .to_biguint();
+    let bit = &index % 2u32;
+    insert_value_from_var_name(
+        "bit",
+        Felt252::from(bit),
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    insert_value_from_var_name(
+        "next_index",
+        Felt252::from(index >> 1),
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData,
+        hint_utils::{get_integer_from_var_name, insert_value_from_var_name},
+    },
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use sha2::{Digest, Sha256};
+
+use super::utils::write_typed_to_var_name;
+use crate::cairo_type::BaseCairoType;
+use crate::fixture::record_oracle;
+use crate::scopes::{find_typed_mut, insert_typed, DETERMINISTIC_RNG_KEY};
+use crate::types::uint256::Uint256;
+
+// Exec scope variable holding the `DeterministicRng`, stored in the root scope
+pub const DETERMINISTIC_RNG: &str = "__deterministic_rng";
+
+// Seeds the generator from a felt, replacing any previous seed
+pub const HINT_SEED_RANDOM: &str = "seed_random(ids.seed)";
+
+// Draw from the seeded generator, failing if it was never seeded
+pub const HINT_RANDOM_FELT: &str = "ids.value = random_felt()";
+pub const HINT_RANDOM_UINT256: &str = "ids.value = random_uint256()";
+
+/// SHA-256 in counter mode: block `i` is `sha256(seed || i)` with `i` as a big-endian u64.
+/// Unlike host randomness, or PRNGs whose output may change between crate versions, the
+/// sequence only depends on the seed, so nondeterministic witnesses drawn from it are
+/// reproducible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeterministicRng {
+    seed: [u8; 32],
+    counter: u64,
+}
+
+impl DeterministicRng {
+    pub fn new(seed: [u8; 32]) -> Self {
+        Self { seed, counter: 0 }
+    }
+
+    pub fn from_felt(seed: Felt252) -> Self {
+        Self::new(seed.to_bytes_be())
+    }
+
+    pub fn from_u64(seed: u64) -> Self {
+        Self::from_felt(Felt252::from(seed))
+    }
+
+    /// Number of 32-byte blocks drawn so far.
+    pub fn counter(&self) -> u64 {
+        self.counter
+    }
+
+    pub fn next_block(&mut self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(self.seed);
+        hasher.update(self.counter.to_be_bytes());
+        self.counter += 1;
+        hasher.finalize().into()
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let block = self.next_block();
+        u64::from_be_bytes(block[..8].try_into().expect("8 bytes"))
+    }
+
+    /// Uniform over the field, drawing 252-bit values until one is below the prime.
+    pub fn next_felt(&mut self) -> Felt252 {
+        let max = Felt252::MAX.to_bytes_be();
+        loop {
+            let mut block = self.next_block();
+            block[0] &= 0x0f;
+            if block <= max {
+                return Felt252::from_bytes_be(&block);
+            }
+        }
+    }
+
+    pub fn next_uint256(&mut self) -> Uint256 {
+        Uint256::from_bytes_be(&self.next_block())
+    }
+}
+
+/// Seeds the generator the random hints draw from, restarting its sequence.
+pub fn seed_random(exec_scopes: &mut ExecutionScopes, rng: DeterministicRng) {
+    insert_typed(exec_scopes, &DETERMINISTIC_RNG_KEY, rng);
+}
+
+fn rng(exec_scopes: &mut ExecutionScopes) -> Result<&mut DeterministicRng, HintError> {
+    find_typed_mut(exec_scopes, &DETERMINISTIC_RNG_KEY)?.ok_or_else(|| {
+        HintError::CustomHint(
+            "no random seed set, seed it with `seed_random` before drawing values"
+                .to_string()
+                .into_boxed_str(),
+        )
+    })
+}
+
+pub fn hint_seed_random(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let seed = get_integer_from_var_name("seed", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    seed_random(exec_scopes, DeterministicRng::from_felt(seed));
+    Ok(())
+}
+
+pub fn hint_random_felt(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let value = rng(exec_scopes)?.next_felt();
+    record_oracle(exec_scopes, "random_felt", &value)?;
+    insert_value_from_var_name(
+        "value",
+        value,
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )
+}
+
+pub fn hint_random_uint256(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let value = rng(exec_scopes)?.next_uint256();
+    record_oracle(exec_scopes, "random_uint256", &value)?;
+    write_typed_to_var_name("value", &value, vm, hint_data)
+}
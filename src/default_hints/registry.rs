@@ -0,0 +1,227 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+use std::time::Duration;
+
+use cairo_vm::{types::relocatable::Relocatable, vm::errors::hint_errors::HintError};
+
+use super::HintImpl;
+
+// Namespace of the hints registered by `default_hint_mapping`
+pub const DEFAULT_NAMESPACE: &str = "cairo-vm-base";
+
+/// The hint being run, as seen by a `HintMiddleware`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HintCall<'a> {
+    pub code: &'a str,
+    pub pc: Relocatable,
+}
+
+/// Callbacks around every hint run by an `ExtendedHintProcessor`, whether it comes from the
+/// registry, an extension or the builtin processor, e.g. for logging, metrics, or injecting
+/// failures in tests.
+pub trait HintMiddleware {
+    /// Called before the hint runs. An error skips the hint and the `before` of the following
+    /// middleware, and becomes the result of the hint.
+    fn before(&mut self, _call: &HintCall) -> Result<(), HintError> {
+        Ok(())
+    }
+
+    /// Called after the hint with the time it took, returning its result, possibly replaced.
+    fn after(
+        &mut self,
+        _call: &HintCall,
+        _elapsed: Duration,
+        result: Result<(), HintError>,
+    ) -> Result<(), HintError> {
+        result
+    }
+}
+
+// Shared with the clones of the registry, so the state of a middleware outlives the processor
+pub type SharedMiddleware = Rc<RefCell<dyn HintMiddleware>>;
+
+/// Hint code to implementation mapping, keeping track of which namespace (usually a crate)
+/// registered each hint so that sets of hints can be composed without silent overwrites.
+#[derive(Clone, Default)]
+pub struct HintRegistry {
+    namespace: String,
+    hints: HashMap<String, (String, HintImpl)>,
+    // Hints matching every code that starts with the prefix, as (prefix, namespace, hint)
+    prefixes: Vec<(String, String, HintImpl)>,
+    middleware: Vec<SharedMiddleware>,
+}
+
+impl fmt::Debug for HintRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HintRegistry")
+            .field("namespace", &self.namespace)
+            .field("hints", &self.hints)
+            .field("prefixes", &self.prefixes)
+            .field("middleware", &self.middleware.len())
+            .finish()
+    }
+}
+
+impl HintRegistry {
+    pub fn new(namespace: &str) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            hints: HashMap::new(),
+            prefixes: vec![],
+            middleware: vec![],
+        }
+    }
+
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// Registers a hint under this registry's namespace, failing if the code is already taken.
+    pub fn register(&mut self, code: impl Into<String>, hint: HintImpl) -> Result<(), String> {
+        let code = code.into();
+        if let Some((namespace, _)) = self.hints.get(&code) {
+            return Err(format!(
+                "hint already registered by {namespace}, can't register it for {}: {code}",
+                self.namespace
+            ));
+        }
+        self.hints.insert(code, (self.namespace.clone(), hint));
+        Ok(())
+    }
+
+    pub fn register_all<C: Into<String>>(
+        &mut self,
+        hints: impl IntoIterator<Item = (C, HintImpl)>,
+    ) -> Result<(), String> {
+        hints
+            .into_iter()
+            .try_for_each(|(code, hint)| self.register(code, hint))
+    }
+
+    /// Registers a hint for every code starting with `prefix`, for hints whose code carries its
+    /// own arguments, e.g. `assert_eq(ids.a, ids.b)  # main.cairo:12`. Exact codes take
+    /// precedence, then the longest matching prefix. Fails if the prefix is already taken.
+    pub fn register_prefix(
+        &mut self,
+        prefix: impl Into<String>,
+        hint: HintImpl,
+    ) -> Result<(), String> {
+        let prefix = prefix.into();
+        if let Some((_, namespace, _)) = self.prefixes.iter().find(|(p, _, _)| *p == prefix) {
+            return Err(format!(
+                "hint prefix already registered by {namespace}, can't register it for {}: {prefix}",
+                self.namespace
+            ));
+        }
+        self.prefixes.push((prefix, self.namespace.clone(), hint));
+        Ok(())
+    }
+
+    /// Replaces the implementation of a hint, or registers it if missing.
+    /// Returns the previous implementation, if any.
+    pub fn override_hint(&mut self, code: impl Into<String>, hint: HintImpl) -> Option<HintImpl> {
+        self.hints
+            .insert(code.into(), (self.namespace.clone(), hint))
+            .map(|(_, previous)| previous)
+    }
+
+    /// Wraps every hint with `middleware`. `before` callbacks run in the order the middleware
+    /// was added and `after` callbacks in reverse order.
+    pub fn add_middleware(&mut self, middleware: impl HintMiddleware + 'static) {
+        self.add_shared_middleware(Rc::new(RefCell::new(middleware)));
+    }
+
+    /// Adds middleware whose state is also kept by the caller, e.g. to read metrics after a run.
+    pub fn add_shared_middleware(&mut self, middleware: SharedMiddleware) {
+        self.middleware.push(middleware);
+    }
+
+    pub fn middleware(&self) -> &[SharedMiddleware] {
+        &self.middleware
+    }
+
+    /// Adds all the hints of `other`, keeping their namespaces, and then its middleware. Nothing
+    /// is added if any code or prefix is already registered.
+    pub fn merge(&mut self, other: HintRegistry) -> Result<(), String> {
+        let mut collisions = other
+            .hints
+            .iter()
+            .filter_map(|(code, (namespace, _))| {
+                self.hints.get(code).map(|(existing, _)| {
+                    format!("{code} (registered by {existing} and {namespace})")
+                })
+            })
+            .chain(other.prefixes.iter().filter_map(|(prefix, namespace, _)| {
+                self.prefixes
+                    .iter()
+                    .find(|(p, _, _)| p == prefix)
+                    .map(|(_, existing, _)| {
+                        format!("{prefix}* (registered by {existing} and {namespace})")
+                    })
+            }))
+            .collect::<Vec<_>>();
+        if !collisions.is_empty() {
+            collisions.sort();
+            return Err(format!(
+                "conflicting hint codes when merging {} into {}: {}",
+                other.namespace,
+                self.namespace,
+                collisions.join(", ")
+            ));
+        }
+        self.hints.extend(other.hints);
+        self.prefixes.extend(other.prefixes);
+        self.middleware.extend(other.middleware);
+        Ok(())
+    }
+
+    // Namespace and implementation of the exact code, or else of its longest registered prefix
+    fn lookup(&self, code: &str) -> Option<(&str, HintImpl)> {
+        if let Some((namespace, hint)) = self.hints.get(code) {
+            return Some((namespace, *hint));
+        }
+        self.prefixes
+            .iter()
+            .filter(|(prefix, _, _)| code.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _, _)| prefix.len())
+            .map(|(_, namespace, hint)| (namespace.as_str(), *hint))
+    }
+
+    pub fn get(&self, code: &str) -> Option<HintImpl> {
+        self.lookup(code).map(|(_, hint)| hint)
+    }
+
+    pub fn contains(&self, code: &str) -> bool {
+        self.lookup(code).is_some()
+    }
+
+    // Namespace that registered the hint
+    pub fn namespace_of(&self, code: &str) -> Option<&str> {
+        self.lookup(code).map(|(namespace, _)| namespace)
+    }
+
+    // Hints registered with an exact code, prefixes excluded
+    pub fn len(&self) -> usize {
+        self.hints.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hints.is_empty() && self.prefixes.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, HintImpl)> {
+        self.hints
+            .iter()
+            .map(|(code, (_, hint))| (code.as_str(), *hint))
+    }
+
+    // The exact codes only, hints registered by prefix need the registry itself
+    pub fn into_map(self) -> HashMap<String, HintImpl> {
+        self.hints
+            .into_iter()
+            .map(|(code, (_, hint))| (code, hint))
+            .collect()
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData,
+        hint_utils::{
+            get_integer_from_var_name, get_ptr_from_var_name, insert_value_from_var_name,
+        },
+    },
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_traits::ToPrimitive;
+
+use super::utils::write_typed_to_var_name;
+use crate::fixture::read_input;
+use crate::types::keccak_bytes::bytes_from_le_u64_limbs;
+use crate::types::rlp::{list_items, RlpBytes, RlpHeader};
+
+// Exec scope variable read by `load_rlp_bytes`
+pub const RLP_BYTES: &str = "rlp_bytes";
+
+// Loads the `RlpBytes` stored in the exec scopes under `rlp_bytes` as little-endian u64 words
+pub const HINT_LOAD_RLP_BYTES: &str = r#"ids.rlp = segments.gen_arg(rlp_bytes.to_limbs())
+ids.rlp_len = len(rlp_bytes)"#;
+
+// Header of the item starting at byte `ids.item_start` of the encoding, the payload start being
+// an offset in the encoding as well
+pub const HINT_RLP_ITEM_HEADER: &str = r#"header = decode_rlp_header(ids.rlp, ids.rlp_len, ids.item_start)
+ids.is_list = header.is_list
+ids.payload_start = ids.item_start + header.offset
+ids.payload_len = header.len"#;
+
+// Number of items of the list starting at byte `ids.item_start` of the encoding
+pub const HINT_RLP_LIST_LEN: &str =
+    r#"ids.n_items = len(decode_rlp_list(ids.rlp, ids.rlp_len, ids.item_start))"#;
+
+fn custom_error(message: String) -> HintError {
+    HintError::CustomHint(message.into_boxed_str())
+}
+
+fn get_usize(
+    name: &str,
+    vm: &VirtualMachine,
+    hint_data: &HintProcessorData,
+) -> Result<usize, HintError> {
+    let value = get_integer_from_var_name(name, vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    value
+        .to_usize()
+        .ok_or_else(|| custom_error(format!("{name} out of range: {value}")))
+}
+
+// The encoding from `ids.item_start` on
+fn read_item(vm: &VirtualMachine, hint_data: &HintProcessorData) -> Result<Vec<u8>, HintError> {
+    let rlp = get_ptr_from_var_name("rlp", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let rlp_len = get_usize("rlp_len", vm, hint_data)?;
+    let item_start = get_usize("item_start", vm, hint_data)?;
+    if item_start >= rlp_len {
+        return Err(custom_error(format!(
+            "item start {item_start} past the {rlp_len} bytes of the encoding"
+        )));
+    }
+    let limbs: Vec<Felt252> = vm
+        .get_integer_range(rlp, rlp_len.div_ceil(8))?
+        .into_iter()
+        .map(|limb| *limb)
+        .collect();
+    let mut bytes = bytes_from_le_u64_limbs(&limbs, rlp_len).map_err(custom_error)?;
+    Ok(bytes.split_off(item_start))
+}
+
+pub fn hint_load_rlp_bytes(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let rlp_bytes = read_input::<RlpBytes>(exec_scopes, RLP_BYTES)?;
+    write_typed_to_var_name("rlp", &rlp_bytes, vm, hint_data)?;
+    insert_value_from_var_name(
+        "rlp_len",
+        Felt252::from(rlp_bytes.n_bytes()),
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    Ok(())
+}
+
+pub fn hint_rlp_item_header(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let item = read_item(vm, hint_data)?;
+    let header = RlpHeader::decode(&item).map_err(custom_error)?;
+    let item_start = get_usize("item_start", vm, hint_data)?;
+    for (name, value) in [
+        ("is_list", Felt252::from(header.is_list as u8)),
+        ("payload_start", Felt252::from(item_start + header.offset)),
+        ("payload_len", Felt252::from(header.len)),
+    ] {
+        insert_value_from_var_name(name, value, vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    }
+    Ok(())
+}
+
+pub fn hint_rlp_list_len(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let item = read_item(vm, hint_data)?;
+    // Only the list at the start matters, not what follows it in the encoding
+    let item_len = RlpHeader::decode(&item).map_err(custom_error)?.item_len();
+    let n_items = list_items(&item[..item_len]).map_err(custom_error)?.len();
+    insert_value_from_var_name(
+        "n_items",
+        Felt252::from(n_items),
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,288 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData,
+        hint_code,
+        secp::{
+            bigint_utils::nondet_bigint3,
+            ec_utils::{
+                compute_doubling_slope, compute_slope_and_assing_secp_p, ec_double_assign_new_x,
+                ec_double_assign_new_y, ec_negate_import_secp_p, fast_ec_add_assign_new_x,
+                fast_ec_add_assign_new_y,
+            },
+            field_utils::{
+                is_zero_assign_scope_variables, is_zero_nondet, is_zero_pack, reduce_v1,
+                verify_zero,
+            },
+            signature::{div_mod_n_packed_divmod, div_mod_n_safe_div, get_point_from_x},
+        },
+    },
+    types::{exec_scope::ExecutionScopes, relocatable::Relocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::{BigInt, BigUint};
+use num_traits::Zero;
+
+use crate::cairo_type::write_felts;
+use crate::types::ec_point::{Curve, Secp256k1, Secp256k1Point};
+use crate::types::uint384::UInt384;
+
+// Hints of starkware.cairo.common.cairo_secp, which operates on BigInt3 values (three 86 bit
+// limbs). The codes are cairo-vm's, the hints only pin the secp256k1 parameters.
+pub const HINT_DIV_MOD_N: &str = hint_code::DIV_MOD_N_PACKED_DIVMOD_V1;
+pub const HINT_DIV_MOD_N_SAFE_DIV: &str = hint_code::DIV_MOD_N_SAFE_DIV;
+pub const HINT_GET_POINT_FROM_X: &str = hint_code::GET_POINT_FROM_X;
+pub const HINT_EC_NEGATE: &str = hint_code::EC_NEGATE;
+pub const HINT_EC_DOUBLE_SLOPE: &str = hint_code::EC_DOUBLE_SLOPE_V1;
+pub const HINT_COMPUTE_SLOPE: &str = hint_code::COMPUTE_SLOPE_V1;
+pub const HINT_EC_DOUBLE_ASSIGN_NEW_X: &str = hint_code::EC_DOUBLE_ASSIGN_NEW_X_V1;
+pub const HINT_EC_DOUBLE_ASSIGN_NEW_Y: &str = hint_code::EC_DOUBLE_ASSIGN_NEW_Y;
+pub const HINT_FAST_EC_ADD_ASSIGN_NEW_X: &str = hint_code::FAST_EC_ADD_ASSIGN_NEW_X;
+pub const HINT_FAST_EC_ADD_ASSIGN_NEW_Y: &str = hint_code::FAST_EC_ADD_ASSIGN_NEW_Y;
+pub const HINT_NONDET_BIGINT3: &str = hint_code::NONDET_BIGINT3_V1;
+pub const HINT_VERIFY_ZERO: &str = hint_code::VERIFY_ZERO_V1;
+pub const HINT_REDUCE: &str = hint_code::REDUCE_V1;
+pub const HINT_IS_ZERO_PACK: &str = hint_code::IS_ZERO_PACK_V1;
+pub const HINT_IS_ZERO_NONDET: &str = hint_code::IS_ZERO_NONDET;
+pub const HINT_IS_ZERO_ASSIGN_SCOPE_VARS: &str = hint_code::IS_ZERO_ASSIGN_SCOPE_VARS;
+
+pub const BIGINT3_LIMB_BITS: usize = 86;
+
+fn secp_p() -> BigInt {
+    Secp256k1::p().into()
+}
+
+/// Splits `value` into the three 86 bit limbs of a BigInt3, `value` being below 2^258.
+pub fn split_bigint3(value: &BigUint) -> [Felt252; 3] {
+    let mask = (BigUint::from(1u8) << BIGINT3_LIMB_BITS) - 1u8;
+    std::array::from_fn(|i| Felt252::from(&((value >> (i * BIGINT3_LIMB_BITS)) & &mask)))
+}
+
+/// Packs BigInt3 limbs, reading them as signed felts like `secp_utils.pack` does.
+pub fn pack_bigint3(limbs: &[Felt252; 3]) -> BigInt {
+    limbs.iter().rev().fold(BigInt::zero(), |acc, limb| {
+        (acc << BIGINT3_LIMB_BITS) + limb.to_bigint()
+    })
+}
+
+/// Writes `point` as a cairo_secp `EcPoint { x: BigInt3, y: BigInt3 }`.
+pub fn write_point_bigint3(
+    vm: &mut VirtualMachine,
+    address: Relocatable,
+    point: &Secp256k1Point,
+) -> Result<Relocatable, HintError> {
+    let x = split_bigint3(&point.x.to_biguint());
+    let y = split_bigint3(&point.y.to_biguint());
+    write_felts(vm, address, x.into_iter().chain(y))
+}
+
+/// Reads a cairo_secp `EcPoint`, reducing its coordinates mod p.
+pub fn read_point_bigint3(
+    vm: &VirtualMachine,
+    address: Relocatable,
+) -> Result<Secp256k1Point, HintError> {
+    let felts = vm.get_integer_range(address, 6)?;
+    let coordinate = |limbs: &[std::borrow::Cow<Felt252>]| {
+        let limbs = [*limbs[0], *limbs[1], *limbs[2]];
+        let value = pack_bigint3(&limbs) % secp_p();
+        let value = if value < BigInt::zero() {
+            value + secp_p()
+        } else {
+            value
+        };
+        UInt384::new(value.to_biguint().unwrap())
+    };
+    Ok(Secp256k1Point::new(
+        coordinate(&felts[..3]),
+        coordinate(&felts[3..]),
+    ))
+}
+
+pub fn hint_div_mod_n(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    div_mod_n_packed_divmod(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_div_mod_n_safe_div(
+    _vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    _hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    div_mod_n_safe_div(exec_scopes, "a", "b", 0)
+}
+
+pub fn hint_get_point_from_x(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    get_point_from_x(
+        vm,
+        exec_scopes,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+        constants,
+    )
+}
+
+pub fn hint_ec_negate(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    ec_negate_import_secp_p(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_ec_double_slope(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    compute_doubling_slope(
+        vm,
+        exec_scopes,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+        "point",
+        &secp_p(),
+        &Secp256k1::a().into(),
+    )
+}
+
+pub fn hint_compute_slope(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    compute_slope_and_assing_secp_p(
+        vm,
+        exec_scopes,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+        "point0",
+        "point1",
+        &secp_p(),
+    )
+}
+
+pub fn hint_ec_double_assign_new_x(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    ec_double_assign_new_x(
+        vm,
+        exec_scopes,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+        &secp_p(),
+        "point",
+    )
+}
+
+pub fn hint_ec_double_assign_new_y(
+    _vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    _hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    ec_double_assign_new_y(exec_scopes)
+}
+
+pub fn hint_fast_ec_add_assign_new_x(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    fast_ec_add_assign_new_x(
+        vm,
+        exec_scopes,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+        &secp_p(),
+        "point0",
+        "point1",
+    )
+}
+
+pub fn hint_fast_ec_add_assign_new_y(
+    _vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    _hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    fast_ec_add_assign_new_y(exec_scopes)
+}
+
+pub fn hint_nondet_bigint3(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    nondet_bigint3(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_verify_zero(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    verify_zero(
+        vm,
+        exec_scopes,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+        &secp_p(),
+    )
+}
+
+pub fn hint_reduce(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    reduce_v1(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_is_zero_pack(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    is_zero_pack(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_is_zero_nondet(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    _hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    is_zero_nondet(vm, exec_scopes)
+}
+
+pub fn hint_is_zero_assign_scope_vars(
+    _vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    _hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    is_zero_assign_scope_variables(exec_scopes)
+}
@@ -2,14 +2,27 @@ use std::collections::HashMap;
 
 use cairo_vm::{
     hint_processor::builtin_hint_processor::{
-        builtin_hint_processor_definition::HintProcessorData, sha256_utils::sha256_finalize,
+        builtin_hint_processor_definition::HintProcessorData,
+        hint_code,
+        sha256_utils::{
+            sha256_finalize, sha256_input, sha256_main_arbitrary_input_length,
+            sha256_main_constant_input_length,
+        },
     },
     types::exec_scope::ExecutionScopes,
     vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
     Felt252,
 };
 
-// There is a mismatch in the line breaks, temp fix while investigating
+// Chunk loop of `_sha256_input`, padding the last word when fewer than 4 bytes are left
+pub const HINT_SHA256_INPUT: &str = hint_code::SHA256_INPUT;
+pub const HINT_SHA256_MAIN_CONSTANT_INPUT_LENGTH: &str =
+    hint_code::SHA256_MAIN_CONSTANT_INPUT_LENGTH;
+pub const HINT_SHA256_MAIN_ARBITRARY_INPUT_LENGTH: &str =
+    hint_code::SHA256_MAIN_ARBITRARY_INPUT_LENGTH;
+
+// The line breaks of the imports differ from `hint_code::SHA256_FINALIZE`, which the builtin
+// processor still runs
 pub const HINT_SHA256_FINALIZE: &str = r#"# Add dummy pairs of input and output.
 from starkware.cairo.common.cairo_sha256.sha256_utils import (
     IV,
@@ -28,6 +41,35 @@ output = sha2_compress_function(IV, w)
 padding = (message + IV + output) * number_of_missing_blocks
 segments.write_arg(ids.sha256_ptr_end, padding)"#;
 
+pub fn hint_sha256_input(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    sha256_input(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+// Compresses the block at `ids.sha256_start` from the initial state
+pub fn hint_sha256_main_constant_input_length(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    sha256_main_constant_input_length(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
+}
+
+// Compresses the block at `ids.sha256_start` from the state at `ids.state`
+pub fn hint_sha256_main_arbitrary_input_length(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    sha256_main_arbitrary_input_length(vm, &hint_data.ids_data, &hint_data.ap_tracking, constants)
+}
+
 pub fn hint_sha256_finalize(
     vm: &mut VirtualMachine,
     _exec_scopes: &mut ExecutionScopes,
@@ -0,0 +1,277 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData,
+        hint_utils::{
+            get_constant_from_var_name, get_integer_from_var_name, get_ptr_from_var_name,
+            insert_value_from_var_name,
+        },
+    },
+    types::{
+        exec_scope::ExecutionScopes,
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+
+use crate::cairo_type::write_felts;
+
+pub const SHA512_INPUT_CHUNK_SIZE_FELTS: usize = 16;
+pub const SHA512_STATE_SIZE_FELTS: usize = 8;
+pub const SHA512_SCHEDULE_SIZE_FELTS: usize = 80;
+
+pub const IV: [u64; SHA512_STATE_SIZE_FELTS] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+// Chunk loop of `_sha512_input`, padding the last word when fewer than 8 bytes are left
+pub const HINT_SHA512_INPUT: &str = "ids.full_word = int(ids.n_bytes >= 8)";
+
+pub const HINT_SHA512_MESSAGE_SCHEDULE: &str = r#"from starkware.cairo.common.cairo_sha512.sha512_utils import compute_message_schedule
+
+_sha512_input_chunk_size_felts = ids.SHA512_INPUT_CHUNK_SIZE_FELTS
+assert 0 <= _sha512_input_chunk_size_felts < 100
+w = compute_message_schedule(memory.get_range(ids.sha512_start, _sha512_input_chunk_size_felts))
+segments.write_arg(ids.output, w)"#;
+
+pub const HINT_SHA512_MAIN: &str = r#"from starkware.cairo.common.cairo_sha512.sha512_utils import (
+    compute_message_schedule,
+    sha512_compress_function,
+)
+
+_sha512_input_chunk_size_felts = ids.SHA512_INPUT_CHUNK_SIZE_FELTS
+assert 0 <= _sha512_input_chunk_size_felts < 100
+_sha512_state_size_felts = ids.SHA512_STATE_SIZE_FELTS
+assert 0 <= _sha512_state_size_felts < 100
+w = compute_message_schedule(memory.get_range(ids.sha512_start, _sha512_input_chunk_size_felts))
+new_state = sha512_compress_function(memory.get_range(ids.state, _sha512_state_size_felts), w)
+segments.write_arg(ids.output, new_state)"#;
+
+pub const HINT_SHA512_FINALIZE: &str = r#"# Add dummy pairs of input and output.
+from starkware.cairo.common.cairo_sha512.sha512_utils import (
+    IV,
+    compute_message_schedule,
+    sha512_compress_function,
+)
+
+number_of_missing_blocks = (-ids.n) % ids.BATCH_SIZE
+assert 0 <= number_of_missing_blocks < 20
+_sha512_input_chunk_size_felts = ids.SHA512_INPUT_CHUNK_SIZE_FELTS
+assert 0 <= _sha512_input_chunk_size_felts < 100
+
+message = [0] * _sha512_input_chunk_size_felts
+w = compute_message_schedule(message)
+output = sha512_compress_function(IV, w)
+padding = (message + IV + output) * number_of_missing_blocks
+segments.write_arg(ids.sha512_ptr_end, padding)"#;
+
+// Checks a size constant of the Cairo library against the one this implementation expects
+fn check_size_constant(
+    name: &'static str,
+    expected: usize,
+    constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let value = get_constant_from_var_name(name, constants)?;
+    if value.to_usize() != Some(expected) {
+        return Err(HintError::InvalidValue(Box::new((
+            name,
+            *value,
+            Felt252::from(expected),
+        ))));
+    }
+    Ok(())
+}
+
+fn read_words<const N: usize>(
+    vm: &VirtualMachine,
+    address: Relocatable,
+) -> Result<[u64; N], HintError> {
+    let mut words = [0u64; N];
+    for (i, word) in words.iter_mut().enumerate() {
+        let word_address = (address + i)?;
+        let value = vm.get_integer(word_address)?;
+        *word = value.to_u64().ok_or_else(|| {
+            HintError::CustomHint(
+                format!("sha512 word at {word_address} does not fit in 64 bits: {value}")
+                    .into_boxed_str(),
+            )
+        })?;
+    }
+    Ok(words)
+}
+
+/// Expands a 16 word block into the 80 word message schedule.
+pub fn message_schedule(
+    block: &[u64; SHA512_INPUT_CHUNK_SIZE_FELTS],
+) -> [u64; SHA512_SCHEDULE_SIZE_FELTS] {
+    let mut w = [0u64; SHA512_SCHEDULE_SIZE_FELTS];
+    w[..SHA512_INPUT_CHUNK_SIZE_FELTS].copy_from_slice(block);
+    for i in SHA512_INPUT_CHUNK_SIZE_FELTS..SHA512_SCHEDULE_SIZE_FELTS {
+        let s0 = w[i - 15].rotate_right(1) ^ w[i - 15].rotate_right(8) ^ (w[i - 15] >> 7);
+        let s1 = w[i - 2].rotate_right(19) ^ w[i - 2].rotate_right(61) ^ (w[i - 2] >> 6);
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+    }
+    w
+}
+
+/// Compresses a 16 word block into `state`.
+pub fn compress(
+    state: &mut [u64; SHA512_STATE_SIZE_FELTS],
+    block: &[u64; SHA512_INPUT_CHUNK_SIZE_FELTS],
+) {
+    let mut bytes = [0u8; 128];
+    for (chunk, word) in bytes.chunks_exact_mut(8).zip(block) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    sha2::compress512(state, &[bytes.into()]);
+}
+
+fn write_words(
+    vm: &mut VirtualMachine,
+    address: Relocatable,
+    words: &[u64],
+) -> Result<Relocatable, HintError> {
+    write_felts(vm, address, words.iter().map(|word| Felt252::from(*word)))
+}
+
+pub fn hint_sha512_input(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let n_bytes =
+        get_integer_from_var_name("n_bytes", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let full_word = if n_bytes >= Felt252::from(8) {
+        Felt252::ONE
+    } else {
+        Felt252::ZERO
+    };
+    insert_value_from_var_name(
+        "full_word",
+        full_word,
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )
+}
+
+pub fn hint_sha512_message_schedule(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    check_size_constant(
+        "SHA512_INPUT_CHUNK_SIZE_FELTS",
+        SHA512_INPUT_CHUNK_SIZE_FELTS,
+        constants,
+    )?;
+    let input = get_ptr_from_var_name(
+        "sha512_start",
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    let output = get_ptr_from_var_name("output", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let w = message_schedule(&read_words(vm, input)?);
+    write_words(vm, output, &w)?;
+    Ok(())
+}
+
+// Compresses the block at `ids.sha512_start` from the state at `ids.state`
+pub fn hint_sha512_main(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    check_size_constant(
+        "SHA512_INPUT_CHUNK_SIZE_FELTS",
+        SHA512_INPUT_CHUNK_SIZE_FELTS,
+        constants,
+    )?;
+    check_size_constant(
+        "SHA512_STATE_SIZE_FELTS",
+        SHA512_STATE_SIZE_FELTS,
+        constants,
+    )?;
+    let input = get_ptr_from_var_name(
+        "sha512_start",
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    let state = get_ptr_from_var_name("state", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let output = get_ptr_from_var_name("output", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+
+    let mut new_state = read_words(vm, state)?;
+    compress(&mut new_state, &read_words(vm, input)?);
+    write_words(vm, output, &new_state)?;
+    Ok(())
+}
+
+// Fills the batch with (zero block, IV, compressed output) entries, as the Cairo side expects
+pub fn hint_sha512_finalize(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    check_size_constant(
+        "SHA512_INPUT_CHUNK_SIZE_FELTS",
+        SHA512_INPUT_CHUNK_SIZE_FELTS,
+        constants,
+    )?;
+    let batch_size = get_constant_from_var_name("BATCH_SIZE", constants)?;
+    let n = get_integer_from_var_name("n", vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    let missing_blocks = Some(batch_size.to_biguint())
+        .filter(|batch_size| *batch_size != BigUint::ZERO)
+        .and_then(|batch_size| {
+            // Python's (-n) % batch_size, with n the integer value of the felt
+            ((&batch_size - n.to_biguint() % &batch_size) % &batch_size).to_usize()
+        })
+        .filter(|missing| *missing < 20)
+        .ok_or_else(|| {
+            HintError::AssertionFailed(
+                "assert 0 <= number_of_missing_blocks < 20"
+                    .to_string()
+                    .into_boxed_str(),
+            )
+        })?;
+    let sha512_ptr_end = get_ptr_from_var_name(
+        "sha512_ptr_end",
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+
+    let message = [0u64; SHA512_INPUT_CHUNK_SIZE_FELTS];
+    let mut output = IV;
+    compress(&mut output, &message);
+    let block_len = message.len() + IV.len() + output.len();
+    let padding: Vec<MaybeRelocatable> = message
+        .iter()
+        .chain(IV.iter())
+        .chain(output.iter())
+        .cycle()
+        .take(block_len * missing_blocks)
+        .map(|word| Felt252::from(*word).into())
+        .collect();
+    vm.load_data(sha512_ptr_end, &padding)?;
+    Ok(())
+}
@@ -0,0 +1,2107 @@
+use crate::cairo_type::{CairoType, CairoWritable};
+use crate::default_hints::{
+    blake2s, breakpoint, debug, default_hint_mapping, dict, ed25519, find_element, keccak, logger,
+    memory, poseidon, program_input, progress, proof, random, rlp, secp, sha256, sha512, uint256,
+    utils, witness_file, HintImpl, HintRegistry,
+};
+use crate::fixture::{record_oracle, FixtureRecorder};
+use crate::provenance::{ProvenanceTracker, TrackedWritable};
+use crate::testing::HintTester;
+use crate::types::dict::CairoDict;
+use crate::types::felt::Felt;
+use crate::types::keccak_bytes::KeccakBytes;
+use crate::types::sha256_bytes::Sha256Bytes;
+use crate::types::sha512_bytes::Sha512Bytes;
+use crate::types::uint256::Uint256;
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData,
+        hint_processor_definition::HintReference,
+    },
+    types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::BigUint;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[test]
+fn test_dict_hints() {
+    let hints = default_hint_mapping();
+    let dict_new = hints.get(dict::HINT_DICT_NEW).unwrap();
+    let dict_read = hints.get(dict::HINT_DICT_READ).unwrap();
+    for code in [
+        dict::HINT_DEFAULT_DICT_NEW,
+        dict::HINT_DICT_WRITE,
+        dict::HINT_DICT_SQUASH_COPY_DICT,
+        dict::HINT_DICT_SQUASH_UPDATE_PTR,
+    ] {
+        assert!(hints.contains(code));
+    }
+
+    let mut tester = HintTester::new(&["dict_ptr", "key", "value"]);
+    let mut dict = CairoDict::<Felt, Felt>::new();
+    dict.insert(Felt(Felt252::from(2)), Felt(Felt252::from(20)));
+    dict.insert_initial_dict(&mut tester.vm, &mut tester.exec_scopes)
+        .unwrap();
+
+    tester.run(dict::HINT_DICT_NEW, dict_new).unwrap();
+    let dict_ptr = tester.vm.get_relocatable(tester.vm.get_ap()).unwrap();
+
+    tester.write_id_value("dict_ptr", dict_ptr).unwrap();
+    tester.write_id_value("key", Felt252::from(2)).unwrap();
+    tester.run(dict::HINT_DICT_READ, dict_read).unwrap();
+    tester.assert_id("value", &[Felt252::from(20).into()]);
+}
+
+#[test]
+fn test_dict_update_and_dict_manager() {
+    use crate::default_hints::dict_manager;
+
+    let hints = default_hint_mapping();
+    assert!(hints.contains(dict::HINT_SQUASH_DICT));
+    let ids = [
+        "default_value",
+        "dict_ptr",
+        "key",
+        "prev_value",
+        "new_value",
+    ];
+    let mut tester = HintTester::new(&ids);
+    assert!(dict_manager::dict_manager(&tester.exec_scopes).is_none());
+    assert!(dict_manager::tracked_dicts(&tester.exec_scopes).is_empty());
+    tester
+        .write_id_value("default_value", Felt252::from(7))
+        .unwrap();
+    tester
+        .run_registered(&hints, dict::HINT_DEFAULT_DICT_NEW)
+        .unwrap();
+    let base = tester.vm.get_relocatable(tester.vm.get_ap()).unwrap();
+
+    tester.write_id_value("dict_ptr", base).unwrap();
+    tester.write_id_value("key", Felt252::from(3)).unwrap();
+    tester
+        .write_id_value("prev_value", Felt252::from(7))
+        .unwrap();
+    tester
+        .write_id_value("new_value", Felt252::from(30))
+        .unwrap();
+    tester
+        .run_registered(&hints, dict::HINT_DICT_UPDATE)
+        .unwrap();
+
+    let value = |key: u64, exec_scopes: &ExecutionScopes| {
+        dict_manager::get_dict_value(exec_scopes, base, Felt252::from(key)).unwrap()
+    };
+    assert_eq!(
+        value(3, &tester.exec_scopes),
+        Some(Felt252::from(30).into())
+    );
+    assert_eq!(value(4, &tester.exec_scopes), Some(Felt252::from(7).into()));
+    let end = (base + 3usize).unwrap();
+    let dict = dict_manager::get_dict(&tester.exec_scopes, end).unwrap();
+    assert_eq!(dict.current_ptr, end);
+    assert_eq!(dict.entries.len(), 1);
+    assert_eq!(dict_manager::tracked_dicts(&tester.exec_scopes), vec![dict]);
+    assert!(dict_manager::get_dict(&tester.exec_scopes, tester.id_address("key")).is_err());
+
+    // Same dict, updated with a stale previous value
+    let exec_scopes = std::mem::replace(&mut tester.exec_scopes, ExecutionScopes::new());
+    let mut tester = HintTester::new(&ids);
+    tester.exec_scopes = exec_scopes;
+    tester.add_segment(&[]).unwrap();
+    tester.write_id_value("dict_ptr", end).unwrap();
+    tester.write_id_value("key", Felt252::from(3)).unwrap();
+    tester
+        .write_id_value("prev_value", Felt252::from(7))
+        .unwrap();
+    tester
+        .write_id_value("new_value", Felt252::from(31))
+        .unwrap();
+    tester
+        .run_registered(&hints, dict::HINT_DICT_UPDATE)
+        .unwrap_err();
+    assert_eq!(
+        value(3, &tester.exec_scopes),
+        Some(Felt252::from(30).into())
+    );
+}
+
+#[test]
+fn test_usort_hints() {
+    use crate::default_hints::usort;
+
+    let hints = default_hint_mapping();
+    for code in [
+        dict::HINT_SQUASH_DICT_INNER_FIRST_ITERATION,
+        dict::HINT_SQUASH_DICT_INNER_SKIP_LOOP,
+        dict::HINT_SQUASH_DICT_INNER_CHECK_ACCESS_INDEX,
+        dict::HINT_SQUASH_DICT_INNER_CONTINUE_LOOP,
+        dict::HINT_SQUASH_DICT_INNER_LEN_ASSERT,
+        dict::HINT_SQUASH_DICT_INNER_USED_ACCESSES_ASSERT,
+        dict::HINT_SQUASH_DICT_INNER_ASSERT_LEN_KEYS,
+        dict::HINT_SQUASH_DICT_INNER_NEXT_KEY,
+    ] {
+        assert!(hints.contains(code));
+    }
+
+    let mut tester = HintTester::new(&[
+        "input",
+        "input_len",
+        "output_len",
+        "output",
+        "multiplicities",
+        "value",
+        "next_item_index",
+    ]);
+    let input = [3, 1, 3, 2].map(|value| Felt252::from(value).into());
+    let input = tester.add_segment(&input).unwrap();
+    tester.write_id_value("input", input).unwrap();
+    tester
+        .write_id_value("input_len", Felt252::from(4))
+        .unwrap();
+    tester
+        .run_registered(&hints, usort::HINT_USORT_ENTER_SCOPE)
+        .unwrap();
+    tester
+        .run_registered(&hints, usort::HINT_USORT_BODY)
+        .unwrap();
+    tester.assert_id("output_len", &[Felt252::from(3).into()]);
+    let output = tester
+        .vm
+        .get_relocatable(tester.id_address("output"))
+        .unwrap();
+    let multiplicities = tester
+        .vm
+        .get_relocatable(tester.id_address("multiplicities"))
+        .unwrap();
+    tester.assert_memory(output, &[1, 2, 3].map(|value| Felt252::from(value).into()));
+    tester.assert_memory(
+        multiplicities,
+        &[1, 1, 2].map(|value| Felt252::from(value).into()),
+    );
+
+    // 3 is at positions 0 and 2, a single step leaves one position unverified
+    tester.write_id_value("value", Felt252::from(3)).unwrap();
+    tester
+        .run_registered(&hints, usort::HINT_USORT_VERIFY)
+        .unwrap();
+    tester
+        .run_registered(&hints, usort::HINT_USORT_VERIFY_MULTIPLICITY_BODY)
+        .unwrap();
+    tester.assert_id("next_item_index", &[Felt252::ZERO.into()]);
+    tester
+        .run_registered(&hints, usort::HINT_USORT_VERIFY_MULTIPLICITY_ASSERT)
+        .unwrap_err();
+}
+
+#[test]
+fn test_blake2s_hints_registered() {
+    let hints = default_hint_mapping();
+    for code in [
+        blake2s::HINT_BLAKE2S_COMPUTE,
+        blake2s::HINT_BLAKE2S_FINALIZE,
+        blake2s::HINT_BLAKE2S_FINALIZE_V2,
+        blake2s::HINT_BLAKE2S_FINALIZE_V3,
+        blake2s::HINT_BLAKE2S_ADD_UINT256,
+        blake2s::HINT_BLAKE2S_ADD_UINT256_BIGEND,
+    ] {
+        assert!(hints.contains(code));
+    }
+}
+
+#[test]
+fn test_hint_registry_composition() {
+    let mut hints = default_hint_mapping();
+    let default_len = hints.len();
+    assert_eq!(
+        hints.namespace_of(poseidon::HINT_POSEIDON_HASH),
+        Some("cairo-vm-base")
+    );
+
+    let mut downstream = HintRegistry::new("downstream");
+    downstream
+        .register("custom_hint", poseidon::hint_poseidon_hash)
+        .unwrap();
+    let err = downstream
+        .register("custom_hint", poseidon::hint_poseidon_hash)
+        .unwrap_err();
+    assert!(err.contains("downstream"));
+    hints.merge(downstream).unwrap();
+    assert_eq!(hints.len(), default_len + 1);
+    assert_eq!(hints.namespace_of("custom_hint"), Some("downstream"));
+
+    // Merging a registry that collides with existing codes adds nothing
+    let mut conflicting = HintRegistry::new("conflicting");
+    conflicting
+        .register_all([
+            ("another_hint", poseidon::hint_poseidon_hash as HintImpl),
+            (keccak::HINT_KECCAK_FINALIZE, poseidon::hint_poseidon_hash),
+        ])
+        .unwrap();
+    let err = hints.merge(conflicting).unwrap_err();
+    assert!(err.contains("cairo-vm-base and conflicting"));
+    assert!(!hints.contains("another_hint"));
+
+    assert!(hints
+        .override_hint(keccak::HINT_KECCAK_FINALIZE, poseidon::hint_poseidon_hash)
+        .is_some());
+    assert_eq!(
+        hints.namespace_of(keccak::HINT_KECCAK_FINALIZE),
+        Some("cairo-vm-base")
+    );
+    assert_eq!(hints.into_map().len(), default_len + 1);
+}
+
+#[test]
+fn test_hint_registry_prefixes() {
+    let mut hints = HintRegistry::new("downstream");
+    hints
+        .register_prefix("check(", poseidon::hint_poseidon_hash)
+        .unwrap();
+    hints
+        .register_prefix("check(ids.x", keccak::hint_keccak_finalize)
+        .unwrap();
+    hints
+        .register("check(ids.x)", poseidon::hint_hades_permutation)
+        .unwrap();
+    assert!(hints
+        .register_prefix("check(", poseidon::hint_poseidon_hash)
+        .is_err());
+
+    let get = |code: &str| hints.get(code).map(|hint| hint as usize);
+    assert_eq!(
+        get("check(ids.a)"),
+        Some(poseidon::hint_poseidon_hash as usize)
+    );
+    assert_eq!(
+        get("check(ids.x, 1)"),
+        Some(keccak::hint_keccak_finalize as usize)
+    );
+    assert_eq!(
+        get("check(ids.x)"),
+        Some(poseidon::hint_hades_permutation as usize)
+    );
+    assert_eq!(get("other(ids.a)"), None);
+    assert_eq!(hints.namespace_of("check(ids.a)"), Some("downstream"));
+
+    let mut conflicting = HintRegistry::new("conflicting");
+    conflicting
+        .register_prefix("check(", poseidon::hint_poseidon_hash)
+        .unwrap();
+    let err = hints.merge(conflicting).unwrap_err();
+    assert!(err.contains("downstream and conflicting"), "{err}");
+    assert_eq!(hints.into_map().len(), 1);
+}
+
+#[test]
+fn test_blake2s_add_uint256_hint() {
+    let mut tester = HintTester::new(&["data", "low", "high"]);
+    let data = tester.vm.add_memory_segment();
+    tester.write_id_value("data", data).unwrap();
+    tester
+        .write_id_value("low", Felt252::from(0x0000000200000001u64))
+        .unwrap();
+    tester.write_id_value("high", Felt252::from(3u64)).unwrap();
+    tester
+        .run(
+            blake2s::HINT_BLAKE2S_ADD_UINT256,
+            blake2s::hint_blake2s_add_uint256,
+        )
+        .unwrap();
+
+    let words = tester
+        .vm
+        .get_integer_range(data, 8)
+        .unwrap()
+        .into_iter()
+        .map(|word| word.into_owned())
+        .collect::<Vec<_>>();
+    let expected = [1u64, 2, 0, 0, 3, 0, 0, 0].map(Felt252::from);
+    assert_eq!(words, expected);
+}
+
+// Lanes of keccak-f[1600] applied to the zero state, the dummy output of the finalize hint
+const KECCAK_F_ZERO_FIRST_LANES: [u64; 2] = [0xf1258f7940e1dde7, 0x84d5ccf933c0478a];
+
+#[test]
+fn test_keccak_write_args_hint() {
+    let hints = default_hint_mapping();
+    let mut tester = HintTester::new(&["inputs", "low", "high"]);
+    let inputs = tester.add_segment(&[]).unwrap();
+    tester.write_id_value("inputs", inputs).unwrap();
+    tester
+        .write_id_value("low", Felt252::from((2u128 << 64) | 1))
+        .unwrap();
+    tester
+        .write_id_value("high", Felt252::from((4u128 << 64) | 3))
+        .unwrap();
+    tester
+        .run_registered(&hints, keccak::HINT_KECCAK_WRITE_ARGS)
+        .unwrap();
+    tester.assert_memory(
+        inputs,
+        &[1u64, 2, 3, 4].map(|word| MaybeRelocatable::from(Felt252::from(word))),
+    );
+}
+
+#[test]
+fn test_keccak_block_permutation_hint() {
+    use num_traits::ToPrimitive;
+
+    let hints = default_hint_mapping();
+    // The padded block of the empty message: `0x01` then `0x80` as the last byte of the rate
+    let mut block = [0u64; 25];
+    block[0] = 0x01;
+    block[16] = 0x80 << 56;
+    let mut tester = HintTester::new(&["keccak_ptr"]).with_constant("KECCAK_STATE_SIZE_FELTS", 25);
+    let state = tester
+        .add_segment(&block.map(|word| MaybeRelocatable::from(Felt252::from(word))))
+        .unwrap();
+    let keccak_ptr = (state + 25usize).unwrap();
+    tester.write_id_value("keccak_ptr", keccak_ptr).unwrap();
+    tester
+        .run_registered(&hints, keccak::HINT_BLOCK_PERMUTATION)
+        .unwrap();
+
+    // The first four lanes are keccak256("") in little-endian words
+    let digest = tester
+        .vm
+        .get_integer_range(keccak_ptr, 4)
+        .unwrap()
+        .into_iter()
+        .flat_map(|word| word.to_u64().unwrap().to_le_bytes())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        hex::encode(digest),
+        "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    );
+
+    let mut tester = HintTester::new(&["keccak_ptr"]).with_constant("KECCAK_STATE_SIZE_FELTS", 24);
+    tester.write_id_value("keccak_ptr", keccak_ptr).unwrap();
+    assert!(tester
+        .run_registered(&hints, keccak::HINT_BLOCK_PERMUTATION)
+        .is_err());
+}
+
+#[test]
+fn test_keccak_finalize_hint() {
+    let hints = default_hint_mapping();
+    let mut tester = HintTester::new(&["keccak_ptr_end"])
+        .with_constant("KECCAK_STATE_SIZE_FELTS", 25)
+        .with_constant("BLOCK_SIZE", 3);
+    let keccak_ptr_end = tester.add_segment(&[]).unwrap();
+    tester
+        .write_id_value("keccak_ptr_end", keccak_ptr_end)
+        .unwrap();
+    tester
+        .run_registered(&hints, keccak::HINT_KECCAK_FINALIZE)
+        .unwrap();
+
+    // Three dummy pairs of the zero state and its permutation
+    let cells = tester.vm.get_integer_range(keccak_ptr_end, 150).unwrap();
+    for pair in cells.chunks(50) {
+        assert!(pair[..25].iter().all(|cell| **cell == Felt252::ZERO));
+        assert_eq!(
+            [*pair[25], *pair[26]],
+            KECCAK_F_ZERO_FIRST_LANES.map(Felt252::from)
+        );
+    }
+    assert!(tester
+        .vm
+        .get_maybe(&(keccak_ptr_end + 150usize).unwrap())
+        .is_none());
+}
+
+#[test]
+fn test_fixture_recording() {
+    let mut tester = HintTester::new(&["inputs", "n_bytes"]);
+    FixtureRecorder::enable(&mut tester.exec_scopes);
+    tester
+        .exec_scopes
+        .insert_value("keccak_bytes", KeccakBytes(vec![0xab, 0xcd]));
+    tester
+        .run(
+            keccak::HINT_LOAD_KECCAK_BYTES,
+            keccak::hint_load_keccak_bytes,
+        )
+        .unwrap();
+    let exec_scopes = &mut tester.exec_scopes;
+    record_oracle(exec_scopes, "block_hash", &"0x01").unwrap();
+    record_oracle(exec_scopes, "block_hash", &"0x02").unwrap();
+
+    let recorder = FixtureRecorder::take(exec_scopes).unwrap();
+    assert_eq!(
+        recorder.to_json(),
+        serde_json::json!({
+            "inputs": { "keccak_bytes": "0xabcd" },
+            "oracles": { "block_hash": ["0x01", "0x02"] }
+        })
+    );
+    assert!(FixtureRecorder::take(exec_scopes).is_none());
+}
+
+#[test]
+fn test_fixture_recording_entry_points() {
+    use crate::default_hints::random::{seed_random, DeterministicRng};
+    use crate::default_hints::witness_file::{allow_witness_files, WitnessFiles};
+    use crate::types::short_string::ShortString;
+    use crate::types::FromAnyStr;
+
+    let dir = std::env::temp_dir().join(format!("fixture_witness_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("w.bin"), [1u8, 2, 3]).unwrap();
+
+    let hints = default_hint_mapping();
+    let mut tester = HintTester::with_id_sizes(&[
+        ("input", 1),
+        ("value", 1),
+        ("name", 1),
+        ("data", 1),
+        ("n_bytes", 1),
+    ]);
+    FixtureRecorder::enable(&mut tester.exec_scopes);
+    let writable: Rc<dyn TrackedWritable> = Rc::new(Felt(Felt252::from(42)));
+    tester
+        .exec_scopes
+        .insert_value(program_input::PROGRAM_INPUT_WRITABLE, writable);
+    program_input::ProgramInput::from_json_str(r#""0x2a""#)
+        .unwrap()
+        .insert_into(&mut tester.exec_scopes);
+    seed_random(&mut tester.exec_scopes, DeterministicRng::from_u64(1));
+    allow_witness_files(&mut tester.exec_scopes, WitnessFiles::new().with_dir(&dir));
+    tester
+        .write_id("name", &ShortString::from_any_str("w.bin").unwrap())
+        .unwrap();
+
+    for code in [
+        program_input::HINT_WRITE_PROGRAM_INPUT,
+        random::HINT_RANDOM_FELT,
+        witness_file::HINT_LOAD_WITNESS_FILE,
+    ] {
+        tester.run_registered(&hints, code).unwrap();
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let drawn = DeterministicRng::from_u64(1).next_felt();
+    let recorder = FixtureRecorder::take(&mut tester.exec_scopes).unwrap();
+    assert_eq!(
+        recorder.to_json(),
+        serde_json::json!({
+            "inputs": {
+                "program_input": "0x2a",
+                "witness_file:w.bin": "0x010203",
+            },
+            "oracles": { "random_felt": [serde_json::to_value(drawn).unwrap()] }
+        })
+    );
+}
+
+#[test]
+fn test_write_program_input_hint() {
+    let mut tester = HintTester::new(&["input"]);
+    let input = Uint256::new(BigUint::from(1u32) << 128usize | BigUint::from(2u32));
+    let writable: Rc<dyn TrackedWritable> = Rc::new(input.clone());
+    tester
+        .exec_scopes
+        .insert_value(program_input::PROGRAM_INPUT_WRITABLE, writable);
+    tester
+        .run(
+            program_input::HINT_WRITE_PROGRAM_INPUT,
+            program_input::hint_write_program_input,
+        )
+        .unwrap();
+
+    let input_ptr = tester
+        .vm
+        .get_relocatable(tester.id_address("input"))
+        .unwrap();
+    assert_eq!(Uint256::from_memory(&tester.vm, input_ptr).unwrap(), input);
+}
+
+#[test]
+fn test_load_input_hint() {
+    let mut registry = HintRegistry::new("test");
+    program_input::register_load_input::<Uint256>(&mut registry).unwrap();
+    let load_input = registry.get(program_input::HINT_LOAD_INPUT).unwrap();
+
+    let mut tester = HintTester::new(&["input_ptr"]);
+    let input_ptr = tester.add_segment(&[]).unwrap();
+    tester.write_id_value("input_ptr", input_ptr).unwrap();
+    program_input::ProgramInput::from_json_str(r#""0x100000000000000000000000000000002""#)
+        .unwrap()
+        .insert_into(&mut tester.exec_scopes);
+
+    tester
+        .run(program_input::HINT_LOAD_INPUT, load_input)
+        .unwrap();
+    assert_eq!(
+        Uint256::from_memory(&tester.vm, input_ptr).unwrap(),
+        Uint256::new(BigUint::from(1u32) << 128usize | BigUint::from(2u32))
+    );
+
+    // Inputs that don't match the registered type fail with a descriptive error
+    program_input::ProgramInput::from_json_str(r#"{"unexpected": true}"#)
+        .unwrap()
+        .insert_into(&mut tester.exec_scopes);
+    assert!(tester
+        .run(program_input::HINT_LOAD_INPUT, load_input)
+        .is_err());
+}
+
+#[test]
+fn test_provenance_tracking() {
+    let mut registry = HintRegistry::new("test");
+    program_input::register_load_input::<Uint256>(&mut registry).unwrap();
+    let load_input = registry.get(program_input::HINT_LOAD_INPUT).unwrap();
+
+    let mut tester = HintTester::new(&["input_ptr"]);
+    let input_ptr = tester.add_segment(&[]).unwrap();
+    tester.write_id_value("input_ptr", input_ptr).unwrap();
+    ProvenanceTracker::enable(&mut tester.exec_scopes);
+    program_input::ProgramInput::from_json_str(r#""0x2a""#)
+        .unwrap()
+        .insert_into(&mut tester.exec_scopes);
+    tester
+        .run(program_input::HINT_LOAD_INPUT, load_input)
+        .unwrap();
+
+    // Lines of the memory dumpers and snapshots end with the origin of the cell
+    let lines =
+        debug::format_tracked_memory_range(&tester.vm, &tester.exec_scopes, input_ptr, 2).unwrap();
+    assert_eq!(
+        lines[1],
+        format!(
+            "{}: 0x0 <- program_input (UintBits<256, 128>.high)",
+            (input_ptr + 1usize).unwrap()
+        )
+    );
+    let snapshot = debug::MemorySnapshot::capture(&mut tester.vm)
+        .with_provenance(ProvenanceTracker::in_scopes(&tester.exec_scopes).unwrap());
+    let json = snapshot.to_json();
+    let cell = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|cell| cell["address"] == input_ptr.to_string())
+        .unwrap();
+    assert_eq!(cell["source"], "program_input (UintBits<256, 128>.low)");
+
+    // Failed hints name the origin of their ids
+    let error = ProvenanceTracker::in_scopes(&tester.exec_scopes)
+        .unwrap()
+        .annotate_hint_error(
+            HintError::WrongHintData,
+            &tester.vm,
+            &tester.hint_data(program_input::HINT_LOAD_INPUT),
+        );
+    assert!(
+        error
+            .to_string()
+            .contains("ids.input_ptr from program_input (UintBits<256, 128>.low)"),
+        "{error}"
+    );
+
+    // Inputs behind a trait object keep their type, and the segments they allocate are tracked
+    let writable: Rc<dyn TrackedWritable> = Rc::new(KeccakBytes(vec![1; 9]));
+    let bytes_ptr = tester.add_segment(&[]).unwrap();
+    crate::provenance::write_tracked(
+        &mut tester.vm,
+        &mut tester.exec_scopes,
+        bytes_ptr,
+        "witness",
+        writable.as_ref(),
+    )
+    .unwrap();
+
+    let tracker = ProvenanceTracker::take(&mut tester.exec_scopes).unwrap();
+    let vm = &tester.vm;
+    assert_eq!(tracker.len(), 6);
+    let high = tracker.get((input_ptr + 1usize).unwrap()).unwrap();
+    assert_eq!(high.source, program_input::PROGRAM_INPUT);
+    assert_eq!(high.field, "high");
+    assert_eq!(high.type_name, "UintBits<256, 128>");
+    assert!(tracker.get(vm.get_fp()).is_none());
+    let limbs = vm.get_relocatable(bytes_ptr).unwrap();
+    let limb = tracker.get((limbs + 1usize).unwrap()).unwrap();
+    assert_eq!(limb.to_string(), "witness (KeccakBytes.limbs[1])");
+    assert_eq!(
+        tracker.get((bytes_ptr + 1usize).unwrap()).unwrap().field,
+        "n_bytes"
+    );
+
+    let dump = tracker.dump(vm);
+    assert!(dump.starts_with(&format!(
+        "{input_ptr}: 42 <- program_input (UintBits<256, 128>.low)"
+    )));
+
+    let error = tracker.annotate(HintError::WrongHintData, input_ptr);
+    assert!(error.to_string().contains("written by program_input"));
+}
+
+struct RecordingReporter(Rc<RefCell<Vec<(u64, u64, String)>>>);
+
+impl progress::ProgressReporter for RecordingReporter {
+    fn report(&mut self, current: u64, total: u64, label: &str) {
+        self.0
+            .borrow_mut()
+            .push((current, total, label.to_string()));
+    }
+}
+
+#[test]
+fn test_progress_hint() {
+    let mut tester = HintTester::new(&["current", "total", "label"]);
+    tester.write_id_value("current", Felt252::from(3)).unwrap();
+    tester.write_id_value("total", Felt252::from(10)).unwrap();
+    tester
+        .write_id_value("label", Felt252::from_bytes_be_slice(b"blocks"))
+        .unwrap();
+
+    // No reporter injected, the hint does nothing
+    tester
+        .run(progress::HINT_PROGRESS, progress::hint_progress)
+        .unwrap();
+
+    let reports = Rc::new(RefCell::new(vec![]));
+    progress::set_progress_reporter(&mut tester.exec_scopes, RecordingReporter(reports.clone()));
+    tester.exec_scopes.enter_scope(HashMap::new());
+    tester
+        .run(progress::HINT_PROGRESS, progress::hint_progress)
+        .unwrap();
+    assert_eq!(*reports.borrow(), vec![(3, 10, "blocks".to_string())]);
+}
+
+#[test]
+fn test_poseidon_helpers() {
+    let a = Felt252::from_hex("0xaa").unwrap();
+    let b = Felt252::from_hex("0xbb").unwrap();
+    let c = Felt252::from_hex("0xcc").unwrap();
+    let expected =
+        Felt252::from_hex("0x2742e049f7e1613e4a014efeec0d742882a798ae0af8b8dd730358c23848775")
+            .unwrap();
+    assert_eq!(poseidon::poseidon_hash_many(&[a, b, c]), expected);
+}
+
+#[test]
+fn test_poseidon_hash_many_hint_matches_helper() {
+    let mut tester = HintTester::new(&["elements", "n", "res"]);
+    let values = (1..=5u64).map(Felt252::from).collect::<Vec<_>>();
+    let elements = tester
+        .add_segment(
+            &values
+                .iter()
+                .map(|value| (*value).into())
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+    tester.write_id_value("elements", elements).unwrap();
+    tester
+        .write_id_value("n", Felt252::from(values.len()))
+        .unwrap();
+    tester
+        .run(
+            poseidon::HINT_POSEIDON_HASH_MANY,
+            poseidon::hint_poseidon_hash_many,
+        )
+        .unwrap();
+
+    tester.assert_id("res", &[poseidon::poseidon_hash_many(&values).into()]);
+}
+
+#[test]
+fn test_print_memory_hints() {
+    let hints = default_hint_mapping();
+    for code in [
+        debug::INFO_FELT_HEX,
+        debug::INFO_STRING,
+        debug::INFO_UINT256,
+        debug::INFO_UINT384,
+        debug::DEBUG_FELT_HEX,
+        debug::DEBUG_STRING,
+        debug::DEBUG_UINT256,
+        debug::DEBUG_UINT384,
+    ] {
+        assert!(hints.contains(code));
+    }
+
+    let mut tester = HintTester::new(&["start", "end"]);
+    let segment = tester.vm.add_memory_segment();
+    tester.vm.insert_value(segment, Felt252::from(255)).unwrap();
+    tester
+        .vm
+        .insert_value((segment + 2usize).unwrap(), segment)
+        .unwrap();
+    assert_eq!(
+        debug::format_memory_range(&tester.vm, segment, 3).unwrap(),
+        vec![
+            format!("{segment}: 0xff"),
+            format!("{}: <unset>", (segment + 1usize).unwrap()),
+            format!("{}: {segment}", (segment + 2usize).unwrap()),
+        ]
+    );
+
+    tester.write_id_value("start", segment).unwrap();
+    tester
+        .write_id_value("end", (segment + 3usize).unwrap())
+        .unwrap();
+    tester
+        .run_registered(&hints, debug::PRINT_MEMORY_RANGE)
+        .unwrap();
+}
+
+#[test]
+fn test_breakpoint_hint() {
+    use breakpoint::{BreakpointConfig, BreakpointState, PromptHandler};
+
+    let hint = default_hint_mapping()
+        .get(breakpoint::HINT_BREAKPOINT)
+        .unwrap();
+    let mut tester = HintTester::new(&["a", "b"]);
+    let fp = tester.vm.get_fp();
+    tester.write_id_value("a", Felt252::from(7)).unwrap();
+
+    // Disabled by default
+    tester.run(breakpoint::HINT_BREAKPOINT, hint).unwrap();
+
+    let states = Rc::new(RefCell::new(vec![]));
+    let recorded = states.clone();
+    breakpoint::enable_breakpoints(
+        &mut tester.exec_scopes,
+        BreakpointConfig::new(
+            move |_: &VirtualMachine, _: &mut ExecutionScopes, state: &BreakpointState| {
+                recorded.borrow_mut().push(state.clone());
+                Ok(())
+            },
+        ),
+    );
+    tester.run(breakpoint::HINT_BREAKPOINT, hint).unwrap();
+    let state = states.borrow()[0].clone();
+    assert_eq!(state.fp, fp);
+    assert_eq!(
+        state.ids,
+        vec![
+            ("a".to_string(), Some(Felt252::from(7).into())),
+            ("b".to_string(), None),
+        ]
+    );
+    assert!(state.to_string().contains("ids.a = 0x7"));
+
+    // The print handler writes to the output sink
+    let buffer = logger::BufferSink::new();
+    logger::set_output_sink(&mut tester.exec_scopes, buffer.clone());
+    breakpoint::enable_breakpoints(&mut tester.exec_scopes, BreakpointConfig::print());
+    tester.run(breakpoint::HINT_BREAKPOINT, hint).unwrap();
+    assert_eq!(
+        buffer.rendered(),
+        [
+            format!(
+                "Breakpoint: pc={} ap={} fp={fp}",
+                tester.vm.get_pc(),
+                tester.vm.get_ap()
+            ),
+            "  ids.a = 0x7".to_string(),
+            "  ids.b = <unset>".to_string(),
+        ]
+    );
+
+    // Shares the prompt output with the test, the config requiring a 'static handler
+    #[derive(Clone, Default)]
+    struct Output(Rc<RefCell<Vec<u8>>>);
+    impl std::io::Write for Output {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+    let output = Output::default();
+    let input = format!("mem {} 2\nstate\nabort\n", (fp - 2usize).unwrap());
+    breakpoint::enable_breakpoints(
+        &mut tester.exec_scopes,
+        BreakpointConfig::new(PromptHandler::new(
+            std::io::Cursor::new(input),
+            output.clone(),
+        ))
+        .with_ids(["a"]),
+    );
+    let err = tester.run(breakpoint::HINT_BREAKPOINT, hint).unwrap_err();
+    assert!(err.to_string().contains("aborted at breakpoint"));
+    let output = String::from_utf8(output.0.borrow().clone()).unwrap();
+    assert!(output.contains(&format!("{}: 0x7", (fp - 2usize).unwrap())));
+    assert!(output.contains(&format!("{}: <unset>", (fp - 1usize).unwrap())));
+    assert!(!output.contains("ids.b"));
+}
+
+#[test]
+fn test_sha256_main_hint() {
+    let hints = default_hint_mapping();
+    assert!(hints.contains(sha256::HINT_SHA256_INPUT));
+    assert!(hints.contains(sha256::HINT_SHA256_MAIN_ARBITRARY_INPUT_LENGTH));
+    let sha256_main = hints
+        .get(sha256::HINT_SHA256_MAIN_CONSTANT_INPUT_LENGTH)
+        .unwrap();
+
+    let mut tester = HintTester::new(&["sha256_start", "output"]).with_constant(
+        "starkware.cairo.common.cairo_sha256.sha256_utils.SHA256_INPUT_CHUNK_SIZE_FELTS",
+        16,
+    );
+    let block: Vec<_> = Sha256Bytes(b"abc".to_vec())
+        .padded_words()
+        .into_iter()
+        .map(|word| Felt252::from(word).into())
+        .collect();
+    let input = tester.add_segment(&block).unwrap();
+    let output = tester.add_segment(&[]).unwrap();
+    tester.write_id_value("sha256_start", input).unwrap();
+    tester.write_id_value("output", output).unwrap();
+    tester
+        .run(sha256::HINT_SHA256_MAIN_CONSTANT_INPUT_LENGTH, sha256_main)
+        .unwrap();
+
+    let digest: Vec<u8> = (0..8usize)
+        .flat_map(|i| {
+            let word = tester.vm.get_integer((output + i).unwrap()).unwrap();
+            word.to_biguint().to_u32_digits()[0].to_be_bytes()
+        })
+        .collect();
+    assert_eq!(
+        hex::encode(digest),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn test_sha512_hints() {
+    let hints = default_hint_mapping();
+    assert!(hints.contains(sha512::HINT_SHA512_INPUT));
+    let sha512_main = hints.get(sha512::HINT_SHA512_MAIN).unwrap();
+    let sha512_finalize = hints.get(sha512::HINT_SHA512_FINALIZE).unwrap();
+    let constants = [
+        ("SHA512_INPUT_CHUNK_SIZE_FELTS", 16),
+        ("SHA512_STATE_SIZE_FELTS", 8),
+        ("BATCH_SIZE", 4),
+    ];
+    let tester = |ids: &[&str]| {
+        constants
+            .iter()
+            .fold(HintTester::new(ids), |tester, (name, value)| {
+                tester.with_constant(&format!("sha512_utils.{name}"), *value)
+            })
+    };
+    let words = |words: &[u64]| -> Vec<_> {
+        words
+            .iter()
+            .map(|word| Felt252::from(*word).into())
+            .collect()
+    };
+
+    let mut main = tester(&["sha512_start", "state", "output"]);
+    let input = main
+        .add_segment(&words(&Sha512Bytes(b"abc".to_vec()).padded_words()))
+        .unwrap();
+    let state = main.add_segment(&words(&sha512::IV)).unwrap();
+    let output = main.add_segment(&[]).unwrap();
+    main.write_id_value("sha512_start", input).unwrap();
+    main.write_id_value("state", state).unwrap();
+    main.write_id_value("output", output).unwrap();
+    main.run(sha512::HINT_SHA512_MAIN, sha512_main).unwrap();
+
+    let digest: Vec<u8> = (0..8usize)
+        .flat_map(|i| {
+            let word = main.vm.get_integer((output + i).unwrap()).unwrap();
+            word.to_biguint().to_u64_digits()[0].to_be_bytes()
+        })
+        .collect();
+    assert_eq!(
+        hex::encode(digest),
+        "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+         2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+    );
+
+    // One block out of a batch of 4 leaves 3 dummy blocks of 16 + 8 + 8 words
+    let mut finalize = tester(&["n", "sha512_ptr_end"]);
+    let end = finalize.add_segment(&[]).unwrap();
+    finalize.write_id_value("n", Felt252::ONE).unwrap();
+    finalize.write_id_value("sha512_ptr_end", end).unwrap();
+    finalize
+        .run(sha512::HINT_SHA512_FINALIZE, sha512_finalize)
+        .unwrap();
+    assert!(finalize.vm.get_maybe(&(end + 95usize).unwrap()).is_some());
+    assert!(finalize.vm.get_maybe(&(end + 96usize).unwrap()).is_none());
+    assert_eq!(
+        *finalize.vm.get_integer((end + 16usize).unwrap()).unwrap(),
+        Felt252::from(sha512::IV[0])
+    );
+}
+
+#[test]
+fn test_secp_hints() {
+    use crate::types::ec_point::{Curve, Secp256k1, Secp256k1Point};
+    use crate::types::FromAnyStr;
+    use num_bigint::BigInt;
+
+    let hints = default_hint_mapping();
+    for code in [
+        secp::HINT_DIV_MOD_N,
+        secp::HINT_GET_POINT_FROM_X,
+        secp::HINT_EC_DOUBLE_SLOPE,
+        secp::HINT_COMPUTE_SLOPE,
+        secp::HINT_FAST_EC_ADD_ASSIGN_NEW_X,
+        secp::HINT_VERIFY_ZERO,
+    ] {
+        assert!(hints.contains(code));
+    }
+
+    let generator = Secp256k1Point::from_any_str(
+        "0x0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\
+         483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+    )
+    .unwrap();
+    // point: EcPoint of two BigInt3, res: BigInt3
+    let mut tester = HintTester::with_id_sizes(&[("point", 6), ("res", 3)]);
+    let point = tester.id_address("point");
+    secp::write_point_bigint3(&mut tester.vm, point, &generator).unwrap();
+    assert_eq!(
+        secp::read_point_bigint3(&tester.vm, point).unwrap(),
+        generator
+    );
+
+    for code in [secp::HINT_EC_NEGATE, secp::HINT_NONDET_BIGINT3] {
+        tester.run_registered(&hints, code).unwrap();
+    }
+    let res = tester.id_address("res");
+    let limbs: Vec<Felt252> = (0..3usize)
+        .map(|i| *tester.vm.get_integer((res + i).unwrap()).unwrap())
+        .collect();
+    let negated_y = BigInt::from(Secp256k1::p()) - BigInt::from(generator.y.to_biguint());
+    assert_eq!(secp::pack_bigint3(&limbs.try_into().unwrap()), negated_y);
+}
+
+#[test]
+fn test_memory_hints() {
+    let hints = default_hint_mapping();
+    let ids = ["len", "continue_copying", "temporary_array"];
+    let mut tester = HintTester::new(&ids);
+    tester.write_id_value("len", Felt252::from(2)).unwrap();
+    tester
+        .run_registered(&hints, memory::HINT_MEMCPY_ENTER_SCOPE)
+        .unwrap();
+    assert_eq!(tester.exec_scopes.data.len(), 2);
+
+    // Each step needs a fresh frame, as ids.continue_copying is written once per iteration
+    let mut flags = vec![];
+    for _ in 0..2 {
+        let mut step = HintTester::new(&ids);
+        step.exec_scopes = std::mem::replace(&mut tester.exec_scopes, ExecutionScopes::new());
+        step.run_registered(&hints, memory::HINT_MEMCPY_CONTINUE_COPYING)
+            .unwrap();
+        flags.push(step.read_id::<Felt>("continue_copying").unwrap().0);
+        tester.exec_scopes = step.exec_scopes;
+    }
+    assert_eq!(flags, [Felt252::ONE, Felt252::ZERO]);
+    tester
+        .run_registered(&hints, memory::HINT_VM_EXIT_SCOPE)
+        .unwrap();
+    assert_eq!(tester.exec_scopes.data.len(), 1);
+
+    tester
+        .run_registered(&hints, memory::HINT_TEMPORARY_ARRAY)
+        .unwrap();
+    let temporary_array = tester
+        .vm
+        .get_relocatable(tester.id_address("temporary_array"))
+        .unwrap();
+    assert!(temporary_array.segment_index < 0);
+    tester
+        .run_registered(&hints, memory::HINT_ADD_SEGMENT)
+        .unwrap();
+    assert_eq!(
+        tester
+            .vm
+            .get_relocatable(tester.vm.get_ap())
+            .unwrap()
+            .segment_index,
+        2
+    );
+}
+
+#[test]
+fn test_find_element_hints() {
+    let hints = default_hint_mapping();
+    // Sorted array of (key, value) pairs, searched for key 9
+    let elements: Vec<MaybeRelocatable> = [1u64, 10, 4, 40, 9, 90]
+        .map(|x| Felt252::from(x).into())
+        .to_vec();
+    let search = || {
+        let mut tester = HintTester::new(&["array_ptr", "elm_size", "n_elms", "key", "index"]);
+        let array = tester.add_segment(&elements).unwrap();
+        tester.write_id_value("array_ptr", array).unwrap();
+        tester.write_id_value("elm_size", Felt252::from(2)).unwrap();
+        tester.write_id_value("n_elms", Felt252::from(3)).unwrap();
+        tester.write_id_value("key", Felt252::from(9)).unwrap();
+        tester
+    };
+
+    let mut tester = search();
+    tester
+        .run_registered(&hints, find_element::HINT_FIND_ELEMENT)
+        .unwrap();
+    tester.assert_id("index", &[Felt252::from(2).into()]);
+
+    // A wrong index is rejected, a max size below n_elms too
+    let mut tester = search();
+    find_element::set_find_element_index(&mut tester.exec_scopes, 1);
+    assert!(tester
+        .run_registered(&hints, find_element::HINT_FIND_ELEMENT)
+        .is_err());
+    find_element::set_find_element_max_size(&mut tester.exec_scopes, 2);
+    assert!(tester
+        .run_registered(&hints, find_element::HINT_SEARCH_SORTED_LOWER)
+        .is_err());
+    find_element::set_find_element_max_size(&mut tester.exec_scopes, 3);
+    tester
+        .run_registered(&hints, find_element::HINT_SEARCH_SORTED_LOWER)
+        .unwrap();
+    tester.assert_id("index", &[Felt252::from(2).into()]);
+}
+
+#[test]
+fn test_hint_tester() {
+    use crate::types::FromAnyStr;
+
+    let mut tester =
+        HintTester::with_id_sizes(&[("a", 2), ("b", 2), ("carry_low", 1), ("carry_high", 1)]);
+    tester
+        .write_id(
+            "a",
+            &Uint256::from_any_str("0xffffffffffffffffffffffffffffffff").unwrap(),
+        )
+        .unwrap();
+    tester
+        .write_id("b", &Uint256::from_any_str("1").unwrap())
+        .unwrap();
+    tester
+        .run_registered(&default_hint_mapping(), uint256::HINT_UINT256_ADD)
+        .unwrap();
+    tester.assert_id("carry_low", &[Felt252::ONE.into(), Felt252::ZERO.into()]);
+    assert_eq!(
+        tester.read_id::<Felt>("carry_high").unwrap(),
+        Felt(Felt252::ZERO)
+    );
+
+    assert!(matches!(
+        tester.run_registered(&HintRegistry::new("empty"), uint256::HINT_UINT256_ADD),
+        Err(HintError::UnknownHint(_))
+    ));
+    let ptr = tester.add_segment(&[Felt252::from(7).into()]).unwrap();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        tester.assert_memory(ptr, &[Felt252::from(8).into()])
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_random_hints() {
+    use crate::cairo_type::BaseCairoType;
+    use crate::default_hints::random::{seed_random, DeterministicRng};
+    use sha2::{Digest, Sha256};
+
+    // Each draw goes to a fresh tester, as memory cells are written once
+    let hints = default_hint_mapping();
+    let draw = |exec_scopes: &mut ExecutionScopes, code: &str| {
+        let mut tester = HintTester::with_id_sizes(&[("value", 2)]);
+        std::mem::swap(&mut tester.exec_scopes, exec_scopes);
+        let result = tester.run_registered(&hints, code);
+        std::mem::swap(&mut tester.exec_scopes, exec_scopes);
+        result.map(|_| tester)
+    };
+    let draw_uint256 = |exec_scopes: &mut ExecutionScopes| {
+        draw(exec_scopes, random::HINT_RANDOM_UINT256)
+            .unwrap()
+            .read_id::<Uint256>("value")
+            .unwrap()
+    };
+
+    let mut exec_scopes = ExecutionScopes::new();
+    let err = draw(&mut exec_scopes, random::HINT_RANDOM_FELT)
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("no random seed set"));
+
+    let mut tester = HintTester::with_id_sizes(&[("seed", 1)]);
+    tester.write_id_value("seed", Felt252::from(42)).unwrap();
+    tester
+        .run_registered(&hints, random::HINT_SEED_RANDOM)
+        .unwrap();
+    let mut exec_scopes = tester.exec_scopes;
+    let first = draw_uint256(&mut exec_scopes);
+    assert_ne!(draw_uint256(&mut exec_scopes), first);
+
+    // Block i is sha256(seed || i)
+    let mut hasher = Sha256::new();
+    hasher.update(Felt252::from(42).to_bytes_be());
+    hasher.update(0u64.to_be_bytes());
+    let block: [u8; 32] = hasher.finalize().into();
+    assert_eq!(first, Uint256::from_bytes_be(&block));
+    let mut rng = DeterministicRng::from_u64(42);
+    assert_eq!(rng.next_uint256(), first);
+    assert_eq!(rng.counter(), 1);
+
+    // Reseeding restarts the sequence, other seeds give other values
+    seed_random(&mut exec_scopes, DeterministicRng::from_u64(42));
+    assert_eq!(draw_uint256(&mut exec_scopes), first);
+    seed_random(&mut exec_scopes, DeterministicRng::from_u64(43));
+    assert_ne!(draw_uint256(&mut exec_scopes), first);
+
+    seed_random(&mut exec_scopes, DeterministicRng::from_u64(7));
+    let tester = draw(&mut exec_scopes, random::HINT_RANDOM_FELT).unwrap();
+    let mut rng = DeterministicRng::from_u64(7);
+    tester.assert_memory(tester.id_address("value"), &[rng.next_felt().into()]);
+    let felts: Vec<Felt252> = (0..100).map(|_| rng.next_felt()).collect();
+    assert!(felts
+        .iter()
+        .any(|felt| felt.to_biguint() >= BigUint::from(1u32) << 250));
+}
+
+#[test]
+fn test_witness_file_hints() {
+    use crate::default_hints::witness_file::{
+        allow_witness_files, felts_from_be_bytes, WitnessFiles,
+    };
+    use crate::types::short_string::ShortString;
+    use crate::types::FromAnyStr;
+
+    let dir = std::env::temp_dir().join(format!("witness_files_{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("blocks")).unwrap();
+    let bytes: Vec<u8> = (0u8..20).collect();
+    std::fs::write(dir.join("blocks/1.bin"), &bytes).unwrap();
+    let mut felts = [0u8; 64];
+    felts[31] = 1;
+    felts[63] = 2;
+    std::fs::write(dir.join("felts.bin"), felts).unwrap();
+    let outside = dir.with_extension("secret");
+    std::fs::write(&outside, b"secret").unwrap();
+
+    let hints = default_hint_mapping();
+    let tester_for = |name: &str| {
+        let mut tester = HintTester::with_id_sizes(&[
+            ("name", 1),
+            ("data", 1),
+            ("n_bytes", 1),
+            ("felts", 1),
+            ("n_felts", 1),
+        ]);
+        let name = ShortString::from_any_str(name).unwrap();
+        tester.write_id("name", &name).unwrap();
+        tester
+    };
+
+    // Nothing is allowed by default
+    let mut tester = tester_for("blocks/1.bin");
+    let err = tester
+        .run_registered(&hints, witness_file::HINT_LOAD_WITNESS_FILE)
+        .unwrap_err();
+    assert!(err.to_string().contains("no files allowed"), "{err}");
+
+    let files = WitnessFiles::new()
+        .with_dir(&dir)
+        .with_file("secret", &outside)
+        .with_max_bytes(64);
+    allow_witness_files(&mut tester.exec_scopes, files.clone());
+    tester
+        .run_registered(&hints, witness_file::HINT_LOAD_WITNESS_FILE)
+        .unwrap();
+    tester.assert_id("n_bytes", &[Felt252::from(20).into()]);
+    let data = tester
+        .vm
+        .get_relocatable(tester.id_address("data"))
+        .unwrap();
+    tester.assert_memory(
+        data,
+        &KeccakBytes(bytes.clone())
+            .to_limbs()
+            .into_iter()
+            .map(MaybeRelocatable::from)
+            .collect::<Vec<_>>(),
+    );
+
+    let mut tester = tester_for("felts.bin");
+    allow_witness_files(&mut tester.exec_scopes, files.clone());
+    tester
+        .run_registered(&hints, witness_file::HINT_LOAD_WITNESS_FELTS)
+        .unwrap();
+    tester.assert_id("n_felts", &[Felt252::from(2).into()]);
+    let ptr = tester
+        .vm
+        .get_relocatable(tester.id_address("felts"))
+        .unwrap();
+    tester.assert_memory(ptr, &[Felt252::ONE.into(), Felt252::TWO.into()]);
+
+    // Registered names, and nothing outside of the allowed directories
+    assert_eq!(files.read("secret").unwrap(), b"secret");
+    for name in [
+        "../witness.secret",
+        "/etc/passwd",
+        "blocks/../../x",
+        "missing.bin",
+        "",
+    ] {
+        assert!(files.resolve(name).is_err(), "{name}");
+    }
+    let err = files
+        .clone()
+        .with_max_bytes(10)
+        .read("blocks/1.bin")
+        .unwrap_err();
+    assert!(err.contains("more than the 10 allowed"), "{err}");
+
+    assert!(felts_from_be_bytes(&bytes).is_err());
+    assert!(felts_from_be_bytes(&[0xff; 32]).is_err());
+    assert_eq!(
+        felts_from_be_bytes(&Felt252::MAX.to_bytes_be()).unwrap(),
+        [Felt252::MAX]
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+    std::fs::remove_file(&outside).unwrap();
+}
+
+#[test]
+fn test_typed_scope_keys() {
+    use crate::scopes::{
+        find_typed_mut, get_typed, get_typed_mut, get_typed_ref, insert_typed, remove_typed,
+        ScopeKey, LOG_LEVEL_KEY,
+    };
+
+    const COUNTER: ScopeKey<u64> = ScopeKey::local("counter");
+    const TOTAL: ScopeKey<u64> = ScopeKey::root("total");
+    // The same name as `COUNTER` read as another type
+    const COUNTER_AS_FELT: ScopeKey<Felt252> = ScopeKey::local("counter");
+
+    let mut exec_scopes = ExecutionScopes::new();
+    insert_typed(&mut exec_scopes, &COUNTER, 1);
+    insert_typed(&mut exec_scopes, &TOTAL, 10);
+    *get_typed_mut(&mut exec_scopes, &COUNTER).unwrap() += 1;
+    assert_eq!(get_typed(&exec_scopes, &COUNTER).unwrap(), 2);
+
+    let err = get_typed_ref(&exec_scopes, &COUNTER_AS_FELT).unwrap_err();
+    assert!(err.to_string().contains("counter is not a"), "{err}");
+    assert!(find_typed_mut(&mut exec_scopes, &COUNTER_AS_FELT).is_err());
+
+    // Root variables stay visible from nested scopes, local ones don't
+    exec_scopes.enter_scope(HashMap::new());
+    assert_eq!(get_typed(&exec_scopes, &TOTAL).unwrap(), 10);
+    assert!(matches!(
+        get_typed(&exec_scopes, &COUNTER),
+        Err(HintError::VariableNotInScopeError(_))
+    ));
+    assert!(find_typed_mut(&mut exec_scopes, &COUNTER)
+        .unwrap()
+        .is_none());
+    insert_typed(&mut exec_scopes, &LOG_LEVEL_KEY, "debug");
+    exec_scopes.exit_scope().unwrap();
+
+    assert_eq!(remove_typed(&mut exec_scopes, &TOTAL), Some(10));
+    assert_eq!(remove_typed(&mut exec_scopes, &TOTAL), None);
+    assert!(get_typed(&exec_scopes, &LOG_LEVEL_KEY).is_err());
+    assert_eq!(get_typed(&exec_scopes, &COUNTER).unwrap(), 2);
+}
+
+#[test]
+fn test_typed_var_name_helpers() {
+    use crate::default_hints::utils::{get_typed_from_var_name, write_typed_to_var_name};
+    use crate::types::uint384::UInt384;
+
+    let mut tester = HintTester::with_id_sizes(&[("value", 2), ("res", 4)]);
+    let value = Uint256::new(BigUint::from(u128::MAX) + BigUint::from(5u32));
+    tester.write_id("value", &value).unwrap();
+    let hint_data = tester.hint_data("");
+    let read: Uint256 = get_typed_from_var_name("value", &tester.vm, &hint_data).unwrap();
+    assert_eq!(read, value);
+
+    let res = UInt384::new(BigUint::from(1u32) << 300);
+    write_typed_to_var_name("res", &res, &mut tester.vm, &hint_data).unwrap();
+    assert_eq!(tester.read_id::<UInt384>("res").unwrap(), res);
+    assert_eq!(
+        get_typed_from_var_name::<UInt384>("res", &tester.vm, &hint_data).unwrap(),
+        res
+    );
+    assert!(get_typed_from_var_name::<Uint256>("missing", &tester.vm, &hint_data).is_err());
+}
+
+#[test]
+fn test_proof_hints() {
+    use crate::types::proof::{MerkleProof, MptNodeKind, MptProof};
+
+    // A branch of 17 empty items, an extension and a leaf, with even paths
+    let branch = format!("0xd1{}", "80".repeat(17));
+    let json = format!(r#"["{branch}", "0xc482001207", "0xc482201205"]"#);
+    let mpt_proof: MptProof = serde_json::from_str(&json).unwrap();
+    let kinds: Vec<_> = mpt_proof.iter().map(|node| node.kind().unwrap()).collect();
+    assert_eq!(
+        kinds,
+        [
+            MptNodeKind::Branch,
+            MptNodeKind::Extension,
+            MptNodeKind::Leaf
+        ]
+    );
+
+    let mut tester = HintTester::with_id_sizes(&[
+        ("proof", 3),
+        ("node", 1),
+        ("node_bytes_len", 1),
+        ("node_type", 1),
+        ("index", 1),
+        ("bit", 1),
+        ("next_index", 1),
+    ]);
+    let hints = default_hint_mapping();
+    tester.exec_scopes.insert_value(proof::MPT_PROOF, mpt_proof);
+    tester
+        .run_registered(&hints, proof::HINT_LOAD_MPT_PROOF)
+        .unwrap();
+    let proof_addr = tester.id_address("proof");
+    assert_eq!(
+        *tester.vm.get_integer(proof_addr).unwrap(),
+        Felt252::from(3)
+    );
+    let bytes_len = tester
+        .vm
+        .get_relocatable((proof_addr + 1usize).unwrap())
+        .unwrap();
+    tester.assert_memory(
+        bytes_len,
+        &[Felt252::from(18).into(), Felt252::from(5).into()],
+    );
+
+    // The leaf node, fed back to the node type hint
+    let nodes = tester
+        .vm
+        .get_relocatable((proof_addr + 2usize).unwrap())
+        .unwrap();
+    let leaf = tester
+        .vm
+        .get_relocatable((nodes + 2usize).unwrap())
+        .unwrap();
+    tester.write_id_value("node", leaf).unwrap();
+    tester
+        .write_id_value("node_bytes_len", Felt252::from(5))
+        .unwrap();
+    tester
+        .run_registered(&hints, proof::HINT_MPT_NODE_TYPE)
+        .unwrap();
+    assert_eq!(
+        tester.read_id::<Felt>("node_type").unwrap(),
+        Felt(Felt252::TWO)
+    );
+
+    // Memory is write once, each nibble is read in a fresh frame
+    let key = Uint256::new(BigUint::from(0xabcdu32) | (BigUint::from(0x7u32) << 252u32));
+    for (index, expected) in [(0u32, 0x7u32), (1, 0), (62, 0xc), (63, 0xd)] {
+        let mut tester =
+            HintTester::with_id_sizes(&[("key", 2), ("nibble_index", 1), ("nibble", 1)]);
+        tester.write_id("key", &key).unwrap();
+        tester
+            .write_id_value("nibble_index", Felt252::from(index))
+            .unwrap();
+        tester
+            .run_registered(&hints, proof::HINT_MPT_KEY_NIBBLE)
+            .unwrap();
+        tester.assert_id("nibble", &[Felt252::from(expected).into()]);
+    }
+
+    tester.write_id_value("index", Felt252::from(5)).unwrap();
+    tester
+        .run_registered(&hints, proof::HINT_MERKLE_PATH_BIT)
+        .unwrap();
+    tester.assert_id("bit", &[Felt252::ONE.into(), Felt252::TWO.into()]);
+
+    let merkle = MerkleProof {
+        leaf: Felt(Felt252::from(10)),
+        index: 2,
+        siblings: vec![Felt(Felt252::from(11)), Felt(Felt252::from(12))],
+    };
+    let bits: Vec<bool> = merkle.path().map(|(_, is_right)| is_right).collect();
+    assert_eq!(bits, [false, true]);
+    let mut vm = VirtualMachine::new(false, false);
+    let base = vm.add_memory_segment();
+    assert_eq!(
+        merkle.to_memory(&mut vm, base).unwrap(),
+        (base + 4usize).unwrap()
+    );
+    let siblings = vm.get_relocatable((base + 3usize).unwrap()).unwrap();
+    assert_eq!(
+        *vm.get_integer((siblings + 1usize).unwrap()).unwrap(),
+        Felt252::from(12)
+    );
+}
+
+#[test]
+fn test_rlp_hints() {
+    use crate::types::rlp::{RlpBytes, RlpItem};
+
+    // ["cat", "dog"] followed by a long string, so the encoding spans several chunks
+    let item = RlpItem::List(vec![
+        RlpItem::List(vec![
+            RlpItem::Bytes(b"cat".to_vec()),
+            RlpItem::Bytes(b"dog".to_vec()),
+        ]),
+        RlpItem::Bytes(vec![0xaa; 60]),
+    ]);
+    let rlp_bytes = RlpBytes(item);
+    let n_bytes = rlp_bytes.n_bytes();
+    let hints = default_hint_mapping();
+
+    let mut tester = HintTester::new(&["rlp", "rlp_len"]);
+    tester
+        .exec_scopes
+        .insert_value(rlp::RLP_BYTES, rlp_bytes.clone());
+    tester
+        .run_registered(&hints, rlp::HINT_LOAD_RLP_BYTES)
+        .unwrap();
+    tester.assert_id("rlp_len", &[Felt252::from(n_bytes).into()]);
+    let rlp_ptr = tester.vm.get_relocatable(tester.id_address("rlp")).unwrap();
+    let limbs: Vec<MaybeRelocatable> = rlp_bytes.to_limbs().into_iter().map(Into::into).collect();
+    tester.assert_memory(rlp_ptr, &limbs);
+
+    // Outer list header (0xf8 len), inner list at byte 2, long string at byte 11
+    let decoded = |code: &str, item_start: usize, outputs: &[&str]| {
+        let mut ids = vec!["rlp", "rlp_len", "item_start"];
+        ids.extend(outputs);
+        let mut tester = HintTester::new(&ids);
+        let rlp_ptr = tester.add_segment(&limbs).unwrap();
+        tester.write_id_value("rlp", rlp_ptr).unwrap();
+        tester
+            .write_id_value("rlp_len", Felt252::from(n_bytes))
+            .unwrap();
+        tester
+            .write_id_value("item_start", Felt252::from(item_start))
+            .unwrap();
+        tester.run_registered(&hints, code).map(|_| tester)
+    };
+    let header = ["is_list", "payload_start", "payload_len"];
+    for (item_start, expected) in [(0, [1, 2, n_bytes - 2]), (2, [1, 3, 8]), (11, [0, 13, 60])] {
+        let tester = decoded(rlp::HINT_RLP_ITEM_HEADER, item_start, &header).unwrap();
+        let expected: Vec<MaybeRelocatable> = expected
+            .iter()
+            .map(|value| Felt252::from(*value).into())
+            .collect();
+        tester.assert_id("is_list", &expected);
+    }
+
+    let tester = decoded(rlp::HINT_RLP_LIST_LEN, 2, &["n_items"]).unwrap();
+    tester.assert_id("n_items", &[Felt252::TWO.into()]);
+    // The long string is not a list, and the start must be in the encoding
+    assert!(decoded(rlp::HINT_RLP_LIST_LEN, 11, &["n_items"]).is_err());
+    assert!(decoded(rlp::HINT_RLP_ITEM_HEADER, n_bytes, &header).is_err());
+}
+
+#[test]
+fn test_uint256_hints() {
+    let hints = default_hint_mapping();
+    for code in [
+        uint256::HINT_UINT256_ADD,
+        uint256::HINT_UINT256_SQRT,
+        uint256::HINT_UINT256_SIGNED_NN,
+        uint256::HINT_UINT256_MUL_DIV_MOD,
+    ] {
+        assert!(hints.contains(code));
+    }
+
+    // Uint256 ids, followed by each other in the frame
+    let mut tester =
+        HintTester::with_id_sizes(&[("a", 2), ("div", 2), ("quotient", 2), ("remainder", 2)]);
+    let a_ptr = tester.id_address("a");
+    let values = [u128::MAX, 7, 10, 0].map(|limb| MaybeRelocatable::from(Felt252::from(limb)));
+    tester.vm.load_data(a_ptr, &values.to_vec()).unwrap();
+    tester
+        .run_registered(&hints, uint256::HINT_UINT256_UNSIGNED_DIV_REM)
+        .unwrap();
+    let a: BigUint = (BigUint::from(7u32) << 128) + u128::MAX;
+    let limbs: Vec<Felt252> = (4..8usize)
+        .map(|i| *tester.vm.get_integer((a_ptr + i).unwrap()).unwrap())
+        .collect();
+    let quotient = a.clone() / 10u32;
+    assert_eq!(
+        limbs[0],
+        Felt252::from(&quotient & BigUint::from(u128::MAX))
+    );
+    assert_eq!(limbs[1], Felt252::from(quotient >> 128));
+    assert_eq!(limbs[2], Felt252::from(a % 10u32));
+    assert_eq!(limbs[3], Felt252::ZERO);
+
+    let mut tester =
+        HintTester::with_id_sizes(&[("a", 2), ("b", 2), ("carry_low", 1), ("carry_high", 1)]);
+    let values =
+        [u128::MAX, 1, 1, u128::MAX].map(|limb| MaybeRelocatable::from(Felt252::from(limb)));
+    tester
+        .vm
+        .load_data(tester.id_address("a"), &values.to_vec())
+        .unwrap();
+    tester
+        .run_registered(&hints, uint256::HINT_UINT256_ADD)
+        .unwrap();
+    tester.assert_id("carry_low", &[Felt252::ONE.into()]);
+    tester.assert_id("carry_high", &[Felt252::ONE.into()]);
+}
+
+#[test]
+fn test_ed25519_decompress_hint() {
+    let hints = default_hint_mapping();
+    for code in [
+        ed25519::HINT_UINT512_UNSIGNED_DIV_REM,
+        ed25519::HINT_INV_MOD_P_UINT512,
+        ed25519::HINT_IS_ZERO_ASSIGN_SCOPE_VARS,
+    ] {
+        assert!(hints.contains(code));
+    }
+    assert_eq!(ed25519::l().bits(), 253);
+
+    // Base point, y = 4/5
+    let mut encoded = [0x66u8; 32];
+    encoded[0] = 0x58;
+    let x = BigUint::parse_bytes(
+        b"15112221349535400772501151409588531511454012693041857206046113283949847762202",
+        10,
+    )
+    .unwrap();
+    let y = BigUint::parse_bytes(
+        b"46316835694926478169428394003475163141307993866256225615783033603165251855960",
+        10,
+    )
+    .unwrap();
+    assert_eq!(ed25519::decompress(&encoded).unwrap(), (x.clone(), y));
+    assert!(ed25519::decompress(&[0xff; 32]).is_err());
+
+    // compressed: Uint256, point: (x, y) BigInt3 pair
+    let mut tester = HintTester::with_id_sizes(&[("compressed", 2), ("point", 6)]);
+    let value = BigUint::from_bytes_le(&encoded);
+    let low: BigUint = &value & ((BigUint::from(1u8) << 128) - 1u8);
+    let compressed: [MaybeRelocatable; 2] =
+        [low, value >> 128].map(|limb| Felt252::from(&limb).into());
+    tester
+        .vm
+        .load_data(tester.id_address("compressed"), &compressed.to_vec())
+        .unwrap();
+    tester
+        .run_registered(&hints, ed25519::HINT_ED25519_DECOMPRESS)
+        .unwrap();
+    let point = tester.id_address("point");
+    let limbs: Vec<Felt252> = (0..3usize)
+        .map(|i| *tester.vm.get_integer((point + i).unwrap()).unwrap())
+        .collect();
+    assert_eq!(secp::pack_bigint3(&limbs.try_into().unwrap()), x.into());
+}
+
+#[test]
+fn test_sqrt_mod() {
+    // 97 - 1 = 3 * 2^5 exercises the Tonelli-Shanks loop
+    let p = BigUint::from(97u32);
+    let mut residues = 0;
+    for x in 0..97u32 {
+        let x = BigUint::from(x);
+        if let Some(root) = utils::sqrt_mod(&x, &p).unwrap() {
+            assert_eq!(&root * &root % &p, x);
+            residues += 1;
+        }
+    }
+    assert_eq!(residues, 49);
+
+    let p = (BigUint::from(1u8) << 255) - 19u8;
+    let root = utils::sqrt_mod(&BigUint::from(4u8), &p).unwrap().unwrap();
+    assert!(root == BigUint::from(2u8) || root == &p - 2u8);
+
+    // No z has z^4 = 8 mod 9, the non-residue search gives up at p instead of looping forever
+    assert!(utils::sqrt_mod(&BigUint::from(1u8), &BigUint::from(9u8)).is_err());
+}
+
+#[test]
+fn test_modular_arithmetic_hints() {
+    use crate::types::uint384::UInt384;
+
+    let hints = default_hint_mapping();
+    // UInt384 ids: a, b / x, g / p, then the results
+    let names = ["a", "b", "p", "res", "is_quad_residue"];
+    let offsets = [0, 4, 8, 12, 16];
+    let mut vm = VirtualMachine::new(false, false);
+    vm.add_memory_segment();
+    vm.add_memory_segment();
+    vm.set_fp(17);
+    let mut ids_data: HashMap<String, HintReference> = names
+        .iter()
+        .zip(offsets)
+        .map(|(name, offset)| (name.to_string(), HintReference::new_simple(offset - 17)))
+        .collect();
+    for (alias, name) in [("x", "a"), ("g", "b"), ("x_inv", "res"), ("root", "res")] {
+        ids_data.insert(alias.to_string(), ids_data[name].clone());
+    }
+    let fp = vm.get_fp();
+    let write = |vm: &mut VirtualMachine, offset: usize, value: u32| {
+        let address = (fp - (17 - offset)).unwrap();
+        UInt384::new(BigUint::from(value))
+            .to_memory(vm, address)
+            .unwrap();
+    };
+    let read = |vm: &VirtualMachine| {
+        UInt384::from_memory(vm, (fp - 5).unwrap())
+            .unwrap()
+            .to_biguint()
+    };
+    write(&mut vm, 0, 3);
+    write(&mut vm, 4, 5);
+    write(&mut vm, 8, 97);
+
+    let run = |vm: &mut VirtualMachine, code: &str| {
+        let hint_data = HintProcessorData::new_default(code.into(), ids_data.clone());
+        hints.get(code).unwrap()(vm, &mut ExecutionScopes::new(), &hint_data, &HashMap::new())
+    };
+    run(&mut vm, utils::HINT_DIV_MOD).unwrap();
+    assert_eq!(read(&vm) * 5u32 % 97u32, BigUint::from(3u32));
+
+    // 5 is a non-residue mod 97, so the root is the one of g * x = 25
+    vm = VirtualMachine::new(false, false);
+    vm.add_memory_segment();
+    vm.add_memory_segment();
+    vm.set_fp(17);
+    write(&mut vm, 0, 5);
+    write(&mut vm, 4, 5);
+    write(&mut vm, 8, 97);
+    run(&mut vm, utils::HINT_SQRT_MOD).unwrap();
+    let root = read(&vm);
+    assert_eq!(*vm.get_integer((fp - 1).unwrap()).unwrap(), Felt252::ZERO);
+    assert_eq!(&root * &root % 97u32, BigUint::from(25u32));
+
+    vm = VirtualMachine::new(false, false);
+    vm.add_memory_segment();
+    vm.add_memory_segment();
+    vm.set_fp(17);
+    write(&mut vm, 0, 0);
+    write(&mut vm, 8, 97);
+    assert!(run(&mut vm, utils::HINT_INV_MOD).is_err());
+
+    // Odd composite moduli fail the hint instead of hanging the VM
+    for (x, p) in [(1, 9), (4, 15)] {
+        vm = VirtualMachine::new(false, false);
+        vm.add_memory_segment();
+        vm.add_memory_segment();
+        vm.set_fp(17);
+        write(&mut vm, 0, x);
+        write(&mut vm, 4, 2);
+        write(&mut vm, 8, p);
+        assert!(run(&mut vm, utils::HINT_SQRT_MOD).is_err());
+    }
+}
+
+#[test]
+fn test_logger_capture_and_filter() {
+    use logger::{BufferLogger, LevelFilter, LogLevel};
+
+    let filter = LevelFilter::parse("info,cairo::uint256=debug,cairo::string=off").unwrap();
+    assert!(filter.enabled(LogLevel::Debug, logger::TARGET_UINT256));
+    assert!(!filter.enabled(LogLevel::Debug, logger::TARGET_FELT));
+    assert!(!filter.enabled(LogLevel::Info, logger::TARGET_STRING));
+    assert!(LevelFilter::parse("verbose").is_err());
+
+    let mut tester = HintTester::new(&["value"]);
+    tester.write_id_value("value", Felt252::from(0x41)).unwrap();
+    let buffer = BufferLogger::new(filter);
+    logger::set_logger(&mut tester.exec_scopes, buffer.clone());
+
+    for (code, hint) in [
+        (debug::INFO_FELT_HEX, debug::info_felt_hex as HintImpl),
+        (debug::DEBUG_FELT, debug::debug_felt),
+        (debug::INFO_STRING, debug::info_string),
+    ] {
+        tester.run(code, hint).unwrap();
+    }
+    let records = buffer.records();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].level, LogLevel::Info);
+    assert_eq!(records[0].target, logger::TARGET_FELT);
+    assert_eq!(buffer.messages(), vec!["0x41".to_string()]);
+}
+
+#[test]
+fn test_output_sink_capture() {
+    use logger::{BufferSink, JsonLinesSink, LogLevel, OutputKind, OutputSink};
+
+    let mut tester = HintTester::new(&["value"]);
+    tester.write_id_value("value", Felt252::from(0x41)).unwrap();
+    let buffer = BufferSink::new();
+    logger::set_output_sink(&mut tester.exec_scopes, buffer.clone());
+
+    // Prints and the records passing the default info level go to the same sink
+    for (code, hint) in [
+        (debug::PRINT_FELT_HEX, debug::print_felt_hex as HintImpl),
+        (debug::INFO_FELT_HEX, debug::info_felt_hex),
+        (debug::DEBUG_FELT, debug::debug_felt),
+    ] {
+        tester.run(code, hint).unwrap();
+    }
+    assert_eq!(buffer.rendered(), vec!["Value: 0x41", "Info: 0x41"]);
+    let lines = buffer.lines();
+    assert_eq!(lines[0].kind, OutputKind::Print);
+    assert_eq!(lines[0].target, logger::TARGET_PRINT);
+    assert_eq!(lines[1].kind, OutputKind::Log(LogLevel::Info));
+    assert_eq!(lines[1].target, logger::TARGET_FELT);
+    assert!(buffer.contains("Info: 0x41"));
+
+    // A logger set over the sink takes the records, the prints still reach the sink
+    let records = logger::BufferLogger::new(logger::LevelFilter::default());
+    logger::set_logger(&mut tester.exec_scopes, records.clone());
+    buffer.clear();
+    for (code, hint) in [
+        (debug::PRINT_FELT_HEX, debug::print_felt_hex as HintImpl),
+        (debug::INFO_FELT_HEX, debug::info_felt_hex),
+    ] {
+        tester.run(code, hint).unwrap();
+    }
+    assert_eq!(buffer.rendered(), vec!["Value: 0x41"]);
+    assert_eq!(records.messages(), vec!["0x41"]);
+
+    let mut json = JsonLinesSink::new(Vec::new());
+    for line in &lines {
+        json.write(line.kind, &line.target, &line.message);
+    }
+    let json = String::from_utf8(json.into_inner()).unwrap();
+    let records: Vec<serde_json::Value> = json
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(
+        records,
+        vec![
+            serde_json::json!({
+                "kind": "print",
+                "level": null,
+                "target": "cairo::print",
+                "message": "Value: 0x41",
+            }),
+            serde_json::json!({
+                "kind": "log",
+                "level": "info",
+                "target": "cairo::felt",
+                "message": "0x41",
+            }),
+        ]
+    );
+}
+
+#[test]
+fn test_print_sink_capture() {
+    use std::{cell::RefCell, rc::Rc};
+
+    // `ids.value` is the Uint256 struct itself
+    let mut tester = HintTester::with_id_sizes(&[("value", 2)]);
+    tester
+        .write_id(
+            "value",
+            &Uint256::new((BigUint::from(1u32) << 128) + 0xffu32),
+        )
+        .unwrap();
+
+    let lines = Rc::new(RefCell::new(Vec::new()));
+    let sink = lines.clone();
+    logger::set_print_sink(&mut tester.exec_scopes, move |line: &str| {
+        sink.borrow_mut().push(line.to_string())
+    });
+    tester
+        .run(debug::PRINT_UINT256, debug::print_uint256)
+        .unwrap();
+    assert_eq!(
+        *lines.borrow(),
+        vec![format!("Value: 0x{:032x}{:032x}", 1, 0xff)]
+    );
+}
+
+#[test]
+fn test_print_felt_signed() {
+    use std::{cell::RefCell, rc::Rc};
+
+    let mut tester = HintTester::new(&["value"]);
+    tester.write_id_value("value", -Felt252::from(5)).unwrap();
+
+    let lines = Rc::new(RefCell::new(Vec::new()));
+    let sink = lines.clone();
+    logger::set_print_sink(&mut tester.exec_scopes, move |line: &str| {
+        sink.borrow_mut().push(line.to_string())
+    });
+    tester
+        .run_registered(&default_hint_mapping(), debug::PRINT_FELT_SIGNED)
+        .unwrap();
+    assert_eq!(*lines.borrow(), vec!["Value: -5".to_string()]);
+}
+
+#[test]
+fn test_print_relocatable_and_segment() {
+    use std::{cell::RefCell, rc::Rc};
+
+    let hints = default_hint_mapping();
+    let mut tester = HintTester::new(&["ptr", "len"]);
+    let segment = tester.add_segment(&[Felt252::from(0x2a).into()]).unwrap();
+    let other = tester.vm.add_memory_segment();
+    tester
+        .vm
+        .insert_value((segment + 1usize).unwrap(), other)
+        .unwrap();
+    tester.write_id_value("ptr", segment).unwrap();
+    tester.write_id_value("len", Felt252::from(3)).unwrap();
+
+    let lines = Rc::new(RefCell::new(Vec::new()));
+    let sink = lines.clone();
+    logger::set_print_sink(&mut tester.exec_scopes, move |line: &str| {
+        sink.borrow_mut().push(line.to_string())
+    });
+    tester
+        .run_registered(&hints, debug::PRINT_RELOCATABLE)
+        .unwrap();
+    tester.run_registered(&hints, debug::PRINT_SEGMENT).unwrap();
+    assert_eq!(
+        *lines.borrow(),
+        vec![
+            format!("Pointer: {segment}"),
+            format!("Segment {} from offset 0, 3 cells:", segment.segment_index),
+            "address | value".to_string(),
+            "--------+------".to_string(),
+            format!("{segment}     | 0x2a"),
+            format!("{}     | {other}", (segment + 1usize).unwrap()),
+            format!("{}     | <unset>", (segment + 2usize).unwrap()),
+        ]
+    );
+
+    // A felt id prints as a value instead of failing
+    let mut tester = HintTester::new(&["ptr"]);
+    tester.write_id_value("ptr", Felt252::from(7)).unwrap();
+    let sink = lines.clone();
+    logger::set_print_sink(&mut tester.exec_scopes, move |line: &str| {
+        sink.borrow_mut().push(line.to_string())
+    });
+    tester
+        .run_registered(&hints, debug::PRINT_RELOCATABLE)
+        .unwrap();
+    assert_eq!(lines.borrow().last().unwrap(), "Value: 0x7");
+}
+
+#[test]
+fn test_memory_snapshot_diff() {
+    use crate::default_hints::debug::{MemoryChange, MemorySnapshot};
+    use std::{cell::RefCell, rc::Rc};
+
+    let mut vm = VirtualMachine::new(false, false);
+    let segment = vm.add_memory_segment();
+    let temp = vm.add_temporary_segment();
+    vm.insert_value(segment, Felt252::from(255)).unwrap();
+    vm.insert_value(temp, segment).unwrap();
+    let before = MemorySnapshot::capture(&mut vm);
+    assert_eq!(before.len(), 1);
+    assert_eq!(before.get(segment), Some(&Felt252::from(255).into()));
+    // Temporary segments are only read by range
+    assert_eq!(
+        MemorySnapshot::capture_range(&vm, temp, 1)
+            .unwrap()
+            .get(temp),
+        Some(&segment.into())
+    );
+
+    let cell = (segment + 2usize).unwrap();
+    vm.insert_value(cell, Felt252::from(7)).unwrap();
+    let after = MemorySnapshot::capture(&mut vm);
+    assert!(vm.segments.segment_used_sizes.is_none());
+    let changes = before.diff(&after);
+    assert_eq!(
+        changes,
+        vec![MemoryChange {
+            address: cell,
+            old: None,
+            new: Some(Felt252::from(7).into()),
+            provenance: None,
+        }]
+    );
+    assert_eq!(changes[0].to_string(), format!("{cell}: <unset> -> 0x7"));
+    assert!(after.diff(&after).is_empty());
+    assert_eq!(
+        MemorySnapshot::capture_range(&vm, segment, 2)
+            .unwrap()
+            .len(),
+        1
+    );
+
+    let lines = Rc::new(RefCell::new(Vec::new()));
+    let sink = lines.clone();
+    let mut exec_scopes = ExecutionScopes::new();
+    logger::set_print_sink(&mut exec_scopes, move |line: &str| {
+        sink.borrow_mut().push(line.to_string())
+    });
+    let dump = default_hint_mapping()
+        .get(debug::HINT_DUMP_MEMORY_SNAPSHOT)
+        .unwrap();
+    let hint_data =
+        HintProcessorData::new_default(debug::HINT_DUMP_MEMORY_SNAPSHOT.into(), HashMap::new());
+    dump(&mut vm, &mut exec_scopes, &hint_data, &HashMap::new()).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&lines.borrow()[0]).unwrap();
+    assert_eq!(json, after.to_json());
+    assert_eq!(json[0]["address"], "-1:0");
+    assert_eq!(json[1]["value"], "0xff");
+}
+
+#[test]
+fn test_assert_hints() {
+    use crate::default_hints::assert::{self, Assertion};
+
+    let parsed =
+        Assertion::parse("assert_le(ids.amount, 100, \"a, b\")  # wallet.cairo:42").unwrap();
+    assert_eq!(parsed.name, "assert_le");
+    assert_eq!(parsed.lhs, "ids.amount");
+    assert_eq!(parsed.rhs, "100");
+    assert_eq!(parsed.label.as_deref(), Some("a, b"));
+    assert_eq!(parsed.location.as_deref(), Some("wallet.cairo:42"));
+    assert_eq!(
+        Assertion::parse(assert::HINT_ASSERT_EQ).unwrap().label,
+        None
+    );
+    assert!(Assertion::parse("assert_eq(ids.a)").is_none());
+    assert!(Assertion::parse("assert_eq(ids.a, ids.b) trailing").is_none());
+
+    let hints = default_hint_mapping();
+    let mut tester = HintTester::new(&["amount", "balance", "ptr"]);
+    let segment = tester.add_segment(&[]).unwrap();
+    tester.write_id_value("amount", Felt252::from(100)).unwrap();
+    tester.write_id_value("balance", Felt252::from(50)).unwrap();
+    tester.write_id_value("ptr", segment).unwrap();
+
+    tester
+        .run_registered(&hints, "assert_eq(ids.amount, 0x64)")
+        .unwrap();
+    tester
+        .run_registered(&hints, "assert_le(ids.balance, ids.amount)")
+        .unwrap();
+    tester
+        .run_registered(&hints, "assert_le(-1, ids.amount)")
+        .unwrap_err();
+    let mut failure = |code: &str| match tester.run_registered(&hints, code) {
+        Err(HintError::CustomHint(message)) => message.to_string(),
+        other => panic!("unexpected result {other:?}"),
+    };
+    assert_eq!(
+        failure("assert_le(ids.amount, ids.balance, \"withdrawal\")  # wallet.cairo:42"),
+        "assert_le failed (withdrawal) at wallet.cairo:42: \
+         ids.amount = 0x64 (100) > ids.balance = 0x32 (50)"
+    );
+    assert_eq!(
+        failure("assert_eq(ids.amount, ids.balance)"),
+        "assert_eq failed: ids.amount = 0x64 (100) != ids.balance = 0x32 (50)"
+    );
+    tester
+        .run_registered(&hints, "assert_le(ids.ptr, ids.amount)")
+        .unwrap_err();
+    tester
+        .run_registered(&hints, "assert_eq(ids.ptr, ids.ptr)")
+        .unwrap();
+    assert!(hints.get("assert_ne(ids.a, ids.b)").is_none());
+}
+
+#[test]
+fn test_profiling_timers() {
+    use crate::default_hints::profiling::{self, Profiler, ProfilingReport};
+    use crate::types::short_string::ShortString;
+
+    let mut profiler = Profiler::default();
+    profiler.start("hash", 10).unwrap();
+    assert!(profiler.start("hash", 11).is_err());
+    profiler.stop("hash", 25).unwrap();
+    profiler.start("hash", 30).unwrap();
+    profiler.start("write", 31).unwrap();
+    profiler.stop("hash", 40).unwrap();
+    assert!(profiler.stop("hash", 41).is_err());
+    assert_eq!(profiler.running().collect::<Vec<_>>(), vec!["write"]);
+    let hash = profiler.report().get("hash").unwrap();
+    assert_eq!((hash.count, hash.steps), (2, 25));
+
+    let hints = default_hint_mapping();
+    let mut tester = HintTester::new(&["label"]);
+    assert_eq!(profiling::profiling_report(&tester.exec_scopes), None);
+    tester
+        .write_id(
+            "label",
+            &ShortString(Felt252::from_bytes_be_slice(b"hash blocks")),
+        )
+        .unwrap();
+    tester
+        .run_registered(&hints, profiling::HINT_START_TIMER)
+        .unwrap();
+    tester
+        .run_registered(&hints, "start_timer(\"outer\")")
+        .unwrap();
+    tester
+        .run_registered(&hints, "stop_timer(\"hash blocks\")")
+        .unwrap();
+    assert!(tester
+        .run_registered(&hints, "stop_timer(\"missing\")")
+        .is_err());
+    assert!(tester.run_registered(&hints, "start_timer(label)").is_err());
+
+    let report = profiling::profiling_report(&tester.exec_scopes).unwrap();
+    assert_eq!(report.sections.len(), 1);
+    assert_eq!(report.get("hash blocks").unwrap().count, 1);
+    let json = serde_json::to_string(&report).unwrap();
+    assert_eq!(
+        serde_json::from_str::<ProfilingReport>(&json).unwrap(),
+        report
+    );
+    assert!(report.to_string().starts_with("hash blocks: 0 steps"));
+}
+
+#[test]
+fn test_step_budget() {
+    use crate::default_hints::budget::{self, StepBudget};
+    use crate::default_hints::profiling::Profiler;
+    use cairo_vm::types::relocatable::Relocatable;
+
+    let pc = Relocatable::from((0, 12));
+    let budget = StepBudget::new()
+        .with_max_steps(1000)
+        .with_section("hash", 100);
+    let mut profiler = Profiler::default();
+    budget.check_step(1000, pc, None).unwrap();
+    assert_eq!(
+        budget.check_step(1001, pc, None).unwrap_err().to_string(),
+        HintError::CustomHint("step budget exceeded at pc 0:12: 1001 steps, budget 1000".into())
+            .to_string()
+    );
+
+    profiler.start("hash", 400).unwrap();
+    profiler.start("other", 0).unwrap();
+    budget.check_step(500, pc, Some(&profiler)).unwrap();
+    let err = budget.check_step(501, pc, Some(&profiler)).unwrap_err();
+    assert!(
+        err.to_string().contains(
+            "step budget of section hash exceeded at pc 0:12: 101 steps since \
+             start_timer at step 400, budget 100"
+        ),
+        "{err}"
+    );
+    profiler.stop("hash", 450).unwrap();
+    budget.check_step(900, pc, Some(&profiler)).unwrap();
+
+    // No budget set, or one the run is within
+    let hints = default_hint_mapping();
+    let mut tester = HintTester::new(&[]);
+    tester
+        .run_registered(&hints, budget::HINT_CHECK_STEP_BUDGET)
+        .unwrap();
+    budget::set_step_budget(&mut tester.exec_scopes, budget);
+    tester
+        .run_registered(&hints, budget::HINT_CHECK_STEP_BUDGET)
+        .unwrap();
+    tester
+        .run_registered(&hints, "start_timer(\"hash\")")
+        .unwrap();
+    tester
+        .run_registered(&hints, "stop_timer(\"hash\")")
+        .unwrap();
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData,
+        hint_code,
+        uint256_utils::{
+            split_64, uint128_add, uint256_add, uint256_expanded_unsigned_div_rem,
+            uint256_mul_div_mod, uint256_signed_nn, uint256_sqrt, uint256_sub,
+            uint256_unsigned_div_rem,
+        },
+    },
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+// Hints of starkware.cairo.common.uint256, with cairo-vm's codes and implementations
+pub const HINT_UINT256_ADD: &str = hint_code::UINT256_ADD;
+pub const HINT_UINT256_ADD_LOW: &str = hint_code::UINT256_ADD_LOW;
+pub const HINT_UINT128_ADD: &str = hint_code::UINT128_ADD;
+pub const HINT_UINT256_SUB: &str = hint_code::UINT256_SUB;
+pub const HINT_SPLIT_64: &str = hint_code::SPLIT_64;
+pub const HINT_UINT256_SQRT: &str = hint_code::UINT256_SQRT;
+pub const HINT_UINT256_SQRT_FELT: &str = hint_code::UINT256_SQRT_FELT;
+pub const HINT_UINT256_SIGNED_NN: &str = hint_code::UINT256_SIGNED_NN;
+pub const HINT_UINT256_UNSIGNED_DIV_REM: &str = hint_code::UINT256_UNSIGNED_DIV_REM;
+pub const HINT_UINT256_EXPANDED_UNSIGNED_DIV_REM: &str =
+    hint_code::UINT256_EXPANDED_UNSIGNED_DIV_REM;
+pub const HINT_UINT256_MUL_DIV_MOD: &str = hint_code::UINT256_MUL_DIV_MOD;
+
+// Carry of the high and low limbs
+pub fn hint_uint256_add(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    uint256_add(vm, &hint_data.ids_data, &hint_data.ap_tracking, false)
+}
+
+// Carry of the low limb only
+pub fn hint_uint256_add_low(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    uint256_add(vm, &hint_data.ids_data, &hint_data.ap_tracking, true)
+}
+
+pub fn hint_uint128_add(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    uint128_add(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_uint256_sub(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    uint256_sub(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_split_64(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    split_64(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_uint256_sqrt(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    uint256_sqrt(vm, &hint_data.ids_data, &hint_data.ap_tracking, false)
+}
+
+// Same as uint256_sqrt, with the root written as a felt
+pub fn hint_uint256_sqrt_felt(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    uint256_sqrt(vm, &hint_data.ids_data, &hint_data.ap_tracking, true)
+}
+
+pub fn hint_uint256_signed_nn(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    uint256_signed_nn(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_uint256_unsigned_div_rem(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    uint256_unsigned_div_rem(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_uint256_expanded_unsigned_div_rem(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    uint256_expanded_unsigned_div_rem(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_uint256_mul_div_mod(
+    vm: &mut VirtualMachine,
+    _exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    uint256_mul_div_mod(vm, &hint_data.ids_data, &hint_data.ap_tracking)
+}
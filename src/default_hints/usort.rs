@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData,
+        hint_code,
+        usort::{
+            usort_body, usort_enter_scope, verify_multiplicity_assert, verify_multiplicity_body,
+            verify_usort,
+        },
+    },
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+// The `starkware.cairo.common.usort` hints, `usort_body` leaving `positions_dict` in the scope
+// entered by `usort_enter_scope` for the verify hints
+pub const HINT_USORT_ENTER_SCOPE: &str = hint_code::USORT_ENTER_SCOPE;
+pub const HINT_USORT_BODY: &str = hint_code::USORT_BODY;
+pub const HINT_USORT_VERIFY: &str = hint_code::USORT_VERIFY;
+pub const HINT_USORT_VERIFY_MULTIPLICITY_ASSERT: &str = hint_code::USORT_VERIFY_MULTIPLICITY_ASSERT;
+pub const HINT_USORT_VERIFY_MULTIPLICITY_BODY: &str = hint_code::USORT_VERIFY_MULTIPLICITY_BODY;
+
+pub fn hint_usort_enter_scope(
+    _vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    _hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    usort_enter_scope(exec_scopes)
+}
+
+// Sorted distinct values of `ids.input` to `ids.output`, with their counts to
+// `ids.multiplicities`
+pub fn hint_usort_body(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    usort_body(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_usort_verify(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    verify_usort(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
+
+pub fn hint_usort_verify_multiplicity_assert(
+    _vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    _hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    verify_multiplicity_assert(exec_scopes)
+}
+
+pub fn hint_usort_verify_multiplicity_body(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    verify_multiplicity_body(vm, exec_scopes, &hint_data.ids_data, &hint_data.ap_tracking)
+}
@@ -3,12 +3,59 @@ use std::collections::HashMap;
 use cairo_vm::{
     hint_processor::builtin_hint_processor::{
         builtin_hint_processor_definition::HintProcessorData,
-        hint_utils::{get_integer_from_var_name, insert_value_from_var_name},
+        hint_utils::{
+            get_integer_from_var_name, get_relocatable_from_var_name, insert_value_from_var_name,
+        },
     },
     types::{exec_scope::ExecutionScopes, relocatable::MaybeRelocatable},
     vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
     Felt252,
 };
+use num_bigint::BigUint;
+use num_traits::{One, ToPrimitive, Zero};
+
+use crate::cairo_type::{CairoType, CairoWritable};
+use crate::types::uint384::UInt384;
+
+/// Reads the `ids` variable `name` as a `T`, e.g. a `Uint256` or a custom struct, from the
+/// address of the variable.
+pub fn get_typed_from_var_name<T: CairoType>(
+    name: &str,
+    vm: &VirtualMachine,
+    hint_data: &HintProcessorData,
+) -> Result<T, HintError> {
+    let address =
+        get_relocatable_from_var_name(name, vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    T::from_memory(vm, address)
+}
+
+/// Writes `value` at the address of the `ids` variable `name`, filling its `T::n_fields()`
+/// cells.
+pub fn write_typed_to_var_name<T: CairoWritable>(
+    name: &str,
+    value: &T,
+    vm: &mut VirtualMachine,
+    hint_data: &HintProcessorData,
+) -> Result<(), HintError> {
+    let address =
+        get_relocatable_from_var_name(name, vm, &hint_data.ids_data, &hint_data.ap_tracking)?;
+    value.to_memory(vm, address)?;
+    Ok(())
+}
+
+/// `value` as a `u64`, failing with an error naming it otherwise.
+pub fn felt_to_u64(value: &Felt252, name: &str) -> Result<u64, HintError> {
+    value.to_u64().ok_or_else(|| {
+        HintError::CustomHint(format!("{name} does not fit in a u64: {value}").into_boxed_str())
+    })
+}
+
+/// `value` as a `usize`, failing with an error naming it otherwise.
+pub fn felt_to_usize(value: &Felt252, name: &str) -> Result<usize, HintError> {
+    value.to_usize().ok_or_else(|| {
+        HintError::CustomHint(format!("{name} does not fit in a usize: {value}").into_boxed_str())
+    })
+}
 
 pub const HINT_BIT_LENGTH: &str = "ids.bit_length = ids.x.bit_length()";
 
@@ -29,3 +76,165 @@ pub fn hint_bit_length(
 
     Ok(())
 }
+
+// Modular arithmetic over UInt384 values (four 96 bit limbs), in the format of garaga's hints
+pub const HINT_INV_MOD: &str = r#"from garaga.hints.io import bigint_pack, bigint_fill
+x = bigint_pack(ids.x, 4, 2**96)
+p = bigint_pack(ids.p, 4, 2**96)
+bigint_fill(pow(x, -1, p), ids.x_inv, 4, 2**96)"#;
+
+pub const HINT_DIV_MOD: &str = r#"from garaga.hints.io import bigint_pack, bigint_fill
+a = bigint_pack(ids.a, 4, 2**96)
+b = bigint_pack(ids.b, 4, 2**96)
+p = bigint_pack(ids.p, 4, 2**96)
+bigint_fill(a * pow(b, -1, p) % p, ids.res, 4, 2**96)"#;
+
+// `ids.g` is a non-residue, when `ids.x` has no root `ids.root` is a root of g * x instead
+pub const HINT_SQRT_MOD: &str = r#"from garaga.hints.io import bigint_pack, bigint_fill
+from starkware.python.math_utils import is_quad_residue, sqrt
+x = bigint_pack(ids.x, 4, 2**96)
+g = bigint_pack(ids.g, 4, 2**96)
+p = bigint_pack(ids.p, 4, 2**96)
+if is_quad_residue(x, p):
+    ids.is_quad_residue = 1
+    bigint_fill(sqrt(x, p), ids.root, 4, 2**96)
+else:
+    ids.is_quad_residue = 0
+    bigint_fill(sqrt(x * g % p, p), ids.root, 4, 2**96)"#;
+
+fn math_error(message: String) -> HintError {
+    HintError::CustomHint(message.into_boxed_str())
+}
+
+/// Square root of `x` mod the odd prime `p` with Tonelli-Shanks, `None` for non-residues. An
+/// error when the search finds no root, which only happens for composite `p`.
+pub fn sqrt_mod(x: &BigUint, p: &BigUint) -> Result<Option<BigUint>, String> {
+    let x = x % p;
+    if x.is_zero() {
+        return Ok(Some(x));
+    }
+    let no_root = || format!("no square root of {x} found mod {p}, which must be prime");
+    let one = BigUint::one();
+    let p_minus_one = p - 1u32;
+    let legendre = |a: &BigUint| a.modpow(&(&p_minus_one >> 1u32), p);
+    if legendre(&x) != one {
+        return Ok(None);
+    }
+
+    // p - 1 = q * 2^s with q odd
+    let s = p_minus_one.trailing_zeros().ok_or_else(no_root)?;
+    let q = &p_minus_one >> s;
+    let mut z = BigUint::from(2u32);
+    while legendre(&z) != p_minus_one {
+        z += 1u32;
+        if &z >= p {
+            return Err(no_root());
+        }
+    }
+
+    let mut m = s;
+    let mut c = z.modpow(&q, p);
+    let mut t = x.modpow(&q, p);
+    let mut r = x.modpow(&((&q + 1u32) >> 1u32), p);
+    while t != one {
+        // Least i with t^(2^i) = 1, below m for prime p
+        let mut i = 0;
+        let mut t2i = t.clone();
+        while t2i != one {
+            t2i = &t2i * &t2i % p;
+            i += 1;
+            if i == m {
+                return Err(no_root());
+            }
+        }
+        let b = c.modpow(&(one.clone() << (m - i - 1)), p);
+        m = i;
+        c = &b * &b % p;
+        t = t * &c % p;
+        r = r * b % p;
+    }
+    match &r * &r % p == x {
+        true => Ok(Some(r)),
+        false => Err(no_root()),
+    }
+}
+
+fn read_uint384(
+    name: &str,
+    vm: &VirtualMachine,
+    hint_data: &HintProcessorData,
+) -> Result<BigUint, HintError> {
+    Ok(get_typed_from_var_name::<UInt384>(name, vm, hint_data)?.to_biguint())
+}
+
+fn write_uint384(
+    name: &str,
+    value: BigUint,
+    vm: &mut VirtualMachine,
+    hint_data: &HintProcessorData,
+) -> Result<(), HintError> {
+    write_typed_to_var_name(name, &UInt384::new(value), vm, hint_data)
+}
+
+pub fn hint_inv_mod(
+    vm: &mut VirtualMachine,
+    _exec_scope: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let x = read_uint384("x", vm, hint_data)?;
+    let p = read_uint384("p", vm, hint_data)?;
+    let x_inv = x
+        .modinv(&p)
+        .ok_or_else(|| math_error(format!("{x} is not invertible mod {p}")))?;
+    write_uint384("x_inv", x_inv, vm, hint_data)
+}
+
+pub fn hint_div_mod(
+    vm: &mut VirtualMachine,
+    _exec_scope: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let a = read_uint384("a", vm, hint_data)?;
+    let b = read_uint384("b", vm, hint_data)?;
+    let p = read_uint384("p", vm, hint_data)?;
+    let b_inv = b
+        .modinv(&p)
+        .ok_or_else(|| math_error(format!("{b} is not invertible mod {p}")))?;
+    write_uint384("res", a * b_inv % &p, vm, hint_data)
+}
+
+pub fn hint_sqrt_mod(
+    vm: &mut VirtualMachine,
+    _exec_scope: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let x = read_uint384("x", vm, hint_data)?;
+    let g = read_uint384("g", vm, hint_data)?;
+    let p = read_uint384("p", vm, hint_data)?;
+    if p.is_zero() || !p.bit(0) {
+        return Err(math_error(format!(
+            "sqrt_mod expects an odd prime modulus, got {p}"
+        )));
+    }
+
+    let (is_quad_residue, root) = match sqrt_mod(&x, &p).map_err(math_error)? {
+        Some(root) => (Felt252::ONE, root),
+        None => {
+            let root = sqrt_mod(&(x * g % &p), &p)
+                .map_err(math_error)?
+                .ok_or_else(|| math_error("g is not a quadratic non-residue".to_string()))?;
+            (Felt252::ZERO, root)
+        }
+    };
+    insert_value_from_var_name(
+        "is_quad_residue",
+        is_quad_residue,
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    write_uint384("root", root, vm, hint_data)
+}
@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData,
+        hint_utils::insert_value_from_var_name,
+    },
+    types::exec_scope::ExecutionScopes,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+use super::utils::get_typed_from_var_name;
+use crate::cairo_type::write_felts;
+use crate::fixture::record_input;
+use crate::scopes::{get_typed_ref, insert_typed, WITNESS_FILES_KEY};
+use crate::types::{keccak_bytes::KeccakBytes, short_string::ShortString};
+
+// Exec scope variable holding the `WitnessFiles` the hints may read, stored in the root scope
+pub const WITNESS_FILES: &str = "__witness_files";
+
+// `ids.name` is a short string naming an allowed file, e.g. `let name = 'blocks.bin';`. The
+// bytes are written in 64-bit little-endian chunks like `KeccakBytes`
+pub const HINT_LOAD_WITNESS_FILE: &str = r#"witness = load_witness_file(ids.name)
+ids.data = segments.gen_arg(witness.to_limbs())
+ids.n_bytes = len(witness)"#;
+
+// Same, for files of consecutive 32-byte big-endian felts
+pub const HINT_LOAD_WITNESS_FELTS: &str = r#"felts = load_witness_felts(ids.name)
+ids.felts = segments.gen_arg(felts)
+ids.n_felts = len(felts)"#;
+
+/// Files the witness hints may read: files registered under a name, and files under allowed
+/// directories named by their relative path. Nothing is readable by default.
+#[derive(Debug, Clone, Default)]
+pub struct WitnessFiles {
+    files: HashMap<String, PathBuf>,
+    dirs: Vec<PathBuf>,
+    max_bytes: Option<usize>,
+}
+
+impl WitnessFiles {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_file(mut self, name: &str, path: impl Into<PathBuf>) -> Self {
+        self.files.insert(name.to_string(), path.into());
+        self
+    }
+
+    /// Allows the files under `dir`, named by their path relative to it. Paths going up with
+    /// `..` or through symlinks pointing out of the directory are rejected.
+    pub fn with_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dirs.push(dir.into());
+        self
+    }
+
+    /// Rejects files larger than `max_bytes` before reading them.
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    pub fn resolve(&self, name: &str) -> Result<PathBuf, String> {
+        if let Some(path) = self.files.get(name) {
+            return Ok(path.clone());
+        }
+        let relative = Path::new(name);
+        let is_relative = !name.is_empty()
+            && relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+        if is_relative {
+            for dir in &self.dirs {
+                let path = dir.join(relative);
+                let (Ok(path), Ok(dir)) = (path.canonicalize(), dir.canonicalize()) else {
+                    continue;
+                };
+                if path.starts_with(&dir) {
+                    return Ok(path);
+                }
+            }
+        }
+        Err(format!("witness file {name} is not allowed"))
+    }
+
+    pub fn read(&self, name: &str) -> Result<Vec<u8>, String> {
+        let path = self.resolve(name)?;
+        let read_error = |e: std::io::Error| format!("failed to read {}: {e}", path.display());
+        if let Some(max_bytes) = self.max_bytes {
+            let len = std::fs::metadata(&path).map_err(read_error)?.len();
+            if len > max_bytes as u64 {
+                return Err(format!(
+                    "witness file {name} has {len} bytes, more than the {max_bytes} allowed"
+                ));
+            }
+        }
+        std::fs::read(&path).map_err(read_error)
+    }
+}
+
+/// Sets the files the witness hints may read, replacing any previous config.
+pub fn allow_witness_files(exec_scopes: &mut ExecutionScopes, files: WitnessFiles) {
+    insert_typed(exec_scopes, &WITNESS_FILES_KEY, files);
+}
+
+/// Splits `bytes` into 32-byte big-endian felts, failing on a trailing partial felt or a
+/// value outside of the field.
+pub fn felts_from_be_bytes(bytes: &[u8]) -> Result<Vec<Felt252>, String> {
+    if bytes.len() % 32 != 0 {
+        return Err(format!(
+            "{} bytes are not a whole number of 32-byte felts",
+            bytes.len()
+        ));
+    }
+    let max = Felt252::MAX.to_bytes_be();
+    bytes
+        .chunks(32)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let chunk: [u8; 32] = chunk.try_into().expect("32-byte chunks");
+            if chunk > max {
+                return Err(format!("felt {i} is not below the field prime"));
+            }
+            Ok(Felt252::from_bytes_be(&chunk))
+        })
+        .collect()
+}
+
+fn custom_error(message: String) -> HintError {
+    HintError::CustomHint(message.into_boxed_str())
+}
+
+// Recorded as `witness_file:<name>` inputs, so fixtures don't depend on the files
+fn read_witness(
+    vm: &VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+) -> Result<Vec<u8>, HintError> {
+    let name = get_typed_from_var_name::<ShortString>("name", vm, hint_data)?
+        .to_ascii()
+        .map_err(custom_error)?;
+    let files = get_typed_ref(exec_scopes, &WITNESS_FILES_KEY).map_err(|_| {
+        custom_error(format!(
+            "can't load witness file {name}, no files allowed with `allow_witness_files`"
+        ))
+    })?;
+    let bytes = files.read(&name).map_err(custom_error)?;
+    record_input(
+        exec_scopes,
+        &format!("witness_file:{name}"),
+        &KeccakBytes(bytes.clone()),
+    )?;
+    Ok(bytes)
+}
+
+pub fn hint_load_witness_file(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let witness = KeccakBytes(read_witness(vm, exec_scopes, hint_data)?);
+    let data = witness.write_limbs(vm)?;
+    insert_value_from_var_name(
+        "data",
+        data,
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    insert_value_from_var_name(
+        "n_bytes",
+        Felt252::from(witness.0.len()),
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    Ok(())
+}
+
+pub fn hint_load_witness_felts(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    hint_data: &HintProcessorData,
+    _constants: &HashMap<String, Felt252>,
+) -> Result<(), HintError> {
+    let felts =
+        felts_from_be_bytes(&read_witness(vm, exec_scopes, hint_data)?).map_err(custom_error)?;
+    let segment = vm.add_memory_segment();
+    let n_felts = felts.len();
+    write_felts(vm, segment, felts)?;
+    insert_value_from_var_name(
+        "felts",
+        segment,
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    insert_value_from_var_name(
+        "n_felts",
+        Felt252::from(n_felts),
+        vm,
+        &hint_data.ids_data,
+        &hint_data.ap_tracking,
+    )?;
+    Ok(())
+}
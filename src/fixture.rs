@@ -0,0 +1,122 @@
+use cairo_vm::{types::exec_scope::ExecutionScopes, vm::errors::hint_errors::HintError};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::any::Any;
+use std::collections::HashMap;
+use std::path::Path;
+
+// Exec scope variable holding the recorder, always stored in the root scope
+pub const FIXTURE_RECORDER: &str = "__fixture_recorder";
+
+/// Records the typed values hints read during a run, so the run can be replayed from a
+/// self-contained JSON fixture of the form `{"inputs": {..}, "oracles": {name: [..]}}`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FixtureRecorder {
+    inputs: HashMap<String, Value>,
+    oracles: HashMap<String, Vec<Value>>,
+}
+
+fn to_json_value<T: Serialize>(name: &str, value: &T) -> Result<Value, HintError> {
+    serde_json::to_value(value).map_err(|e| {
+        HintError::CustomHint(format!("failed to record {name}: {e}").into_boxed_str())
+    })
+}
+
+impl FixtureRecorder {
+    // Turns recording on for the run using these exec scopes
+    pub fn enable(exec_scopes: &mut ExecutionScopes) {
+        exec_scopes.data[0].insert(FIXTURE_RECORDER.to_string(), Box::new(Self::default()));
+    }
+
+    // Removes the recorder from the exec scopes, typically once the run is over
+    pub fn take(exec_scopes: &mut ExecutionScopes) -> Option<Self> {
+        exec_scopes.data[0]
+            .remove(FIXTURE_RECORDER)
+            .and_then(|recorder| recorder.downcast::<Self>().ok())
+            .map(|recorder| *recorder)
+    }
+
+    fn from_scopes(exec_scopes: &mut ExecutionScopes) -> Option<&mut Self> {
+        exec_scopes.data[0]
+            .get_mut(FIXTURE_RECORDER)
+            .and_then(|recorder| recorder.downcast_mut::<Self>())
+    }
+
+    // Inputs are expected to be constant during a run, only the first read is kept
+    pub fn record_input<T: Serialize>(&mut self, name: &str, value: &T) -> Result<(), HintError> {
+        if !self.inputs.contains_key(name) {
+            self.inputs
+                .insert(name.to_string(), to_json_value(name, value)?);
+        }
+        Ok(())
+    }
+
+    // Oracle responses are kept in the order they were produced
+    pub fn record_oracle<T: Serialize>(&mut self, name: &str, value: &T) -> Result<(), HintError> {
+        let value = to_json_value(name, value)?;
+        self.oracles
+            .entry(name.to_string())
+            .or_default()
+            .push(value);
+        Ok(())
+    }
+
+    pub fn to_json(&self) -> Value {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        let oracles = self
+            .oracles
+            .iter()
+            .map(|(name, responses)| (name.clone(), Value::Array(responses.clone())))
+            .collect();
+        let mut fixture = Map::new();
+        fixture.insert("inputs".to_string(), Value::Object(inputs));
+        fixture.insert("oracles".to_string(), Value::Object(oracles));
+        Value::Object(fixture)
+    }
+
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(&self.to_json())?;
+        std::fs::write(path, json)
+    }
+}
+
+/// Reads a typed input from the exec scopes, recording it if a `FixtureRecorder` is enabled.
+pub fn read_input<T: Any + Clone + Serialize>(
+    exec_scopes: &mut ExecutionScopes,
+    name: &str,
+) -> Result<T, HintError> {
+    let value = exec_scopes.get::<T>(name)?;
+    if let Some(recorder) = FixtureRecorder::from_scopes(exec_scopes) {
+        recorder.record_input(name, &value)?;
+    }
+    Ok(value)
+}
+
+/// Records an input read by other means than `read_input`, e.g. the program input or a witness
+/// file, if a `FixtureRecorder` is enabled.
+pub fn record_input<T: Serialize>(
+    exec_scopes: &mut ExecutionScopes,
+    name: &str,
+    value: &T,
+) -> Result<(), HintError> {
+    match FixtureRecorder::from_scopes(exec_scopes) {
+        Some(recorder) => recorder.record_input(name, value),
+        None => Ok(()),
+    }
+}
+
+/// Records a value returned by an oracle if a `FixtureRecorder` is enabled.
+pub fn record_oracle<T: Serialize>(
+    exec_scopes: &mut ExecutionScopes,
+    name: &str,
+    value: &T,
+) -> Result<(), HintError> {
+    match FixtureRecorder::from_scopes(exec_scopes) {
+        Some(recorder) => recorder.record_oracle(name, value),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,651 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::builtin_hint_processor_definition::{
+            BuiltinHintProcessor, HintProcessorData,
+        },
+        hint_processor_definition::HintProcessorLogic,
+    },
+    types::exec_scope::ExecutionScopes,
+    vm::{
+        errors::hint_errors::HintError,
+        runners::cairo_runner::{ResourceTracker, RunResources},
+        vm_core::VirtualMachine,
+    },
+};
+
+use crate::default_hints::{
+    budget::{check_step_budget, set_step_budget, StepBudget},
+    default_hint_mapping,
+    logger::{
+        set_boxed_logger, set_boxed_output_sink, set_boxed_print_sink, CairoLogger, OutputSink,
+        PrintSink,
+    },
+    HintCall, HintRegistry,
+};
+use crate::input_provider::{set_boxed_input_provider, InputProvider};
+use crate::provenance::ProvenanceTracker;
+use crate::stats::HintStatsCollector;
+
+/// Hint processor running the hints of a `HintRegistry`, then any user extension, and falling
+/// back to cairo-vm's `BuiltinHintProcessor` for the hints none of them know.
+///
+/// Hints are compiled into `HintProcessorData`, extensions must accept that format and return
+/// `HintError::UnknownHint` for the hints they don't handle.
+pub struct ExtendedHintProcessor {
+    hints: HintRegistry,
+    extensions: Vec<Box<dyn HintProcessorLogic>>,
+    builtin: BuiltinHintProcessor,
+    logger: Option<Box<dyn CairoLogger>>,
+    print_sink: Option<Box<dyn PrintSink>>,
+    output_sink: Option<Box<dyn OutputSink>>,
+    input_provider: Option<Box<dyn InputProvider>>,
+    step_budget: Option<StepBudget>,
+    stats: Option<HintStatsCollector>,
+}
+
+impl Default for ExtendedHintProcessor {
+    fn default() -> Self {
+        Self::new(default_hint_mapping())
+    }
+}
+
+impl ExtendedHintProcessor {
+    pub fn new(hints: HintRegistry) -> Self {
+        Self {
+            hints,
+            extensions: vec![],
+            builtin: BuiltinHintProcessor::new_empty(),
+            logger: None,
+            print_sink: None,
+            output_sink: None,
+            input_provider: None,
+            step_budget: None,
+            stats: None,
+        }
+    }
+
+    // Limits the number of steps of the run, tracked by the builtin processor
+    pub fn with_run_resources(mut self, run_resources: RunResources) -> Self {
+        self.set_run_resources(run_resources);
+        self
+    }
+
+    pub fn set_run_resources(&mut self, run_resources: RunResources) {
+        let extra_hints = std::mem::take(&mut self.builtin.extra_hints);
+        self.builtin = BuiltinHintProcessor::new(extra_hints, run_resources);
+    }
+
+    pub fn with_extension(mut self, extension: impl HintProcessorLogic + 'static) -> Self {
+        self.extensions.push(Box::new(extension));
+        self
+    }
+
+    // Logger of the info_* and debug_* hints, moved into the exec scopes on the first hint
+    pub fn with_logger(mut self, logger: impl CairoLogger + 'static) -> Self {
+        self.logger = Some(Box::new(logger));
+        self
+    }
+
+    // Sink of the print_* hints, moved into the exec scopes on the first hint
+    pub fn with_print_sink(mut self, sink: impl PrintSink + 'static) -> Self {
+        self.print_sink = Some(Box::new(sink));
+        self
+    }
+
+    // Sink of all the print, info and debug output, moved into the exec scopes on the first hint
+    pub fn with_output_sink(mut self, sink: impl OutputSink + 'static) -> Self {
+        self.output_sink = Some(Box::new(sink));
+        self
+    }
+
+    // Provider of the data fetched with `input_provider::fetch_input`, moved into the exec
+    // scopes on the first hint
+    pub fn with_input_provider(mut self, provider: impl InputProvider + 'static) -> Self {
+        self.input_provider = Some(Box::new(provider));
+        self
+    }
+
+    // Budget checked before every hint, moved into the exec scopes on the first hint
+    pub fn with_step_budget(mut self, budget: StepBudget) -> Self {
+        self.step_budget = Some(budget);
+        self
+    }
+
+    // Records the count and duration of every hint run, see `stats::ExecutionStats`
+    pub fn with_stats(mut self) -> Self {
+        self.enable_stats();
+        self
+    }
+
+    pub fn enable_stats(&mut self) {
+        self.stats.get_or_insert_with(HintStatsCollector::default);
+    }
+
+    pub fn stats(&self) -> Option<&HintStatsCollector> {
+        self.stats.as_ref()
+    }
+
+    pub fn stats_mut(&mut self) -> Option<&mut HintStatsCollector> {
+        self.stats.as_mut()
+    }
+
+    pub fn hints(&self) -> &HintRegistry {
+        &self.hints
+    }
+
+    pub fn hints_mut(&mut self) -> &mut HintRegistry {
+        &mut self.hints
+    }
+
+    // Gives access to `BuiltinHintProcessor::add_hint` for closure based hints
+    pub fn builtin_mut(&mut self) -> &mut BuiltinHintProcessor {
+        &mut self.builtin
+    }
+
+    // Registry hints first, then the extensions, then the builtin processor
+    fn run_hint(
+        &mut self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &Box<dyn Any>,
+        data: &HintProcessorData,
+    ) -> Result<(), HintError> {
+        if let Some(hint) = self.hints.get(&data.code) {
+            let constants: &HashMap<_, _> = &data.constants;
+            return hint(vm, exec_scopes, data, constants);
+        }
+
+        for extension in self.extensions.iter_mut() {
+            match extension.execute_hint(vm, exec_scopes, hint_data) {
+                Err(HintError::UnknownHint(_)) => continue,
+                result => return result,
+            }
+        }
+
+        self.builtin.execute_hint(vm, exec_scopes, hint_data)
+    }
+}
+
+// `Instant::now` panics on wasm32-unknown-unknown, hints are only counted there
+pub(crate) fn timer_start() -> Option<Instant> {
+    if cfg!(all(target_arch = "wasm32", target_os = "unknown")) {
+        return None;
+    }
+    Some(Instant::now())
+}
+
+impl HintProcessorLogic for ExtendedHintProcessor {
+    fn execute_hint(
+        &mut self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &Box<dyn Any>,
+    ) -> Result<(), HintError> {
+        let data = hint_data
+            .downcast_ref::<HintProcessorData>()
+            .ok_or(HintError::WrongHintData)?;
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "execute_hint",
+            code = data.code.lines().next().unwrap_or_default()
+        )
+        .entered();
+
+        // The logger and the print sink wrap the output sink, taking their lines from it
+        if let Some(sink) = self.output_sink.take() {
+            set_boxed_output_sink(exec_scopes, sink);
+        }
+        if let Some(sink) = self.print_sink.take() {
+            set_boxed_print_sink(exec_scopes, sink);
+        }
+        if let Some(logger) = self.logger.take() {
+            set_boxed_logger(exec_scopes, logger);
+        }
+        if let Some(provider) = self.input_provider.take() {
+            set_boxed_input_provider(exec_scopes, provider);
+        }
+        if let Some(budget) = self.step_budget.take() {
+            set_step_budget(exec_scopes, budget);
+        }
+        check_step_budget(vm, exec_scopes)?;
+
+        if self.stats.is_none() && self.hints.middleware().is_empty() {
+            return self
+                .run_hint(vm, exec_scopes, hint_data, data)
+                .map_err(|error| annotate_error(error, vm, exec_scopes, data));
+        }
+
+        // Cloned out of the registry, which `run_hint` borrows mutably
+        let middleware = self.hints.middleware().to_vec();
+        let call = HintCall {
+            code: &data.code,
+            pc: vm.get_pc(),
+        };
+        let mut entered = 0;
+        let mut result = Ok(());
+        for layer in &middleware {
+            result = layer.borrow_mut().before(&call);
+            if result.is_err() {
+                break;
+            }
+            entered += 1;
+        }
+
+        let mut elapsed = Duration::ZERO;
+        if result.is_ok() {
+            let start = timer_start();
+            result = self.run_hint(vm, exec_scopes, hint_data, data);
+            elapsed = start.map(|start| start.elapsed()).unwrap_or_default();
+            if let Some(stats) = self.stats.as_mut() {
+                stats.record(&data.code, elapsed);
+            }
+        }
+        for layer in middleware[..entered].iter().rev() {
+            result = layer.borrow_mut().after(&call, elapsed, result);
+        }
+        result.map_err(|error| annotate_error(error, vm, exec_scopes, data))
+    }
+}
+
+// Points failed hints at the origin of their ids when a `ProvenanceTracker` is enabled. Unknown
+// hints are left as is, callers falling back on another processor match on them
+fn annotate_error(
+    error: HintError,
+    vm: &VirtualMachine,
+    exec_scopes: &ExecutionScopes,
+    data: &HintProcessorData,
+) -> HintError {
+    if matches!(error, HintError::UnknownHint(_)) {
+        return error;
+    }
+    match ProvenanceTracker::in_scopes(exec_scopes) {
+        Some(tracker) => tracker.annotate_hint_error(error, vm, data),
+        None => error,
+    }
+}
+
+impl ResourceTracker for ExtendedHintProcessor {
+    fn consumed(&self) -> bool {
+        self.builtin.consumed()
+    }
+
+    fn consume_step(&mut self) {
+        self.builtin.consume_step()
+    }
+
+    fn get_n_steps(&self) -> Option<usize> {
+        self.builtin.get_n_steps()
+    }
+
+    fn run_resources(&self) -> &RunResources {
+        self.builtin.run_resources()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::default_hints::HintRegistry;
+    use crate::hint_processor::ExtendedHintProcessor;
+    use cairo_vm::{
+        hint_processor::{
+            builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData,
+            hint_processor_definition::HintProcessorLogic,
+        },
+        serde::deserialize_program::ApTracking,
+        types::exec_scope::ExecutionScopes,
+        vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+        Felt252,
+    };
+    use std::any::Any;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    fn mark_registry_hint(
+        _vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        _hint_data: &HintProcessorData,
+        _constants: &HashMap<String, Felt252>,
+    ) -> Result<(), HintError> {
+        exec_scopes.insert_value("handled_by", "registry");
+        Ok(())
+    }
+
+    struct MarkExtension;
+
+    impl HintProcessorLogic for MarkExtension {
+        fn execute_hint(
+            &mut self,
+            _vm: &mut VirtualMachine,
+            exec_scopes: &mut ExecutionScopes,
+            hint_data: &Box<dyn Any>,
+        ) -> Result<(), HintError> {
+            let data = hint_data.downcast_ref::<HintProcessorData>().unwrap();
+            if data.code != "extension_hint" {
+                return Err(HintError::UnknownHint(data.code.clone().into_boxed_str()));
+            }
+            exec_scopes.insert_value("handled_by", "extension");
+            Ok(())
+        }
+    }
+
+    fn run(
+        processor: &mut ExtendedHintProcessor,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        code: &str,
+    ) -> Result<(), HintError> {
+        let hint_data = processor
+            .compile_hint(
+                code,
+                &ApTracking::default(),
+                &HashMap::new(),
+                &[],
+                Rc::new(HashMap::new()),
+            )
+            .unwrap();
+        processor.execute_hint(vm, exec_scopes, &hint_data)
+    }
+
+    #[test]
+    fn test_extended_hint_processor_dispatch() {
+        let mut registry = HintRegistry::new("test");
+        registry
+            .register("registry_hint", mark_registry_hint)
+            .unwrap();
+        let mut processor = ExtendedHintProcessor::new(registry).with_extension(MarkExtension);
+
+        let mut vm = VirtualMachine::new(false, false);
+        vm.add_memory_segment();
+        vm.add_memory_segment();
+        let mut exec_scopes = ExecutionScopes::new();
+
+        run(&mut processor, &mut vm, &mut exec_scopes, "registry_hint").unwrap();
+        assert_eq!(exec_scopes.get::<&str>("handled_by").unwrap(), "registry");
+
+        run(&mut processor, &mut vm, &mut exec_scopes, "extension_hint").unwrap();
+        assert_eq!(exec_scopes.get::<&str>("handled_by").unwrap(), "extension");
+
+        // Falls back to the builtin hint processor
+        run(
+            &mut processor,
+            &mut vm,
+            &mut exec_scopes,
+            "memory[ap] = segments.add()",
+        )
+        .unwrap();
+        assert!(vm.get_relocatable(vm.get_ap()).is_ok());
+
+        assert!(matches!(
+            run(&mut processor, &mut vm, &mut exec_scopes, "unknown_hint"),
+            Err(HintError::UnknownHint(_))
+        ));
+    }
+
+    #[test]
+    fn test_hint_stats() {
+        use crate::stats::{ExecutionStats, HintStats};
+
+        let mut registry = HintRegistry::new("test");
+        registry
+            .register("registry_hint", mark_registry_hint)
+            .unwrap();
+        let mut processor = ExtendedHintProcessor::new(registry);
+        let mut vm = VirtualMachine::new(false, false);
+        let mut exec_scopes = ExecutionScopes::new();
+
+        run(&mut processor, &mut vm, &mut exec_scopes, "registry_hint").unwrap();
+        assert!(processor.stats().is_none());
+
+        processor = processor.with_stats();
+        for _ in 0..3 {
+            run(&mut processor, &mut vm, &mut exec_scopes, "registry_hint").unwrap();
+        }
+        assert!(run(&mut processor, &mut vm, &mut exec_scopes, "unknown_hint").is_err());
+        let stats = processor.stats().unwrap();
+        assert_eq!(stats.get("registry_hint").unwrap().count, 3);
+        assert_eq!(stats.get("unknown_hint").unwrap().count, 1);
+
+        let report = ExecutionStats {
+            steps: 10,
+            hints: stats
+                .iter()
+                .map(|(code, stats)| (code.clone(), *stats))
+                .collect(),
+            ..Default::default()
+        };
+        assert_eq!(report.hint_invocations(), 4);
+        let json = report.to_json();
+        assert_eq!(json["hints"]["registry_hint"]["count"], 3);
+        assert_eq!(
+            serde_json::from_value::<ExecutionStats>(json).unwrap(),
+            report
+        );
+        let hint = HintStats {
+            count: 4,
+            total_nanos: 100,
+        };
+        assert_eq!(hint.mean(), std::time::Duration::from_nanos(25));
+    }
+
+    #[test]
+    fn test_hint_middleware() {
+        use crate::default_hints::{HintCall, HintMiddleware};
+        use std::cell::RefCell;
+        use std::time::Duration;
+
+        // Records the calls it sees, failing the hints whose code is `fail`
+        #[derive(Default)]
+        struct Recorder {
+            name: &'static str,
+            fail: Option<&'static str>,
+            events: Rc<RefCell<Vec<String>>>,
+        }
+
+        impl HintMiddleware for Recorder {
+            fn before(&mut self, call: &HintCall) -> Result<(), HintError> {
+                self.events
+                    .borrow_mut()
+                    .push(format!("{} before {} at {}", self.name, call.code, call.pc));
+                match self.fail {
+                    Some(code) if code == call.code => Err(HintError::CustomHint(
+                        format!("{code} failed by {}", self.name).into_boxed_str(),
+                    )),
+                    _ => Ok(()),
+                }
+            }
+
+            fn after(
+                &mut self,
+                call: &HintCall,
+                _elapsed: Duration,
+                result: Result<(), HintError>,
+            ) -> Result<(), HintError> {
+                self.events.borrow_mut().push(format!(
+                    "{} after {} ok={}",
+                    self.name,
+                    call.code,
+                    result.is_ok()
+                ));
+                result
+            }
+        }
+
+        let events = Rc::new(RefCell::new(vec![]));
+        let mut registry = HintRegistry::new("test");
+        registry
+            .register("registry_hint", mark_registry_hint)
+            .unwrap();
+        registry.add_middleware(Recorder {
+            name: "outer",
+            events: events.clone(),
+            ..Default::default()
+        });
+        let inner: Rc<RefCell<Recorder>> = Rc::new(RefCell::new(Recorder {
+            name: "inner",
+            fail: Some("registry_hint"),
+            events: events.clone(),
+        }));
+        let mut processor = ExtendedHintProcessor::new(registry.clone());
+        let mut vm = VirtualMachine::new(false, false);
+        vm.add_memory_segment();
+        vm.add_memory_segment();
+        let mut exec_scopes = ExecutionScopes::new();
+
+        // Wraps registry and builtin hints alike, `after` in reverse order
+        run(&mut processor, &mut vm, &mut exec_scopes, "registry_hint").unwrap();
+        processor.hints_mut().add_shared_middleware(inner.clone());
+        run(
+            &mut processor,
+            &mut vm,
+            &mut exec_scopes,
+            "memory[ap] = segments.add()",
+        )
+        .unwrap();
+        assert_eq!(
+            events.take(),
+            [
+                "outer before registry_hint at 0:0",
+                "outer after registry_hint ok=true",
+                "outer before memory[ap] = segments.add() at 0:0",
+                "inner before memory[ap] = segments.add() at 0:0",
+                "inner after memory[ap] = segments.add() ok=true",
+                "outer after memory[ap] = segments.add() ok=true",
+            ]
+        );
+
+        // An injected failure skips the hint, the outer middleware seeing the error
+        exec_scopes.insert_value("handled_by", "nobody");
+        let err = run(&mut processor, &mut vm, &mut exec_scopes, "registry_hint").unwrap_err();
+        assert!(err.to_string().contains("registry_hint failed by inner"));
+        assert_eq!(exec_scopes.get::<&str>("handled_by").unwrap(), "nobody");
+        assert_eq!(
+            events.take(),
+            [
+                "outer before registry_hint at 0:0",
+                "inner before registry_hint at 0:0",
+                "outer after registry_hint ok=false",
+            ]
+        );
+
+        // Clones share the middleware, `merge` brings it along
+        assert_eq!(registry.middleware().len(), 1);
+        let mut merged = HintRegistry::new("merged");
+        merged.merge(registry).unwrap();
+        assert_eq!(merged.middleware().len(), 1);
+        inner.borrow_mut().fail = None;
+        run(&mut processor, &mut vm, &mut exec_scopes, "registry_hint").unwrap();
+        assert_eq!(exec_scopes.get::<&str>("handled_by").unwrap(), "registry");
+    }
+
+    #[test]
+    fn test_input_provider() {
+        use crate::fixture::FixtureRecorder;
+        use crate::input_provider::{
+            fetch_input, set_input_provider, AsyncAdapter, AsyncInputProvider, InputFuture,
+        };
+        use crate::types::uint256::Uint256;
+        use num_bigint::BigUint;
+        use serde_json::{json, Value};
+        use std::cell::Cell;
+        use std::future::Future;
+        use std::pin::Pin;
+        use std::task::{Context, Poll};
+
+        fn fetch_balance(
+            _vm: &mut VirtualMachine,
+            exec_scopes: &mut ExecutionScopes,
+            _hint_data: &HintProcessorData,
+            _constants: &HashMap<String, Felt252>,
+        ) -> Result<(), HintError> {
+            let balance: Uint256 = fetch_input(exec_scopes, "balance:0xabc")?;
+            exec_scopes.insert_value("balance", balance);
+            Ok(())
+        }
+
+        let mut exec_scopes = ExecutionScopes::new();
+        let err = fetch_input::<Uint256>(&mut exec_scopes, "balance:0xabc").unwrap_err();
+        assert!(err.to_string().contains("no input provider set"), "{err}");
+
+        // Queried lazily through the hint processor, once per key
+        let calls = Rc::new(Cell::new(0));
+        let provider_calls = calls.clone();
+        let mut registry = HintRegistry::new("test");
+        registry.register("fetch_balance", fetch_balance).unwrap();
+        let mut processor =
+            ExtendedHintProcessor::new(registry).with_input_provider(move |key: &str| {
+                provider_calls.set(provider_calls.get() + 1);
+                match key {
+                    "balance:0xabc" => Ok(json!("0x1000")),
+                    _ => Err(format!("unknown key {key}")),
+                }
+            });
+        let mut vm = VirtualMachine::new(false, false);
+        FixtureRecorder::enable(&mut exec_scopes);
+        for _ in 0..2 {
+            run(&mut processor, &mut vm, &mut exec_scopes, "fetch_balance").unwrap();
+        }
+        assert_eq!(calls.get(), 1);
+        assert_eq!(
+            exec_scopes.get::<Uint256>("balance").unwrap(),
+            Uint256::new(BigUint::from(0x1000u32))
+        );
+        let err = fetch_input::<Value>(&mut exec_scopes, "balance:0xdef").unwrap_err();
+        assert!(
+            err.to_string().contains("unknown key balance:0xdef"),
+            "{err}"
+        );
+        let err = fetch_input::<u8>(&mut exec_scopes, "balance:0xabc").unwrap_err();
+        assert!(err.to_string().contains("invalid balance:0xabc"), "{err}");
+        let fixture = FixtureRecorder::take(&mut exec_scopes).unwrap().to_json();
+        assert_eq!(fixture["oracles"]["balance:0xabc"][0], "0x1000");
+
+        // Async providers, polled until ready
+        struct YieldOnce(bool);
+
+        impl Future for YieldOnce {
+            type Output = ();
+
+            fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+                if self.0 {
+                    return Poll::Ready(());
+                }
+                self.0 = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+
+        struct Remote;
+
+        impl AsyncInputProvider for Remote {
+            fn fetch<'a>(&'a mut self, key: &'a str) -> InputFuture<'a> {
+                Box::pin(async move {
+                    YieldOnce(false).await;
+                    Ok(json!({ "key": key }))
+                })
+            }
+        }
+
+        let mut exec_scopes = ExecutionScopes::new();
+        set_input_provider(&mut exec_scopes, AsyncAdapter::new(Remote));
+        let value: Value = fetch_input(&mut exec_scopes, "header:1").unwrap();
+        assert_eq!(value, json!({ "key": "header:1" }));
+
+        let recorded: HashMap<String, Value> = HashMap::from([("header:1".to_string(), json!(1))]);
+        set_input_provider(&mut exec_scopes, recorded);
+        assert_eq!(fetch_input::<u8>(&mut exec_scopes, "header:1").unwrap(), 1);
+        assert!(fetch_input::<u8>(&mut exec_scopes, "header:2").is_err());
+    }
+
+    #[test]
+    fn test_extended_hint_processor_default_hints() {
+        let processor = ExtendedHintProcessor::default();
+        assert!(processor
+            .hints()
+            .contains(crate::default_hints::utils::HINT_BIT_LENGTH));
+    }
+}
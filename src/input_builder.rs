@@ -0,0 +1,303 @@
+//! Program inputs composed in code rather than written as JSON by hand, e.g. in tests or
+//! services assembling inputs from several sources.
+
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+
+use crate::cairo_type::{CairoWritable, Layout};
+use crate::default_hints::program_input::ProgramInput;
+
+type WriteField = Rc<dyn Fn(&mut VirtualMachine, Relocatable) -> Result<Relocatable, HintError>>;
+
+// How a field is laid out in the input struct
+#[derive(Clone)]
+enum Shape {
+    Value(Layout),
+    Array,
+}
+
+#[derive(Clone)]
+struct InputField {
+    name: String,
+    json: Result<Value, String>,
+    shape: Shape,
+    write: WriteField,
+}
+
+/// Builds a program input field by field, as the JSON the hints parse or directly in memory,
+/// the fields being laid out one after the other in the order they were added.
+///
+/// ```ignore
+/// let input = InputBuilder::new()
+///     .field("balance", Uint256::from(..))
+///     .array("proof", proof_nodes)
+///     .schema(Input::layout());
+/// let json = input.to_program_input()?;
+/// let end = input.write(vm, address)?;
+/// ```
+#[derive(Clone, Default)]
+pub struct InputBuilder {
+    fields: Vec<InputField>,
+    schema: Option<Layout>,
+}
+
+impl InputBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A value laid out in place, as a member of the input struct.
+    pub fn field<T: Serialize + CairoWritable + 'static>(
+        mut self,
+        name: impl Into<String>,
+        value: T,
+    ) -> Self {
+        let name = name.into();
+        let json = serde_json::to_value(&value).map_err(|e| format!("invalid {name}: {e}"));
+        self.fields.push(InputField {
+            shape: Shape::Value(T::layout()),
+            name,
+            json,
+            write: Rc::new(move |vm, address| value.to_memory(vm, address)),
+        });
+        self
+    }
+
+    /// An array laid out as `(<name>_len, <name>)`, the length followed by a pointer to a new
+    /// segment holding the values.
+    pub fn array<T: Serialize + CairoWritable + 'static>(
+        mut self,
+        name: impl Into<String>,
+        values: impl IntoIterator<Item = T>,
+    ) -> Self {
+        let name = name.into();
+        let values: Vec<T> = values.into_iter().collect();
+        let json = serde_json::to_value(&values).map_err(|e| format!("invalid {name}: {e}"));
+        self.fields.push(InputField {
+            shape: Shape::Array,
+            name,
+            json,
+            write: Rc::new(move |vm, address| {
+                let segment = vm.add_memory_segment();
+                let mut ptr = segment;
+                for value in &values {
+                    ptr = value.to_memory(vm, ptr)?;
+                }
+                vm.insert_value(address, Felt252::from(values.len()))?;
+                vm.insert_value((address + 1)?, segment)?;
+                Ok((address + 2)?)
+            }),
+        });
+        self
+    }
+
+    /// Checks the fields against `layout`, e.g. the `layout()` of the input struct of the
+    /// program, before emitting the input.
+    pub fn schema(mut self, layout: Layout) -> Self {
+        self.schema = Some(layout);
+        self
+    }
+
+    /// The members written by `write`, arrays taking their length and pointer cells.
+    pub fn layout(&self) -> Layout {
+        self.fields
+            .iter()
+            .fold(Layout::new("ProgramInput"), |layout, field| {
+                match &field.shape {
+                    Shape::Value(nested) => layout.nested(&field.name, nested.clone()),
+                    Shape::Array => layout
+                        .felt(format!("{}_len", field.name))
+                        .pointer(&field.name),
+                }
+            })
+    }
+
+    /// Fails on a duplicate field or a value that didn't serialize and, when a schema is set,
+    /// on members missing from it, out of order or of another size.
+    pub fn validate(&self) -> Result<(), String> {
+        let mut names = HashSet::new();
+        for field in &self.fields {
+            if !names.insert(field.name.as_str()) {
+                return Err(format!("input field {} is set twice", field.name));
+            }
+            if let Err(e) = &field.json {
+                return Err(e.clone());
+            }
+        }
+        let Some(schema) = &self.schema else {
+            return Ok(());
+        };
+        let layout = self.layout();
+        for (i, expected) in schema.fields.iter().enumerate() {
+            let Some(member) = layout.fields.get(i) else {
+                return Err(format!(
+                    "input field {} of {} is missing",
+                    expected.name, schema.name
+                ));
+            };
+            if member.name != expected.name {
+                return Err(format!(
+                    "input field {} is where {} expects {}",
+                    member.name, schema.name, expected.name
+                ));
+            }
+            if member.width() != expected.width() {
+                return Err(format!(
+                    "input field {} takes {} felts, {} expects {}",
+                    member.name,
+                    member.width(),
+                    schema.name,
+                    expected.width()
+                ));
+            }
+        }
+        match layout.fields.get(schema.fields.len()) {
+            Some(extra) => Err(format!(
+                "input field {} is not in {}",
+                extra.name, schema.name
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// The input as a JSON object with one key per field.
+    pub fn to_json(&self) -> Result<Value, String> {
+        self.validate()?;
+        let mut object = Map::new();
+        for field in &self.fields {
+            object.insert(field.name.clone(), field.json.clone()?);
+        }
+        Ok(Value::Object(object))
+    }
+
+    /// The input the `load_input` hints read from the exec scopes.
+    pub fn to_program_input(&self) -> Result<ProgramInput, String> {
+        self.to_json().map(ProgramInput)
+    }
+
+    /// Deserializes the input as the `T` a program takes, e.g. to pass it to `run_program`.
+    pub fn build<T: DeserializeOwned>(&self) -> Result<T, String> {
+        serde_json::from_value(self.to_json()?).map_err(|e| format!("invalid program input: {e}"))
+    }
+
+    /// Writes the fields one after the other from `address`, returning the end of the input.
+    pub fn write(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        self.validate()
+            .map_err(|e| HintError::CustomHint(e.into_boxed_str()))?;
+        self.fields
+            .iter()
+            .try_fold(address, |address, field| (field.write)(vm, address))
+    }
+}
+
+// Field names only, the values being behind closures
+impl std::fmt::Debug for InputBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InputBuilder")
+            .field(
+                "fields",
+                &self
+                    .fields
+                    .iter()
+                    .map(|field| &field.name)
+                    .collect::<Vec<_>>(),
+            )
+            .field("schema", &self.schema.as_ref().map(|schema| &schema.name))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{felt, uint256};
+    use cairo_vm::{vm::vm_core::VirtualMachine, Felt252};
+    use num_bigint::BigUint;
+    use serde::Deserialize;
+
+    #[test]
+    fn test_input_builder() {
+        use crate::cairo_type::Layout;
+        use crate::input_builder::InputBuilder;
+
+        #[derive(Debug, Deserialize)]
+        struct Input {
+            owner: felt::Felt,
+            balance: uint256::Uint256,
+            proof: Vec<felt::Felt>,
+        }
+
+        let proof = [3u64, 4].map(|value| felt::Felt(Felt252::from(value)));
+        let builder = InputBuilder::new()
+            .field("owner", felt::Felt(Felt252::from(7)))
+            .field(
+                "balance",
+                uint256::Uint256::new(BigUint::from(1u32) << 128usize),
+            )
+            .array("proof", proof.clone());
+        let input: Input = builder.build().unwrap();
+        assert_eq!(input.owner, felt::Felt(Felt252::from(7)));
+        assert_eq!(input.balance.to_biguint(), BigUint::from(1u32) << 128usize);
+        assert_eq!(input.proof, proof);
+
+        let schema = Layout::new("Input")
+            .felt("owner")
+            .member::<uint256::Uint256>("balance")
+            .felt("proof_len")
+            .pointer("proof");
+        let builder = builder.schema(schema.clone());
+        assert_eq!(builder.layout().size(), 5);
+        let mut vm = VirtualMachine::new(false, false);
+        let base = vm.add_memory_segment();
+        let end = builder.write(&mut vm, base).unwrap();
+        assert_eq!(end, (base + 5usize).unwrap());
+        assert_eq!(
+            *vm.get_integer((base + 2usize).unwrap()).unwrap(),
+            Felt252::ONE
+        );
+        assert_eq!(
+            *vm.get_integer((base + 3usize).unwrap()).unwrap(),
+            Felt252::from(2)
+        );
+        let array = vm.get_relocatable((base + 4usize).unwrap()).unwrap();
+        assert_eq!(
+            *vm.get_integer((array + 1usize).unwrap()).unwrap(),
+            Felt252::from(4)
+        );
+
+        let swapped = InputBuilder::new()
+            .field("balance", uint256::Uint256::new(BigUint::from(1u32)))
+            .field("owner", felt::Felt(Felt252::from(7)))
+            .schema(schema.clone());
+        assert_eq!(
+            swapped.to_json().unwrap_err(),
+            "input field balance is where Input expects owner"
+        );
+        let wrong_size = InputBuilder::new()
+            .field("owner", felt::Felt(Felt252::from(7)))
+            .field("balance", felt::Felt(Felt252::from(1)))
+            .schema(schema);
+        assert_eq!(
+            wrong_size.to_json().unwrap_err(),
+            "input field balance takes 1 felts, Input expects 2"
+        );
+        let twice = InputBuilder::new()
+            .field("owner", felt::Felt(Felt252::from(7)))
+            .field("owner", felt::Felt(Felt252::from(8)));
+        assert_eq!(
+            twice.to_json().unwrap_err(),
+            "input field owner is set twice"
+        );
+    }
+}
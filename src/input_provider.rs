@@ -0,0 +1,175 @@
+//! Lazily fetched external data. Instead of materializing every proof and block of a run in
+//! the program input JSON, hints ask an `InputProvider` for the data they need by key, e.g.
+//! `storage_proof:0xabc..`, and the provider fetches it, from an RPC endpoint for instance.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+use cairo_vm::{types::exec_scope::ExecutionScopes, vm::errors::hint_errors::HintError};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::fixture::record_oracle;
+use crate::scopes::{find_typed_mut, insert_typed, INPUT_PROVIDER_KEY};
+
+// Exec scope variable holding the `ProviderState`, stored in the root scope
+pub const INPUT_PROVIDER: &str = "__input_provider";
+
+/// Source of the external data hints request by key, queried once per key and run.
+pub trait InputProvider {
+    fn fetch(&mut self, key: &str) -> Result<Value, String>;
+}
+
+impl<F: FnMut(&str) -> Result<Value, String>> InputProvider for F {
+    fn fetch(&mut self, key: &str) -> Result<Value, String> {
+        self(key)
+    }
+}
+
+/// Fixed data, e.g. to replay a run from recorded responses.
+impl InputProvider for HashMap<String, Value> {
+    fn fetch(&mut self, key: &str) -> Result<Value, String> {
+        self.get(key)
+            .cloned()
+            .ok_or_else(|| format!("no input for {key}"))
+    }
+}
+
+pub type InputFuture<'a> = Pin<Box<dyn Future<Output = Result<Value, String>> + 'a>>;
+
+/// Async flavor of `InputProvider`, run to completion by an `AsyncAdapter` as hints are
+/// synchronous.
+pub trait AsyncInputProvider {
+    fn fetch<'a>(&'a mut self, key: &'a str) -> InputFuture<'a>;
+}
+
+type BlockOn = Box<dyn for<'a> FnMut(InputFuture<'a>) -> Result<Value, String>>;
+
+/// Runs an `AsyncInputProvider` as an `InputProvider`, blocking on each fetch. By default the
+/// future is polled on the current thread, which suits runtime independent clients; clients
+/// needing a runtime, such as tokio based ones, go through `with_block_on`.
+pub struct AsyncAdapter<P> {
+    provider: P,
+    block_on: BlockOn,
+}
+
+impl<P: AsyncInputProvider> AsyncAdapter<P> {
+    pub fn new(provider: P) -> Self {
+        Self {
+            provider,
+            block_on: Box::new(|future: InputFuture<'_>| block_on(future)),
+        }
+    }
+
+    /// Blocks with `block_on`, e.g. `|future| runtime.block_on(future)`.
+    pub fn with_block_on(
+        mut self,
+        block_on: impl for<'a> FnMut(InputFuture<'a>) -> Result<Value, String> + 'static,
+    ) -> Self {
+        self.block_on = Box::new(block_on);
+        self
+    }
+}
+
+impl<P: AsyncInputProvider> InputProvider for AsyncAdapter<P> {
+    fn fetch(&mut self, key: &str) -> Result<Value, String> {
+        (self.block_on)(self.provider.fetch(key))
+    }
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Polls `future` on the current thread, parking it until the future is woken.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// The provider of a run along with the responses it gave, so that every key is fetched once.
+pub struct ProviderState {
+    provider: Box<dyn InputProvider>,
+    cache: HashMap<String, Value>,
+}
+
+impl ProviderState {
+    pub fn new(provider: Box<dyn InputProvider>) -> Self {
+        Self {
+            provider,
+            cache: HashMap::new(),
+        }
+    }
+
+    pub fn fetch(&mut self, key: &str) -> Result<&Value, String> {
+        if !self.cache.contains_key(key) {
+            let value = self.provider.fetch(key)?;
+            self.cache.insert(key.to_string(), value);
+        }
+        Ok(&self.cache[key])
+    }
+
+    /// Number of distinct keys fetched so far.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+/// Injects the provider `fetch_input` queries.
+pub fn set_input_provider(
+    exec_scopes: &mut ExecutionScopes,
+    provider: impl InputProvider + 'static,
+) {
+    set_boxed_input_provider(exec_scopes, Box::new(provider));
+}
+
+pub fn set_boxed_input_provider(
+    exec_scopes: &mut ExecutionScopes,
+    provider: Box<dyn InputProvider>,
+) {
+    insert_typed(
+        exec_scopes,
+        &INPUT_PROVIDER_KEY,
+        ProviderState::new(provider),
+    );
+}
+
+/// Fetches the data named `key` and deserializes it as a `T`, for hints needing external data.
+/// Responses are cached for the run and recorded as oracle responses when a `FixtureRecorder`
+/// is enabled.
+pub fn fetch_input<T: DeserializeOwned>(
+    exec_scopes: &mut ExecutionScopes,
+    key: &str,
+) -> Result<T, HintError> {
+    let custom_error = |message: String| HintError::CustomHint(message.into_boxed_str());
+    let state = find_typed_mut(exec_scopes, &INPUT_PROVIDER_KEY)?.ok_or_else(|| {
+        custom_error(format!(
+            "can't fetch {key}, no input provider set with `set_input_provider`"
+        ))
+    })?;
+    let value = state
+        .fetch(key)
+        .map_err(|e| custom_error(format!("failed to fetch {key}: {e}")))?
+        .clone();
+    record_oracle(exec_scopes, key, &value)?;
+    serde_json::from_value(value).map_err(|e| custom_error(format!("invalid {key}: {e}")))
+}
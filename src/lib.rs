@@ -1,5 +1,65 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+// The std prelude items that come from alloc, imported by the modules built without std
+pub(crate) mod stdlib {
+    pub(crate) mod prelude {
+        pub(crate) use alloc::{
+            format,
+            string::{String, ToString},
+            vec,
+            vec::Vec,
+        };
+    }
+}
+
 pub mod cairo_type;
+pub mod segment_allocator;
+pub mod types;
+pub mod validate;
+
+#[cfg(feature = "std")]
+pub mod builtin_segments;
+#[cfg(feature = "std")]
+pub mod cairo1_utils;
+#[cfg(feature = "std")]
+pub mod chunked_writer;
+#[cfg(feature = "std")]
+pub mod codegen;
+#[cfg(feature = "std")]
+pub mod commitments;
+#[cfg(feature = "std")]
 pub mod default_hints;
+#[cfg(feature = "std")]
+pub mod fixture;
+#[cfg(feature = "std")]
+pub mod hint_processor;
+#[cfg(feature = "std")]
+pub mod input_builder;
+#[cfg(feature = "std")]
+pub mod input_provider;
+#[cfg(feature = "std")]
+pub mod output;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+#[cfg(feature = "std")]
+pub mod pipeline;
+#[cfg(feature = "std")]
+pub mod provenance;
+#[cfg(any(feature = "rpc", feature = "starknet-rpc"))]
+pub mod providers;
+#[cfg(feature = "std")]
+pub mod runner;
+#[cfg(feature = "std")]
+pub mod scope_snapshot;
+#[cfg(feature = "std")]
+pub mod scopes;
+#[cfg(feature = "std")]
+pub mod stats;
+#[cfg(feature = "std")]
 pub mod stwo_utils;
-pub mod types;
+#[cfg(feature = "std")]
+pub mod testing;
+#[cfg(feature = "std")]
 pub mod vm;
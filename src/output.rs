@@ -0,0 +1,187 @@
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+use num_traits::ToPrimitive;
+
+use crate::cairo_type::CairoType;
+
+/// A value decoded from the output segment through an `OutputReader`. Implemented for every
+/// `CairoType`; structs with variable length fields implement it by reading field by field.
+pub trait CairoReadable: Sized {
+    fn read(reader: &mut OutputReader) -> Result<Self, HintError>;
+}
+
+impl<T: CairoType> CairoReadable for T {
+    fn read(reader: &mut OutputReader) -> Result<Self, HintError> {
+        let address = reader.reserve(T::n_fields())?;
+        T::from_memory(reader.vm, address)
+    }
+}
+
+/// Sequential reader over the output segment, decoding values in the order the program wrote
+/// them instead of by hardcoded felt offsets.
+pub struct OutputReader<'a> {
+    vm: &'a VirtualMachine,
+    base: Relocatable,
+    len: usize,
+    offset: usize,
+}
+
+impl<'a> OutputReader<'a> {
+    pub fn new(vm: &'a VirtualMachine, base: Relocatable, len: usize) -> Self {
+        Self {
+            vm,
+            base,
+            len,
+            offset: 0,
+        }
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.len - self.offset
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    fn reserve(&mut self, size: usize) -> Result<Relocatable, HintError> {
+        if size > self.remaining() {
+            return Err(HintError::CustomHint(
+                format!(
+                    "output has {} felts, can't read {size} felts at offset {}",
+                    self.len, self.offset
+                )
+                .into_boxed_str(),
+            ));
+        }
+        let address = (self.base + self.offset)?;
+        self.offset += size;
+        Ok(address)
+    }
+
+    pub fn read<T: CairoReadable>(&mut self) -> Result<T, HintError> {
+        T::read(self)
+    }
+
+    pub fn read_felt(&mut self) -> Result<Felt252, HintError> {
+        let address = self.reserve(1)?;
+        Ok(self.vm.get_integer(address)?.into_owned())
+    }
+
+    fn read_len(&mut self) -> Result<usize, HintError> {
+        let len = self.read_felt()?;
+        len.to_usize().ok_or_else(|| {
+            HintError::CustomHint(format!("invalid array length {len}").into_boxed_str())
+        })
+    }
+
+    /// Reads a length followed by that many inlined elements.
+    pub fn read_array<T: CairoReadable>(&mut self) -> Result<Vec<T>, HintError> {
+        let len = self.read_len()?;
+        (0..len).map(|_| self.read()).collect()
+    }
+
+    /// Reads a pointer and decodes the value it points to, outside of the output segment.
+    pub fn read_ptr<T: CairoType>(&mut self) -> Result<T, HintError> {
+        let address = self.reserve(1)?;
+        let ptr = self.vm.get_relocatable(address)?;
+        T::from_memory(self.vm, ptr)
+    }
+
+    /// Reads a `(len, ptr)` pair and decodes the `len` values starting at `ptr`.
+    pub fn read_ptr_array<T: CairoType>(&mut self) -> Result<Vec<T>, HintError> {
+        let len = self.read_len()?;
+        let address = self.reserve(1)?;
+        let ptr = self.vm.get_relocatable(address)?;
+        T::from_memory_range(self.vm, ptr, len)
+    }
+
+    /// Errors if part of the output was left unread, e.g. after the program added a field.
+    pub fn finish(self) -> Result<(), HintError> {
+        if !self.is_empty() {
+            return Err(HintError::CustomHint(
+                format!("{} output felts left unread", self.remaining()).into_boxed_str(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::output::{CairoReadable, OutputReader};
+    use crate::types::{felt::Felt, uint256::Uint256};
+    use cairo_vm::{
+        types::relocatable::MaybeRelocatable,
+        vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+        Felt252,
+    };
+    use num_bigint::BigUint;
+
+    #[derive(Debug, PartialEq)]
+    struct Report {
+        block: Felt,
+        hashes: Vec<Uint256>,
+        roots: Vec<Felt>,
+    }
+
+    impl CairoReadable for Report {
+        fn read(reader: &mut OutputReader) -> Result<Self, HintError> {
+            Ok(Self {
+                block: reader.read()?,
+                hashes: reader.read_array()?,
+                roots: reader.read_ptr_array()?,
+            })
+        }
+    }
+
+    #[test]
+    fn test_read_output_struct() {
+        let mut vm = VirtualMachine::new(false, false);
+        let output = vm.add_memory_segment();
+        let roots = vm.add_memory_segment();
+        vm.load_data(
+            roots,
+            &vec![MaybeRelocatable::from(7), MaybeRelocatable::from(8)],
+        )
+        .unwrap();
+        let cells: Vec<MaybeRelocatable> = vec![
+            100.into(),
+            1.into(),
+            5.into(),
+            0.into(),
+            2.into(),
+            roots.into(),
+            42.into(),
+        ];
+        vm.load_data(output, &cells).unwrap();
+
+        let mut reader = OutputReader::new(&vm, output, cells.len());
+        let report: Report = reader.read().unwrap();
+        assert_eq!(
+            report,
+            Report {
+                block: Felt(Felt252::from(100)),
+                hashes: vec![Uint256::new(BigUint::from(5u32))],
+                roots: vec![Felt(Felt252::from(7)), Felt(Felt252::from(8))],
+            }
+        );
+        assert_eq!(reader.remaining(), 1);
+        assert_eq!(reader.read_felt().unwrap(), Felt252::from(42));
+        assert!(reader.read_felt().is_err());
+        reader.finish().unwrap();
+
+        // Leftover felts are reported
+        let mut reader = OutputReader::new(&vm, output, cells.len());
+        reader.read_felt().unwrap();
+        assert!(reader.finish().is_err());
+    }
+}
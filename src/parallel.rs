@@ -0,0 +1,161 @@
+//! Parallel conversion of large arrays of parsed inputs into felts, ahead of the single batched
+//! write of their cells. Converting millions of `Uint256`s into limbs is independent per value,
+//! so it is spread over the rayon thread pool instead of running inside `to_memory` one value
+//! at a time.
+//!
+//! ```ignore
+//! let balances: Vec<Uint256> = serde_json::from_value(input["balances"].take())?;
+//! let cells = PreparedFelts::prepare(&balances);
+//! let segment = vm.add_memory_segment();
+//! cells.write(vm, segment)?;
+//! ```
+
+use cairo_vm::{
+    types::relocatable::{MaybeRelocatable, Relocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use rayon::prelude::*;
+
+use crate::types::{
+    bounded_uint::{Uint128, Uint16, Uint32, Uint64, Uint8},
+    felt::Felt,
+    uint_bits::{Inline, UintBits},
+};
+
+/// Values laid out as `N_FELTS` felts in place, the cells `to_memory` writes, so that they can
+/// be computed without the VM.
+pub trait InlineFelts: Sync {
+    const N_FELTS: usize;
+
+    /// Fills the `N_FELTS` cells of the value.
+    fn fill_cells(&self, cells: &mut [MaybeRelocatable]);
+}
+
+impl<const BITS: usize, const LIMB: usize> InlineFelts for UintBits<BITS, LIMB, Inline> {
+    const N_FELTS: usize = Self::N_LIMBS;
+
+    fn fill_cells(&self, cells: &mut [MaybeRelocatable]) {
+        for (cell, limb) in cells.iter_mut().zip(self.to_limbs()) {
+            *cell = limb.into();
+        }
+    }
+}
+
+impl InlineFelts for Felt252 {
+    const N_FELTS: usize = 1;
+
+    fn fill_cells(&self, cells: &mut [MaybeRelocatable]) {
+        cells[0] = (*self).into();
+    }
+}
+
+impl InlineFelts for Felt {
+    const N_FELTS: usize = 1;
+
+    fn fill_cells(&self, cells: &mut [MaybeRelocatable]) {
+        cells[0] = self.0.into();
+    }
+}
+
+macro_rules! inline_bounded_uint {
+    ($($ty:ty),*) => {
+        $(
+            impl InlineFelts for $ty {
+                const N_FELTS: usize = 1;
+
+                fn fill_cells(&self, cells: &mut [MaybeRelocatable]) {
+                    cells[0] = self.to_felt().into();
+                }
+            }
+        )*
+    };
+}
+
+inline_bounded_uint!(Uint8, Uint16, Uint32, Uint64, Uint128);
+
+/// The cells of an array of values, computed in parallel and written with one `load_data`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreparedFelts {
+    cells: Vec<MaybeRelocatable>,
+}
+
+impl PreparedFelts {
+    pub fn prepare<T: InlineFelts>(values: &[T]) -> Self {
+        let mut cells = vec![MaybeRelocatable::from(Felt252::ZERO); values.len() * T::N_FELTS];
+        if T::N_FELTS > 0 {
+            cells
+                .par_chunks_mut(T::N_FELTS)
+                .zip(values.par_iter())
+                .for_each(|(cells, value)| value.fill_cells(cells));
+        }
+        Self { cells }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Writes the cells from `address`, returning the address right after them.
+    pub fn write(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        Ok(vm.load_data(address, &self.cells)?)
+    }
+}
+
+/// Writes `values` one after the other from `address` like their `to_memory` would, the
+/// conversion to felts running in parallel.
+pub fn write_array_parallel<T: InlineFelts>(
+    vm: &mut VirtualMachine,
+    address: Relocatable,
+    values: &[T],
+) -> Result<Relocatable, HintError> {
+    PreparedFelts::prepare(values).write(vm, address)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cairo_type::CairoWritable;
+    use crate::types::{felt, uint256};
+    use cairo_vm::{vm::vm_core::VirtualMachine, Felt252};
+    use num_bigint::BigUint;
+
+    #[test]
+    fn test_write_array_parallel() {
+        use crate::parallel::{write_array_parallel, PreparedFelts};
+        use crate::types::bounded_uint::Uint64;
+
+        let values: Vec<uint256::Uint256> = (0..1000u32)
+            .map(|i| uint256::Uint256::new((BigUint::from(i) << 200usize) + i))
+            .collect();
+        let mut vm = VirtualMachine::new(false, false);
+        let parallel = vm.add_memory_segment();
+        let end = write_array_parallel(&mut vm, parallel, &values).unwrap();
+        assert_eq!(end, (parallel + 2000usize).unwrap());
+
+        // The same cells as writing the values one by one
+        let sequential = vm.add_memory_segment();
+        let mut ptr = sequential;
+        for value in &values {
+            ptr = value.to_memory(&mut vm, ptr).unwrap();
+        }
+        assert_eq!(
+            vm.get_integer_range(parallel, 2000).unwrap(),
+            vm.get_integer_range(sequential, 2000).unwrap()
+        );
+
+        let prepared = PreparedFelts::prepare(&[Uint64(7), Uint64(8)]);
+        assert_eq!(prepared.len(), 2);
+        let address = vm.add_memory_segment();
+        prepared.write(&mut vm, address).unwrap();
+        assert_eq!(*vm.get_integer(address).unwrap(), Felt252::from(7));
+        assert!(PreparedFelts::prepare::<felt::Felt>(&[]).is_empty());
+    }
+}
@@ -0,0 +1,251 @@
+use cairo_vm::Felt252;
+use std::marker::PhantomData;
+
+/// A fact or commitment produced by a stage, e.g. the output hash of a proven program run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fact {
+    pub stage: String,
+    pub value: Felt252,
+}
+
+/// Typed result of a stage along with the facts it commits to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StageOutput<T> {
+    pub value: T,
+    pub facts: Vec<Felt252>,
+}
+
+impl<T> StageOutput<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            facts: vec![],
+        }
+    }
+
+    pub fn with_fact(mut self, fact: Felt252) -> Self {
+        self.facts.push(fact);
+        self
+    }
+}
+
+/// One step of a pipeline, typically a single program run: build the VM inputs from
+/// `Input`, run the program and read `Output` back from memory.
+pub trait Stage {
+    type Input;
+    type Output;
+
+    fn name(&self) -> &str;
+    fn run(&mut self, input: Self::Input) -> Result<StageOutput<Self::Output>, String>;
+}
+
+/// A `Stage` backed by a closure, see `stage`.
+pub struct FnStage<I, O, F> {
+    name: String,
+    f: F,
+    _types: PhantomData<fn(I) -> O>,
+}
+
+pub fn stage<I, O, F>(name: &str, f: F) -> FnStage<I, O, F>
+where
+    F: FnMut(I) -> Result<StageOutput<O>, String>,
+{
+    FnStage {
+        name: name.to_string(),
+        f,
+        _types: PhantomData,
+    }
+}
+
+impl<I, O, F> Stage for FnStage<I, O, F>
+where
+    F: FnMut(I) -> Result<StageOutput<O>, String>,
+{
+    type Input = I;
+    type Output = O;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn run(&mut self, input: I) -> Result<StageOutput<O>, String> {
+        (self.f)(input)
+    }
+}
+
+/// Final value of a pipeline run and every fact produced along the way, in stage order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PipelineOutput<T> {
+    pub value: T,
+    pub facts: Vec<Fact>,
+}
+
+type RunFn<I, O> = Box<dyn FnMut(I, &mut Vec<Fact>) -> Result<O, String>>;
+
+/// Chain of stages where the output of each stage is mapped into the input of the next one
+/// by a user supplied transform, which also sees the facts produced so far.
+pub struct Pipeline<I, O> {
+    stages: Vec<String>,
+    run: RunFn<I, O>,
+}
+
+fn run_stage<S: Stage>(
+    stage: &mut S,
+    input: S::Input,
+    facts: &mut Vec<Fact>,
+) -> Result<S::Output, String> {
+    let output = stage
+        .run(input)
+        .map_err(|e| format!("stage {} failed: {e}", stage.name()))?;
+    facts.extend(output.facts.into_iter().map(|value| Fact {
+        stage: stage.name().to_string(),
+        value,
+    }));
+    Ok(output.value)
+}
+
+impl<I: 'static, O: 'static> Pipeline<I, O> {
+    pub fn new<S>(mut stage: S) -> Self
+    where
+        S: Stage<Input = I, Output = O> + 'static,
+    {
+        Self {
+            stages: vec![stage.name().to_string()],
+            run: Box::new(move |input, facts| run_stage(&mut stage, input, facts)),
+        }
+    }
+
+    pub fn then<S, F>(mut self, mut transform: F, mut stage: S) -> Pipeline<I, S::Output>
+    where
+        S: Stage + 'static,
+        S::Output: 'static,
+        F: FnMut(O, &[Fact]) -> Result<S::Input, String> + 'static,
+    {
+        let name = stage.name().to_string();
+        self.stages.push(name.clone());
+        let mut previous = self.run;
+        Pipeline {
+            stages: self.stages,
+            run: Box::new(move |input, facts| {
+                let output = previous(input, facts)?;
+                let input = transform(output, facts)
+                    .map_err(|e| format!("transform into stage {name} failed: {e}"))?;
+                run_stage(&mut stage, input, facts)
+            }),
+        }
+    }
+
+    /// Runs each branch on its own input and feeds all their outputs to a single stage,
+    /// e.g. one level of an aggregation tree.
+    pub fn aggregate<S, F>(
+        mut branches: Vec<Pipeline<I, O>>,
+        mut transform: F,
+        mut stage: S,
+    ) -> Pipeline<Vec<I>, S::Output>
+    where
+        S: Stage + 'static,
+        S::Output: 'static,
+        F: FnMut(Vec<O>, &[Fact]) -> Result<S::Input, String> + 'static,
+    {
+        let name = stage.name().to_string();
+        let mut stages = branches
+            .iter()
+            .flat_map(|branch| branch.stages.clone())
+            .collect::<Vec<_>>();
+        stages.push(name.clone());
+        Pipeline {
+            stages,
+            run: Box::new(move |inputs: Vec<I>, facts| {
+                if inputs.len() != branches.len() {
+                    return Err(format!(
+                        "stage {name} expects {} inputs, got {}",
+                        branches.len(),
+                        inputs.len()
+                    ));
+                }
+                let outputs = branches
+                    .iter_mut()
+                    .zip(inputs)
+                    .map(|(branch, input)| (branch.run)(input, facts))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let input = transform(outputs, facts)
+                    .map_err(|e| format!("transform into stage {name} failed: {e}"))?;
+                run_stage(&mut stage, input, facts)
+            }),
+        }
+    }
+
+    // Names of the stages in execution order
+    pub fn stages(&self) -> &[String] {
+        &self.stages
+    }
+
+    pub fn run(&mut self, input: I) -> Result<PipelineOutput<O>, String> {
+        let mut facts = vec![];
+        let value = (self.run)(input, &mut facts)?;
+        Ok(PipelineOutput { value, facts })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pipeline::{stage, Pipeline, StageOutput};
+    use cairo_vm::Felt252;
+
+    fn double(name: &str) -> impl crate::pipeline::Stage<Input = u64, Output = u64> {
+        stage(name, |x: u64| {
+            Ok(StageOutput::new(x * 2).with_fact(Felt252::from(x * 2)))
+        })
+    }
+
+    #[test]
+    fn test_pipeline_passes_outputs_and_facts() {
+        let mut pipeline = Pipeline::new(double("first")).then(
+            |x, facts| {
+                assert_eq!(facts.len(), 1);
+                Ok(x + 1)
+            },
+            double("second"),
+        );
+        assert_eq!(pipeline.stages(), ["first", "second"]);
+
+        let output = pipeline.run(3).unwrap();
+        assert_eq!(output.value, 14);
+        let facts = output
+            .facts
+            .iter()
+            .map(|fact| (fact.stage.as_str(), fact.value))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            facts,
+            vec![("first", Felt252::from(6)), ("second", Felt252::from(14))]
+        );
+    }
+
+    #[test]
+    fn test_pipeline_aggregate() {
+        let sum = stage("sum", |values: Vec<u64>| {
+            Ok(StageOutput::new(values.iter().sum::<u64>()))
+        });
+        let mut pipeline = Pipeline::aggregate(
+            vec![Pipeline::new(double("a")), Pipeline::new(double("b"))],
+            |outputs, _| Ok(outputs),
+            sum,
+        );
+        let output = pipeline.run(vec![1, 2]).unwrap();
+        assert_eq!(output.value, 6);
+        assert_eq!(output.facts.len(), 2);
+
+        let err = pipeline.run(vec![1]).unwrap_err();
+        assert!(err.contains("expects 2 inputs"));
+    }
+
+    #[test]
+    fn test_pipeline_stage_error() {
+        let failing = stage("failing", |_: u64| {
+            Err::<StageOutput<u64>, _>("boom".into())
+        });
+        let mut pipeline = Pipeline::new(double("first")).then(|x, _| Ok(x), failing);
+        assert_eq!(pipeline.run(1).unwrap_err(), "stage failing failed: boom");
+    }
+}
@@ -0,0 +1,257 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use cairo_vm::{
+    hint_processor::builtin_hint_processor::{
+        builtin_hint_processor_definition::HintProcessorData,
+        hint_utils::get_relocatable_from_var_name,
+    },
+    types::{
+        exec_scope::ExecutionScopes,
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+
+use crate::cairo_type::{CairoWritable, Layout};
+
+// Exec scope variable holding the tracker, always stored in the root scope
+pub const PROVENANCE_TRACKER: &str = "__provenance_tracker";
+
+/// Where a memory cell came from: the input or variable it was written for, the type that laid
+/// it out and the cell within that type, named after its `layout()`, e.g. `x.d0`. Cells of the
+/// segments a type allocates, such as the limbs of `KeccakBytes`, are named after the pointer
+/// to them, e.g. `limbs[3]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Provenance {
+    pub source: String,
+    pub type_name: String,
+    pub field: String,
+}
+
+impl fmt::Display for Provenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}.{})", self.source, self.type_name, self.field)
+    }
+}
+
+/// `CairoWritable` with its layout reachable through a trait object, for inputs stored as
+/// `dyn TrackedWritable` whose cells are still named after their type. Implemented for every
+/// `CairoWritable`.
+pub trait TrackedWritable: CairoWritable {
+    fn tracked_layout(&self) -> Layout;
+}
+
+impl<T: CairoWritable> TrackedWritable for T {
+    fn tracked_layout(&self) -> Layout {
+        T::layout()
+    }
+}
+
+// `alloc::vec::Vec<my_crate::Header>` to `Vec<Header>`, for the default layout names
+fn short_type_name(name: &str) -> String {
+    let mut short = String::with_capacity(name.len());
+    let mut path = String::new();
+    for c in name.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            path.push(c);
+        } else {
+            short.push_str(path.rsplit("::").next().unwrap_or_default());
+            path.clear();
+            short.push(c);
+        }
+    }
+    short.push_str(path.rsplit("::").next().unwrap_or_default());
+    short
+}
+
+/// Opt-in record of the cells written by this crate, to trace a bad felt back to its input.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProvenanceTracker {
+    cells: BTreeMap<Relocatable, Provenance>,
+}
+
+impl ProvenanceTracker {
+    // Turns tracking on for the run using these exec scopes
+    pub fn enable(exec_scopes: &mut ExecutionScopes) {
+        exec_scopes.data[0].insert(PROVENANCE_TRACKER.to_string(), Box::new(Self::default()));
+    }
+
+    // Removes the tracker from the exec scopes, typically once the run is over
+    pub fn take(exec_scopes: &mut ExecutionScopes) -> Option<Self> {
+        exec_scopes.data[0]
+            .remove(PROVENANCE_TRACKER)
+            .and_then(|tracker| tracker.downcast::<Self>().ok())
+            .map(|tracker| *tracker)
+    }
+
+    pub fn from_scopes(exec_scopes: &mut ExecutionScopes) -> Option<&mut Self> {
+        exec_scopes.data[0]
+            .get_mut(PROVENANCE_TRACKER)
+            .and_then(|tracker| tracker.downcast_mut::<Self>())
+    }
+
+    // Read-only access for the dumpers and the error paths
+    pub fn in_scopes(exec_scopes: &ExecutionScopes) -> Option<&Self> {
+        exec_scopes.data[0]
+            .get(PROVENANCE_TRACKER)
+            .and_then(|tracker| tracker.downcast_ref::<Self>())
+    }
+
+    /// Records the cells in `[start, end)`, which must be in the same segment, named after the
+    /// cells of `layout` and by offset past them.
+    pub fn record(&mut self, source: &str, layout: &Layout, start: Relocatable, end: Relocatable) {
+        if start.segment_index != end.segment_index {
+            return;
+        }
+        let type_name = short_type_name(&layout.name);
+        let mut fields: BTreeMap<usize, String> = layout
+            .cells()
+            .into_iter()
+            .map(|cell| (cell.offset, cell.name))
+            .collect();
+        for offset in 0..end.offset.saturating_sub(start.offset) {
+            let provenance = Provenance {
+                source: source.to_string(),
+                type_name: type_name.clone(),
+                field: fields.remove(&offset).unwrap_or_else(|| offset.to_string()),
+            };
+            self.cells.insert(
+                Relocatable::from((start.segment_index, start.offset + offset)),
+                provenance,
+            );
+        }
+    }
+
+    /// Records the segments from `first_segment` on that the cells in `[start, end)` point to,
+    /// and the segments those point to in turn, as allocated by a type while writing them.
+    /// A segment is taken to end at its first unset cell.
+    pub fn record_children(
+        &mut self,
+        vm: &VirtualMachine,
+        first_segment: usize,
+        start: Relocatable,
+        end: Relocatable,
+    ) {
+        let mut pending: Vec<Relocatable> = (start.offset..end.offset)
+            .map(|offset| Relocatable::from((start.segment_index, offset)))
+            .collect();
+        let mut visited = BTreeSet::new();
+        while let Some(address) = pending.pop() {
+            let Some(MaybeRelocatable::RelocatableValue(child)) = vm.get_maybe(&address) else {
+                continue;
+            };
+            let is_new = child.segment_index >= first_segment as isize && child.offset == 0;
+            if !is_new || !visited.insert(child.segment_index) {
+                continue;
+            }
+            let Some(parent) = self.cells.get(&address).cloned() else {
+                continue;
+            };
+            let mut cell = child;
+            while vm.get_maybe(&cell).is_some() {
+                let provenance = Provenance {
+                    field: format!("{}[{}]", parent.field, cell.offset),
+                    ..parent.clone()
+                };
+                self.cells.insert(cell, provenance);
+                pending.push(cell);
+                cell.offset += 1;
+            }
+        }
+    }
+
+    pub fn get(&self, address: Relocatable) -> Option<&Provenance> {
+        self.cells.get(&address)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Relocatable, &Provenance)> {
+        self.cells.iter()
+    }
+
+    /// One line per tracked cell with its current value, ordered by address.
+    pub fn dump(&self, vm: &VirtualMachine) -> String {
+        let mut dump = String::new();
+        for (address, provenance) in &self.cells {
+            let value = vm
+                .get_maybe(address)
+                .map(|value| value.to_string())
+                .unwrap_or_else(|| "<unset>".to_string());
+            dump.push_str(&format!("{address}: {value} <- {provenance}\n"));
+        }
+        dump
+    }
+
+    /// Adds the provenance of the cell at `address` to an error raised while processing it.
+    pub fn annotate(&self, error: HintError, address: Relocatable) -> HintError {
+        match self.get(address) {
+            Some(provenance) => HintError::CustomHint(
+                format!("{error} (cell {address} written by {provenance})").into_boxed_str(),
+            ),
+            None => error,
+        }
+    }
+
+    /// Adds the provenance of the ids of a failed hint to its error: of the cell of each id, or
+    /// of the cell a pointer id points to.
+    pub fn annotate_hint_error(
+        &self,
+        error: HintError,
+        vm: &VirtualMachine,
+        hint_data: &HintProcessorData,
+    ) -> HintError {
+        let mut names: Vec<&String> = hint_data.ids_data.keys().collect();
+        names.sort();
+        let sources: Vec<String> = names
+            .into_iter()
+            .filter_map(|name| {
+                let address = get_relocatable_from_var_name(
+                    name,
+                    vm,
+                    &hint_data.ids_data,
+                    &hint_data.ap_tracking,
+                )
+                .ok()?;
+                let provenance = self.get(address).or_else(|| match vm.get_maybe(&address) {
+                    Some(MaybeRelocatable::RelocatableValue(ptr)) => self.get(ptr),
+                    _ => None,
+                })?;
+                Some(format!("ids.{name} from {provenance}"))
+            })
+            .collect();
+        match sources.is_empty() {
+            true => error,
+            false => {
+                HintError::CustomHint(format!("{error} ({})", sources.join(", ")).into_boxed_str())
+            }
+        }
+    }
+}
+
+/// Writes `value` at `address`, recording its cells and the segments it allocates if a
+/// `ProvenanceTracker` is enabled.
+pub fn write_tracked<T: TrackedWritable + ?Sized>(
+    vm: &mut VirtualMachine,
+    exec_scopes: &mut ExecutionScopes,
+    address: Relocatable,
+    source: &str,
+    value: &T,
+) -> Result<Relocatable, HintError> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::trace_span!("write_tracked", source, %address).entered();
+    let first_segment = vm.segments.num_segments();
+    let end = value.to_memory(vm, address)?;
+    if let Some(tracker) = ProvenanceTracker::from_scopes(exec_scopes) {
+        tracker.record(source, &value.tracked_layout(), address, end);
+        tracker.record_children(vm, first_segment, address, end);
+    }
+    Ok(end)
+}
@@ -0,0 +1,435 @@
+//! An `InputProvider` fetching Ethereum block headers and account and storage proofs over
+//! JSON-RPC. Keys name the data and the block it is read at, `latest` when omitted:
+//!
+//! - `block_header:<block>` gives a `BlockHeader`
+//! - `account_proof:<address>[@<block>]` gives an `AccountProof`
+//! - `storage_proof:<address>:<slot>[,<slot>..][@<block>]` gives an `AccountProof` along with
+//!   the proofs of the slots
+//!
+//! where `<block>` is a decimal or `0x` number, a tag such as `finalized`, or a block hash.
+
+use serde_json::{json, Value};
+
+use super::transport::{HttpTransport, RpcTransport};
+use crate::input_provider::InputProvider;
+use crate::types::{
+    bounded_uint::Uint64,
+    fixed_bytes::{Bytes20, Bytes32},
+    hex_bytes_padded,
+    proof::MptProof,
+    rlp::{RlpBytes, RlpItem},
+    uint256::Uint256,
+    FromAnyStr,
+};
+
+/// A block header with its RLP encoding, whose keccak is the block hash.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockHeader {
+    pub hash: Bytes32,
+    pub parent_hash: Bytes32,
+    pub number: Uint64,
+    pub timestamp: Uint64,
+    pub state_root: Bytes32,
+    pub transactions_root: Bytes32,
+    pub receipts_root: Bytes32,
+    pub rlp: RlpBytes,
+}
+
+/// An `eth_getProof` response: the account, its proof against the state root and the proofs
+/// of the requested storage slots against its storage root.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountProof {
+    pub address: Bytes20,
+    pub balance: Uint256,
+    pub nonce: Uint64,
+    pub code_hash: Bytes32,
+    pub storage_hash: Bytes32,
+    pub account_proof: MptProof,
+    #[serde(default)]
+    pub storage_proof: Vec<StorageProof>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StorageProof {
+    pub key: Uint256,
+    pub value: Uint256,
+    pub proof: MptProof,
+}
+
+#[derive(Clone, Copy)]
+enum HeaderField {
+    Data,
+    Quantity,
+}
+
+// Header fields in RLP order. The first 15 are in every header, the others were added by forks
+// (London, Shanghai, Cancun, Prague) and are encoded up to the last one the block has
+const HEADER_FIELDS: [(&str, HeaderField); 21] = [
+    ("parentHash", HeaderField::Data),
+    ("sha3Uncles", HeaderField::Data),
+    ("miner", HeaderField::Data),
+    ("stateRoot", HeaderField::Data),
+    ("transactionsRoot", HeaderField::Data),
+    ("receiptsRoot", HeaderField::Data),
+    ("logsBloom", HeaderField::Data),
+    ("difficulty", HeaderField::Quantity),
+    ("number", HeaderField::Quantity),
+    ("gasLimit", HeaderField::Quantity),
+    ("gasUsed", HeaderField::Quantity),
+    ("timestamp", HeaderField::Quantity),
+    ("extraData", HeaderField::Data),
+    ("mixHash", HeaderField::Data),
+    ("nonce", HeaderField::Data),
+    ("baseFeePerGas", HeaderField::Quantity),
+    ("withdrawalsRoot", HeaderField::Data),
+    ("blobGasUsed", HeaderField::Quantity),
+    ("excessBlobGas", HeaderField::Quantity),
+    ("parentBeaconBlockRoot", HeaderField::Data),
+    ("requestsHash", HeaderField::Data),
+];
+const REQUIRED_HEADER_FIELDS: usize = 15;
+
+impl BlockHeader {
+    /// Encodes the header of an `eth_getBlockBy*` response, checking that it hashes to the
+    /// block hash.
+    pub fn from_rpc_block(block: &Value) -> Result<Self, String> {
+        let field = |name: &str| {
+            block
+                .get(name)
+                .and_then(Value::as_str)
+                .ok_or_else(|| format!("block has no {name}"))
+        };
+        let mut items = Vec::new();
+        for (i, (name, kind)) in HEADER_FIELDS.into_iter().enumerate() {
+            let value = match field(name) {
+                Ok(value) => value,
+                Err(_) if i >= REQUIRED_HEADER_FIELDS => break,
+                Err(e) => return Err(e),
+            };
+            let mut bytes = hex_bytes_padded(value, None)
+                .map_err(|e| format!("invalid block {name} {value}: {e}"))?;
+            if let HeaderField::Quantity = kind {
+                let start = bytes
+                    .iter()
+                    .position(|byte| *byte != 0)
+                    .unwrap_or(bytes.len());
+                bytes.drain(..start);
+            }
+            items.push(RlpItem::Bytes(bytes));
+        }
+        let rlp = RlpBytes(RlpItem::List(items));
+        let hash = Bytes32::from_any_str(field("hash")?)?;
+        if keccak256(&rlp.encoded()) != hash.0 {
+            return Err(format!(
+                "header of block {} doesn't hash to its hash, it may have fields this version \
+                 doesn't know of",
+                field("number")?
+            ));
+        }
+        Ok(Self {
+            hash,
+            parent_hash: Bytes32::from_any_str(field("parentHash")?)?,
+            number: Uint64::from_any_str(field("number")?)?,
+            timestamp: Uint64::from_any_str(field("timestamp")?)?,
+            state_root: Bytes32::from_any_str(field("stateRoot")?)?,
+            transactions_root: Bytes32::from_any_str(field("transactionsRoot")?)?,
+            receipts_root: Bytes32::from_any_str(field("receiptsRoot")?)?,
+            rlp,
+        })
+    }
+}
+
+pub use crate::commitments::keccak256;
+
+// The block parameter of the RPC methods: a hash, or a number or tag passed as is
+enum BlockId {
+    Hash(String),
+    Number(String),
+}
+
+fn block_id(block: &str) -> Result<BlockId, String> {
+    if block.starts_with("0x") && block.len() == 66 {
+        return Ok(BlockId::Hash(block.to_string()));
+    }
+    if block.starts_with("0x") || block.chars().any(|c| c.is_ascii_alphabetic()) {
+        return Ok(BlockId::Number(block.to_string()));
+    }
+    let number: u64 = block
+        .parse()
+        .map_err(|_| format!("invalid block {block}"))?;
+    Ok(BlockId::Number(format!("{number:#x}")))
+}
+
+/// Fetches headers and proofs through an `RpcTransport`, converting them to the crate's types.
+///
+/// ```ignore
+/// let provider = EthRpcProvider::http("http://localhost:8545")?;
+/// let processor = ExtendedHintProcessor::new(registry).with_input_provider(provider);
+/// ```
+pub struct EthRpcProvider<T> {
+    transport: T,
+}
+
+impl EthRpcProvider<HttpTransport> {
+    pub fn http(url: &str) -> Result<Self, String> {
+        HttpTransport::new(url).map(Self::new)
+    }
+}
+
+impl<T: RpcTransport> EthRpcProvider<T> {
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+
+    pub fn block_header(&mut self, block: &str) -> Result<BlockHeader, String> {
+        let response = match block_id(block)? {
+            BlockId::Hash(hash) => self
+                .transport
+                .call("eth_getBlockByHash", json!([hash, false]))?,
+            BlockId::Number(number) => self
+                .transport
+                .call("eth_getBlockByNumber", json!([number, false]))?,
+        };
+        if response.is_null() {
+            return Err(format!("block {block} not found"));
+        }
+        BlockHeader::from_rpc_block(&response)
+    }
+
+    /// The proof of `address` and of its storage `slots`, given as hex.
+    pub fn account_proof(
+        &mut self,
+        address: &str,
+        slots: &[&str],
+        block: &str,
+    ) -> Result<AccountProof, String> {
+        let slots = slots
+            .iter()
+            .map(|slot| {
+                hex_bytes_padded(slot, Some(32))
+                    .map(|bytes| format!("0x{}", hex::encode(bytes)))
+                    .map_err(|e| format!("invalid storage slot {slot}: {e}"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // EIP-1898 block parameter for hashes
+        let block_param = match block_id(block)? {
+            BlockId::Hash(hash) => json!({ "blockHash": hash }),
+            BlockId::Number(number) => json!(number),
+        };
+        let response = self
+            .transport
+            .call("eth_getProof", json!([address, slots, block_param]))?;
+        let proof: AccountProof = serde_json::from_value(response)
+            .map_err(|e| format!("invalid eth_getProof response: {e}"))?;
+        if proof.storage_proof.len() != slots.len() {
+            return Err(format!(
+                "eth_getProof returned {} storage proofs for {} slots",
+                proof.storage_proof.len(),
+                slots.len()
+            ));
+        }
+        Ok(proof)
+    }
+}
+
+impl<T: RpcTransport> InputProvider for EthRpcProvider<T> {
+    fn fetch(&mut self, key: &str) -> Result<Value, String> {
+        let (kind, target) = key
+            .split_once(':')
+            .ok_or_else(|| format!("invalid input key {key}"))?;
+        let (target, block) = target.split_once('@').unwrap_or((target, "latest"));
+        let value = match kind {
+            "block_header" => serde_json::to_value(self.block_header(target)?),
+            "account_proof" => serde_json::to_value(self.account_proof(target, &[], block)?),
+            "storage_proof" => {
+                let (address, slots) = target
+                    .split_once(':')
+                    .ok_or_else(|| format!("no storage slot in {key}"))?;
+                let slots: Vec<&str> = slots.split(',').collect();
+                serde_json::to_value(self.account_proof(address, &slots, block)?)
+            }
+            _ => return Err(format!("unknown input key kind {kind}")),
+        };
+        value.map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input_provider::InputProvider;
+    use crate::providers::eth_rpc::{keccak256, AccountProof, BlockHeader, EthRpcProvider};
+    use crate::providers::transport::{HttpTransport, RpcTransport};
+    use crate::types::rlp::RlpItem;
+    use serde_json::{json, Value};
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    fn rpc_block() -> Value {
+        let mut block = json!({
+            "parentHash": format!("0x{}", "11".repeat(32)),
+            "sha3Uncles": "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347",
+            "miner": format!("0x{}", "22".repeat(20)),
+            "stateRoot": format!("0x{}", "33".repeat(32)),
+            "transactionsRoot": format!("0x{}", "44".repeat(32)),
+            "receiptsRoot": format!("0x{}", "55".repeat(32)),
+            "logsBloom": format!("0x{}", "00".repeat(256)),
+            "difficulty": "0x0",
+            "number": "0x1234",
+            "gasLimit": "0x1c9c380",
+            "gasUsed": "0x0",
+            "timestamp": "0x65000000",
+            "extraData": "0x",
+            "mixHash": format!("0x{}", "66".repeat(32)),
+            "nonce": "0x0000000000000000",
+            "baseFeePerGas": "0x7",
+            "transactions": [],
+        });
+        let missing_hash = BlockHeader::from_rpc_block(&block).unwrap_err();
+        assert!(missing_hash.contains("block has no hash"));
+        let fields = [
+            "parentHash",
+            "sha3Uncles",
+            "miner",
+            "stateRoot",
+            "transactionsRoot",
+            "receiptsRoot",
+            "logsBloom",
+        ];
+        let mut items: Vec<RlpItem> = fields
+            .iter()
+            .map(|name| RlpItem::Bytes(hex::decode(&block[name].as_str().unwrap()[2..]).unwrap()))
+            .collect();
+        items.extend([
+            RlpItem::uint(0),
+            RlpItem::uint(0x1234),
+            RlpItem::uint(0x1c9c380),
+            RlpItem::uint(0),
+            RlpItem::uint(0x65000000),
+            RlpItem::Bytes(vec![]),
+            RlpItem::Bytes(vec![0x66; 32]),
+            RlpItem::Bytes(vec![0; 8]),
+            RlpItem::uint(7),
+        ]);
+        let hash = keccak256(&RlpItem::List(items).encode());
+        block["hash"] = json!(format!("0x{}", hex::encode(hash)));
+        block
+    }
+
+    #[test]
+    fn test_keccak256() {
+        assert_eq!(
+            hex::encode(keccak256(b"")),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            hex::encode(keccak256(&[0xc0])),
+            "1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347"
+        );
+        // 135 bytes leave a single padding byte, 136 a whole padding block
+        assert_ne!(keccak256(&[0; 135]), keccak256(&[0; 136]));
+    }
+
+    #[test]
+    fn test_eth_rpc_provider() {
+        let block = rpc_block();
+        let proof = json!({
+            "address": format!("0x{}", "ab".repeat(20)),
+            "balance": "0xde0b6b3a7640000",
+            "nonce": "0x1",
+            "codeHash": "0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+            "storageHash": format!("0x{}", "77".repeat(32)),
+            "accountProof": ["0xc0"],
+            "storageProof": [{"key": "0x0", "value": "0x2a", "proof": ["0xc0"]}],
+        });
+        let mut requests = Vec::new();
+        let transport = move |method: &str, params: Value| {
+            requests.push((method.to_string(), params.clone()));
+            match method {
+                "eth_getBlockByNumber" => {
+                    assert_eq!(params, json!(["0x1234", false]));
+                    Ok(block.clone())
+                }
+                "eth_getProof" => {
+                    assert_eq!(params[1], json!([format!("0x{}", "00".repeat(32))]));
+                    assert_eq!(
+                        params[2],
+                        json!({"blockHash": format!("0x{}", "88".repeat(32))})
+                    );
+                    Ok(proof.clone())
+                }
+                _ => Err(format!("unexpected {method}")),
+            }
+        };
+        let mut provider = EthRpcProvider::new(transport);
+
+        let header: BlockHeader =
+            serde_json::from_value(provider.fetch("block_header:4660").unwrap()).unwrap();
+        assert_eq!(header.number.0, 0x1234);
+        assert_eq!(header.state_root.0, [0x33; 32]);
+        assert_eq!(keccak256(&header.rlp.encoded()), header.hash.0);
+
+        let key = format!(
+            "storage_proof:0x{}:0x0@0x{}",
+            "ab".repeat(20),
+            "88".repeat(32)
+        );
+        let account: AccountProof = serde_json::from_value(provider.fetch(&key).unwrap()).unwrap();
+        assert_eq!(account.nonce.0, 1);
+        assert_eq!(account.account_proof.len(), 1);
+        assert_eq!(
+            account.storage_proof[0].value,
+            crate::types::uint256::Uint256::new(42u32.into())
+        );
+
+        // The header must hash to the block hash
+        let mut tampered = rpc_block();
+        tampered["gasUsed"] = json!("0x1");
+        assert!(BlockHeader::from_rpc_block(&tampered)
+            .unwrap_err()
+            .contains("doesn't hash to its hash"));
+        assert_eq!(
+            provider.fetch("receipt:0x1").unwrap_err(),
+            "unknown input key kind receipt"
+        );
+    }
+
+    #[test]
+    fn test_http_transport() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+            while !String::from_utf8_lossy(&request).contains("\"params\"") {
+                let n = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..n]);
+            }
+            let body = r#"{"jsonrpc":"2.0","id":1,"result":"0x1234"}"#;
+            let (first, second) = body.split_at(10);
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n{:x}\r\n{first}\r\n{:x}\r\n{second}\r\n0\r\n\r\n",
+                first.len(),
+                second.len()
+            )
+            .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        let mut transport = HttpTransport::new(&format!("http://127.0.0.1:{port}/rpc")).unwrap();
+        let result = transport.call("eth_blockNumber", json!([])).unwrap();
+        assert_eq!(result, json!("0x1234"));
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /rpc HTTP/1.1\r\n"));
+        assert!(request.contains("\"method\":\"eth_blockNumber\""));
+
+        // TLS and IPv6 hosts are handled by the client
+        assert!(HttpTransport::new("https://rpc.example/v1").is_ok());
+        assert!(HttpTransport::new("http://[::1]:9545/rpc/v0_8").is_ok());
+        assert!(HttpTransport::new("ws://rpc.example")
+            .unwrap_err()
+            .contains("unsupported RPC url"));
+    }
+}
@@ -0,0 +1,9 @@
+//! `InputProvider` implementations backed by external data sources, each behind its own
+//! feature.
+
+pub mod transport;
+
+#[cfg(feature = "rpc")]
+pub mod eth_rpc;
+#[cfg(feature = "starknet-rpc")]
+pub mod starknet_rpc;
@@ -0,0 +1,412 @@
+//! An `InputProvider` fetching Starknet block headers, state updates, contract storage and
+//! storage proofs over JSON-RPC. Keys name the data and the block it is read at, `latest` when
+//! omitted:
+//!
+//! - `block_header:<block>` gives a `StarknetBlockHeader`
+//! - `state_update:<block>` gives a `StateUpdate`
+//! - `storage:<contract>:<key>[@<block>]` gives the `Felt` stored at `key`
+//! - `storage_u256:<contract>:<key>[@<block>]` gives the `Uint256` stored as a Cairo `u256`,
+//!   its low half at `key` and its high half at `key + 1`
+//! - `storage_proof:<contract>:<key>[,<key>..][@<block>]` gives a `StorageProof`
+//!
+//! where `<block>` is a decimal number, a `0x` block hash, or a tag such as `latest`.
+
+use cairo_vm::Felt252;
+use serde_json::{json, Value};
+
+use super::transport::{HttpTransport, RpcTransport};
+use crate::input_provider::InputProvider;
+use crate::types::{bounded_uint::Uint64, felt::Felt, uint256::Uint256, FromAnyStr};
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StarknetBlockHeader {
+    pub block_hash: Felt,
+    pub parent_hash: Felt,
+    pub block_number: Uint64,
+    pub new_root: Felt,
+    pub timestamp: Uint64,
+    pub sequencer_address: Felt,
+    pub starknet_version: String,
+}
+
+/// The state diff of a block along with the state roots before and after it.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StateUpdate {
+    pub block_hash: Felt,
+    pub old_root: Felt,
+    pub new_root: Felt,
+    pub state_diff: StateDiff,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub struct StateDiff {
+    #[serde(default)]
+    pub storage_diffs: Vec<StorageDiff>,
+    #[serde(default)]
+    pub declared_classes: Vec<DeclaredClass>,
+    #[serde(default)]
+    pub deployed_contracts: Vec<DeployedContract>,
+    #[serde(default)]
+    pub replaced_classes: Vec<ReplacedClass>,
+    #[serde(default)]
+    pub nonces: Vec<NonceUpdate>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StorageDiff {
+    pub address: Felt,
+    pub storage_entries: Vec<StorageEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StorageEntry {
+    pub key: Felt,
+    pub value: Felt,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DeclaredClass {
+    pub class_hash: Felt,
+    pub compiled_class_hash: Felt,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DeployedContract {
+    pub address: Felt,
+    pub class_hash: Felt,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ReplacedClass {
+    pub contract_address: Felt,
+    pub class_hash: Felt,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NonceUpdate {
+    pub contract_address: Felt,
+    pub nonce: Felt,
+}
+
+/// A `starknet_getStorageProof` response: the proof of the contract leaves against the
+/// contracts tree root, and per contract the proof of its storage keys against its storage
+/// root.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StorageProof {
+    pub global_roots: GlobalRoots,
+    pub contracts_proof: ContractsProof,
+    pub contracts_storage_proofs: Vec<Vec<ProofNode>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GlobalRoots {
+    pub contracts_tree_root: Felt,
+    pub classes_tree_root: Felt,
+    pub block_hash: Felt,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ContractsProof {
+    pub nodes: Vec<ProofNode>,
+    pub contract_leaves_data: Vec<ContractLeaf>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ContractLeaf {
+    pub nonce: Felt,
+    pub class_hash: Felt,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_root: Option<Felt>,
+}
+
+/// A node of a proof in the Merkle-Patricia trees of the Starknet state, keyed by its hash.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ProofNode {
+    pub node_hash: Felt,
+    pub node: MerkleNode,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum MerkleNode {
+    Binary { left: Felt, right: Felt },
+    Edge { path: Felt, length: u8, child: Felt },
+}
+
+// The `block_id` of the RPC methods
+fn block_id(block: &str) -> Result<Value, String> {
+    if block.starts_with("0x") {
+        return Ok(json!({ "block_hash": block }));
+    }
+    if block.chars().all(|c| c.is_ascii_digit()) {
+        let number: u64 = block
+            .parse()
+            .map_err(|_| format!("invalid block {block}"))?;
+        return Ok(json!({ "block_number": number }));
+    }
+    Ok(json!(block))
+}
+
+fn parse_felt(value: &str) -> Result<Felt, String> {
+    Felt::from_any_str(value).map_err(|e| format!("invalid felt {value}: {e}"))
+}
+
+/// Fetches Starknet data through an `RpcTransport`, converting it to the crate's types.
+///
+/// ```ignore
+/// let provider = StarknetRpcProvider::http("http://localhost:9545/rpc/v0_8")?;
+/// let processor = ExtendedHintProcessor::new(registry).with_input_provider(provider);
+/// ```
+pub struct StarknetRpcProvider<T> {
+    transport: T,
+}
+
+impl StarknetRpcProvider<HttpTransport> {
+    pub fn http(url: &str) -> Result<Self, String> {
+        HttpTransport::new(url).map(Self::new)
+    }
+}
+
+impl<T: RpcTransport> StarknetRpcProvider<T> {
+    pub fn new(transport: T) -> Self {
+        Self { transport }
+    }
+
+    fn call<R: serde::de::DeserializeOwned>(
+        &mut self,
+        method: &str,
+        params: Value,
+    ) -> Result<R, String> {
+        let response = self.transport.call(method, params)?;
+        serde_json::from_value(response).map_err(|e| format!("invalid {method} response: {e}"))
+    }
+
+    pub fn block_header(&mut self, block: &str) -> Result<StarknetBlockHeader, String> {
+        self.call(
+            "starknet_getBlockWithTxHashes",
+            json!({ "block_id": block_id(block)? }),
+        )
+    }
+
+    pub fn state_update(&mut self, block: &str) -> Result<StateUpdate, String> {
+        self.call(
+            "starknet_getStateUpdate",
+            json!({ "block_id": block_id(block)? }),
+        )
+    }
+
+    pub fn storage(&mut self, contract: &str, key: &str, block: &str) -> Result<Felt, String> {
+        self.storage_at(&parse_felt(contract)?, &parse_felt(key)?, block)
+    }
+
+    /// A Cairo `u256` in storage, its 128-bit halves being at `key` and `key + 1`.
+    pub fn storage_u256(
+        &mut self,
+        contract: &str,
+        key: &str,
+        block: &str,
+    ) -> Result<Uint256, String> {
+        let (contract_address, low_key) = (parse_felt(contract)?, parse_felt(key)?);
+        let high_key = Felt(low_key.0 + Felt252::ONE);
+        let low = self.storage_at(&contract_address, &low_key, block)?;
+        let high = self.storage_at(&contract_address, &high_key, block)?;
+        let (low, high) = (low.0.to_biguint(), high.0.to_biguint());
+        if low.bits() > 128 || high.bits() > 128 {
+            return Err(format!(
+                "storage at {key} of {contract} is not a u256, a half exceeds 128 bits"
+            ));
+        }
+        Ok(Uint256::new(low + (high << 128u32)))
+    }
+
+    fn storage_at(&mut self, contract: &Felt, key: &Felt, block: &str) -> Result<Felt, String> {
+        self.call(
+            "starknet_getStorageAt",
+            json!({
+                "contract_address": contract,
+                "key": key,
+                "block_id": block_id(block)?,
+            }),
+        )
+    }
+
+    /// The proof of the `keys` of `contract`, and of the contract itself.
+    pub fn storage_proof(
+        &mut self,
+        contract: &str,
+        keys: &[&str],
+        block: &str,
+    ) -> Result<StorageProof, String> {
+        let contract = parse_felt(contract)?;
+        let keys = keys
+            .iter()
+            .map(|key| parse_felt(key))
+            .collect::<Result<Vec<_>, _>>()?;
+        let proof: StorageProof = self.call(
+            "starknet_getStorageProof",
+            json!({
+                "block_id": block_id(block)?,
+                "contract_addresses": [contract],
+                "contracts_storage_keys": [{
+                    "contract_address": contract,
+                    "storage_keys": keys,
+                }],
+            }),
+        )?;
+        if proof.contracts_storage_proofs.len() != 1 {
+            return Err(format!(
+                "starknet_getStorageProof returned {} storage proofs for 1 contract",
+                proof.contracts_storage_proofs.len()
+            ));
+        }
+        Ok(proof)
+    }
+}
+
+impl<T: RpcTransport> InputProvider for StarknetRpcProvider<T> {
+    fn fetch(&mut self, key: &str) -> Result<Value, String> {
+        let (kind, target) = key
+            .split_once(':')
+            .ok_or_else(|| format!("invalid input key {key}"))?;
+        let (target, block) = target.split_once('@').unwrap_or((target, "latest"));
+        let storage_key = || {
+            target
+                .split_once(':')
+                .ok_or_else(|| format!("no storage key in {key}"))
+        };
+        let value = match kind {
+            "block_header" => serde_json::to_value(self.block_header(target)?),
+            "state_update" => serde_json::to_value(self.state_update(target)?),
+            "storage" => {
+                let (contract, storage_key) = storage_key()?;
+                serde_json::to_value(self.storage(contract, storage_key, block)?)
+            }
+            "storage_u256" => {
+                let (contract, storage_key) = storage_key()?;
+                serde_json::to_value(self.storage_u256(contract, storage_key, block)?)
+            }
+            "storage_proof" => {
+                let (contract, keys) = storage_key()?;
+                let keys: Vec<&str> = keys.split(',').collect();
+                serde_json::to_value(self.storage_proof(contract, &keys, block)?)
+            }
+            _ => return Err(format!("unknown input key kind {kind}")),
+        };
+        value.map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input_provider::InputProvider;
+    use crate::providers::starknet_rpc::{
+        MerkleNode, StarknetBlockHeader, StarknetRpcProvider, StateUpdate, StorageProof,
+    };
+    use crate::types::{felt::Felt, uint256::Uint256};
+    use cairo_vm::Felt252;
+    use num_bigint::BigUint;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_starknet_rpc_provider() {
+        let transport = |method: &str, params: Value| match method {
+            "starknet_getBlockWithTxHashes" => {
+                assert_eq!(params, json!({"block_id": {"block_number": 600000}}));
+                Ok(json!({
+                    "status": "ACCEPTED_ON_L1",
+                    "block_hash": "0x1a",
+                    "parent_hash": "0x19",
+                    "block_number": 600000,
+                    "new_root": "0x2b",
+                    "timestamp": 1700000000,
+                    "sequencer_address": "0x1",
+                    "starknet_version": "0.13.2",
+                    "transactions": ["0x5"],
+                }))
+            }
+            "starknet_getStateUpdate" => Ok(json!({
+                "block_hash": "0x1a",
+                "old_root": "0x2a",
+                "new_root": "0x2b",
+                "state_diff": {
+                    "storage_diffs": [
+                        {"address": "0x49d", "storage_entries": [{"key": "0x5", "value": "0x7"}]}
+                    ],
+                    "deprecated_declared_classes": [],
+                    "nonces": [{"contract_address": "0x3", "nonce": "0x2"}],
+                },
+            })),
+            "starknet_getStorageAt" => {
+                assert_eq!(params["block_id"], json!({"block_hash": "0xabc"}));
+                let key = params["key"].as_str().unwrap();
+                // low half at 0x10, high half at 0x11
+                match Felt252::from_hex(key).unwrap() == Felt252::from(0x10) {
+                    true => Ok(json!("0x5")),
+                    false => Ok(json!("0x1")),
+                }
+            }
+            "starknet_getStorageProof" => {
+                assert_eq!(
+                    params["contracts_storage_keys"][0]["storage_keys"]
+                        .as_array()
+                        .unwrap()
+                        .len(),
+                    2
+                );
+                Ok(json!({
+                    "classes_proof": [],
+                    "contracts_proof": {
+                        "nodes": [{"node_hash": "0x2b", "node": {"left": "0x1", "right": "0x2"}}],
+                        "contract_leaves_data": [{"nonce": "0x0", "class_hash": "0x3", "storage_root": "0x4"}],
+                    },
+                    "contracts_storage_proofs": [[
+                        {"node_hash": "0x4", "node": {"path": "0x5", "length": 251, "child": "0x6"}}
+                    ]],
+                    "global_roots": {"contracts_tree_root": "0x2b", "classes_tree_root": "0x0", "block_hash": "0x1a"},
+                }))
+            }
+            _ => Err(format!("unexpected {method}")),
+        };
+        let mut provider = StarknetRpcProvider::new(transport);
+
+        let header: StarknetBlockHeader =
+            serde_json::from_value(provider.fetch("block_header:600000").unwrap()).unwrap();
+        assert_eq!(header.block_number.0, 600000);
+        assert_eq!(header.block_hash, Felt(Felt252::from(0x1a)));
+
+        let update: StateUpdate =
+            serde_json::from_value(provider.fetch("state_update:latest").unwrap()).unwrap();
+        assert_eq!(update.old_root, Felt(Felt252::from(0x2a)));
+        assert_eq!(
+            update.state_diff.storage_diffs[0].storage_entries[0]
+                .value
+                .0,
+            Felt252::from(7)
+        );
+        assert_eq!(update.state_diff.nonces.len(), 1);
+
+        let value: Felt =
+            serde_json::from_value(provider.fetch("storage:0x49d:0x10@0xabc").unwrap()).unwrap();
+        assert_eq!(value, Felt(Felt252::from(5)));
+        let balance: Uint256 =
+            serde_json::from_value(provider.fetch("storage_u256:0x49d:0x10@0xabc").unwrap())
+                .unwrap();
+        assert_eq!(balance, Uint256::new((BigUint::from(1u32) << 128) + 5u32));
+
+        let proof: StorageProof =
+            serde_json::from_value(provider.fetch("storage_proof:0x49d:0x10,0x11").unwrap())
+                .unwrap();
+        assert_eq!(
+            proof.contracts_proof.contract_leaves_data[0].storage_root,
+            Some(Felt(Felt252::from(4)))
+        );
+        assert!(matches!(
+            proof.contracts_storage_proofs[0][0].node,
+            MerkleNode::Edge { length: 251, .. }
+        ));
+        assert_eq!(
+            provider.fetch("storage:0x49d").unwrap_err(),
+            "no storage key in storage:0x49d"
+        );
+    }
+}
@@ -0,0 +1,84 @@
+//! JSON-RPC transports shared by the RPC backed providers.
+
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+/// Sends a JSON-RPC request, returning its `result`.
+pub trait RpcTransport {
+    fn call(&mut self, method: &str, params: Value) -> Result<Value, String>;
+}
+
+/// Closures answering requests, e.g. with recorded responses in tests.
+impl<F: FnMut(&str, Value) -> Result<Value, String>> RpcTransport for F {
+    fn call(&mut self, method: &str, params: Value) -> Result<Value, String> {
+        self(method, params)
+    }
+}
+
+/// JSON-RPC over HTTP(S) with `ureq`, TLS through rustls, reusing connections between requests.
+#[derive(Debug, Clone)]
+pub struct HttpTransport {
+    agent: ureq::Agent,
+    url: String,
+    next_id: u64,
+}
+
+impl HttpTransport {
+    pub fn new(url: &str) -> Result<Self, String> {
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            return Err(format!(
+                "unsupported RPC url {url}, expected http:// or https://"
+            ));
+        }
+        Ok(Self {
+            agent: agent(Duration::from_secs(30)),
+            url: url.to_string(),
+            next_id: 0,
+        })
+    }
+
+    /// Timeout of each request, 30 seconds by default.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.agent = agent(timeout);
+        self
+    }
+}
+
+fn agent(timeout: Duration) -> ureq::Agent {
+    ureq::AgentBuilder::new().timeout(timeout).build()
+}
+
+impl RpcTransport for HttpTransport {
+    fn call(&mut self, method: &str, params: Value) -> Result<Value, String> {
+        self.next_id += 1;
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": self.next_id,
+            "method": method,
+            "params": params,
+        });
+        let response = self
+            .agent
+            .post(&self.url)
+            .send_json(request)
+            .map_err(|e| match e {
+                ureq::Error::Status(status, response) => format!(
+                    "RPC endpoint answered HTTP {status}: {}",
+                    response.into_string().unwrap_or_default()
+                ),
+                e => format!("RPC request to {} failed: {e}", self.url),
+            })?;
+        let mut response: Value = response
+            .into_json()
+            .map_err(|e| format!("invalid {method} response: {e}"))?;
+        if let Some(error) = response.get("error") {
+            let message = error.get("message").and_then(Value::as_str);
+            return Err(format!(
+                "{method} failed: {}",
+                message.unwrap_or("unknown error")
+            ));
+        }
+        Ok(response["result"].take())
+    }
+}
@@ -0,0 +1,396 @@
+use std::path::Path;
+use std::rc::Rc;
+
+use cairo_vm::{
+    cairo_run::{cairo_run_pie, cairo_run_program_with_initial_scope, CairoRunConfig},
+    types::{
+        exec_scope::ExecutionScopes, layout_name::LayoutName, program::Program,
+        relocatable::Relocatable,
+    },
+    vm::{
+        errors::{
+            cairo_run_errors::CairoRunError, hint_errors::HintError, runner_errors::RunnerError,
+        },
+        runners::{
+            cairo_pie::CairoPie,
+            cairo_runner::{CairoRunner, RunResources},
+        },
+    },
+    Felt252,
+};
+use serde::Serialize;
+
+use crate::cairo_type::{CairoType, CairoWritable};
+use crate::default_hints::program_input::ProgramInput;
+use crate::hint_processor::ExtendedHintProcessor;
+use crate::output::OutputReader;
+use crate::provenance::TrackedWritable;
+use crate::scopes::{insert_typed, PROGRAM_INPUT_WRITABLE_KEY};
+use crate::stats::{ExecutionStats, ResourceUsage};
+
+#[derive(Debug, Clone)]
+pub struct RunConfig {
+    pub entrypoint: String,
+    pub layout: LayoutName,
+    pub proof_mode: bool,
+    pub trace_enabled: bool,
+    pub allow_missing_builtins: Option<bool>,
+    // Maximum number of steps, unlimited if None. Replaces the run resources of the hint
+    // processor in every entry point
+    pub max_steps: Option<usize>,
+    // Fills `RunOutput::stats`, timing every hint
+    pub collect_stats: bool,
+}
+
+impl Default for RunConfig {
+    fn default() -> Self {
+        Self {
+            entrypoint: "main".to_string(),
+            layout: LayoutName::all_cairo,
+            proof_mode: false,
+            trace_enabled: false,
+            allow_missing_builtins: None,
+            max_steps: None,
+            collect_stats: false,
+        }
+    }
+}
+
+/// A finished run along with the content of its output segment.
+pub struct RunOutput {
+    pub runner: CairoRunner,
+    pub output: Vec<Felt252>,
+    // Set when the run was configured with `collect_stats`
+    pub stats: Option<ExecutionStats>,
+    output_base: Option<Relocatable>,
+}
+
+impl RunOutput {
+    #[allow(clippy::result_large_err)]
+    fn from_runner(
+        mut runner: CairoRunner,
+        config: &RunConfig,
+        hint_processor: &ExtendedHintProcessor,
+    ) -> Result<Self, CairoRunError> {
+        let stats = match config.collect_stats {
+            true => Some(ExecutionStats::from_runner(
+                &runner,
+                hint_processor.stats(),
+            )?),
+            false => None,
+        };
+        let (output_base, output) = match runner.vm.get_output_builtin_mut() {
+            Ok(builtin) => {
+                let base = Relocatable::from((builtin.base() as isize, 0));
+                let size = runner
+                    .vm
+                    .get_segment_used_size(base.segment_index as usize)
+                    .unwrap_or(0);
+                let output = runner
+                    .vm
+                    .get_integer_range(base, size)?
+                    .into_iter()
+                    .map(|value| value.into_owned())
+                    .collect();
+                (Some(base), output)
+            }
+            Err(_) => (None, vec![]),
+        };
+        Ok(Self {
+            runner,
+            output,
+            stats,
+            output_base,
+        })
+    }
+
+    /// Builds the Cairo PIE of the run, which can be written with `CairoPie::write_zip_file`
+    /// and re-executed with `run_cairo_pie`. Not available for proof mode runs.
+    pub fn to_cairo_pie(&self) -> Result<CairoPie, RunnerError> {
+        self.runner.get_cairo_pie()
+    }
+
+    /// Steps, memory holes, builtin instances and segment sizes of the run, see
+    /// `stats::minimum_layout` to pick a layout from them.
+    pub fn execution_resources(&self) -> Result<ResourceUsage, RunnerError> {
+        ResourceUsage::from_runner(&self.runner)
+    }
+
+    /// Sequential reader over the output segment, see `OutputReader`.
+    pub fn reader(&self) -> Result<OutputReader<'_>, HintError> {
+        let base = self.output_base.ok_or_else(|| {
+            HintError::CustomHint("program has no output builtin".to_string().into_boxed_str())
+        })?;
+        Ok(OutputReader::new(&self.runner.vm, base, self.output.len()))
+    }
+
+    /// Reads a typed value from the start of the output segment.
+    pub fn read_output<T: CairoType>(&self) -> Result<T, HintError> {
+        self.read_output_at(0)
+    }
+
+    /// Reads a typed value at `offset` felts into the output segment.
+    pub fn read_output_at<T: CairoType>(&self, offset: usize) -> Result<T, HintError> {
+        let base = self.output_base.ok_or_else(|| {
+            HintError::CustomHint("program has no output builtin".to_string().into_boxed_str())
+        })?;
+        if offset + T::n_fields() > self.output.len() {
+            return Err(HintError::CustomHint(
+                format!(
+                    "output has {} felts, can't read {} felts at offset {offset}",
+                    self.output.len(),
+                    T::n_fields()
+                )
+                .into_boxed_str(),
+            ));
+        }
+        T::from_memory(&self.runner.vm, (base + offset)?)
+    }
+}
+
+/// Loads a compiled Cairo program, makes `inputs` available to the `program_input` hint and runs
+/// it with the `ExtendedHintProcessor`, returning the runner and its output segment.
+#[allow(clippy::result_large_err)]
+pub fn run_program<I>(
+    program_bytes: &[u8],
+    inputs: I,
+    config: &RunConfig,
+) -> Result<RunOutput, CairoRunError>
+where
+    I: CairoWritable + Serialize + 'static,
+{
+    let mut hint_processor = ExtendedHintProcessor::default();
+    run_program_with_hint_processor(program_bytes, inputs, config, &mut hint_processor)
+}
+
+// Applies the step limit and stats collection of `config` to the hint processor
+fn configure_hint_processor(config: &RunConfig, hint_processor: &mut ExtendedHintProcessor) {
+    if let Some(max_steps) = config.max_steps {
+        hint_processor.set_run_resources(RunResources::new(max_steps));
+    }
+    if config.collect_stats {
+        hint_processor.enable_stats();
+    }
+}
+
+// Same as `run_program` for callers that registered their own hints
+#[allow(clippy::result_large_err)]
+pub fn run_program_with_hint_processor<I>(
+    program_bytes: &[u8],
+    inputs: I,
+    config: &RunConfig,
+    hint_processor: &mut ExtendedHintProcessor,
+) -> Result<RunOutput, CairoRunError>
+where
+    I: CairoWritable + Serialize + 'static,
+{
+    let program = Program::from_bytes(program_bytes, Some(&config.entrypoint))?;
+    configure_hint_processor(config, hint_processor);
+
+    let mut exec_scopes = ExecutionScopes::new();
+    // Only meant for hints reading raw fields, inputs that can't be represented as JSON skip it
+    if let Ok(json) = serde_json::to_value(&inputs) {
+        ProgramInput(json).insert_into(&mut exec_scopes);
+    }
+    let inputs: Rc<dyn TrackedWritable> = Rc::new(inputs);
+    insert_typed(&mut exec_scopes, &PROGRAM_INPUT_WRITABLE_KEY, inputs);
+
+    let cairo_run_config = CairoRunConfig {
+        entrypoint: &config.entrypoint,
+        layout: config.layout,
+        proof_mode: config.proof_mode,
+        trace_enabled: config.trace_enabled,
+        relocate_mem: config.trace_enabled,
+        allow_missing_builtins: config.allow_missing_builtins,
+        ..Default::default()
+    };
+    let runner = cairo_run_program_with_initial_scope(
+        &program,
+        &cairo_run_config,
+        hint_processor,
+        exec_scopes,
+    )?;
+
+    RunOutput::from_runner(runner, config, hint_processor)
+}
+
+/// Re-executes a Cairo PIE, e.g. one produced by `RunOutput::to_cairo_pie`, with the
+/// `ExtendedHintProcessor`, failing if the run doesn't match the PIE. The PIE memory is loaded
+/// before the run, so hints whose results it already holds, like the program input one, must be
+/// overridden with no-ops: writing them again into new segments conflicts with it.
+#[allow(clippy::result_large_err)]
+pub fn run_cairo_pie(
+    pie: &CairoPie,
+    config: &RunConfig,
+    hint_processor: &mut ExtendedHintProcessor,
+) -> Result<RunOutput, CairoRunError> {
+    let cairo_run_config = CairoRunConfig {
+        entrypoint: &config.entrypoint,
+        layout: config.layout,
+        proof_mode: config.proof_mode,
+        trace_enabled: config.trace_enabled,
+        relocate_mem: config.trace_enabled,
+        allow_missing_builtins: config.allow_missing_builtins,
+        ..Default::default()
+    };
+    configure_hint_processor(config, hint_processor);
+    let runner = cairo_run_pie(pie, &cairo_run_config, hint_processor)?;
+    RunOutput::from_runner(runner, config, hint_processor)
+}
+
+/// Reads a Cairo PIE zip file, as written by `CairoPie::write_zip_file`.
+pub fn load_cairo_pie(path: impl AsRef<Path>) -> std::io::Result<CairoPie> {
+    CairoPie::read_zip_file(path.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use cairo_vm::{
+        hint_processor::builtin_hint_processor::{
+            builtin_hint_processor_definition::HintProcessorData,
+            hint_utils::insert_value_from_var_name,
+        },
+        types::exec_scope::ExecutionScopes,
+        vm::vm_core::VirtualMachine,
+    };
+    use num_bigint::BigUint;
+
+    use super::*;
+    use crate::default_hints::program_input::HINT_WRITE_PROGRAM_INPUT;
+    use crate::types::felt::Felt;
+    use crate::types::uint256::Uint256;
+
+    // Compiled from tests/programs/echo_input.cairo: writes the two felts of its input to the
+    // output segment
+    const ECHO_INPUT: &[u8] = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/programs/echo_input.json"
+    ));
+
+    fn input() -> Uint256 {
+        Uint256::new((BigUint::from(7u32) << 128) + BigUint::from(5u32))
+    }
+
+    #[test]
+    fn test_run_program_output() {
+        let run = run_program(ECHO_INPUT, input(), &RunConfig::default()).unwrap();
+        assert_eq!(run.output, vec![Felt252::from(5), Felt252::from(7)]);
+        assert!(run.stats.is_none());
+
+        assert_eq!(run.read_output::<Uint256>().unwrap(), input());
+        assert_eq!(
+            run.read_output_at::<Felt>(1).unwrap(),
+            Felt(Felt252::from(7))
+        );
+        let err = run.read_output_at::<Uint256>(1).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("output has 2 felts, can't read 2 felts at offset 1"));
+
+        let mut reader = run.reader().unwrap();
+        assert_eq!(reader.read::<Felt>().unwrap(), Felt(Felt252::from(5)));
+        assert_eq!(reader.read::<Felt>().unwrap(), Felt(Felt252::from(7)));
+    }
+
+    #[test]
+    fn test_run_program_resources() {
+        let config = RunConfig {
+            collect_stats: true,
+            ..Default::default()
+        };
+        let run = run_program(ECHO_INPUT, input(), &config).unwrap();
+
+        let resources = run.execution_resources().unwrap();
+        // `ap += 1`, the four output assertions, the return value and `ret`
+        assert_eq!(resources.n_steps, 7);
+        assert_eq!(resources.builtin_instances.get("output"), Some(&2));
+
+        let stats = run.stats.unwrap();
+        assert_eq!(stats.steps, 7);
+        assert_eq!(stats.hint_invocations(), 1);
+        assert_eq!(stats.hints[HINT_WRITE_PROGRAM_INPUT].count, 1);
+    }
+
+    #[test]
+    fn test_run_program_max_steps() {
+        let config = RunConfig {
+            max_steps: Some(3),
+            ..Default::default()
+        };
+        assert!(run_program(ECHO_INPUT, input(), &config).is_err());
+
+        let mut hint_processor = ExtendedHintProcessor::default();
+        assert!(
+            run_program_with_hint_processor(ECHO_INPUT, input(), &config, &mut hint_processor)
+                .is_err()
+        );
+
+        let pie = run_program(ECHO_INPUT, input(), &RunConfig::default())
+            .unwrap()
+            .to_cairo_pie()
+            .unwrap();
+        let mut hint_processor = ExtendedHintProcessor::default();
+        hint_processor
+            .hints_mut()
+            .override_hint(HINT_WRITE_PROGRAM_INPUT, |_, _, _, _| Ok(()));
+        assert!(run_cairo_pie(&pie, &config, &mut hint_processor).is_err());
+    }
+
+    fn write_fixed_input(
+        vm: &mut VirtualMachine,
+        _exec_scopes: &mut ExecutionScopes,
+        hint_data: &HintProcessorData,
+        _constants: &HashMap<String, Felt252>,
+    ) -> Result<(), HintError> {
+        let segment = vm.add_memory_segment();
+        vm.insert_value(segment, Felt252::from(1))?;
+        vm.insert_value((segment + 1)?, Felt252::from(2))?;
+        insert_value_from_var_name(
+            "input",
+            segment,
+            vm,
+            &hint_data.ids_data,
+            &hint_data.ap_tracking,
+        )
+    }
+
+    #[test]
+    fn test_run_program_with_hint_processor() {
+        let mut hint_processor = ExtendedHintProcessor::default().with_stats();
+        hint_processor
+            .hints_mut()
+            .override_hint(HINT_WRITE_PROGRAM_INPUT, write_fixed_input);
+
+        let run = run_program_with_hint_processor(
+            ECHO_INPUT,
+            input(),
+            &RunConfig::default(),
+            &mut hint_processor,
+        )
+        .unwrap();
+        assert_eq!(run.output, vec![Felt252::from(1), Felt252::from(2)]);
+        assert_eq!(hint_processor.stats().unwrap().iter().count(), 1);
+    }
+
+    #[test]
+    fn test_cairo_pie_round_trip() {
+        let run = run_program(ECHO_INPUT, input(), &RunConfig::default()).unwrap();
+        let pie = run.to_cairo_pie().unwrap();
+
+        let path = std::env::temp_dir().join(format!("echo_input_{}.zip", std::process::id()));
+        pie.write_zip_file(&path, false).unwrap();
+        let loaded = load_cairo_pie(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, pie);
+
+        let mut hint_processor = ExtendedHintProcessor::default();
+        hint_processor
+            .hints_mut()
+            .override_hint(HINT_WRITE_PROGRAM_INPUT, |_, _, _, _| Ok(()));
+        let rerun = run_cairo_pie(&loaded, &RunConfig::default(), &mut hint_processor).unwrap();
+        assert_eq!(rerun.output, run.output);
+        assert_eq!(rerun.read_output::<Uint256>().unwrap(), input());
+    }
+}
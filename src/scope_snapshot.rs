@@ -0,0 +1,152 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+use cairo_vm::types::exec_scope::ExecutionScopes;
+use serde::{de::DeserializeOwned, Serialize};
+
+type CloneFn = fn(&dyn Any) -> Option<Box<dyn Any>>;
+
+fn clone_value<T: Any + Clone>(value: &dyn Any) -> Option<Box<dyn Any>> {
+    value
+        .downcast_ref::<T>()
+        .map(|value| Box::new(value.clone()) as Box<dyn Any>)
+}
+
+// Duplicates a value through a JSON round trip, for types that are not `Clone`
+fn reserialize_value<T: Any + Serialize + DeserializeOwned>(
+    value: &dyn Any,
+) -> Option<Box<dyn Any>> {
+    let value = value.downcast_ref::<T>()?;
+    let json = serde_json::to_value(value).ok()?;
+    serde_json::from_value::<T>(json)
+        .ok()
+        .map(|value| Box::new(value) as Box<dyn Any>)
+}
+
+/// Copy of the exec scopes variables whose type was registered in a `ScopeSnapshotRegistry`.
+#[derive(Default)]
+pub struct ScopeSnapshot {
+    scopes: Vec<HashMap<String, Box<dyn Any>>>,
+    skipped: Vec<String>,
+}
+
+impl ScopeSnapshot {
+    pub fn depth(&self) -> usize {
+        self.scopes.len()
+    }
+
+    // Names of the variables left out because their type is not registered
+    pub fn skipped(&self) -> &[String] {
+        &self.skipped
+    }
+}
+
+/// Types that can be copied out of and back into `ExecutionScopes`. Exec scopes hold
+/// `Box<dyn Any>` values, so each type has to be registered to know how to duplicate it.
+#[derive(Default, Clone)]
+pub struct ScopeSnapshotRegistry {
+    types: HashMap<TypeId, CloneFn>,
+}
+
+impl ScopeSnapshotRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register_cloneable<T: Any + Clone>(&mut self) -> &mut Self {
+        self.types.insert(TypeId::of::<T>(), clone_value::<T>);
+        self
+    }
+
+    pub fn register_serializable<T: Any + Serialize + DeserializeOwned>(&mut self) -> &mut Self {
+        self.types.insert(TypeId::of::<T>(), reserialize_value::<T>);
+        self
+    }
+
+    fn duplicate(&self, value: &dyn Any) -> Option<Box<dyn Any>> {
+        self.types
+            .get(&value.type_id())
+            .and_then(|clone| clone(value))
+    }
+
+    pub fn snapshot(&self, exec_scopes: &ExecutionScopes) -> ScopeSnapshot {
+        let mut snapshot = ScopeSnapshot::default();
+        for scope in exec_scopes.data.iter() {
+            let mut variables = HashMap::new();
+            for (name, value) in scope.iter() {
+                match self.duplicate(value.as_ref()) {
+                    Some(value) => {
+                        variables.insert(name.clone(), value);
+                    }
+                    None => snapshot.skipped.push(name.clone()),
+                }
+            }
+            snapshot.scopes.push(variables);
+        }
+        snapshot.skipped.sort();
+        snapshot
+    }
+
+    /// Replaces the content of the exec scopes with a copy of the snapshot, the snapshot
+    /// can be restored again. Variables skipped by the snapshot are lost.
+    pub fn restore(&self, snapshot: &ScopeSnapshot, exec_scopes: &mut ExecutionScopes) {
+        exec_scopes.data = snapshot
+            .scopes
+            .iter()
+            .map(|scope| {
+                scope
+                    .iter()
+                    .filter_map(|(name, value)| {
+                        self.duplicate(value.as_ref())
+                            .map(|value| (name.clone(), value))
+                    })
+                    .collect()
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scope_snapshot::ScopeSnapshotRegistry;
+    use crate::types::keccak_bytes::KeccakBytes;
+    use cairo_vm::types::exec_scope::ExecutionScopes;
+    use std::collections::HashMap;
+
+    // Neither Clone nor serializable
+    struct Opaque;
+
+    #[test]
+    fn test_snapshot_and_restore() {
+        let mut registry = ScopeSnapshotRegistry::new();
+        registry
+            .register_cloneable::<u64>()
+            .register_serializable::<KeccakBytes>();
+
+        let mut exec_scopes = ExecutionScopes::new();
+        exec_scopes.insert_value("counter", 1u64);
+        exec_scopes.insert_value("keccak_bytes", KeccakBytes(vec![1, 2]));
+        exec_scopes.insert_value("opaque", Opaque);
+        exec_scopes.enter_scope(HashMap::new());
+        exec_scopes.insert_value("inner", 7u64);
+
+        let snapshot = registry.snapshot(&exec_scopes);
+        assert_eq!(snapshot.depth(), 2);
+        assert_eq!(snapshot.skipped(), ["opaque"]);
+
+        // Speculative changes
+        exec_scopes.insert_value("inner", 8u64);
+        exec_scopes.exit_scope().unwrap();
+        exec_scopes.insert_value("counter", 2u64);
+
+        registry.restore(&snapshot, &mut exec_scopes);
+        assert_eq!(exec_scopes.get::<u64>("inner").unwrap(), 7);
+        exec_scopes.exit_scope().unwrap();
+        assert_eq!(exec_scopes.get::<u64>("counter").unwrap(), 1);
+        assert_eq!(
+            exec_scopes.get::<KeccakBytes>("keccak_bytes").unwrap(),
+            KeccakBytes(vec![1, 2])
+        );
+        assert!(exec_scopes.get_ref::<Opaque>("opaque").is_err());
+    }
+}
@@ -0,0 +1,215 @@
+//! Typed exec scope variables. A `ScopeKey<T>` ties a variable name to the type stored under it
+//! and to the scope it lives in, so that hints read and write it through `get_typed` and
+//! `insert_typed` instead of repeating the name and the `Box<dyn Any>` downcast.
+
+use std::any::{type_name, Any};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use cairo_vm::{
+    types::exec_scope::ExecutionScopes,
+    vm::errors::{exec_scope_errors::ExecScopeError, hint_errors::HintError},
+};
+
+use crate::default_hints::breakpoint::{BreakpointConfig, BREAKPOINT_CONFIG};
+use crate::default_hints::budget::{StepBudget, STEP_BUDGET};
+use crate::default_hints::logger::{OutputSink, LOG_LEVEL_CAIRO, OUTPUT_SINK};
+use crate::default_hints::profiling::{Profiler, PROFILER};
+use crate::default_hints::program_input::{ProgramInput, PROGRAM_INPUT, PROGRAM_INPUT_WRITABLE};
+use crate::default_hints::progress::{ProgressReporter, PROGRESS_REPORTER};
+use crate::default_hints::random::{DeterministicRng, DETERMINISTIC_RNG};
+use crate::default_hints::witness_file::{WitnessFiles, WITNESS_FILES};
+use crate::input_provider::{ProviderState, INPUT_PROVIDER};
+use crate::provenance::TrackedWritable;
+
+/// The program input JSON, read by the `load_input` hints.
+pub const PROGRAM_INPUT_KEY: ScopeKey<ProgramInput> = ScopeKey::local(PROGRAM_INPUT);
+
+/// The typed inputs written by the `write_program_input` hint.
+pub const PROGRAM_INPUT_WRITABLE_KEY: ScopeKey<Rc<dyn TrackedWritable>> =
+    ScopeKey::local(PROGRAM_INPUT_WRITABLE);
+
+/// The sink of all hint output, injected with `logger::set_output_sink`, and wrapped by
+/// `logger::set_logger` and `logger::set_print_sink`.
+pub const OUTPUT_SINK_KEY: ScopeKey<Box<dyn OutputSink>> = ScopeKey::root(OUTPUT_SINK);
+
+/// The cairo-lang style log level string, `"info"` or `"debug"`.
+pub const LOG_LEVEL_KEY: ScopeKey<&'static str> = ScopeKey::local(LOG_LEVEL_CAIRO);
+
+/// The config set by `breakpoint::enable_breakpoints`.
+pub const BREAKPOINT_CONFIG_KEY: ScopeKey<BreakpointConfig> = ScopeKey::root(BREAKPOINT_CONFIG);
+
+/// The reporter injected with `progress::set_progress_reporter`.
+pub const PROGRESS_REPORTER_KEY: ScopeKey<Box<dyn ProgressReporter>> =
+    ScopeKey::root(PROGRESS_REPORTER);
+
+/// The timers of the `start_timer` / `stop_timer` hints and their report.
+pub const PROFILER_KEY: ScopeKey<Profiler> = ScopeKey::root(PROFILER);
+
+/// The budget set with `budget::set_step_budget`.
+pub const STEP_BUDGET_KEY: ScopeKey<StepBudget> = ScopeKey::root(STEP_BUDGET);
+
+/// The generator seeded with `random::seed_random`.
+pub const DETERMINISTIC_RNG_KEY: ScopeKey<DeterministicRng> = ScopeKey::root(DETERMINISTIC_RNG);
+
+/// The files allowed with `witness_file::allow_witness_files`.
+pub const WITNESS_FILES_KEY: ScopeKey<WitnessFiles> = ScopeKey::root(WITNESS_FILES);
+
+/// The provider set with `input_provider::set_input_provider` and its cached responses.
+pub const INPUT_PROVIDER_KEY: ScopeKey<ProviderState> = ScopeKey::root(INPUT_PROVIDER);
+
+/// Name of an exec scope variable holding a `T`, either in the current scope like the
+/// variables of cairo-lang hints, or in the root scope for values injected for the whole run.
+pub struct ScopeKey<T> {
+    name: &'static str,
+    root: bool,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T> ScopeKey<T> {
+    pub const fn local(name: &'static str) -> Self {
+        Self {
+            name,
+            root: false,
+            _value: PhantomData,
+        }
+    }
+
+    pub const fn root(name: &'static str) -> Self {
+        Self {
+            name,
+            root: true,
+            _value: PhantomData,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.root
+    }
+}
+
+// Manual impls, the key being copyable whatever it holds
+impl<T> Clone for ScopeKey<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ScopeKey<T> {}
+
+impl<T> std::fmt::Debug for ScopeKey<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScopeKey")
+            .field("name", &self.name)
+            .field("root", &self.root)
+            .field("type", &type_name::<T>())
+            .finish()
+    }
+}
+
+type Scope = HashMap<String, Box<dyn Any>>;
+
+fn scope<'a, T>(
+    exec_scopes: &'a ExecutionScopes,
+    key: &ScopeKey<T>,
+) -> Result<&'a Scope, HintError> {
+    if key.root {
+        exec_scopes
+            .data
+            .first()
+            .ok_or_else(|| ExecScopeError::NoScopeError.into())
+    } else {
+        exec_scopes.get_local_variables()
+    }
+}
+
+fn scope_mut<'a, T>(
+    exec_scopes: &'a mut ExecutionScopes,
+    key: &ScopeKey<T>,
+) -> Result<&'a mut Scope, HintError> {
+    if key.root {
+        exec_scopes
+            .data
+            .first_mut()
+            .ok_or_else(|| ExecScopeError::NoScopeError.into())
+    } else {
+        exec_scopes.get_local_variables_mut()
+    }
+}
+
+fn wrong_type<T>(key: &ScopeKey<T>) -> HintError {
+    HintError::CustomHint(
+        format!(
+            "exec scope variable {} is not a {}",
+            key.name,
+            type_name::<T>()
+        )
+        .into_boxed_str(),
+    )
+}
+
+/// A copy of the variable, failing if it is missing or holds another type.
+pub fn get_typed<T: Any + Clone>(
+    exec_scopes: &ExecutionScopes,
+    key: &ScopeKey<T>,
+) -> Result<T, HintError> {
+    get_typed_ref(exec_scopes, key).cloned()
+}
+
+pub fn get_typed_ref<'a, T: Any>(
+    exec_scopes: &'a ExecutionScopes,
+    key: &ScopeKey<T>,
+) -> Result<&'a T, HintError> {
+    let value = scope(exec_scopes, key)?
+        .get(key.name)
+        .ok_or_else(|| HintError::VariableNotInScopeError(key.name.into()))?;
+    value.downcast_ref::<T>().ok_or_else(|| wrong_type(key))
+}
+
+pub fn get_typed_mut<'a, T: Any>(
+    exec_scopes: &'a mut ExecutionScopes,
+    key: &ScopeKey<T>,
+) -> Result<&'a mut T, HintError> {
+    let value = scope_mut(exec_scopes, key)?
+        .get_mut(key.name)
+        .ok_or_else(|| HintError::VariableNotInScopeError(key.name.into()))?;
+    value.downcast_mut::<T>().ok_or_else(|| wrong_type(key))
+}
+
+/// The variable if it is set, for optional values such as injected loggers. Still fails if it
+/// holds another type.
+pub fn find_typed_mut<'a, T: Any>(
+    exec_scopes: &'a mut ExecutionScopes,
+    key: &ScopeKey<T>,
+) -> Result<Option<&'a mut T>, HintError> {
+    match scope_mut(exec_scopes, key)?.get_mut(key.name) {
+        Some(value) => value
+            .downcast_mut::<T>()
+            .map(Some)
+            .ok_or_else(|| wrong_type(key)),
+        None => Ok(None),
+    }
+}
+
+/// Sets the variable, replacing any previous value.
+pub fn insert_typed<T: Any>(exec_scopes: &mut ExecutionScopes, key: &ScopeKey<T>, value: T) {
+    if key.root {
+        exec_scopes.data[0].insert(key.name.to_string(), Box::new(value));
+    } else {
+        exec_scopes.insert_value(key.name, value);
+    }
+}
+
+/// Removes the variable, returning it if it was set and held a `T`.
+pub fn remove_typed<T: Any>(exec_scopes: &mut ExecutionScopes, key: &ScopeKey<T>) -> Option<T> {
+    scope_mut(exec_scopes, key)
+        .ok()?
+        .remove(key.name)
+        .and_then(|value| value.downcast::<T>().ok())
+        .map(|value| *value)
+}
@@ -0,0 +1,242 @@
+//! Named segments with bump allocation of typed values, for hints building input graphs that span
+//! several segments, e.g. nodes in one segment pointing to their children in another.
+
+use crate::cairo_type::CairoType;
+use crate::stdlib::prelude::*;
+use crate::types::pointer::CairoPointer;
+use alloc::collections::BTreeMap;
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+
+/// A segment allocated under a name and the number of cells handed out from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NamedSegment {
+    pub base: Relocatable,
+    pub used: usize,
+}
+
+impl NamedSegment {
+    /// The address the next allocation starts at.
+    pub fn next(&self) -> Result<Relocatable, HintError> {
+        Ok((self.base + self.used)?)
+    }
+}
+
+/// Maps logical names to segments, adding a segment the first time a name is used, and bump
+/// allocates values in them so that consecutive allocations under a name are contiguous.
+///
+/// ```ignore
+/// let mut segments = SegmentAllocator::new();
+/// let node = segments.alloc::<Node>(vm, "nodes")?;
+/// let children = segments.alloc_array::<Uint256>(vm, "children", 16)?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SegmentAllocator {
+    segments: BTreeMap<String, NamedSegment>,
+}
+
+impl SegmentAllocator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The segment allocated under `name`, added to the VM if there is none yet.
+    pub fn segment(&mut self, vm: &mut VirtualMachine, name: &str) -> Relocatable {
+        self.entry(vm, name).base
+    }
+
+    /// Allocates `n_cells` cells under `name`, returning their start.
+    pub fn alloc_cells(
+        &mut self,
+        vm: &mut VirtualMachine,
+        name: &str,
+        n_cells: usize,
+    ) -> Result<Relocatable, HintError> {
+        let segment = self.entry(vm, name);
+        let start = segment.next()?;
+        segment.used = segment.used.checked_add(n_cells).ok_or_else(|| {
+            HintError::CustomHint(
+                format!("allocating {n_cells} cells in segment {name} overflows").into_boxed_str(),
+            )
+        })?;
+        Ok(start)
+    }
+
+    /// Allocates room for a `T` under `name`, to be written through the returned pointer.
+    pub fn alloc<T: CairoType>(
+        &mut self,
+        vm: &mut VirtualMachine,
+        name: &str,
+    ) -> Result<CairoPointer<T>, HintError> {
+        self.alloc_array(vm, name, 1)
+    }
+
+    /// Allocates `len` contiguous `T`s under `name`, the pointer being to the first one.
+    pub fn alloc_array<T: CairoType>(
+        &mut self,
+        vm: &mut VirtualMachine,
+        name: &str,
+        len: usize,
+    ) -> Result<CairoPointer<T>, HintError> {
+        let n_cells = len.checked_mul(T::n_fields()).ok_or_else(|| {
+            HintError::CustomHint(
+                format!("allocating {len} values in segment {name} overflows").into_boxed_str(),
+            )
+        })?;
+        self.alloc_cells(vm, name, n_cells).map(CairoPointer::new)
+    }
+
+    /// Allocates a `T` under `name` and writes `value` there.
+    pub fn write<T: CairoType>(
+        &mut self,
+        vm: &mut VirtualMachine,
+        name: &str,
+        value: &T,
+    ) -> Result<CairoPointer<T>, HintError> {
+        let pointer = self.alloc::<T>(vm, name)?;
+        pointer.write_pointee(vm, value)?;
+        Ok(pointer)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&NamedSegment> {
+        self.segments.get(name)
+    }
+
+    /// Name of the segment `address` points into, if it was allocated here.
+    pub fn name_of(&self, address: Relocatable) -> Option<&str> {
+        self.segments
+            .iter()
+            .find(|(_, segment)| segment.base.segment_index == address.segment_index)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Named segments in name order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &NamedSegment)> {
+        self.segments
+            .iter()
+            .map(|(name, segment)| (name.as_str(), segment))
+    }
+
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    fn entry(&mut self, vm: &mut VirtualMachine, name: &str) -> &mut NamedSegment {
+        self.segments
+            .entry(name.to_string())
+            .or_insert_with(|| NamedSegment {
+                base: vm.add_memory_segment(),
+                used: 0,
+            })
+    }
+}
+
+// One `name: base (used cells)` line per segment
+impl core::fmt::Display for SegmentAllocator {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (name, segment) in self.iter() {
+            writeln!(f, "{name}: {} ({} cells)", segment.base, segment.used)?;
+        }
+        Ok(())
+    }
+}
+
+// Serialized as a map from name to `{"base": "1:0", "used": 4}`, for debug dumps
+#[cfg(feature = "serde")]
+impl serde::Serialize for SegmentAllocator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        #[derive(serde::Serialize)]
+        struct Entry {
+            base: String,
+            used: usize,
+        }
+
+        let mut map = serializer.serialize_map(Some(self.segments.len()))?;
+        for (name, segment) in self.iter() {
+            map.serialize_entry(
+                name,
+                &Entry {
+                    base: segment.base.to_string(),
+                    used: segment.used,
+                },
+            )?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use cairo_vm::vm::vm_core::VirtualMachine;
+    use num_bigint::BigUint;
+
+    #[test]
+    fn test_segment_allocator() {
+        use crate::segment_allocator::SegmentAllocator;
+        use crate::types::pointer::CairoPointer;
+        use crate::types::uint256::Uint256;
+
+        let mut vm = VirtualMachine::new(false, false);
+        let mut segments = SegmentAllocator::new();
+        assert!(segments.is_empty());
+
+        let first = segments
+            .write(&mut vm, "values", &Uint256::new(BigUint::from(1u32)))
+            .unwrap();
+        let array = segments
+            .alloc_array::<Uint256>(&mut vm, "values", 2)
+            .unwrap();
+        assert_eq!(array.address(), (first.address() + 2usize).unwrap());
+        assert_eq!(segments.get("values").unwrap().used, 6);
+        assert_eq!(
+            segments.get("values").unwrap().next().unwrap(),
+            (first.address() + 6usize).unwrap()
+        );
+
+        // A node in another segment pointing to the values
+        let node = segments
+            .alloc::<CairoPointer<Uint256>>(&mut vm, "nodes")
+            .unwrap();
+        node.write_pointee(&mut vm, &first).unwrap();
+        assert_ne!(node.address().segment_index, first.address().segment_index);
+        assert_eq!(
+            node.deref(&vm).unwrap().deref(&vm).unwrap(),
+            Uint256::new(BigUint::from(1u32))
+        );
+
+        assert_eq!(segments.segment(&mut vm, "values"), first.address());
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments.name_of(array.address()), Some("values"));
+        assert_eq!(
+            segments.name_of((node.address() + 1usize).unwrap()),
+            Some("nodes")
+        );
+        assert_eq!(segments.name_of(vm.add_memory_segment()), None);
+
+        let names: Vec<&str> = segments.iter().map(|(name, _)| name).collect();
+        assert_eq!(names, ["nodes", "values"]);
+        assert_eq!(
+            segments.to_string(),
+            format!(
+                "nodes: {} (1 cells)\nvalues: {} (6 cells)\n",
+                node.address(),
+                first.address()
+            )
+        );
+        assert_eq!(
+            serde_json::to_value(&segments).unwrap()["values"],
+            serde_json::json!({"base": first.address().to_string(), "used": 6})
+        );
+    }
+}
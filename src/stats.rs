@@ -0,0 +1,344 @@
+//! Execution statistics of a run: steps, memory holes, builtin instances and per-hint counts and
+//! durations, to pick a layout and estimate the proving cost of a program.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::time::Duration;
+
+use cairo_vm::{
+    types::layout_name::LayoutName,
+    vm::{errors::runner_errors::RunnerError, runners::cairo_runner::CairoRunner},
+};
+use serde::{Deserialize, Serialize};
+
+/// Invocations of a hint and the total time spent running it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HintStats {
+    pub count: u64,
+    pub total_nanos: u64,
+}
+
+impl HintStats {
+    pub fn total(&self) -> Duration {
+        Duration::from_nanos(self.total_nanos)
+    }
+
+    pub fn mean(&self) -> Duration {
+        match self.count {
+            0 => Duration::ZERO,
+            count => Duration::from_nanos(self.total_nanos / count),
+        }
+    }
+}
+
+/// Per-hint stats recorded by the `ExtendedHintProcessor` once enabled with `with_stats`, keyed
+/// by hint code.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HintStatsCollector {
+    hints: HashMap<String, HintStats>,
+}
+
+impl HintStatsCollector {
+    pub fn record(&mut self, code: &str, elapsed: Duration) {
+        let stats = self.hints.entry(code.to_string()).or_default();
+        stats.count += 1;
+        stats.total_nanos = stats
+            .total_nanos
+            .saturating_add(elapsed.as_nanos().try_into().unwrap_or(u64::MAX));
+    }
+
+    pub fn get(&self, code: &str) -> Option<&HintStats> {
+        self.hints.get(code)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &HintStats)> {
+        self.hints.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.hints.clear()
+    }
+}
+
+/// Serializable report of a finished run. Builtins are keyed by name and count instances, hints
+/// are keyed by code and left empty when the hint processor didn't collect stats.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionStats {
+    pub steps: usize,
+    pub memory_holes: usize,
+    pub builtins: BTreeMap<String, usize>,
+    pub hints: BTreeMap<String, HintStats>,
+}
+
+impl ExecutionStats {
+    // Memory holes are only known once the segments are finalized, i.e. after the run ends
+    pub fn from_runner(
+        runner: &CairoRunner,
+        hints: Option<&HintStatsCollector>,
+    ) -> Result<Self, RunnerError> {
+        let resources = runner.get_execution_resources()?;
+        Ok(Self {
+            steps: resources.n_steps,
+            memory_holes: resources.n_memory_holes,
+            builtins: resources
+                .builtin_instance_counter
+                .into_iter()
+                .map(|(name, instances)| (name.to_str().to_string(), instances))
+                .collect(),
+            hints: hints
+                .map(|hints| {
+                    hints
+                        .iter()
+                        .map(|(code, stats)| (code.clone(), *stats))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        })
+    }
+
+    pub fn hint_invocations(&self) -> u64 {
+        self.hints.values().map(|stats| stats.count).sum()
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("stats are always representable as JSON")
+    }
+
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json)
+    }
+}
+
+/// Resources used by a finished run. Builtins are keyed by name and count instances, segment
+/// sizes are indexed by segment.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceUsage {
+    pub n_steps: usize,
+    pub n_memory_holes: usize,
+    pub builtin_instances: BTreeMap<String, usize>,
+    pub segment_sizes: Vec<usize>,
+}
+
+impl ResourceUsage {
+    pub fn from_runner(runner: &CairoRunner) -> Result<Self, RunnerError> {
+        let resources = runner.get_execution_resources()?;
+        let segment_sizes = (0..runner.vm.segments.num_segments())
+            .map(|index| runner.vm.get_segment_used_size(index).unwrap_or(0))
+            .collect();
+        Ok(Self {
+            n_steps: resources.n_steps,
+            n_memory_holes: resources.n_memory_holes,
+            builtin_instances: resources
+                .builtin_instance_counter
+                .into_iter()
+                .map(|(name, instances)| (name.to_str().to_string(), instances))
+                .collect(),
+            segment_sizes,
+        })
+    }
+
+    /// Smallest power of two number of steps `layout` needs to fit the run, enough for both the
+    /// executed steps and the builtin instances, or `None` if it lacks one of the builtins.
+    pub fn required_steps(&self, layout: LayoutName) -> Option<usize> {
+        let (_, builtins) = LAYOUT_BUILTINS.iter().find(|(name, _)| *name == layout)?;
+        let mut steps = self.n_steps;
+        for (name, instances) in &self.builtin_instances {
+            // The segment arena is a Cairo 1 pseudo builtin, no layout allocates it
+            if name == "segment_arena" {
+                continue;
+            }
+            let (_, ratio) = builtins.iter().find(|(builtin, _)| builtin == name)?;
+            if let Some(ratio) = ratio {
+                let components = instances.div_ceil(instances_per_component(name));
+                steps = steps.max(components * ratio);
+            }
+        }
+        Some(steps.next_power_of_two())
+    }
+}
+
+/// Layout picked by `minimum_layout` and its steps, see `ResourceUsage::required_steps`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayoutEstimate {
+    pub layout: LayoutName,
+    pub required_steps: usize,
+}
+
+// Builtins of each fixed layout with their ratio, the number of steps per instance; the output
+// builtin has none. Mirrors cairo-vm's layout definitions, which aren't public.
+type BuiltinRatios = &'static [(&'static str, Option<usize>)];
+
+const LAYOUT_BUILTINS: &[(LayoutName, BuiltinRatios)] = &[
+    (LayoutName::plain, &[]),
+    (
+        LayoutName::small,
+        &[
+            ("output", None),
+            ("pedersen", Some(8)),
+            ("range_check", Some(8)),
+            ("ecdsa", Some(512)),
+        ],
+    ),
+    (
+        LayoutName::dex,
+        &[
+            ("output", None),
+            ("pedersen", Some(8)),
+            ("range_check", Some(8)),
+            ("ecdsa", Some(512)),
+        ],
+    ),
+    (
+        LayoutName::recursive,
+        &[
+            ("output", None),
+            ("pedersen", Some(128)),
+            ("range_check", Some(8)),
+            ("bitwise", Some(8)),
+        ],
+    ),
+    (
+        LayoutName::starknet,
+        &[
+            ("output", None),
+            ("pedersen", Some(32)),
+            ("range_check", Some(16)),
+            ("ecdsa", Some(2048)),
+            ("bitwise", Some(64)),
+            ("ec_op", Some(1024)),
+            ("poseidon", Some(32)),
+        ],
+    ),
+    (
+        LayoutName::starknet_with_keccak,
+        &[
+            ("output", None),
+            ("pedersen", Some(32)),
+            ("range_check", Some(16)),
+            ("ecdsa", Some(2048)),
+            ("bitwise", Some(64)),
+            ("ec_op", Some(1024)),
+            ("keccak", Some(2048)),
+            ("poseidon", Some(32)),
+        ],
+    ),
+    (
+        LayoutName::recursive_large_output,
+        &[
+            ("output", None),
+            ("pedersen", Some(128)),
+            ("range_check", Some(8)),
+            ("bitwise", Some(8)),
+            ("poseidon", Some(8)),
+        ],
+    ),
+    (
+        LayoutName::recursive_with_poseidon,
+        &[
+            ("output", None),
+            ("pedersen", Some(256)),
+            ("range_check", Some(16)),
+            ("bitwise", Some(16)),
+            ("poseidon", Some(64)),
+        ],
+    ),
+    (
+        LayoutName::all_solidity,
+        &[
+            ("output", None),
+            ("pedersen", Some(8)),
+            ("range_check", Some(8)),
+            ("ecdsa", Some(512)),
+            ("bitwise", Some(256)),
+            ("ec_op", Some(256)),
+        ],
+    ),
+    (
+        LayoutName::all_cairo,
+        &[
+            ("output", None),
+            ("pedersen", Some(256)),
+            ("range_check", Some(8)),
+            ("ecdsa", Some(2048)),
+            ("bitwise", Some(16)),
+            ("ec_op", Some(1024)),
+            ("keccak", Some(2048)),
+            ("poseidon", Some(256)),
+            ("range_check96", Some(8)),
+            ("add_mod", Some(128)),
+            ("mul_mod", Some(256)),
+        ],
+    ),
+];
+
+// Keccak instances come in components of 16, sharing the steps of the ratio
+fn instances_per_component(builtin: &str) -> usize {
+    match builtin {
+        "keccak" => 16,
+        _ => 1,
+    }
+}
+
+/// Fixed layout needing the fewest steps to prove the run, the simpler layout winning ties.
+/// `None` when no layout has all of its builtins. Only builtin capacity is accounted for, not
+/// the range check units or diluted pool, so the estimate is a lower bound.
+pub fn minimum_layout(resources: &ResourceUsage) -> Option<LayoutEstimate> {
+    LAYOUT_BUILTINS
+        .iter()
+        .filter_map(|(layout, _)| {
+            Some(LayoutEstimate {
+                layout: *layout,
+                required_steps: resources.required_steps(*layout)?,
+            })
+        })
+        .min_by_key(|estimate| estimate.required_steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stats::{minimum_layout, LayoutEstimate, ResourceUsage};
+    use cairo_vm::types::layout_name::LayoutName;
+
+    #[test]
+    fn test_minimum_layout() {
+        let mut resources = ResourceUsage {
+            n_steps: 1000,
+            ..Default::default()
+        };
+        assert_eq!(
+            minimum_layout(&resources),
+            Some(LayoutEstimate {
+                layout: LayoutName::plain,
+                required_steps: 1024,
+            })
+        );
+
+        // 200 pedersen instances: 1600 steps on small, 6400 on starknet, 25600 on recursive
+        resources
+            .builtin_instances
+            .insert("pedersen".to_string(), 200);
+        resources.builtin_instances.insert("output".to_string(), 4);
+        assert_eq!(resources.required_steps(LayoutName::plain), None);
+        assert_eq!(resources.required_steps(LayoutName::starknet), Some(8192));
+        assert_eq!(
+            minimum_layout(&resources).unwrap(),
+            LayoutEstimate {
+                layout: LayoutName::small,
+                required_steps: 2048,
+            }
+        );
+
+        // 17 keccak instances fill 2 components of 16
+        resources.builtin_instances.insert("keccak".to_string(), 17);
+        assert_eq!(
+            minimum_layout(&resources).unwrap(),
+            LayoutEstimate {
+                layout: LayoutName::starknet_with_keccak,
+                required_steps: 8192,
+            }
+        );
+        resources.builtin_instances.insert("unknown".to_string(), 1);
+        assert_eq!(minimum_layout(&resources), None);
+    }
+}
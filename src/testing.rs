@@ -0,0 +1,475 @@
+//! Fixtures for unit testing hints without a compiled program: a VM whose frame holds the `ids`,
+//! fabricated `HintProcessorData`, and memory assertions.
+//!
+//! ```ignore
+//! let mut tester = HintTester::with_id_sizes(&[("a", 2), ("b", 2), ("carry_low", 1), ("carry_high", 1)]);
+//! tester.write_id("a", &Uint256::from_any_str("0xffffffffffffffffffffffffffffffff")?)?;
+//! tester.write_id("b", &Uint256::from_any_str("1")?)?;
+//! tester.run(HINT_UINT256_ADD, hint_uint256_add)?;
+//! tester.assert_id("carry_low", &[Felt252::ONE.into()]);
+//! ```
+//!
+//! Memory layouts are pinned with golden files, so a changed layout fails with a diff instead of
+//! silently breaking compiled programs:
+//!
+//! ```ignore
+//! let snapshots = concat!(env!("CARGO_MANIFEST_DIR"), "/src/types/snapshots");
+//! assert_layout_snapshot(snapshots, "uint256", &Uint256::from_any_str("0x2")?);
+//! ```
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use cairo_vm::{
+    hint_processor::{
+        builtin_hint_processor::builtin_hint_processor_definition::HintProcessorData,
+        hint_processor_definition::HintReference,
+    },
+    types::{
+        exec_scope::ExecutionScopes,
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+use crate::cairo_type::{CairoType, CairoWritable};
+use crate::default_hints::debug::MemorySnapshot;
+use crate::default_hints::{HintImpl, HintRegistry};
+
+/// VM, exec scopes and constants a hint runs against. The frame holds the ids one after the
+/// other, the first one at `fp - frame_size`, and `ap` starts at `fp`, past the frame.
+pub struct HintTester {
+    pub vm: VirtualMachine,
+    pub exec_scopes: ExecutionScopes,
+    pub constants: HashMap<String, Felt252>,
+    ids_data: HashMap<String, HintReference>,
+    // Offset of each id from fp
+    offsets: HashMap<String, i32>,
+}
+
+impl HintTester {
+    /// One cell per id, e.g. felts or pointers.
+    pub fn new(ids: &[&str]) -> Self {
+        Self::with_id_sizes(&ids.iter().map(|id| (*id, 1)).collect::<Vec<_>>())
+    }
+
+    /// `(name, n_fields)` per id, for struct ids such as a `Uint256` taking two cells.
+    pub fn with_id_sizes(ids: &[(&str, usize)]) -> Self {
+        let mut vm = VirtualMachine::new(false, false);
+        // Program and execution segments
+        vm.add_memory_segment();
+        vm.add_memory_segment();
+        let frame_size: usize = ids.iter().map(|(_, size)| size).sum();
+        vm.set_fp(frame_size);
+        vm.set_ap(frame_size);
+
+        let mut offset = -(frame_size as i32);
+        let mut ids_data = HashMap::new();
+        let mut offsets = HashMap::new();
+        for (name, size) in ids {
+            ids_data.insert(name.to_string(), HintReference::new_simple(offset));
+            offsets.insert(name.to_string(), offset);
+            offset += *size as i32;
+        }
+        Self {
+            vm,
+            exec_scopes: ExecutionScopes::new(),
+            constants: HashMap::new(),
+            ids_data,
+            offsets,
+        }
+    }
+
+    /// Sets a constant read by the hint, under its full path such as
+    /// `starkware.cairo.common.cairo_sha256.sha256_utils.BATCH_SIZE`.
+    pub fn with_constant(mut self, name: &str, value: impl Into<Felt252>) -> Self {
+        self.constants.insert(name.to_string(), value.into());
+        self
+    }
+
+    // Panics on ids missing from the frame, a mistake in the test itself
+    pub fn id_address(&self, name: &str) -> Relocatable {
+        let offset = self
+            .offsets
+            .get(name)
+            .unwrap_or_else(|| panic!("unknown id {name}"));
+        (self.vm.get_fp() + *offset).expect("id address in the frame")
+    }
+
+    /// Writes a value at the address of an id, e.g. a felt, a pointer or a `Uint256`.
+    pub fn write_id<T: CairoWritable>(&mut self, name: &str, value: &T) -> Result<(), HintError> {
+        let address = self.id_address(name);
+        value.to_memory(&mut self.vm, address)?;
+        Ok(())
+    }
+
+    pub fn write_id_value(
+        &mut self,
+        name: &str,
+        value: impl Into<MaybeRelocatable>,
+    ) -> Result<(), HintError> {
+        let address = self.id_address(name);
+        self.vm.insert_value(address, value.into())?;
+        Ok(())
+    }
+
+    pub fn read_id<T: CairoType>(&self, name: &str) -> Result<T, HintError> {
+        T::from_memory(&self.vm, self.id_address(name))
+    }
+
+    /// Writes `values` to a new segment and returns its start, e.g. for an array id.
+    pub fn add_segment(&mut self, values: &[MaybeRelocatable]) -> Result<Relocatable, HintError> {
+        let start = self.vm.add_memory_segment();
+        self.vm.load_data(start, &values.to_vec())?;
+        Ok(start)
+    }
+
+    pub fn hint_data(&self, code: &str) -> HintProcessorData {
+        HintProcessorData::new_default(code.to_string(), self.ids_data.clone())
+    }
+
+    pub fn run(&mut self, code: &str, hint: HintImpl) -> Result<(), HintError> {
+        let hint_data = self.hint_data(code);
+        hint(
+            &mut self.vm,
+            &mut self.exec_scopes,
+            &hint_data,
+            &self.constants,
+        )
+    }
+
+    /// Runs the hint registered for `code`, failing with `UnknownHint` if there is none.
+    pub fn run_registered(&mut self, hints: &HintRegistry, code: &str) -> Result<(), HintError> {
+        let hint = hints
+            .get(code)
+            .ok_or_else(|| HintError::UnknownHint(code.to_string().into_boxed_str()))?;
+        self.run(code, hint)
+    }
+
+    pub fn assert_memory(&self, address: Relocatable, expected: &[MaybeRelocatable]) {
+        assert_memory(&self.vm, address, expected)
+    }
+
+    pub fn assert_id(&self, name: &str, expected: &[MaybeRelocatable]) {
+        assert_memory(&self.vm, self.id_address(name), expected)
+    }
+}
+
+/// Asserts that the cells from `address` hold `expected`, listing every mismatching cell.
+pub fn assert_memory(vm: &VirtualMachine, address: Relocatable, expected: &[MaybeRelocatable]) {
+    let mismatches: Vec<String> = expected
+        .iter()
+        .enumerate()
+        .filter_map(|(i, expected)| {
+            let cell = (address + i).expect("address in range");
+            let actual = vm.get_maybe(&cell);
+            (actual.as_ref() != Some(expected))
+                .then(|| format!("  {cell}: expected {expected}, got {actual:?}"))
+        })
+        .collect();
+    assert!(
+        mismatches.is_empty(),
+        "memory mismatch from {address}:\n{}",
+        mismatches.join("\n")
+    );
+}
+
+/// Every cell `value.to_memory` sets when written at `0:0` of a fresh VM, as one
+/// `address name = value` line per cell after the name of the layout. The cells of the value
+/// itself are named after its `layout()`, the ones in segments it adds are unnamed.
+pub fn render_memory_layout<T: CairoWritable>(value: &T) -> Result<String, HintError> {
+    let mut vm = VirtualMachine::new(false, false);
+    let address = vm.add_memory_segment();
+    value.to_memory(&mut vm, address)?;
+
+    let layout = T::layout();
+    let names: HashMap<usize, String> = layout
+        .cells()
+        .into_iter()
+        .map(|cell| (cell.offset, cell.name))
+        .collect();
+    let mut rendered = format!("{}\n", layout.name);
+    for (cell, value) in MemorySnapshot::capture(&mut vm).iter() {
+        let value = match value {
+            MaybeRelocatable::Int(value) => value.to_hex_string(),
+            MaybeRelocatable::RelocatableValue(ptr) => {
+                format!("{}:{}", ptr.segment_index, ptr.offset)
+            }
+        };
+        let name = names
+            .get(&cell.offset)
+            .filter(|_| cell.segment_index == address.segment_index);
+        let cell = format!("{}:{}", cell.segment_index, cell.offset);
+        rendered += &match name {
+            Some(name) => format!("{cell} {name} = {value}\n"),
+            None => format!("{cell} = {value}\n"),
+        };
+    }
+    Ok(rendered)
+}
+
+/// Compares `render_memory_layout(value)` with the committed `<dir>/<name>.snap`. On a mismatch
+/// or a missing snapshot, the new rendering is written next to it as `<name>.snap.new` and the
+/// assertion fails with a line diff; with `UPDATE_SNAPSHOTS=1` the snapshot is overwritten
+/// instead.
+pub fn assert_layout_snapshot<T: CairoWritable>(dir: impl AsRef<Path>, name: &str, value: &T) {
+    let actual = render_memory_layout(value)
+        .unwrap_or_else(|e| panic!("writing the {name} snapshot value failed: {e}"));
+    let path = dir.as_ref().join(format!("{name}.snap"));
+    let expected = std::fs::read_to_string(&path).ok();
+    if expected.as_deref() == Some(actual.as_str()) {
+        return;
+    }
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some_and(|update| update == "1") {
+        std::fs::create_dir_all(dir.as_ref()).expect("snapshot directory");
+        std::fs::write(&path, &actual).expect("snapshot written");
+        return;
+    }
+    let new_path = path.with_extension("snap.new");
+    // Best effort, the diff below is what the failure is about
+    let _ = std::fs::write(&new_path, &actual);
+    match expected {
+        None => panic!(
+            "no snapshot at {}, review {} and rename it, or rerun with UPDATE_SNAPSHOTS=1:\n{actual}",
+            path.display(),
+            new_path.display()
+        ),
+        Some(expected) => panic!(
+            "memory layout of {name} changed from {} (-) to {} (+), rerun with \
+             UPDATE_SNAPSHOTS=1 if this is intended:\n{}",
+            path.display(),
+            new_path.display(),
+            line_diff(&expected, &actual)
+        ),
+    }
+}
+
+// `-` / `+` / ` ` prefixed lines from the longest common subsequence of the two texts
+fn line_diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+    // lcs[i][j] is the length of the common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut diff = Vec::with_capacity(old.len().max(new.len()));
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            diff.push(format!("  {}", old[i]));
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            diff.push(format!("+ {}", new[j]));
+            j += 1;
+        } else {
+            diff.push(format!("- {}", old[i]));
+            i += 1;
+        }
+    }
+    diff.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::impl_cairo_enum;
+    use crate::testing::{assert_layout_snapshot, render_memory_layout};
+    use crate::types::{
+        bls12_381::{Fq, Fq2, G1Point, G2Point},
+        bounded_uint::Uint64,
+        byte_array::ByteArray,
+        cairo_enum::{Cairo0Enum, Cairo1Enum},
+        chunked_bytes::{Blake2sWords, Sha256Words},
+        dict::CairoDict,
+        ec_point::Secp256k1Point,
+        felt::Felt,
+        fixed_bytes::Bytes32,
+        int::Int64,
+        keccak_bytes::KeccakBytes,
+        option::{CairoOption, NullableOption},
+        pointer::CairoPointer,
+        rlp::{RlpBytes, RlpItem},
+        sha256_bytes::Sha256Bytes,
+        sha512_bytes::Sha512Bytes,
+        short_string::ShortString,
+        uint256::Uint256,
+        uint256_32::Uint256Bits32,
+        uint384::UInt384,
+        FromAnyStr,
+    };
+    use cairo_vm::{types::relocatable::Relocatable, Felt252};
+    use num_bigint::BigUint;
+
+    const SNAPSHOTS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/types/snapshots");
+
+    #[test]
+    fn test_render_memory_layout() {
+        let rendered = render_memory_layout(&Sha256Bytes(b"abcde".to_vec())).unwrap();
+        assert_eq!(
+            rendered,
+            "Sha256Bytes\n0:0 words = 1:0\n1:0 = 0x61626364\n1:1 = 0x65\n"
+        );
+    }
+
+    #[test]
+    fn test_felt_layouts() {
+        assert_layout_snapshot(SNAPSHOTS, "felt", &Felt(Felt252::from(42)));
+        assert_layout_snapshot(SNAPSHOTS, "uint64", &Uint64(255));
+        assert_layout_snapshot(SNAPSHOTS, "int64", &Int64::from_any_str("-1").unwrap());
+        assert_layout_snapshot(
+            SNAPSHOTS,
+            "short_string",
+            &ShortString::from_any_str("hi").unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_limb_layouts() {
+        let one = BigUint::from(1u32);
+        assert_layout_snapshot(SNAPSHOTS, "uint256", &Uint256::new((&one << 128) + 2u32));
+        assert_layout_snapshot(SNAPSHOTS, "uint384", &UInt384::new((&one << 96) + 3u32));
+        assert_layout_snapshot(
+            SNAPSHOTS,
+            "uint256_bits32",
+            &Uint256Bits32::new((BigUint::from(0x11u32) << 224) + 0x22u32),
+        );
+        let mut bytes = [0u8; 32];
+        bytes[15] = 2;
+        bytes[31] = 1;
+        assert_layout_snapshot(SNAPSHOTS, "bytes32", &Bytes32::from(bytes));
+    }
+
+    #[test]
+    fn test_byte_layouts() {
+        assert_layout_snapshot(SNAPSHOTS, "keccak_bytes", &KeccakBytes((1..=9).collect()));
+        assert_layout_snapshot(SNAPSHOTS, "sha256_bytes", &Sha256Bytes(b"abcde".to_vec()));
+        assert_layout_snapshot(SNAPSHOTS, "byte_array", &ByteArray(b"hello".to_vec()));
+        assert_layout_snapshot(
+            SNAPSHOTS,
+            "sha512_bytes",
+            &Sha512Bytes(b"abcdefghij".to_vec()),
+        );
+        assert_layout_snapshot(
+            SNAPSHOTS,
+            "sha256_words",
+            &Sha256Words::new(b"abcde".to_vec()),
+        );
+        assert_layout_snapshot(
+            SNAPSHOTS,
+            "blake2s_words",
+            &Blake2sWords::new(b"abcde".to_vec()),
+        );
+        // ["0x01", "cat"], encoded as c5 01 83 63 61 74
+        let item = RlpItem::List(vec![RlpItem::uint(1), RlpItem::Bytes(b"cat".to_vec())]);
+        assert_layout_snapshot(SNAPSHOTS, "rlp_bytes", &RlpBytes(item));
+    }
+
+    fn biguint(hex: &str) -> BigUint {
+        BigUint::parse_bytes(hex.as_bytes(), 16).unwrap()
+    }
+
+    #[test]
+    fn test_curve_layouts() {
+        let generator = Secp256k1Point::new(
+            UInt384::new(biguint(
+                "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            )),
+            UInt384::new(biguint(
+                "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+            )),
+        );
+        assert_layout_snapshot(SNAPSHOTS, "secp256k1_point", &generator);
+
+        let g1 = G1Point {
+            x: Fq::new(biguint("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb")).unwrap(),
+            y: Fq::new(biguint("08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1")).unwrap(),
+        };
+        assert_layout_snapshot(SNAPSHOTS, "g1_point", &g1);
+
+        let fq = |value: u32| Fq::new(BigUint::from(value)).unwrap();
+        let g2 = G2Point {
+            x: Fq2 {
+                a0: fq(1),
+                a1: fq(2),
+            },
+            y: Fq2 {
+                a0: fq(3),
+                a1: fq(4),
+            },
+        };
+        assert_layout_snapshot(SNAPSHOTS, "g2_point", &g2);
+    }
+
+    #[test]
+    fn test_container_layouts() {
+        // Values of two felts are written to their own segments, in key order
+        let mut dict = CairoDict::new();
+        dict.insert(Felt(Felt252::from(2)), Uint256::new(BigUint::from(7u32)));
+        dict.insert(
+            Felt(Felt252::from(1)),
+            Uint256::new((BigUint::from(1u32) << 128) + 3u32),
+        );
+        assert_layout_snapshot(SNAPSHOTS, "cairo_dict", &dict);
+
+        let pointer = CairoPointer::<Uint256>::new(Relocatable::from((3, 4)));
+        assert_layout_snapshot(SNAPSHOTS, "cairo_pointer", &pointer);
+
+        let tuple = (
+            Uint256::new((BigUint::from(1u32) << 128) + 2u32),
+            Felt(Felt252::from(9)),
+        );
+        assert_layout_snapshot(SNAPSHOTS, "tuple", &tuple);
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Action {
+        Noop,
+        Transfer((Felt, Uint256)),
+        Burn(Uint256),
+    }
+
+    impl_cairo_enum!(Action: Cairo0Enum {
+        Noop,
+        Transfer((Felt, Uint256)),
+        Burn(Uint256),
+    });
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum SierraAction {
+        Noop,
+        Transfer((Felt, Uint256)),
+        Burn(Uint256),
+    }
+
+    impl_cairo_enum!(SierraAction: Cairo1Enum {
+        Noop,
+        Transfer((Felt, Uint256)),
+        Burn(Uint256),
+    });
+
+    #[test]
+    fn test_enum_layouts() {
+        // The padding follows the payload in Cairo 0 and precedes it in Cairo 1
+        let burn = Uint256::new(BigUint::from(7u32));
+        assert_layout_snapshot(SNAPSHOTS, "cairo0_enum", &Action::Burn(burn.clone()));
+        assert_layout_snapshot(SNAPSHOTS, "cairo1_enum", &SierraAction::Burn(burn));
+    }
+
+    #[test]
+    fn test_option_layouts() {
+        let some: CairoOption<Uint256> = Some(Uint256::new(BigUint::from(1u32))).into();
+        assert_layout_snapshot(SNAPSHOTS, "cairo_option", &some);
+        let nullable: NullableOption<Felt> = Some(Felt(Felt252::from(7))).into();
+        assert_layout_snapshot(SNAPSHOTS, "nullable_option", &nullable);
+    }
+}
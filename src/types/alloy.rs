@@ -0,0 +1,109 @@
+//! Conversions between the Cairo types and `alloy_primitives`, for inputs bridged from
+//! Ethereum RPC data.
+
+use crate::stdlib::prelude::*;
+use crate::types::fixed_bytes::{Bytes20, Bytes32};
+use crate::types::keccak_bytes::KeccakBytes;
+use crate::types::uint256::Uint256;
+use crate::types::uint_bits::{LimbLayout, UintBits};
+use alloy_primitives::{Address, Bytes, B256, U256};
+use serde::Serializer;
+
+impl From<U256> for Uint256 {
+    fn from(value: U256) -> Self {
+        Self::from_words_le(value.as_limbs())
+    }
+}
+
+// `U256` has a blanket `From` impl over `ruint` conversions that a `From<Uint256>` impl would
+// make ambiguous, hence a method
+impl Uint256 {
+    pub fn to_u256(&self) -> U256 {
+        U256::from_limbs_slice(self.words())
+    }
+}
+
+impl From<B256> for Uint256 {
+    fn from(value: B256) -> Self {
+        U256::from_be_bytes(value.0).into()
+    }
+}
+
+impl From<Uint256> for B256 {
+    fn from(value: Uint256) -> Self {
+        B256::from(value.to_u256().to_be_bytes::<32>())
+    }
+}
+
+impl From<Address> for Uint256 {
+    fn from(value: Address) -> Self {
+        B256::left_padding_from(value.as_slice()).into()
+    }
+}
+
+// Fails if the value doesn't fit in 160 bits instead of dropping the high bytes
+impl TryFrom<Uint256> for Address {
+    type Error = String;
+
+    fn try_from(value: Uint256) -> Result<Self, Self::Error> {
+        let bytes = B256::from(value).0;
+        if bytes[..12].iter().any(|byte| *byte != 0) {
+            return Err(format!(
+                "0x{} does not fit in an address",
+                hex::encode(bytes)
+            ));
+        }
+        Ok(Address::from_slice(&bytes[12..]))
+    }
+}
+
+impl From<Bytes> for KeccakBytes {
+    fn from(value: Bytes) -> Self {
+        KeccakBytes(value.to_vec())
+    }
+}
+
+impl From<KeccakBytes> for Bytes {
+    fn from(value: KeccakBytes) -> Self {
+        Bytes::from(value.0)
+    }
+}
+
+impl From<B256> for Bytes32 {
+    fn from(value: B256) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<Bytes32> for B256 {
+    fn from(value: Bytes32) -> Self {
+        B256::from(value.0)
+    }
+}
+
+impl From<Address> for Bytes20 {
+    fn from(value: Address) -> Self {
+        Self(value.into_array())
+    }
+}
+
+impl From<Bytes20> for Address {
+    fn from(value: Bytes20) -> Self {
+        Address::from(value.0)
+    }
+}
+
+/// Serializes an unsigned integer as an RPC quantity, i.e. minimal hex such as `"0x1a"` or
+/// `"0x0"`, instead of the zero padded hex of its `Serialize` impl. Use with
+/// `#[serde(serialize_with = "alloy::serialize_quantity")]`.
+///
+/// Deserialization needs no helper, quantities and padded data both parse as hex.
+pub fn serialize_quantity<S, const BITS: usize, const LIMB: usize, L: LimbLayout>(
+    value: &UintBits<BITS, LIMB, L>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&format!("0x{}", value.to_biguint().to_str_radix(16)))
+}
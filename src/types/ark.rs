@@ -0,0 +1,93 @@
+//! Conversions between `UInt384`, the BLS12-381 types and arkworks, to check values computed by
+//! a prover against the ones read from VM memory.
+
+use crate::types::bls12_381::{Fq, Fq2, G1Point, G2Point};
+use crate::types::uint384::UInt384;
+use ark_bls12_381::{Fq as ArkFq, Fq2 as ArkFq2, G1Affine, G2Affine};
+use ark_ff::{BigInteger384, PrimeField};
+
+impl From<BigInteger384> for UInt384 {
+    fn from(value: BigInteger384) -> Self {
+        UInt384::from_words_le(&value.0)
+    }
+}
+
+impl From<UInt384> for BigInteger384 {
+    fn from(value: UInt384) -> Self {
+        let mut words = [0u64; 6];
+        words.copy_from_slice(value.words());
+        BigInteger384::new(words)
+    }
+}
+
+impl From<ArkFq> for Fq {
+    fn from(value: ArkFq) -> Self {
+        Fq(value.into_bigint().into())
+    }
+}
+
+// `Fq` can hold unreduced values through its public field, they are reduced modulo p
+impl From<Fq> for ArkFq {
+    fn from(value: Fq) -> Self {
+        ArkFq::from_le_bytes_mod_order(&value.0.to_biguint().to_bytes_le())
+    }
+}
+
+impl From<ArkFq2> for Fq2 {
+    fn from(value: ArkFq2) -> Self {
+        Fq2 {
+            a0: value.c0.into(),
+            a1: value.c1.into(),
+        }
+    }
+}
+
+impl From<Fq2> for ArkFq2 {
+    fn from(value: Fq2) -> Self {
+        ArkFq2::new(value.a0.into(), value.a1.into())
+    }
+}
+
+// The identity maps to the `(0, 0)` point at infinity and back. Neither direction checks that
+// the point is on the curve, see `G1Point::is_on_curve` and `G1Affine::is_on_curve`.
+impl From<G1Affine> for G1Point {
+    fn from(value: G1Affine) -> Self {
+        if value.infinity {
+            return G1Point::infinity();
+        }
+        G1Point {
+            x: value.x.into(),
+            y: value.y.into(),
+        }
+    }
+}
+
+impl From<G1Point> for G1Affine {
+    fn from(value: G1Point) -> Self {
+        if value.is_infinity() {
+            return G1Affine::identity();
+        }
+        G1Affine::new_unchecked(value.x.into(), value.y.into())
+    }
+}
+
+impl From<G2Affine> for G2Point {
+    fn from(value: G2Affine) -> Self {
+        if value.infinity {
+            return G2Point::infinity();
+        }
+        G2Point {
+            x: value.x.into(),
+            y: value.y.into(),
+        }
+    }
+}
+
+impl From<G2Point> for G2Affine {
+    fn from(value: G2Point) -> Self {
+        if value.is_infinity() {
+            return G2Affine::identity();
+        }
+        G2Affine::new_unchecked(value.x.into(), value.y.into())
+    }
+}
@@ -0,0 +1,234 @@
+//! Consensus-layer headers as served by the beacon node REST API, ready to be written as light
+//! client program inputs.
+
+use crate::cairo_type::{CairoType, CairoWritable, Layout};
+use crate::stdlib::prelude::*;
+use crate::types::{
+    bounded_uint::Uint64,
+    fixed_bytes::{Bytes20, Bytes32, FixedBytes},
+    sha256_bytes::Sha256Bytes,
+    uint256::Uint256,
+};
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+pub const BYTES_PER_LOGS_BLOOM: usize = 256;
+pub const MAX_EXTRA_DATA_BYTES: usize = 32;
+
+/// The `message` of `/eth/v1/beacon/headers/{block_id}`, or the `beacon` header of a light
+/// client update. Laid out as `(slot, proposer_index, parent_root, state_root, body_root)`, the
+/// roots as `Uint256`s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct BeaconBlockHeader {
+    pub slot: Uint64,
+    pub proposer_index: Uint64,
+    pub parent_root: Bytes32,
+    pub state_root: Bytes32,
+    pub body_root: Bytes32,
+}
+
+impl CairoWritable for BeaconBlockHeader {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let mut ptr = self.slot.to_memory(vm, address)?;
+        ptr = self.proposer_index.to_memory(vm, ptr)?;
+        for root in [&self.parent_root, &self.state_root, &self.body_root] {
+            ptr = root.to_memory(vm, ptr)?;
+        }
+        Ok(ptr)
+    }
+
+    fn n_fields() -> usize {
+        2 + 3 * Bytes32::N_FELTS
+    }
+
+    fn layout() -> Layout {
+        Layout::new("BeaconBlockHeader")
+            .member::<Uint64>("slot")
+            .member::<Uint64>("proposer_index")
+            .member::<Bytes32>("parent_root")
+            .member::<Bytes32>("state_root")
+            .member::<Bytes32>("body_root")
+    }
+}
+
+/// The Deneb and Electra `ExecutionPayloadHeader`, e.g. the `execution` header of a light client
+/// update. Laid out field by field in SSZ order, hashes as `Uint256`s, `fee_recipient` in one
+/// felt, `base_fee_per_gas` as a `Uint256`, and the variable-size fields as pointers to
+/// `Sha256Bytes` words: `logs_bloom`, then `(extra_data_len, extra_data)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ExecutionPayloadHeader {
+    pub parent_hash: Bytes32,
+    pub fee_recipient: Bytes20,
+    pub state_root: Bytes32,
+    pub receipts_root: Bytes32,
+    pub logs_bloom: FixedBytes<BYTES_PER_LOGS_BLOOM>,
+    pub prev_randao: Bytes32,
+    pub block_number: Uint64,
+    pub gas_limit: Uint64,
+    pub gas_used: Uint64,
+    pub timestamp: Uint64,
+    pub extra_data: Sha256Bytes,
+    // A decimal string in the REST API
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_decimal"))]
+    pub base_fee_per_gas: Uint256,
+    pub block_hash: Bytes32,
+    pub transactions_root: Bytes32,
+    pub withdrawals_root: Bytes32,
+    pub blob_gas_used: Uint64,
+    pub excess_blob_gas: Uint64,
+}
+
+impl CairoWritable for ExecutionPayloadHeader {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        if self.extra_data.n_bytes() > MAX_EXTRA_DATA_BYTES {
+            return Err(HintError::CustomHint(
+                format!(
+                    "extra data of {} bytes exceeds {MAX_EXTRA_DATA_BYTES} bytes",
+                    self.extra_data.n_bytes()
+                )
+                .into_boxed_str(),
+            ));
+        }
+        let mut ptr = self.parent_hash.to_memory(vm, address)?;
+        ptr = self.fee_recipient.to_memory(vm, ptr)?;
+        ptr = self.state_root.to_memory(vm, ptr)?;
+        ptr = self.receipts_root.to_memory(vm, ptr)?;
+        ptr = Sha256Bytes(self.logs_bloom.0.to_vec()).to_memory(vm, ptr)?;
+        ptr = self.prev_randao.to_memory(vm, ptr)?;
+        for value in [
+            &self.block_number,
+            &self.gas_limit,
+            &self.gas_used,
+            &self.timestamp,
+        ] {
+            ptr = value.to_memory(vm, ptr)?;
+        }
+        vm.insert_value(ptr, Felt252::from(self.extra_data.n_bytes()))?;
+        ptr = self.extra_data.to_memory(vm, (ptr + 1)?)?;
+        ptr = self.base_fee_per_gas.to_memory(vm, ptr)?;
+        for hash in [
+            &self.block_hash,
+            &self.transactions_root,
+            &self.withdrawals_root,
+        ] {
+            ptr = hash.to_memory(vm, ptr)?;
+        }
+        ptr = self.blob_gas_used.to_memory(vm, ptr)?;
+        self.excess_blob_gas.to_memory(vm, ptr)
+    }
+
+    fn n_fields() -> usize {
+        26
+    }
+
+    fn layout() -> Layout {
+        Layout::new("ExecutionPayloadHeader")
+            .member::<Bytes32>("parent_hash")
+            .member::<Bytes20>("fee_recipient")
+            .member::<Bytes32>("state_root")
+            .member::<Bytes32>("receipts_root")
+            .member::<Sha256Bytes>("logs_bloom")
+            .member::<Bytes32>("prev_randao")
+            .member::<Uint64>("block_number")
+            .member::<Uint64>("gas_limit")
+            .member::<Uint64>("gas_used")
+            .member::<Uint64>("timestamp")
+            .felt("extra_data_len")
+            .member::<Sha256Bytes>("extra_data")
+            .member::<Uint256>("base_fee_per_gas")
+            .member::<Bytes32>("block_hash")
+            .member::<Bytes32>("transactions_root")
+            .member::<Bytes32>("withdrawals_root")
+            .member::<Uint64>("blob_gas_used")
+            .member::<Uint64>("excess_blob_gas")
+    }
+}
+
+#[cfg(feature = "serde")]
+fn serialize_decimal<S: serde::Serializer>(
+    value: &Uint256,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    use serde::Serialize;
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&value.to_biguint().to_string())
+    } else {
+        value.serialize(serializer)
+    }
+}
+
+// `hash_tree_root`s, matching the header roots light client updates commit to
+#[cfg(feature = "ssz")]
+mod roots {
+    use super::*;
+    use crate::types::ssz::{merkleize, merkleize_with_limit, mix_in_length, Chunk, SszChunks};
+
+    fn uint64_chunk(value: &Uint64) -> Chunk {
+        let mut chunk = Chunk::default();
+        chunk[..8].copy_from_slice(&value.0.to_le_bytes());
+        chunk
+    }
+
+    impl BeaconBlockHeader {
+        pub fn hash_tree_root(&self) -> Chunk {
+            merkleize(&[
+                uint64_chunk(&self.slot),
+                uint64_chunk(&self.proposer_index),
+                self.parent_root.0,
+                self.state_root.0,
+                self.body_root.0,
+            ])
+        }
+    }
+
+    impl ExecutionPayloadHeader {
+        /// Fails if `extra_data` is longer than 32 bytes.
+        pub fn hash_tree_root(&self) -> Result<Chunk, String> {
+            let mut fee_recipient = Chunk::default();
+            fee_recipient[..20].copy_from_slice(&self.fee_recipient.0);
+            let extra_data = SszChunks::from_bytes(&self.extra_data.0);
+            let extra_data = mix_in_length(
+                &merkleize_with_limit(extra_data.chunks(), MAX_EXTRA_DATA_BYTES.div_ceil(32))?,
+                self.extra_data.n_bytes(),
+            );
+            let base_fee_per_gas: Vec<u8> = self
+                .base_fee_per_gas
+                .words()
+                .iter()
+                .flat_map(|word| word.to_le_bytes())
+                .collect();
+            Ok(merkleize(&[
+                self.parent_hash.0,
+                fee_recipient,
+                self.state_root.0,
+                self.receipts_root.0,
+                SszChunks::from_bytes(&self.logs_bloom.0).merkle_root(),
+                self.prev_randao.0,
+                uint64_chunk(&self.block_number),
+                uint64_chunk(&self.gas_limit),
+                uint64_chunk(&self.gas_used),
+                uint64_chunk(&self.timestamp),
+                extra_data,
+                SszChunks::from_bytes(&base_fee_per_gas).chunks()[0],
+                self.block_hash.0,
+                self.transactions_root.0,
+                self.withdrawals_root.0,
+                uint64_chunk(&self.blob_gas_used),
+                uint64_chunk(&self.excess_blob_gas),
+            ]))
+        }
+    }
+}
@@ -0,0 +1,562 @@
+use crate::cairo_type::{CairoType, CairoWritable, Layout};
+use crate::stdlib::prelude::*;
+use crate::types::uint384::UInt384;
+use crate::types::{check_numeric_input, hex_bytes_padded, FromAnyStr};
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+const P: &str = "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab";
+
+// Flags stored in the three most significant bits of the zcash serialization format
+const COMPRESSED_FLAG: u8 = 0x80;
+const INFINITY_FLAG: u8 = 0x40;
+const SIGN_FLAG: u8 = 0x20;
+
+pub(crate) fn modulus() -> BigUint {
+    BigUint::parse_bytes(P.as_bytes(), 16).unwrap()
+}
+
+fn to_bytes_48(value: &BigUint) -> [u8; 48] {
+    let bytes = value.to_bytes_be();
+    let mut padded = [0u8; 48];
+    padded[48 - bytes.len()..].copy_from_slice(&bytes);
+    padded
+}
+
+// y is "lexicographically largest" when it is greater than (p - 1) / 2
+fn is_largest(y: &BigUint, p: &BigUint) -> bool {
+    y > &((p - 1u32) >> 1)
+}
+
+fn strip_flags(bytes: &[u8]) -> (u8, Vec<u8>) {
+    let mut stripped = bytes.to_vec();
+    let flags = stripped[0] & (COMPRESSED_FLAG | INFINITY_FLAG | SIGN_FLAG);
+    stripped[0] &= !(COMPRESSED_FLAG | INFINITY_FLAG | SIGN_FLAG);
+    (flags, stripped)
+}
+
+/// BLS12-381 base field element, stored as a `UInt384` reduced modulo p.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fq(pub UInt384);
+
+impl Fq {
+    pub fn new(value: BigUint) -> Result<Self, String> {
+        if value >= modulus() {
+            return Err("value exceeds the BLS12-381 base field modulus".to_string());
+        }
+        Ok(Fq(UInt384::new(value)))
+    }
+
+    pub fn zero() -> Self {
+        Fq(UInt384::new(BigUint::zero()))
+    }
+
+    pub fn from_bytes_be(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() != 48 {
+            return Err(format!("expected 48 bytes for Fq, got {}", bytes.len()));
+        }
+        Self::new(BigUint::from_bytes_be(bytes))
+    }
+
+    pub fn to_bytes_be(&self) -> [u8; 48] {
+        to_bytes_48(&self.0.to_biguint())
+    }
+
+    fn sqrt(value: &BigUint, p: &BigUint) -> Option<BigUint> {
+        // p = 3 mod 4, so sqrt(a) = a^((p + 1) / 4)
+        let root = value.modpow(&((p + 1u32) >> 2), p);
+        ((&root * &root) % p == value % p).then_some(root)
+    }
+}
+
+impl CairoType for Fq {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        Ok(Fq(UInt384::from_memory(vm, address)?))
+    }
+}
+
+impl CairoWritable for Fq {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        self.0.to_memory(vm, address)
+    }
+
+    fn n_fields() -> usize {
+        UInt384::n_fields()
+    }
+
+    fn layout() -> Layout {
+        UInt384::layout().renamed("Fq")
+    }
+}
+
+impl FromAnyStr for Fq {
+    fn from_any_str(s: &str) -> Result<Self, String> {
+        Self::new(UInt384::from_any_str(s)?.to_biguint())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Fq {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::types::serde_utils::deserialize_from_any(deserializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Fq {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Quadratic extension element `a0 + a1 * u` with `u^2 = -1`, laid out as Garaga's `E2 { a0, a1 }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fq2 {
+    pub a0: Fq,
+    pub a1: Fq,
+}
+
+impl Fq2 {
+    pub fn zero() -> Self {
+        Fq2 {
+            a0: Fq::zero(),
+            a1: Fq::zero(),
+        }
+    }
+
+    // The standard encoding puts the imaginary part first: a1 || a0
+    pub fn from_bytes_be(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() != 96 {
+            return Err(format!("expected 96 bytes for Fq2, got {}", bytes.len()));
+        }
+        Ok(Fq2 {
+            a0: Fq::from_bytes_be(&bytes[48..])?,
+            a1: Fq::from_bytes_be(&bytes[..48])?,
+        })
+    }
+
+    pub fn to_bytes_be(&self) -> [u8; 96] {
+        let mut bytes = [0u8; 96];
+        bytes[..48].copy_from_slice(&self.a1.to_bytes_be());
+        bytes[48..].copy_from_slice(&self.a0.to_bytes_be());
+        bytes
+    }
+
+    fn is_zero(&self) -> bool {
+        self.a0.0.is_zero() && self.a1.0.is_zero()
+    }
+
+    fn to_pair(&self) -> Fq2Value {
+        (self.a0.0.to_biguint(), self.a1.0.to_biguint())
+    }
+
+    fn from_pair(value: Fq2Value) -> Self {
+        Fq2 {
+            a0: Fq(UInt384::new(value.0)),
+            a1: Fq(UInt384::new(value.1)),
+        }
+    }
+}
+
+type Fq2Value = (BigUint, BigUint);
+
+fn fq2_add(a: &Fq2Value, b: &Fq2Value, p: &BigUint) -> Fq2Value {
+    ((&a.0 + &b.0) % p, (&a.1 + &b.1) % p)
+}
+
+fn fq2_mul(a: &Fq2Value, b: &Fq2Value, p: &BigUint) -> Fq2Value {
+    let real = (&a.0 * &b.0 + p * p - &a.1 * &b.1) % p;
+    let imaginary = (&a.0 * &b.1 + &a.1 * &b.0) % p;
+    (real, imaginary)
+}
+
+fn fq2_pow(a: &Fq2Value, exponent: &BigUint, p: &BigUint) -> Fq2Value {
+    let mut result = (BigUint::one(), BigUint::zero());
+    for i in (0..exponent.bits()).rev() {
+        result = fq2_mul(&result, &result, p);
+        if exponent.bit(i) {
+            result = fq2_mul(&result, a, p);
+        }
+    }
+    result
+}
+
+// Algorithm 9 of https://eprint.iacr.org/2012/685.pdf, valid for p = 3 mod 4
+fn fq2_sqrt(a: &Fq2Value, p: &BigUint) -> Option<Fq2Value> {
+    let minus_one = (p - 1u32, BigUint::zero());
+    let a1 = fq2_pow(a, &((p - 3u32) >> 2), p);
+    let alpha = fq2_mul(&fq2_mul(&a1, &a1, p), a, p);
+    let conjugate = (alpha.0.clone(), (p - &alpha.1) % p);
+    if fq2_mul(&conjugate, &alpha, p) == minus_one {
+        return None;
+    }
+    let x0 = fq2_mul(&a1, a, p);
+    let root = if alpha == minus_one {
+        ((p - &x0.1) % p, x0.0)
+    } else {
+        let b = fq2_pow(
+            &fq2_add(&(BigUint::one(), BigUint::zero()), &alpha, p),
+            &((p - 1u32) >> 1),
+            p,
+        );
+        fq2_mul(&b, &x0, p)
+    };
+    (fq2_mul(&root, &root, p) == *a).then_some(root)
+}
+
+impl CairoType for Fq2 {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        let a0 = Fq::from_memory(vm, address)?;
+        let a1 = Fq::from_memory(vm, (address + Fq::n_fields())?)?;
+        Ok(Fq2 { a0, a1 })
+    }
+}
+
+impl CairoWritable for Fq2 {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let address = self.a0.to_memory(vm, address)?;
+        self.a1.to_memory(vm, address)
+    }
+
+    fn n_fields() -> usize {
+        2 * Fq::n_fields()
+    }
+
+    fn layout() -> Layout {
+        Layout::new("Fq2").member::<Fq>("a0").member::<Fq>("a1")
+    }
+}
+
+impl FromAnyStr for Fq2 {
+    fn from_any_str(s: &str) -> Result<Self, String> {
+        check_numeric_input(s)?;
+        Self::from_bytes_be(&hex_bytes_padded(s, Some(96))?)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Fq2 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::types::serde_utils::deserialize_hex(deserializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Fq2 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::types::serde_utils::serialize_hex(&self.to_bytes_be(), serializer)
+    }
+}
+
+/// Affine G1 point laid out as Garaga's `G1Point { x: UInt384, y: UInt384 }`.
+/// The point at infinity is represented as `(0, 0)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct G1Point {
+    pub x: Fq,
+    pub y: Fq,
+}
+
+impl G1Point {
+    pub fn infinity() -> Self {
+        G1Point {
+            x: Fq::zero(),
+            y: Fq::zero(),
+        }
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.x.0.is_zero() && self.y.0.is_zero()
+    }
+
+    // Checks y^2 = x^3 + 4. The subgroup membership is not checked.
+    pub fn is_on_curve(&self) -> bool {
+        if self.is_infinity() {
+            return true;
+        }
+        let p = modulus();
+        let y = self.y.0.to_biguint();
+        (&y * &y) % &p == Self::curve_rhs(&self.x.0.to_biguint(), &p)
+    }
+
+    /// Parses the zcash encoding, either compressed (48 bytes) or uncompressed (96 bytes).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let compressed = match bytes.len() {
+            48 => true,
+            96 => false,
+            len => return Err(format!("invalid G1 point encoding of {len} bytes")),
+        };
+        let (flags, bytes) = strip_flags(bytes);
+        if (flags & COMPRESSED_FLAG != 0) != compressed {
+            return Err("G1 point compression flag does not match its length".to_string());
+        }
+        if flags & INFINITY_FLAG != 0 {
+            if bytes.iter().any(|b| *b != 0) {
+                return Err("non-zero G1 point at infinity encoding".to_string());
+            }
+            return Ok(Self::infinity());
+        }
+
+        let x = Fq::from_bytes_be(&bytes[..48])?;
+        let point = if compressed {
+            let p = modulus();
+            let mut y = Fq::sqrt(&Self::curve_rhs(&x.0.to_biguint(), &p), &p)
+                .ok_or("x coordinate is not on the BLS12-381 G1 curve")?;
+            if is_largest(&y, &p) != (flags & SIGN_FLAG != 0) {
+                y = &p - y;
+            }
+            G1Point { x, y: Fq::new(y)? }
+        } else {
+            G1Point {
+                x,
+                y: Fq::from_bytes_be(&bytes[48..])?,
+            }
+        };
+        if !point.is_on_curve() {
+            return Err("point is not on the BLS12-381 G1 curve".to_string());
+        }
+        Ok(point)
+    }
+
+    pub fn to_uncompressed_bytes(&self) -> [u8; 96] {
+        let mut bytes = [0u8; 96];
+        if self.is_infinity() {
+            bytes[0] = INFINITY_FLAG;
+            return bytes;
+        }
+        bytes[..48].copy_from_slice(&self.x.to_bytes_be());
+        bytes[48..].copy_from_slice(&self.y.to_bytes_be());
+        bytes
+    }
+
+    fn curve_rhs(x: &BigUint, p: &BigUint) -> BigUint {
+        (x * x * x + 4u32) % p
+    }
+}
+
+impl CairoType for G1Point {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        let x = Fq::from_memory(vm, address)?;
+        let y = Fq::from_memory(vm, (address + Fq::n_fields())?)?;
+        Ok(G1Point { x, y })
+    }
+}
+
+impl CairoWritable for G1Point {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let address = self.x.to_memory(vm, address)?;
+        self.y.to_memory(vm, address)
+    }
+
+    fn n_fields() -> usize {
+        2 * Fq::n_fields()
+    }
+
+    fn layout() -> Layout {
+        Layout::new("G1Point").member::<Fq>("x").member::<Fq>("y")
+    }
+}
+
+impl FromAnyStr for G1Point {
+    fn from_any_str(s: &str) -> Result<Self, String> {
+        check_numeric_input(s)?;
+        Self::from_bytes(&hex_bytes_padded(s, None)?)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for G1Point {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::types::serde_utils::deserialize_hex(deserializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for G1Point {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::types::serde_utils::serialize_hex(&self.to_uncompressed_bytes(), serializer)
+    }
+}
+
+/// Affine G2 point laid out as Garaga's `G2Point { x0, x1, y0, y1 }`.
+/// The point at infinity is represented as all zeros.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct G2Point {
+    pub x: Fq2,
+    pub y: Fq2,
+}
+
+impl G2Point {
+    pub fn infinity() -> Self {
+        G2Point {
+            x: Fq2::zero(),
+            y: Fq2::zero(),
+        }
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.x.is_zero() && self.y.is_zero()
+    }
+
+    // Checks y^2 = x^3 + 4(u + 1). The subgroup membership is not checked.
+    pub fn is_on_curve(&self) -> bool {
+        if self.is_infinity() {
+            return true;
+        }
+        let p = modulus();
+        let y = self.y.to_pair();
+        fq2_mul(&y, &y, &p) == Self::curve_rhs(&self.x.to_pair(), &p)
+    }
+
+    /// Parses the zcash encoding, either compressed (96 bytes) or uncompressed (192 bytes).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let compressed = match bytes.len() {
+            96 => true,
+            192 => false,
+            len => return Err(format!("invalid G2 point encoding of {len} bytes")),
+        };
+        let (flags, bytes) = strip_flags(bytes);
+        if (flags & COMPRESSED_FLAG != 0) != compressed {
+            return Err("G2 point compression flag does not match its length".to_string());
+        }
+        if flags & INFINITY_FLAG != 0 {
+            if bytes.iter().any(|b| *b != 0) {
+                return Err("non-zero G2 point at infinity encoding".to_string());
+            }
+            return Ok(Self::infinity());
+        }
+
+        let x = Fq2::from_bytes_be(&bytes[..96])?;
+        let point = if compressed {
+            let p = modulus();
+            let (y0, y1) = fq2_sqrt(&Self::curve_rhs(&x.to_pair(), &p), &p)
+                .ok_or("x coordinate is not on the BLS12-381 G2 curve")?;
+            let largest = if y1.is_zero() {
+                is_largest(&y0, &p)
+            } else {
+                is_largest(&y1, &p)
+            };
+            let y = if largest != (flags & SIGN_FLAG != 0) {
+                ((&p - y0) % &p, (&p - y1) % &p)
+            } else {
+                (y0, y1)
+            };
+            G2Point {
+                x,
+                y: Fq2::from_pair(y),
+            }
+        } else {
+            G2Point {
+                x,
+                y: Fq2::from_bytes_be(&bytes[96..])?,
+            }
+        };
+        if !point.is_on_curve() {
+            return Err("point is not on the BLS12-381 G2 curve".to_string());
+        }
+        Ok(point)
+    }
+
+    pub fn to_uncompressed_bytes(&self) -> [u8; 192] {
+        let mut bytes = [0u8; 192];
+        if self.is_infinity() {
+            bytes[0] = INFINITY_FLAG;
+            return bytes;
+        }
+        bytes[..96].copy_from_slice(&self.x.to_bytes_be());
+        bytes[96..].copy_from_slice(&self.y.to_bytes_be());
+        bytes
+    }
+
+    fn curve_rhs(x: &Fq2Value, p: &BigUint) -> Fq2Value {
+        let x3 = fq2_mul(&fq2_mul(x, x, p), x, p);
+        fq2_add(&x3, &(BigUint::from(4u32), BigUint::from(4u32)), p)
+    }
+}
+
+impl CairoType for G2Point {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        let x = Fq2::from_memory(vm, address)?;
+        let y = Fq2::from_memory(vm, (address + Fq2::n_fields())?)?;
+        Ok(G2Point { x, y })
+    }
+}
+
+impl CairoWritable for G2Point {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let address = self.x.to_memory(vm, address)?;
+        self.y.to_memory(vm, address)
+    }
+
+    fn n_fields() -> usize {
+        2 * Fq2::n_fields()
+    }
+
+    fn layout() -> Layout {
+        Layout::new("G2Point").member::<Fq2>("x").member::<Fq2>("y")
+    }
+}
+
+impl FromAnyStr for G2Point {
+    fn from_any_str(s: &str) -> Result<Self, String> {
+        check_numeric_input(s)?;
+        Self::from_bytes(&hex_bytes_padded(s, None)?)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for G2Point {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::types::serde_utils::deserialize_hex(deserializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for G2Point {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::types::serde_utils::serialize_hex(&self.to_uncompressed_bytes(), serializer)
+    }
+}
@@ -0,0 +1,136 @@
+use crate::cairo_type::{read_felt_chunks, CairoType, CairoWritable, Layout};
+use crate::stdlib::prelude::*;
+use crate::types::{check_numeric_input, parse_biguint_radix, FromAnyStr, FromStrRadix};
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::BigUint;
+use num_traits::{Num, ToPrimitive};
+
+// Decimal, or hex when prefixed with 0x
+fn parse_unsigned(s: &str) -> Result<BigUint, String> {
+    check_numeric_input(s)?;
+    match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => BigUint::from_str_radix(&hex.replace('_', ""), 16),
+        None => BigUint::from_str_radix(s, 10),
+    }
+    .map_err(|e| format!("invalid unsigned integer {s}: {e}"))
+}
+
+macro_rules! bounded_uint {
+    ($name:ident, $inner:ty, $to_inner:ident) => {
+        /// Unsigned integer stored in a single felt, range checked whenever it is built from a
+        /// wider value so out of range inputs fail before reaching the Cairo range checks.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $name(pub $inner);
+
+        impl $name {
+            pub const MAX: Self = Self(<$inner>::MAX);
+
+            pub fn checked_new(value: impl TryInto<$inner>) -> Result<Self, String> {
+                value
+                    .try_into()
+                    .map(Self)
+                    .map_err(|_| format!("value does not fit in {}", stringify!($name)))
+            }
+
+            pub fn to_felt(&self) -> Felt252 {
+                Felt252::from(self.0)
+            }
+
+            pub fn from_felt(value: &Felt252) -> Result<Self, String> {
+                // Through u128 rather than a BigUint, wider felts never fitting
+                value
+                    .to_u128()
+                    .and_then(|value| value.$to_inner())
+                    .map(Self)
+                    .ok_or_else(|| format!("felt {value} does not fit in {}", stringify!($name)))
+            }
+        }
+
+        impl CairoType for $name {
+            fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+                let value = vm.get_integer(address)?;
+                Self::from_felt(&value).map_err(|e| HintError::CustomHint(e.into_boxed_str()))
+            }
+
+            fn from_memory_range(
+                vm: &VirtualMachine,
+                address: Relocatable,
+                count: usize,
+            ) -> Result<Vec<Self>, HintError> {
+                read_felt_chunks(vm, address, count, 1, |felts| Self::from_felt(&felts[0]))
+            }
+        }
+
+        impl CairoWritable for $name {
+            fn to_memory(
+                &self,
+                vm: &mut VirtualMachine,
+                address: Relocatable,
+            ) -> Result<Relocatable, HintError> {
+                vm.insert_value(address, self.to_felt())?;
+                Ok((address + 1)?)
+            }
+
+            fn n_fields() -> usize {
+                1
+            }
+
+            fn layout() -> Layout {
+                Layout::new(stringify!($name)).felt("value")
+            }
+        }
+
+        impl FromAnyStr for $name {
+            fn from_any_str(s: &str) -> Result<Self, String> {
+                parse_unsigned(s)?
+                    .$to_inner()
+                    .map(Self)
+                    .ok_or_else(|| format!("{s} does not fit in {}", stringify!($name)))
+            }
+        }
+
+        impl FromStrRadix for $name {
+            fn from_str_radix(s: &str, radix: u32) -> Result<Self, String> {
+                parse_biguint_radix(s, radix)?
+                    .$to_inner()
+                    .map(Self)
+                    .ok_or_else(|| format!("{s} does not fit in {}", stringify!($name)))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                crate::types::serde_utils::deserialize_from_any(deserializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                // Decimal in JSON, big-endian bytes in binary formats
+                if serializer.is_human_readable() {
+                    serializer.serialize_str(&self.0.to_string())
+                } else {
+                    serializer.serialize_bytes(&self.0.to_be_bytes())
+                }
+            }
+        }
+    };
+}
+
+bounded_uint!(Uint8, u8, to_u8);
+bounded_uint!(Uint16, u16, to_u16);
+bounded_uint!(Uint32, u32, to_u32);
+bounded_uint!(Uint64, u64, to_u64);
+bounded_uint!(Uint128, u128, to_u128);
@@ -0,0 +1,154 @@
+use crate::cairo_type::{write_felts, CairoType, CairoWritable, Layout, MemoryWriter};
+use crate::stdlib::prelude::*;
+use crate::types::{hex_bytes_padded, FromAnyStr, ParseOptions};
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_traits::ToPrimitive;
+
+// Number of bytes packed in each bytes31 word
+pub const BYTES_IN_WORD: usize = 31;
+
+/// Arbitrary bytes in the Cairo 1 `ByteArray` layout: `(data_start, data_end, pending_word,
+/// pending_word_len)`, where `data` holds the full 31-byte big-endian words and the pending
+/// word the remaining bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ByteArray(pub Vec<u8>);
+
+impl ByteArray {
+    // Full words and the (pending_word, pending_word_len) remainder
+    pub fn to_words(&self) -> (Vec<Felt252>, Felt252, usize) {
+        let mut chunks = self.0.chunks_exact(BYTES_IN_WORD);
+        let words = chunks.by_ref().map(Felt252::from_bytes_be_slice).collect();
+        let pending = chunks.remainder();
+        (words, Felt252::from_bytes_be_slice(pending), pending.len())
+    }
+
+    /// The Cairo 1 serialization used for calldata:
+    /// `[data_len, ..data, pending_word, pending_word_len]`.
+    pub fn to_calldata(&self) -> Vec<Felt252> {
+        let (words, pending_word, pending_len) = self.to_words();
+        let mut calldata = Vec::with_capacity(words.len() + 3);
+        calldata.push(Felt252::from(words.len()));
+        calldata.extend(words);
+        calldata.push(pending_word);
+        calldata.push(Felt252::from(pending_len));
+        calldata
+    }
+}
+
+// Big-endian bytes of `word`, which must fit in `len` bytes
+fn word_bytes(word: &Felt252, len: usize) -> Result<Vec<u8>, HintError> {
+    let bytes = word.to_bytes_be();
+    let (high, low) = bytes.split_at(32 - len);
+    if high.iter().any(|b| *b != 0) {
+        return Err(HintError::CustomHint(
+            format!("ByteArray word {word} does not fit in {len} bytes").into_boxed_str(),
+        ));
+    }
+    Ok(low.to_vec())
+}
+
+impl CairoType for ByteArray {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        let data_start = vm.get_relocatable(address)?;
+        let data_end = vm.get_relocatable((address + 1)?)?;
+        let pending_word = vm.get_integer((address + 2)?)?;
+        let pending_len = vm.get_integer((address + 3)?)?;
+        let pending_len = pending_len
+            .to_usize()
+            .filter(|len| *len < BYTES_IN_WORD)
+            .ok_or_else(|| {
+                HintError::CustomHint(
+                    format!("invalid ByteArray pending_word_len {pending_len}").into_boxed_str(),
+                )
+            })?;
+
+        let n_words = (data_end - data_start)?;
+        let mut bytes = Vec::with_capacity(n_words * BYTES_IN_WORD + pending_len);
+        for word in vm.get_integer_range(data_start, n_words)? {
+            bytes.extend(word_bytes(&word, BYTES_IN_WORD)?);
+        }
+        bytes.extend(word_bytes(&pending_word, pending_len)?);
+        Ok(Self(bytes))
+    }
+}
+
+impl CairoWritable for ByteArray {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let (words, pending_word, pending_len) = self.to_words();
+        let data = vm.add_memory_segment();
+        let data_end = write_felts(vm, data, words)?;
+        let mut writer = MemoryWriter::new(address);
+        writer.push(data);
+        writer.push(data_end);
+        writer.push(pending_word);
+        writer.push(Felt252::from(pending_len));
+        writer.flush(vm)
+    }
+
+    fn n_fields() -> usize {
+        4
+    }
+
+    fn layout() -> Layout {
+        Layout::new("ByteArray")
+            .pointer("data_start")
+            .pointer("data_end")
+            .felt("pending_word")
+            .felt("pending_word_len")
+    }
+}
+
+impl From<&str> for ByteArray {
+    fn from(s: &str) -> Self {
+        Self(s.as_bytes().to_vec())
+    }
+}
+
+impl From<Vec<u8>> for ByteArray {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+// 0x prefixed strings are hex encoded bytes, anything else is taken as UTF-8 text
+impl FromAnyStr for ByteArray {
+    fn from_any_str(s: &str) -> Result<Self, String> {
+        if s.starts_with("0x") || s.starts_with("0X") {
+            return Ok(Self(hex_bytes_padded(s, None)?));
+        }
+        Ok(Self::from(s))
+    }
+
+    // Text, where empty input and whitespace are content
+    fn from_any_str_with_options(s: &str, _options: &ParseOptions) -> Result<Self, String> {
+        Self::from_any_str(s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ByteArray {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::types::serde_utils::deserialize_hex(deserializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ByteArray {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::types::serde_utils::serialize_hex(&self.0, serializer)
+    }
+}
@@ -0,0 +1,97 @@
+use crate::stdlib::prelude::*;
+use crate::types::{
+    byte_array::ByteArray, check_whitespace, keccak_bytes::KeccakBytes, parse_bytes,
+    sha256_bytes::Sha256Bytes, sha512_bytes::Sha512Bytes, FromAnyStr, ParseOptions,
+};
+
+/// Raw bytes read from hex, base64 after the `base64:` prefix, or a JSON array of bytes, to be
+/// converted into the byte type a hint expects.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bytes(pub Vec<u8>);
+
+impl Bytes {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<Vec<u8>> for Bytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Bytes(bytes)
+    }
+}
+
+impl From<&[u8]> for Bytes {
+    fn from(bytes: &[u8]) -> Self {
+        Bytes(bytes.to_vec())
+    }
+}
+
+impl AsRef<[u8]> for Bytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Bytes> for KeccakBytes {
+    fn from(bytes: Bytes) -> Self {
+        KeccakBytes(bytes.0)
+    }
+}
+
+impl From<Bytes> for Sha256Bytes {
+    fn from(bytes: Bytes) -> Self {
+        Sha256Bytes(bytes.0)
+    }
+}
+
+impl From<Bytes> for Sha512Bytes {
+    fn from(bytes: Bytes) -> Self {
+        Sha512Bytes(bytes.0)
+    }
+}
+
+impl From<Bytes> for ByteArray {
+    fn from(bytes: Bytes) -> Self {
+        ByteArray(bytes.0)
+    }
+}
+
+impl FromAnyStr for Bytes {
+    fn from_any_str(s: &str) -> Result<Self, String> {
+        check_whitespace(s)?;
+        Ok(Bytes(parse_bytes(s)?))
+    }
+
+    // Empty input is no bytes whatever the options, only the whitespace handling applies
+    fn from_any_str_with_options(s: &str, options: &ParseOptions) -> Result<Self, String> {
+        match options.trim_whitespace {
+            true => Self::from_any_str(s.trim()),
+            false => Self::from_any_str(s),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Bytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::types::serde_utils::deserialize_bytes(deserializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Bytes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::types::serde_utils::serialize_hex(&self.0, serializer)
+    }
+}
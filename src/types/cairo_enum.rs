@@ -0,0 +1,280 @@
+//! Rust enums mapped to Cairo enums: a discriminant felt followed by the payload of the variant,
+//! padded with zeros to the size of the largest payload so every value spans the same cells.
+//!
+//! ```ignore
+//! enum Action {
+//!     Noop,
+//!     Transfer((Felt, Uint256)),
+//!     Burn(Uint256),
+//! }
+//!
+//! impl_cairo_enum!(Action: Cairo1Enum {
+//!     Noop,
+//!     Transfer((Felt, Uint256)),
+//!     Burn(Uint256),
+//! });
+//! ```
+
+use crate::cairo_type::{CairoWritable, Layout};
+use crate::stdlib::prelude::*;
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+// Named by `impl_cairo_enum!` in the crates invoking it
+#[doc(hidden)]
+pub mod __private {
+    pub use cairo_vm::{
+        types::relocatable::Relocatable,
+        vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    };
+}
+
+/// How the discriminant and the padding of a `CairoEnum` are laid out.
+pub trait EnumEncoding {
+    /// Whether the padding comes before the payload rather than after it.
+    const PAYLOAD_LAST: bool;
+
+    /// The discriminant written for variant `index` out of `n_variants`.
+    fn selector(n_variants: usize, index: usize) -> usize;
+}
+
+/// The usual Cairo 0 convention: the variant index, then the payload followed by the padding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Cairo0Enum;
+
+/// The layout of Cairo 1 enums in memory: the Sierra variant selector, then the padding followed
+/// by the payload. The selector is the index for up to two variants and the jump table offset
+/// `2 * (n_variants - index) - 1` above that. Calldata serializes enums as the index followed by
+/// the unpadded payload instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Cairo1Enum;
+
+impl EnumEncoding for Cairo0Enum {
+    const PAYLOAD_LAST: bool = false;
+
+    fn selector(_n_variants: usize, index: usize) -> usize {
+        index
+    }
+}
+
+impl EnumEncoding for Cairo1Enum {
+    const PAYLOAD_LAST: bool = true;
+
+    fn selector(n_variants: usize, index: usize) -> usize {
+        match n_variants {
+            0..=2 => index,
+            _ => 2 * (n_variants - index) - 1,
+        }
+    }
+}
+
+/// A Rust enum laid out as a Cairo enum, each variant holding at most one `CairoType` payload
+/// (a tuple for several members). Implemented with `impl_cairo_enum!`, which also implements
+/// `CairoType` and `CairoWritable` with the provided `read_enum` / `write_enum` / `enum_layout`.
+pub trait CairoEnum: Sized {
+    type Encoding: EnumEncoding;
+
+    const NAME: &'static str;
+    /// Variant names, in declaration order.
+    const VARIANTS: &'static [&'static str];
+
+    /// Number of felts of the payload of variant `index`, `0` for unit variants.
+    fn payload_size(index: usize) -> usize;
+
+    /// Index of the variant and its payload, `None` for unit variants.
+    fn variant(&self) -> (usize, Option<&dyn CairoWritable>);
+
+    /// Reads the payload of variant `index` at `address`.
+    fn read_variant(
+        index: usize,
+        vm: &VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Self, HintError>;
+
+    /// Size of the largest payload, that every variant is padded to.
+    fn max_payload_size() -> usize {
+        (0..Self::VARIANTS.len())
+            .map(Self::payload_size)
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn enum_n_fields() -> usize {
+        1 + Self::max_payload_size()
+    }
+
+    fn read_enum(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        let n_variants = Self::VARIANTS.len();
+        let discriminant = vm.get_integer(address)?;
+        let index = (0..n_variants)
+            .find(|index| {
+                *discriminant == Felt252::from(Self::Encoding::selector(n_variants, *index))
+            })
+            .ok_or_else(|| {
+                HintError::CustomHint(
+                    format!(
+                        "invalid {} discriminant {discriminant} at {address}",
+                        Self::NAME
+                    )
+                    .into_boxed_str(),
+                )
+            })?;
+        let padding = if Self::Encoding::PAYLOAD_LAST {
+            Self::max_payload_size() - Self::payload_size(index)
+        } else {
+            0
+        };
+        Self::read_variant(index, vm, (address + (1 + padding))?)
+    }
+
+    fn write_enum(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let (index, payload) = self.variant();
+        let selector = Self::Encoding::selector(Self::VARIANTS.len(), index);
+        vm.insert_value(address, Felt252::from(selector))?;
+        let padding = Self::max_payload_size() - Self::payload_size(index);
+        let mut next = (address + 1)?;
+        if Self::Encoding::PAYLOAD_LAST {
+            next = write_zeros(vm, next, padding)?;
+        }
+        if let Some(payload) = payload {
+            next = payload.to_memory(vm, next)?;
+        }
+        if !Self::Encoding::PAYLOAD_LAST {
+            next = write_zeros(vm, next, padding)?;
+        }
+        Ok(next)
+    }
+
+    /// The discriminant as `variant` and the padded payload as unnamed `payload` felts.
+    fn enum_layout() -> Layout {
+        let layout = Layout::new(Self::NAME).felt("variant");
+        match Self::max_payload_size() {
+            0 => layout,
+            size => layout.nested("payload", Layout::felts("payload", size)),
+        }
+    }
+}
+
+fn write_zeros(
+    vm: &mut VirtualMachine,
+    address: Relocatable,
+    n: usize,
+) -> Result<Relocatable, HintError> {
+    for i in 0..n {
+        vm.insert_value((address + i)?, Felt252::ZERO)?;
+    }
+    Ok((address + n)?)
+}
+
+/// Implements `CairoEnum`, `CairoType` and `CairoWritable` for an enum from its encoding and its
+/// variants, each either a unit variant or a tuple variant with a single `CairoType` payload,
+/// listed in declaration order.
+///
+/// ```ignore
+/// impl_cairo_enum!(Action: Cairo0Enum {
+///     Noop,
+///     Transfer((Felt, Uint256)),
+///     Burn(Uint256),
+/// });
+/// ```
+#[macro_export]
+macro_rules! impl_cairo_enum {
+    ($name:ident : $encoding:ty { $($variant:ident $(($payload:ty))?),+ $(,)? }) => {
+        impl $crate::types::cairo_enum::CairoEnum for $name {
+            type Encoding = $encoding;
+
+            const NAME: &'static str = stringify!($name);
+            const VARIANTS: &'static [&'static str] = &[$(stringify!($variant)),+];
+
+            fn payload_size(index: usize) -> usize {
+                [$($crate::impl_cairo_enum!(@size $($payload)?)),+][index]
+            }
+
+            fn variant(&self) -> (usize, Option<&dyn $crate::cairo_type::CairoWritable>) {
+                [$($crate::impl_cairo_enum!(@variant self, $variant $(, $payload)?)),+]
+                    .into_iter()
+                    .enumerate()
+                    .find_map(|(index, payload)| payload.map(|payload| (index, payload)))
+                    .expect("every variant is listed")
+            }
+
+            fn read_variant(
+                index: usize,
+                vm: &$crate::types::cairo_enum::__private::VirtualMachine,
+                address: $crate::types::cairo_enum::__private::Relocatable,
+            ) -> Result<Self, $crate::types::cairo_enum::__private::HintError> {
+                let readers: &[fn(
+                    &$crate::types::cairo_enum::__private::VirtualMachine,
+                    $crate::types::cairo_enum::__private::Relocatable,
+                ) -> Result<Self, $crate::types::cairo_enum::__private::HintError>] = &[$(
+                    |vm, address| $crate::impl_cairo_enum!(@read vm, address, $variant $(, $payload)?)
+                ),+];
+                readers[index](vm, address)
+            }
+        }
+
+        impl $crate::cairo_type::CairoType for $name {
+            fn from_memory(
+                vm: &$crate::types::cairo_enum::__private::VirtualMachine,
+                address: $crate::types::cairo_enum::__private::Relocatable,
+            ) -> Result<Self, $crate::types::cairo_enum::__private::HintError> {
+                <Self as $crate::types::cairo_enum::CairoEnum>::read_enum(vm, address)
+            }
+        }
+
+        impl $crate::cairo_type::CairoWritable for $name {
+            fn to_memory(
+                &self,
+                vm: &mut $crate::types::cairo_enum::__private::VirtualMachine,
+                address: $crate::types::cairo_enum::__private::Relocatable,
+            ) -> Result<
+                $crate::types::cairo_enum::__private::Relocatable,
+                $crate::types::cairo_enum::__private::HintError,
+            > {
+                $crate::types::cairo_enum::CairoEnum::write_enum(self, vm, address)
+            }
+
+            fn n_fields() -> usize {
+                <Self as $crate::types::cairo_enum::CairoEnum>::enum_n_fields()
+            }
+
+            fn layout() -> $crate::cairo_type::Layout {
+                <Self as $crate::types::cairo_enum::CairoEnum>::enum_layout()
+            }
+        }
+    };
+    (@size) => {
+        0
+    };
+    (@size $payload:ty) => {
+        <$payload as $crate::cairo_type::CairoWritable>::n_fields()
+    };
+    (@variant $self:ident, $variant:ident) => {
+        if let Self::$variant = $self {
+            Some(None::<&dyn $crate::cairo_type::CairoWritable>)
+        } else {
+            None
+        }
+    };
+    (@variant $self:ident, $variant:ident, $payload:ty) => {
+        if let Self::$variant(payload) = $self {
+            Some(Some(payload as &dyn $crate::cairo_type::CairoWritable))
+        } else {
+            None
+        }
+    };
+    (@read $vm:ident, $address:ident, $variant:ident) => {{
+        let _ = ($vm, $address);
+        Ok(Self::$variant)
+    }};
+    (@read $vm:ident, $address:ident, $variant:ident, $payload:ty) => {
+        <$payload as $crate::cairo_type::CairoType>::from_memory($vm, $address).map(Self::$variant)
+    };
+}
@@ -0,0 +1,217 @@
+use crate::cairo_type::{write_felts, CairoWritable, Layout};
+use crate::stdlib::prelude::*;
+use crate::types::{check_whitespace, parse_bytes, FromAnyStr, ParseOptions};
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use core::marker::PhantomData;
+use num_traits::ToPrimitive;
+
+/// Byte order of the words of a `ChunkedBytes`.
+pub trait Endian {
+    const NAME: &'static str;
+
+    /// Value of up to 8 bytes.
+    fn word(bytes: &[u8]) -> u64;
+
+    /// The `n` bytes whose value is `word`, the inverse of `word`.
+    fn bytes(word: u64, n: usize) -> Vec<u8>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LittleEndian;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BigEndian;
+
+impl Endian for LittleEndian {
+    const NAME: &'static str = "LittleEndian";
+
+    fn word(bytes: &[u8]) -> u64 {
+        bytes
+            .iter()
+            .rev()
+            .fold(0, |word, byte| (word << 8) | *byte as u64)
+    }
+
+    fn bytes(word: u64, n: usize) -> Vec<u8> {
+        word.to_le_bytes()[..n].to_vec()
+    }
+}
+
+impl Endian for BigEndian {
+    const NAME: &'static str = "BigEndian";
+
+    fn word(bytes: &[u8]) -> u64 {
+        bytes
+            .iter()
+            .fold(0, |word, byte| (word << 8) | *byte as u64)
+    }
+
+    fn bytes(word: u64, n: usize) -> Vec<u8> {
+        word.to_be_bytes()[8 - n..].to_vec()
+    }
+}
+
+/// Bytes written as words of `WORD` bytes in `E` byte order, the input layout of the Cairo
+/// hash implementations: keccak takes little-endian 64-bit words, sha256 big-endian 32-bit
+/// words and blake2s little-endian 32-bit words. A trailing partial word is the value of its
+/// bytes in the same order, e.g. `[0x61, 0x62]` is `0x6162` big-endian and `0x6261`
+/// little-endian.
+///
+/// Written as a pointer to a new segment holding the words.
+pub struct ChunkedBytes<const WORD: usize, E> {
+    pub bytes: Vec<u8>,
+    _endian: PhantomData<E>,
+}
+
+pub type KeccakWords = ChunkedBytes<8, LittleEndian>;
+pub type Sha256Words = ChunkedBytes<4, BigEndian>;
+pub type Blake2sWords = ChunkedBytes<4, LittleEndian>;
+
+impl<const WORD: usize, E: Endian> ChunkedBytes<WORD, E> {
+    const VALID: () = assert!(WORD > 0 && WORD <= 8, "ChunkedBytes words are 1 to 8 bytes");
+
+    pub fn new(bytes: Vec<u8>) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+        Self {
+            bytes,
+            _endian: PhantomData,
+        }
+    }
+
+    pub fn n_bytes(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn to_chunks(&self) -> Vec<Felt252> {
+        chunk_bytes::<WORD, E>(&self.bytes)
+    }
+
+    /// Reads the `n_bytes` bytes back from the words written by `to_chunks`.
+    pub fn from_chunks(chunks: &[Felt252], n_bytes: usize) -> Result<Self, String> {
+        if n_bytes.div_ceil(WORD) != chunks.len() {
+            return Err(format!(
+                "{n_bytes} bytes take {} words of {WORD} bytes, got {}",
+                n_bytes.div_ceil(WORD),
+                chunks.len()
+            ));
+        }
+        let mut bytes = Vec::with_capacity(n_bytes);
+        for (i, chunk) in chunks.iter().enumerate() {
+            let n = WORD.min(n_bytes - i * WORD);
+            let word = chunk
+                .to_u64()
+                .filter(|word| n == 8 || *word >> (8 * n) == 0)
+                .ok_or_else(|| format!("word {chunk} does not fit in {n} bytes"))?;
+            bytes.extend(E::bytes(word, n));
+        }
+        Ok(Self::new(bytes))
+    }
+
+    /// The same bytes for another word layout.
+    pub fn rechunk<const OTHER: usize, F: Endian>(self) -> ChunkedBytes<OTHER, F> {
+        ChunkedBytes::new(self.bytes)
+    }
+}
+
+pub(crate) fn chunk_bytes<const WORD: usize, E: Endian>(bytes: &[u8]) -> Vec<Felt252> {
+    bytes
+        .chunks(WORD)
+        .map(|chunk| Felt252::from(E::word(chunk)))
+        .collect()
+}
+
+// Manual impls, the endianness marker needing none of these traits
+impl<const WORD: usize, E> Clone for ChunkedBytes<WORD, E> {
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+            _endian: PhantomData,
+        }
+    }
+}
+
+impl<const WORD: usize, E> PartialEq for ChunkedBytes<WORD, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl<const WORD: usize, E> Eq for ChunkedBytes<WORD, E> {}
+
+impl<const WORD: usize, E: Endian> core::fmt::Debug for ChunkedBytes<WORD, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ChunkedBytes")
+            .field("word", &WORD)
+            .field("endian", &E::NAME)
+            .field("bytes", &self.bytes)
+            .finish()
+    }
+}
+
+impl<const WORD: usize, E: Endian> From<Vec<u8>> for ChunkedBytes<WORD, E> {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl<const WORD: usize, E: Endian> CairoWritable for ChunkedBytes<WORD, E> {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let chunks = vm.add_memory_segment();
+        write_felts(vm, chunks, self.to_chunks())?;
+        vm.insert_value(address, chunks)?;
+        Ok((address + 1)?)
+    }
+
+    fn n_fields() -> usize {
+        1
+    }
+
+    fn layout() -> Layout {
+        Layout::new(format!("ChunkedBytes<{WORD}, {}>", E::NAME)).pointer("chunks")
+    }
+}
+
+// Hex, or base64 after the `base64:` prefix
+impl<const WORD: usize, E: Endian> FromAnyStr for ChunkedBytes<WORD, E> {
+    fn from_any_str(s: &str) -> Result<Self, String> {
+        check_whitespace(s)?;
+        Ok(Self::new(parse_bytes(s)?))
+    }
+
+    // Empty input is no bytes whatever the options, only the whitespace handling applies
+    fn from_any_str_with_options(s: &str, options: &ParseOptions) -> Result<Self, String> {
+        match options.trim_whitespace {
+            true => Self::from_any_str(s.trim()),
+            false => Self::from_any_str(s),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const WORD: usize, E: Endian> serde::Deserialize<'de> for ChunkedBytes<WORD, E> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::types::serde_utils::deserialize_bytes(deserializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const WORD: usize, E> serde::Serialize for ChunkedBytes<WORD, E> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::types::serde_utils::serialize_hex(&self.bytes, serializer)
+    }
+}
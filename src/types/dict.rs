@@ -0,0 +1,196 @@
+use crate::cairo_type::{CairoType, CairoWritable, Layout};
+use crate::types::{
+    bounded_uint::{Uint128, Uint16, Uint32, Uint64, Uint8},
+    felt::Felt,
+    int::{Int128, Int64},
+    short_string::ShortString,
+};
+#[cfg(feature = "serde")]
+use crate::{stdlib::prelude::*, types::FromAnyStr};
+use cairo_vm::stdlib::collections::{BTreeMap, HashMap};
+use cairo_vm::{
+    types::{
+        exec_scope::ExecutionScopes,
+        relocatable::{MaybeRelocatable, Relocatable},
+    },
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+// Exec scope variable read by the `dict_new` hint
+pub const INITIAL_DICT: &str = "initial_dict";
+
+/// Types usable as dict keys, i.e. encoded as a single felt.
+pub trait DictKey {
+    fn to_key(&self) -> Felt252;
+}
+
+impl DictKey for Felt252 {
+    fn to_key(&self) -> Felt252 {
+        *self
+    }
+}
+
+impl DictKey for Felt {
+    fn to_key(&self) -> Felt252 {
+        self.0
+    }
+}
+
+impl DictKey for ShortString {
+    fn to_key(&self) -> Felt252 {
+        self.0
+    }
+}
+
+macro_rules! dict_key_via_to_felt {
+    ($($name:ty),*) => {
+        $(impl DictKey for $name {
+            fn to_key(&self) -> Felt252 {
+                self.to_felt()
+            }
+        })*
+    };
+}
+
+dict_key_via_to_felt!(Uint8, Uint16, Uint32, Uint64, Uint128, Int64, Int128);
+
+/// A felt-keyed map written as a squashed dict: `(key, prev_value, new_value)` accesses sorted
+/// by key, each key appearing once with `prev_value == new_value`. Values spanning more than
+/// one felt are written to their own segment and referenced by pointer, as Cairo dicts of
+/// structs do. Laid out as `(dict_start, dict_end)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CairoDict<K, V> {
+    entries: BTreeMap<Felt252, (K, V)>,
+}
+
+impl<K, V> Default for CairoDict<K, V> {
+    fn default() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+impl<K: DictKey, V: CairoType> CairoDict<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.entries
+            .insert(key.to_key(), (key, value))
+            .map(|(_, value)| value)
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.entries.get(&key.to_key()).map(|(_, value)| value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // Entries in key order
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.values().map(|(key, value)| (key, value))
+    }
+
+    // Writes `value` at `address` and returns the cell stored in the dict for it
+    fn write_value(
+        value: &V,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<MaybeRelocatable, HintError> {
+        if V::n_fields() == 1 {
+            value.to_memory(vm, address)?;
+            return vm.get_maybe(&address).ok_or(HintError::WrongHintData);
+        }
+        let segment = vm.add_memory_segment();
+        value.to_memory(vm, segment)?;
+        vm.insert_value(address, segment)?;
+        Ok(segment.into())
+    }
+
+    /// The `initial_dict` the `dict_new` hint expects. Values are written to a new segment first,
+    /// those spanning several felts being referenced by pointer.
+    pub fn to_initial_dict(
+        &self,
+        vm: &mut VirtualMachine,
+    ) -> Result<HashMap<MaybeRelocatable, MaybeRelocatable>, HintError> {
+        let values = vm.add_memory_segment();
+        let mut initial_dict = HashMap::with_capacity(self.entries.len());
+        for (i, (key, (_, value))) in self.entries.iter().enumerate() {
+            let cell = Self::write_value(value, vm, (values + i)?)?;
+            initial_dict.insert(key.into(), cell);
+        }
+        Ok(initial_dict)
+    }
+
+    pub fn insert_initial_dict(
+        &self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+    ) -> Result<(), HintError> {
+        let initial_dict = self.to_initial_dict(vm)?;
+        exec_scopes.insert_value(INITIAL_DICT, initial_dict);
+        Ok(())
+    }
+}
+
+impl<K: DictKey, V: CairoType> CairoWritable for CairoDict<K, V> {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let dict_start = vm.add_memory_segment();
+        let mut ptr = dict_start;
+        for (key, (_, value)) in &self.entries {
+            vm.insert_value(ptr, *key)?;
+            let cell = Self::write_value(value, vm, (ptr + 1)?)?;
+            vm.insert_value((ptr + 2)?, cell)?;
+            ptr = (ptr + 3)?;
+        }
+        vm.insert_value((address + 0)?, dict_start)?;
+        vm.insert_value((address + 1)?, ptr)?;
+        Ok((address + 2)?)
+    }
+
+    fn n_fields() -> usize {
+        2
+    }
+
+    fn layout() -> Layout {
+        Layout::new("CairoDict")
+            .pointer("dict_start")
+            .pointer("dict_end")
+    }
+}
+
+// JSON objects keyed by anything `K` parses from, e.g. {"0x1": "5"}
+#[cfg(feature = "serde")]
+impl<'de, K, V> serde::Deserialize<'de> for CairoDict<K, V>
+where
+    K: DictKey + FromAnyStr,
+    V: CairoType + serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = BTreeMap::<String, V>::deserialize(deserializer)?;
+        let mut dict = Self::new();
+        for (key, value) in raw {
+            let key = K::from_any_str(&key).map_err(serde::de::Error::custom)?;
+            if dict.insert(key, value).is_some() {
+                return Err(serde::de::Error::custom("duplicate dict key"));
+            }
+        }
+        Ok(dict)
+    }
+}
@@ -0,0 +1,197 @@
+use crate::cairo_type::{CairoType, CairoWritable, Layout};
+use crate::stdlib::prelude::*;
+use crate::types::uint384::UInt384;
+use crate::types::{check_numeric_input, hex_bytes_padded, FromAnyStr};
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+use core::marker::PhantomData;
+use num_bigint::BigUint;
+
+// Short Weierstrass curve parameters (y^2 = x^3 + ax + b mod p), as hex strings
+pub trait Curve {
+    const NAME: &'static str;
+    const P: &'static str;
+    const A: &'static str;
+    const B: &'static str;
+
+    fn p() -> BigUint {
+        BigUint::parse_bytes(Self::P.as_bytes(), 16).unwrap()
+    }
+
+    fn a() -> BigUint {
+        BigUint::parse_bytes(Self::A.as_bytes(), 16).unwrap()
+    }
+
+    fn b() -> BigUint {
+        BigUint::parse_bytes(Self::B.as_bytes(), 16).unwrap()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Secp256k1;
+
+impl Curve for Secp256k1 {
+    const NAME: &'static str = "secp256k1";
+    const P: &'static str = "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+    const A: &'static str = "0";
+    const B: &'static str = "7";
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Secp256r1;
+
+impl Curve for Secp256r1 {
+    const NAME: &'static str = "secp256r1";
+    const P: &'static str = "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
+    const A: &'static str = "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc";
+    const B: &'static str = "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b";
+}
+
+// Affine point with UInt384 coordinates, laid out in memory as EcPoint { x: UInt384, y: UInt384 }
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EcPoint<C: Curve> {
+    pub x: UInt384,
+    pub y: UInt384,
+    _curve: PhantomData<C>,
+}
+
+pub type Secp256k1Point = EcPoint<Secp256k1>;
+pub type Secp256r1Point = EcPoint<Secp256r1>;
+
+impl<C: Curve> EcPoint<C> {
+    pub fn new(x: UInt384, y: UInt384) -> Self {
+        Self {
+            x,
+            y,
+            _curve: PhantomData,
+        }
+    }
+
+    pub fn is_on_curve(&self) -> bool {
+        let p = C::p();
+        let (x, y) = (self.x.to_biguint(), self.y.to_biguint());
+        if x >= p || y >= p {
+            return false;
+        }
+        let lhs = (&y * &y) % &p;
+        lhs == Self::curve_rhs(&x, &p)
+    }
+
+    /// Parses a SEC1 encoded point: compressed (33 bytes), uncompressed (65 bytes)
+    /// or raw `x || y` (64 bytes). The point is rejected if it is not on the curve.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let point = match (bytes.len(), bytes.first()) {
+            (33, Some(prefix @ (0x02 | 0x03))) => {
+                let x = BigUint::from_bytes_be(&bytes[1..]);
+                let y = Self::decompress_y(&x, *prefix == 0x03)?;
+                Self::new(UInt384::new(x), UInt384::new(y))
+            }
+            (65, Some(0x04)) => Self::new(
+                UInt384::new(BigUint::from_bytes_be(&bytes[1..33])),
+                UInt384::new(BigUint::from_bytes_be(&bytes[33..])),
+            ),
+            (64, _) => Self::new(
+                UInt384::new(BigUint::from_bytes_be(&bytes[..32])),
+                UInt384::new(BigUint::from_bytes_be(&bytes[32..])),
+            ),
+            _ => {
+                return Err(format!(
+                    "invalid {} point encoding of {} bytes",
+                    C::NAME,
+                    bytes.len()
+                ))
+            }
+        };
+        if !point.is_on_curve() {
+            return Err(format!("point is not on the {} curve", C::NAME));
+        }
+        Ok(point)
+    }
+
+    pub fn to_uncompressed_bytes(&self) -> [u8; 65] {
+        let mut bytes = [0u8; 65];
+        bytes[0] = 0x04;
+        let x = self.x.to_biguint().to_bytes_be();
+        let y = self.y.to_biguint().to_bytes_be();
+        bytes[33 - x.len()..33].copy_from_slice(&x);
+        bytes[65 - y.len()..].copy_from_slice(&y);
+        bytes
+    }
+
+    fn curve_rhs(x: &BigUint, p: &BigUint) -> BigUint {
+        (x * x * x + C::a() * x + C::b()) % p
+    }
+
+    // Both supported curves have p = 3 mod 4, so sqrt(a) = a^((p + 1) / 4)
+    fn decompress_y(x: &BigUint, odd: bool) -> Result<BigUint, String> {
+        let p = C::p();
+        if x >= &p {
+            return Err(format!("x coordinate exceeds the {} modulus", C::NAME));
+        }
+        let rhs = Self::curve_rhs(x, &p);
+        let y = rhs.modpow(&((&p + 1u32) >> 2), &p);
+        if (&y * &y) % &p != rhs {
+            return Err(format!("x coordinate is not on the {} curve", C::NAME));
+        }
+        Ok(if y.bit(0) == odd { y } else { &p - y })
+    }
+}
+
+impl<C: Curve> CairoType for EcPoint<C> {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        let x = UInt384::from_memory(vm, address)?;
+        let y = UInt384::from_memory(vm, (address + UInt384::n_fields())?)?;
+        Ok(Self::new(x, y))
+    }
+}
+
+impl<C: Curve> CairoWritable for EcPoint<C> {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let address = self.x.to_memory(vm, address)?;
+        self.y.to_memory(vm, address)
+    }
+
+    fn n_fields() -> usize {
+        2 * UInt384::n_fields()
+    }
+
+    fn layout() -> Layout {
+        Layout::new(format!("EcPoint<{}>", C::NAME))
+            .member::<UInt384>("x")
+            .member::<UInt384>("y")
+    }
+}
+
+impl<C: Curve> FromAnyStr for EcPoint<C> {
+    fn from_any_str(s: &str) -> Result<Self, String> {
+        check_numeric_input(s)?;
+        let bytes = hex_bytes_padded(s, None)?;
+        Self::from_sec1_bytes(&bytes)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C: Curve> serde::Deserialize<'de> for EcPoint<C> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::types::serde_utils::deserialize_hex(deserializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<C: Curve> serde::Serialize for EcPoint<C> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::types::serde_utils::serialize_hex(&self.to_uncompressed_bytes(), serializer)
+    }
+}
@@ -1,14 +1,150 @@
-use crate::cairo_type::{BaseCairoType, CairoType};
-use crate::types::{hex_bytes_padded, FromAnyStr};
+use crate::cairo_type::{BaseCairoType, CairoType, CairoWritable, Layout};
+use crate::stdlib::prelude::*;
+use crate::types::{
+    check_numeric_input, hex_bytes_padded, parse_biguint_radix, FromAnyStr, FromAnyStrWithPolicy,
+    FromStrRadix, OverflowPolicy,
+};
 use cairo_vm::{
     types::relocatable::Relocatable,
     vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
     Felt252,
 };
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Felt(pub Felt252);
 
+// `+`, `-`, `*` and `<<` follow Cairo's field arithmetic, wrapping around the prime, while `%`
+// and `>>` act on the integer value. The `checked_` variants fail instead of wrapping.
+impl Felt {
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        let sum = self.0 + rhs.0;
+        (sum >= self.0).then_some(Felt(sum))
+    }
+
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        (rhs.0 <= self.0).then(|| Felt(self.0 - rhs.0))
+    }
+
+    pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        if let (Some(a), Some(b)) = (self.0.to_u64(), rhs.0.to_u64()) {
+            return Some(Felt(Felt252::from(a as u128 * b as u128)));
+        }
+        let product = self.0.to_biguint() * rhs.0.to_biguint();
+        (product <= Felt252::MAX.to_biguint()).then(|| Felt(Felt252::from(product)))
+    }
+
+    pub fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+        (rhs.0 != Felt252::ZERO)
+            .then(|| Felt(Felt252::from(self.0.to_biguint() % rhs.0.to_biguint())))
+    }
+
+    pub fn checked_shl(&self, shift: u32) -> Option<Self> {
+        let shifted = self.0.to_biguint() << shift;
+        (shifted <= Felt252::MAX.to_biguint()).then(|| Felt(Felt252::from(shifted)))
+    }
+
+    pub fn wrapping_add(&self, rhs: &Self) -> Self {
+        Felt(self.0 + rhs.0)
+    }
+
+    pub fn wrapping_sub(&self, rhs: &Self) -> Self {
+        Felt(self.0 - rhs.0)
+    }
+
+    pub fn wrapping_mul(&self, rhs: &Self) -> Self {
+        Felt(self.0 * rhs.0)
+    }
+
+    pub fn wrapping_shl(&self, shift: u32) -> Self {
+        Felt(Felt252::from(self.0.to_biguint() << shift))
+    }
+
+    // Above P / 2, where the negation is the smaller integer
+    fn is_negative(&self) -> bool {
+        -self.0 < self.0
+    }
+
+    /// Decimal value with felts above `P / 2` shown as the negative `-(P - x)`, as Cairo's
+    /// signed arithmetic reads them, e.g. `-1` instead of `P - 1`.
+    pub fn to_signed_string(&self) -> String {
+        match self.is_negative() {
+            true => format!("-{}", -self.0),
+            false => self.0.to_string(),
+        }
+    }
+
+    /// Inverse of `to_signed_string`: a magnitude parsed like `from_any_str` with an optional
+    /// leading `-`, negative values becoming `P - |x|`. Magnitudes above `P / 2` are rejected,
+    /// as their sign wouldn't survive a round trip.
+    pub fn from_signed_str(s: &str) -> Result<Self, String> {
+        let (negative, magnitude) = match s.strip_prefix('-') {
+            Some(magnitude) => (true, magnitude),
+            None => (false, s),
+        };
+        let magnitude = Felt::from_any_str(magnitude)?;
+        if magnitude.is_negative() {
+            return Err(format!(
+                "{s} is outside of the signed felt range, its magnitude must be at most (P - 1) / 2"
+            ));
+        }
+        match negative {
+            true => Ok(Felt(-magnitude.0)),
+            false => Ok(magnitude),
+        }
+    }
+}
+
+impl core::ops::Add for Felt {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.wrapping_add(&rhs)
+    }
+}
+
+impl core::ops::Sub for Felt {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.wrapping_sub(&rhs)
+    }
+}
+
+impl core::ops::Mul for Felt {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.wrapping_mul(&rhs)
+    }
+}
+
+impl core::ops::Rem for Felt {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        self.checked_rem(&rhs)
+            .expect("attempt to calculate the remainder with a divisor of zero")
+    }
+}
+
+impl core::ops::Shl<u32> for Felt {
+    type Output = Self;
+
+    fn shl(self, shift: u32) -> Self {
+        self.wrapping_shl(shift)
+    }
+}
+
+impl core::ops::Shr<u32> for Felt {
+    type Output = Self;
+
+    fn shr(self, shift: u32) -> Self {
+        Felt(Felt252::from(self.0.to_biguint() >> shift))
+    }
+}
+
 impl BaseCairoType for Felt {
     fn from_bytes_be(bytes: &[u8]) -> Self {
         if bytes.len() > 32 {
@@ -28,6 +164,20 @@ impl CairoType for Felt {
         Ok(Self(*value))
     }
 
+    fn from_memory_range(
+        vm: &VirtualMachine,
+        address: Relocatable,
+        count: usize,
+    ) -> Result<Vec<Self>, HintError> {
+        Ok(vm
+            .get_integer_range(address, count)?
+            .into_iter()
+            .map(|value| Self(*value))
+            .collect())
+    }
+}
+
+impl CairoWritable for Felt {
     fn to_memory(
         &self,
         vm: &mut VirtualMachine,
@@ -40,21 +190,62 @@ impl CairoType for Felt {
     fn n_fields() -> usize {
         1
     }
+
+    fn layout() -> Layout {
+        Layout::new("Felt").felt("value")
+    }
 }
 
+// Inputs at or above the field prime are rejected rather than silently reduced, deserialize
+// with `serde_utils::deserialize_wrapping` to reduce them instead
 impl FromAnyStr for Felt {
     fn from_any_str(s: &str) -> Result<Self, String> {
+        Self::from_any_str_with_policy(s, OverflowPolicy::Error)
+    }
+}
+
+// `Wrap` reduces modulo the prime and `Saturate` clamps to P - 1
+impl FromAnyStrWithPolicy for Felt {
+    fn from_any_str_with_policy(s: &str, policy: OverflowPolicy) -> Result<Self, String> {
+        check_numeric_input(s)?;
         if !s.starts_with("0x") && !s.starts_with("0X") {
-            if let Ok(value) = Felt252::from_dec_str(s) {
-                return Ok(Felt(value));
+            if let Some(value) = BigUint::parse_bytes(s.as_bytes(), 10) {
+                return felt_from_biguint(s, value, policy);
             }
         }
         // If it has a prefix or decimal parsing fails, treat as hex.
         let bytes = hex_bytes_padded(s, None)?;
-        Ok(Felt(Felt252::from_bytes_be_slice(&bytes)))
+        felt_from_biguint(s, BigUint::from_bytes_be(&bytes), policy)
+    }
+}
+
+impl FromStrRadix for Felt {
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, String> {
+        felt_from_biguint(s, parse_biguint_radix(s, radix)?, OverflowPolicy::Error)
     }
 }
 
+fn felt_from_biguint(s: &str, value: BigUint, policy: OverflowPolicy) -> Result<Felt, String> {
+    // Below 2^251 and so below the prime, without building the prime
+    if value.bits() <= 251 {
+        return Ok(Felt(Felt252::from(value)));
+    }
+    let prime = Felt252::MAX.to_biguint() + BigUint::from(1u32);
+    if value >= prime {
+        match policy {
+            OverflowPolicy::Error => {
+                return Err(format!(
+                    "{s} does not fit in a felt, it must be below the prime 0x{prime:x}"
+                ))
+            }
+            OverflowPolicy::Saturate => return Ok(Felt(Felt252::MAX)),
+            OverflowPolicy::Wrap => {}
+        }
+    }
+    Ok(Felt(Felt252::from_bytes_be_slice(&value.to_bytes_be())))
+}
+
+#[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for Felt {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -64,13 +255,19 @@ impl<'de> serde::Deserialize<'de> for Felt {
     }
 }
 
+#[cfg(feature = "serde")]
 impl serde::Serialize for Felt {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let bytes = self.0.to_bytes_be();
-        let hex = hex::encode(bytes);
-        serializer.serialize_str(&format!("0x{hex}"))
+        crate::types::serde_utils::serialize_hex(&self.0.to_bytes_be(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl crate::types::serde_utils::ToMinimalHex for Felt {
+    fn to_minimal_hex(&self) -> String {
+        self.0.to_hex_string()
     }
 }
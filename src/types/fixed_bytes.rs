@@ -0,0 +1,267 @@
+use crate::cairo_type::{
+    read_felt_chunks, write_felts, BaseCairoType, CairoType, CairoWritable, Layout,
+};
+use crate::stdlib::prelude::*;
+use crate::types::{check_numeric_input, felt::Felt, parse_bytes, uint256::Uint256, FromAnyStr};
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+// Bytes that always fit below the field prime
+const MAX_FELT_BYTES: usize = 31;
+// Larger arrays are split into 128-bit limbs, as `Uint256` is
+const LIMB_BYTES: usize = 16;
+
+/// Exactly `N` bytes, e.g. a hash or an address, kept apart from integers so that a value of
+/// the wrong width or byte order doesn't slip through.
+///
+/// Up to 31 bytes are packed in a single felt holding their big-endian value. Larger arrays take
+/// one felt per 128-bit limb, least significant first, so a `Bytes32` has the `Uint256` layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FixedBytes<const N: usize>(pub [u8; N]);
+
+pub type Bytes32 = FixedBytes<32>;
+pub type Bytes20 = FixedBytes<20>;
+pub type Bytes8 = FixedBytes<8>;
+
+impl<const N: usize> FixedBytes<N> {
+    pub const N_FELTS: usize = if N <= MAX_FELT_BYTES {
+        1
+    } else {
+        N.div_ceil(LIMB_BYTES)
+    };
+    // Bytes per felt, the last felt holding the leading bytes
+    const CHUNK: usize = if N <= MAX_FELT_BYTES { N } else { LIMB_BYTES };
+    const VALID: () = assert!(N > 0, "FixedBytes needs at least one byte");
+
+    pub fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    pub fn to_felts(&self) -> Vec<Felt252> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+        self.0
+            .rchunks(Self::CHUNK)
+            .map(Felt252::from_bytes_be_slice)
+            .collect()
+    }
+
+    /// Reads the felts written by `to_felts`, failing on a felt wider than its bytes.
+    pub fn from_felts(felts: &[Felt252]) -> Result<Self, String> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+        if felts.len() != Self::N_FELTS {
+            return Err(format!(
+                "expected {} felts for {N} bytes, got {}",
+                Self::N_FELTS,
+                felts.len()
+            ));
+        }
+        let mut bytes = [0u8; N];
+        for (chunk, felt) in bytes.rchunks_mut(Self::CHUNK).zip(felts) {
+            let be = felt.to_bytes_be();
+            let (high, low) = be.split_at(32 - chunk.len());
+            if high.iter().any(|byte| *byte != 0) {
+                return Err(format!("{felt:#x} does not fit in {} bytes", chunk.len()));
+            }
+            chunk.copy_from_slice(low);
+        }
+        Ok(Self(bytes))
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for FixedBytes<N> {
+    fn from(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl<const N: usize> TryFrom<&[u8]> for FixedBytes<N> {
+    type Error = String;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        bytes
+            .try_into()
+            .map(Self)
+            .map_err(|_| format!("expected {N} bytes, got {}", bytes.len()))
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for FixedBytes<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+// Shorter inputs are left padded with zeros, as for the integer types
+impl<const N: usize> BaseCairoType for FixedBytes<N> {
+    fn from_bytes_be(bytes: &[u8]) -> Self {
+        if bytes.len() > N {
+            panic!(
+                "Invalid bytes length for {N} bytes. Expected at most {N} bytes, got {}",
+                bytes.len()
+            );
+        }
+        let mut padded = [0u8; N];
+        padded[N - bytes.len()..].copy_from_slice(bytes);
+        Self(padded)
+    }
+
+    fn bytes_len() -> usize {
+        N
+    }
+}
+
+impl<const N: usize> CairoType for FixedBytes<N> {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        let felts = (0..Self::N_FELTS)
+            .map(|i| Ok(*vm.get_integer((address + i)?)?))
+            .collect::<Result<Vec<_>, HintError>>()?;
+        Self::from_felts(&felts).map_err(|e| HintError::CustomHint(e.into_boxed_str()))
+    }
+
+    fn from_memory_range(
+        vm: &VirtualMachine,
+        address: Relocatable,
+        count: usize,
+    ) -> Result<Vec<Self>, HintError> {
+        read_felt_chunks(vm, address, count, Self::N_FELTS, Self::from_felts)
+    }
+}
+
+impl<const N: usize> CairoWritable for FixedBytes<N> {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        write_felts(vm, address, self.to_felts())
+    }
+
+    fn n_fields() -> usize {
+        Self::N_FELTS
+    }
+
+    fn layout() -> Layout {
+        let name = format!("FixedBytes<{N}>");
+        match Self::N_FELTS {
+            1 => Layout::new(name).felt("value"),
+            2 => Layout::new(name).felt("low").felt("high"),
+            n => (0..n).fold(Layout::new(name), |layout, i| layout.felt(format!("d{i}"))),
+        }
+    }
+}
+
+// Hex or base64 of exactly `N` bytes, unlike the integer types nothing is padded, a 32-byte hash
+// given for an address is an error
+impl<const N: usize> FromAnyStr for FixedBytes<N> {
+    fn from_any_str(s: &str) -> Result<Self, String> {
+        check_numeric_input(s)?;
+        let bytes = parse_bytes(s)?;
+        Self::try_from(bytes.as_slice()).map_err(|e| format!("{e} in {s}"))
+    }
+}
+
+impl From<Bytes32> for Uint256 {
+    fn from(bytes: Bytes32) -> Self {
+        Uint256::from_bytes_be(&bytes.0)
+    }
+}
+
+impl From<Uint256> for Bytes32 {
+    fn from(value: Uint256) -> Self {
+        let mut bytes = [0u8; 32];
+        for (chunk, word) in bytes.rchunks_exact_mut(8).zip(value.words()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        Self(bytes)
+    }
+}
+
+// Fails if the value is not below the field prime instead of reducing it
+impl TryFrom<Bytes32> for Felt {
+    type Error = String;
+
+    fn try_from(bytes: Bytes32) -> Result<Self, Self::Error> {
+        // Big-endian bytes compare as the values do
+        if bytes.0 > Felt252::MAX.to_bytes_be() {
+            return Err(format!("0x{} does not fit in a felt", hex::encode(bytes.0)));
+        }
+        Ok(Felt(Felt252::from_bytes_be(&bytes.0)))
+    }
+}
+
+impl From<Felt> for Bytes32 {
+    fn from(felt: Felt) -> Self {
+        Self(felt.0.to_bytes_be())
+    }
+}
+
+macro_rules! narrow_conversions {
+    ($($name:ident),*) => {
+        $(
+            impl From<$name> for Uint256 {
+                fn from(bytes: $name) -> Self {
+                    Uint256::from_bytes_be(&bytes.0)
+                }
+            }
+
+            // Fails if the value doesn't fit instead of dropping the high bytes
+            impl TryFrom<Uint256> for $name {
+                type Error = String;
+
+                fn try_from(value: Uint256) -> Result<Self, Self::Error> {
+                    let bytes = Bytes32::from(value).0;
+                    let (high, low) = bytes.split_at(32 - $name::bytes_len());
+                    if high.iter().any(|byte| *byte != 0) {
+                        return Err(format!(
+                            "0x{} does not fit in {} bytes",
+                            hex::encode(bytes),
+                            $name::bytes_len()
+                        ));
+                    }
+                    $name::try_from(low)
+                }
+            }
+
+            impl From<$name> for Felt {
+                fn from(bytes: $name) -> Self {
+                    Felt(Felt252::from_bytes_be_slice(&bytes.0))
+                }
+            }
+
+            impl TryFrom<Felt> for $name {
+                type Error = String;
+
+                fn try_from(felt: Felt) -> Result<Self, Self::Error> {
+                    $name::from_felts(&[felt.0])
+                }
+            }
+        )*
+    };
+}
+
+narrow_conversions!(Bytes20, Bytes8);
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for FixedBytes<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::types::serde_utils::deserialize_hex(deserializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for FixedBytes<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::types::serde_utils::serialize_hex(&self.0, serializer)
+    }
+}
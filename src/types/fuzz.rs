@@ -0,0 +1,402 @@
+//! Generators for fuzzing and property tests: `arbitrary::Arbitrary` impls with the `arbitrary`
+//! feature and `proptest` strategies in `fuzz::strategy` with the `proptest` feature.
+//!
+//! Integers are drawn over their full bit range, with boundary values (0, 1, 2^128, the
+//! maximum, P - 1 for felts) picked a quarter of the time. Curve points are always on their
+//! curve, and field elements reduced.
+
+use crate::stdlib::prelude::*;
+use crate::types::{
+    bls12_381::{self, Fq, Fq2, G1Point, G2Point},
+    ec_point::{Curve, EcPoint},
+    felt::Felt,
+    short_string::{ShortString, MAX_SHORT_STRING_LEN},
+    uint_bits::{LimbLayout, UintBits},
+    OverflowPolicy,
+};
+use cairo_vm::Felt252;
+use num_bigint::BigUint;
+
+const COMPRESSED_FLAG: u8 = 0x80;
+const SIGN_FLAG: u8 = 0x20;
+
+fn felt_boundaries() -> Vec<Felt> {
+    [
+        Felt252::ZERO,
+        Felt252::ONE,
+        Felt252::TWO.pow(128u32),
+        Felt252::TWO.pow(251u32),
+        Felt252::MAX,
+    ]
+    .into_iter()
+    .map(Felt)
+    .collect()
+}
+
+// Reduced modulo P
+fn felt_from_seed(seed: [u8; 32]) -> Felt {
+    Felt(Felt252::from_bytes_be(&seed))
+}
+
+fn uint_boundaries<const BITS: usize, const LIMB: usize, L: LimbLayout>(
+) -> Vec<UintBits<BITS, LIMB, L>> {
+    let one = BigUint::from(1u32);
+    let mut values = vec![BigUint::ZERO, one.clone(), &one << (BITS - 1)];
+    if BITS > 128 {
+        values.push(&one << 128);
+    }
+    values.push((&one << BITS) - 1u32);
+    values.into_iter().map(UintBits::new).collect()
+}
+
+// Keeps the low `BITS` bits of the seed
+fn uint_from_seed<const BITS: usize, const LIMB: usize, L: LimbLayout>(
+    seed: &[u8],
+) -> UintBits<BITS, LIMB, L> {
+    let value = OverflowPolicy::Wrap.apply(BigUint::from_bytes_be(seed), BITS);
+    UintBits::new(value.expect("wrapping never fails"))
+}
+
+fn fq_from_seed(seed: &[u8]) -> Fq {
+    Fq::new(BigUint::from_bytes_be(seed) % bls12_381::modulus()).expect("reduced modulo p")
+}
+
+fn fq_increment(value: &Fq) -> Fq {
+    Fq::new((value.0.to_biguint() + 1u32) % bls12_381::modulus()).expect("reduced modulo p")
+}
+
+// Walks x upwards from the seed until it is the x coordinate of a point, about every other value
+fn g1_from_seed(mut x: Fq, largest_y: bool) -> G1Point {
+    loop {
+        let mut bytes = x.to_bytes_be();
+        bytes[0] |= COMPRESSED_FLAG | if largest_y { SIGN_FLAG } else { 0 };
+        if let Ok(point) = G1Point::from_bytes(&bytes) {
+            return point;
+        }
+        x = fq_increment(&x);
+    }
+}
+
+fn g2_from_seed(mut x: Fq2, largest_y: bool) -> G2Point {
+    loop {
+        let mut bytes = x.to_bytes_be();
+        bytes[0] |= COMPRESSED_FLAG | if largest_y { SIGN_FLAG } else { 0 };
+        if let Ok(point) = G2Point::from_bytes(&bytes) {
+            return point;
+        }
+        x.a0 = fq_increment(&x.a0);
+    }
+}
+
+fn ec_point_from_seed<C: Curve>(seed: &[u8], odd_y: bool) -> EcPoint<C> {
+    let p = C::p();
+    let mut x = BigUint::from_bytes_be(seed) % &p;
+    loop {
+        let mut bytes = [0u8; 33];
+        bytes[0] = if odd_y { 0x03 } else { 0x02 };
+        let x_bytes = x.to_bytes_be();
+        bytes[33 - x_bytes.len()..].copy_from_slice(&x_bytes);
+        if let Ok(point) = EcPoint::from_sec1_bytes(&bytes) {
+            return point;
+        }
+        x = (x + 1u32) % &p;
+    }
+}
+
+// Printable ASCII, at most 31 characters
+fn short_string_from_seed(seed: &[u8]) -> ShortString {
+    let text: Vec<u8> = seed
+        .iter()
+        .take(MAX_SHORT_STRING_LEN)
+        .map(|b| b' ' + b % 95)
+        .collect();
+    ShortString(Felt252::from_bytes_be_slice(&text))
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls {
+    use super::*;
+    use crate::cairo_type::CairoType;
+    use crate::types::{
+        bounded_uint::{Uint128, Uint16, Uint32, Uint64, Uint8},
+        byte_array::ByteArray,
+        bytes::Bytes,
+        dict::{CairoDict, DictKey},
+        fixed_bytes::FixedBytes,
+        int::{Int128, Int64},
+        keccak_bytes::KeccakBytes,
+        option::CairoOption,
+        sha256_bytes::Sha256Bytes,
+        sha512_bytes::Sha512Bytes,
+    };
+    use arbitrary::{Arbitrary, Result, Unstructured};
+
+    impl<'a> Arbitrary<'a> for Felt {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            if u.ratio(1u8, 4u8)? {
+                return Ok(u.choose(&felt_boundaries())?.clone());
+            }
+            Ok(felt_from_seed(u.arbitrary()?))
+        }
+    }
+
+    impl<'a, const BITS: usize, const LIMB: usize, L: LimbLayout> Arbitrary<'a>
+        for UintBits<BITS, LIMB, L>
+    {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            if u.ratio(1u8, 4u8)? {
+                let mut values = uint_boundaries();
+                let index = u.choose_index(values.len())?;
+                return Ok(values.swap_remove(index));
+            }
+            let mut seed = vec![0u8; Self::BYTES];
+            u.fill_buffer(&mut seed)?;
+            Ok(uint_from_seed(&seed))
+        }
+    }
+
+    macro_rules! arbitrary_newtype {
+        ($($name:ty),*) => {
+            $(
+                impl<'a> Arbitrary<'a> for $name {
+                    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                        Ok(Self(u.arbitrary()?))
+                    }
+                }
+            )*
+        };
+    }
+
+    arbitrary_newtype!(
+        Uint8,
+        Uint16,
+        Uint32,
+        Uint64,
+        Uint128,
+        Int64,
+        Int128,
+        KeccakBytes,
+        Bytes,
+        Sha256Bytes,
+        Sha512Bytes,
+        ByteArray
+    );
+
+    impl<'a> Arbitrary<'a> for ShortString {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(short_string_from_seed(u.arbitrary()?))
+        }
+    }
+
+    impl<'a, const N: usize> Arbitrary<'a> for FixedBytes<N> {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self(u.arbitrary()?))
+        }
+    }
+
+    impl<'a, T: Arbitrary<'a>, E> Arbitrary<'a> for CairoOption<T, E> {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::new(u.arbitrary()?))
+        }
+    }
+
+    // Later entries win over earlier ones with the same key
+    impl<'a, K, V> Arbitrary<'a> for CairoDict<K, V>
+    where
+        K: DictKey + Arbitrary<'a>,
+        V: CairoType + Arbitrary<'a>,
+    {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let mut dict = Self::new();
+            for entry in u.arbitrary_iter::<(K, V)>()? {
+                let (key, value) = entry?;
+                dict.insert(key, value);
+            }
+            Ok(dict)
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for Fq {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(fq_from_seed(&u.arbitrary::<[u8; 48]>()?))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for Fq2 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Fq2 {
+                a0: u.arbitrary()?,
+                a1: u.arbitrary()?,
+            })
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for G1Point {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            if u.ratio(1u8, 16u8)? {
+                return Ok(G1Point::infinity());
+            }
+            Ok(g1_from_seed(u.arbitrary()?, u.arbitrary()?))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for G2Point {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            if u.ratio(1u8, 16u8)? {
+                return Ok(G2Point::infinity());
+            }
+            Ok(g2_from_seed(u.arbitrary()?, u.arbitrary()?))
+        }
+    }
+
+    impl<'a, C: Curve> Arbitrary<'a> for EcPoint<C> {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(ec_point_from_seed(
+                &u.arbitrary::<[u8; 32]>()?,
+                u.arbitrary()?,
+            ))
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+pub mod strategy {
+    //! `proptest` strategies for the Cairo types, plus strings their parsers must reject.
+
+    use super::*;
+    use crate::types::{
+        bounded_uint::{Uint128, Uint16, Uint32, Uint64, Uint8},
+        byte_array::ByteArray,
+        bytes::Bytes,
+        int::{Int128, Int64},
+        keccak_bytes::KeccakBytes,
+        option::CairoOption,
+        sha256_bytes::Sha256Bytes,
+        sha512_bytes::Sha512Bytes,
+        uint256::Uint256,
+        uint384::UInt384,
+    };
+    use proptest::prelude::*;
+    use proptest::{collection::vec, option, sample::select};
+
+    pub fn felt() -> impl Strategy<Value = Felt> {
+        prop_oneof![
+            1 => select(felt_boundaries()),
+            3 => any::<[u8; 32]>().prop_map(felt_from_seed),
+        ]
+    }
+
+    pub fn uint_bits<const BITS: usize, const LIMB: usize, L>(
+    ) -> impl Strategy<Value = UintBits<BITS, LIMB, L>>
+    where
+        L: LimbLayout + Clone + core::fmt::Debug + 'static,
+    {
+        prop_oneof![
+            1 => select(uint_boundaries()),
+            3 => vec(any::<u8>(), UintBits::<BITS, LIMB, L>::BYTES)
+                .prop_map(|seed| uint_from_seed(&seed)),
+        ]
+    }
+
+    pub fn uint256() -> impl Strategy<Value = Uint256> {
+        uint_bits()
+    }
+
+    pub fn uint384() -> impl Strategy<Value = UInt384> {
+        uint_bits()
+    }
+
+    macro_rules! newtype_strategy {
+        ($($fn_name:ident: $name:ident($inner:ty)),*) => {
+            $(
+                pub fn $fn_name() -> impl Strategy<Value = $name> {
+                    any::<$inner>().prop_map($name)
+                }
+            )*
+        };
+    }
+
+    newtype_strategy!(
+        uint8: Uint8(u8),
+        uint16: Uint16(u16),
+        uint32: Uint32(u32),
+        uint64: Uint64(u64),
+        uint128: Uint128(u128),
+        int64: Int64(i64),
+        int128: Int128(i128)
+    );
+
+    macro_rules! bytes_strategy {
+        ($($fn_name:ident: $name:ident),*) => {
+            $(
+                pub fn $fn_name(max_len: usize) -> impl Strategy<Value = $name> {
+                    vec(any::<u8>(), 0..=max_len).prop_map($name)
+                }
+            )*
+        };
+    }
+
+    bytes_strategy!(
+        keccak_bytes: KeccakBytes,
+        bytes: Bytes,
+        sha256_bytes: Sha256Bytes,
+        sha512_bytes: Sha512Bytes,
+        byte_array: ByteArray
+    );
+
+    pub fn short_string() -> impl Strategy<Value = ShortString> {
+        vec(any::<u8>(), 0..=MAX_SHORT_STRING_LEN).prop_map(|seed| short_string_from_seed(&seed))
+    }
+
+    pub fn cairo_option<T, E>(
+        value: impl Strategy<Value = T>,
+    ) -> impl Strategy<Value = CairoOption<T, E>>
+    where
+        T: core::fmt::Debug,
+        E: core::fmt::Debug,
+    {
+        option::of(value).prop_map(CairoOption::new)
+    }
+
+    pub fn fq() -> impl Strategy<Value = Fq> {
+        any::<[u8; 48]>().prop_map(|seed| fq_from_seed(&seed))
+    }
+
+    pub fn fq2() -> impl Strategy<Value = Fq2> {
+        (fq(), fq()).prop_map(|(a0, a1)| Fq2 { a0, a1 })
+    }
+
+    pub fn g1_point() -> impl Strategy<Value = G1Point> {
+        prop_oneof![
+            1 => Just(G1Point::infinity()),
+            15 => (fq(), any::<bool>()).prop_map(|(x, largest_y)| g1_from_seed(x, largest_y)),
+        ]
+    }
+
+    pub fn g2_point() -> impl Strategy<Value = G2Point> {
+        prop_oneof![
+            1 => Just(G2Point::infinity()),
+            15 => (fq2(), any::<bool>()).prop_map(|(x, largest_y)| g2_from_seed(x, largest_y)),
+        ]
+    }
+
+    pub fn ec_point<C: Curve + Clone + core::fmt::Debug>() -> impl Strategy<Value = EcPoint<C>> {
+        (any::<[u8; 32]>(), any::<bool>())
+            .prop_map(|(seed, odd_y)| ec_point_from_seed(&seed, odd_y))
+    }
+
+    /// `0x` hex of values in `[2^bits, 2^(bits + 64))`, which an integer type of `bits` bits
+    /// must reject, e.g. `overflowing_hex(256)` for `Uint256` or `overflowing_hex(8)` for
+    /// `Uint8`.
+    pub fn overflowing_hex(bits: usize) -> impl Strategy<Value = String> {
+        any::<u64>().prop_map(move |high| {
+            let value = (BigUint::from(high) | BigUint::from(1u32)) << bits;
+            format!("0x{}", value.to_str_radix(16))
+        })
+    }
+
+    /// `0x` prefixed strings with a non hex character, rejected by every parser.
+    pub fn malformed_hex() -> impl Strategy<Value = String> {
+        "0x[0-9a-f]{0,8}[g-zG-Z][0-9a-f]{0,8}"
+    }
+}
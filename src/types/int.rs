@@ -0,0 +1,137 @@
+use crate::cairo_type::{read_felt_chunks, CairoType, CairoWritable, Layout};
+use crate::stdlib::prelude::*;
+use crate::types::{check_numeric_input, parse_biguint_radix, FromAnyStr, FromStrRadix};
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{Num, ToPrimitive};
+
+// Felts above P / 2 are the negative values P - |x|, as in Cairo's signed arithmetic. Only
+// values within i128 are read, so the magnitude is taken from a u128 instead of a BigInt.
+fn felt_to_i128(value: &Felt252) -> Option<i128> {
+    if let Some(value) = value.to_u128() {
+        return i128::try_from(value).ok();
+    }
+    let magnitude = (-*value).to_u128()?;
+    0i128.checked_sub_unsigned(magnitude)
+}
+
+// Accepts decimal and 0x prefixed hex magnitudes with an optional leading '-'
+fn parse_signed(s: &str) -> Result<BigInt, String> {
+    check_numeric_input(s)?;
+    let (sign, magnitude) = match s.strip_prefix('-') {
+        Some(magnitude) => (Sign::Minus, magnitude),
+        None => (Sign::Plus, s),
+    };
+    let magnitude = match magnitude
+        .strip_prefix("0x")
+        .or_else(|| magnitude.strip_prefix("0X"))
+    {
+        Some(hex) => BigUint::from_str_radix(&hex.replace('_', ""), 16),
+        None => BigUint::from_str_radix(magnitude, 10),
+    }
+    .map_err(|e| format!("invalid signed integer {s}: {e}"))?;
+    Ok(BigInt::from_biguint(sign, magnitude))
+}
+
+macro_rules! signed_int {
+    ($name:ident, $inner:ty, $to_inner:ident) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $name(pub $inner);
+
+        impl $name {
+            pub fn to_felt(&self) -> Felt252 {
+                Felt252::from(self.0)
+            }
+
+            pub fn from_felt(value: &Felt252) -> Result<Self, String> {
+                felt_to_i128(value)
+                    .and_then(|value| value.$to_inner())
+                    .map(Self)
+                    .ok_or_else(|| format!("felt {value} does not fit in {}", stringify!($name)))
+            }
+        }
+
+        impl CairoType for $name {
+            fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+                let value = vm.get_integer(address)?;
+                Self::from_felt(&value).map_err(|e| HintError::CustomHint(e.into_boxed_str()))
+            }
+
+            fn from_memory_range(
+                vm: &VirtualMachine,
+                address: Relocatable,
+                count: usize,
+            ) -> Result<Vec<Self>, HintError> {
+                read_felt_chunks(vm, address, count, 1, |felts| Self::from_felt(&felts[0]))
+            }
+        }
+
+        impl CairoWritable for $name {
+            fn to_memory(
+                &self,
+                vm: &mut VirtualMachine,
+                address: Relocatable,
+            ) -> Result<Relocatable, HintError> {
+                vm.insert_value(address, self.to_felt())?;
+                Ok((address + 1)?)
+            }
+
+            fn n_fields() -> usize {
+                1
+            }
+
+            fn layout() -> Layout {
+                Layout::new(stringify!($name)).felt("value")
+            }
+        }
+
+        impl FromAnyStr for $name {
+            fn from_any_str(s: &str) -> Result<Self, String> {
+                parse_signed(s)?
+                    .$to_inner()
+                    .map(Self)
+                    .ok_or_else(|| format!("{s} does not fit in {}", stringify!($name)))
+            }
+        }
+
+        impl FromStrRadix for $name {
+            fn from_str_radix(s: &str, radix: u32) -> Result<Self, String> {
+                let (sign, magnitude) = match s.strip_prefix('-') {
+                    Some(magnitude) => (Sign::Minus, magnitude),
+                    None => (Sign::Plus, s),
+                };
+                BigInt::from_biguint(sign, parse_biguint_radix(magnitude, radix)?)
+                    .$to_inner()
+                    .map(Self)
+                    .ok_or_else(|| format!("{s} does not fit in {}", stringify!($name)))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                crate::types::serde_utils::deserialize_signed(deserializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_str(&self.0.to_string())
+            }
+        }
+    };
+}
+
+signed_int!(Int64, i64, to_i64);
+signed_int!(Int128, i128, to_i128);
@@ -1,10 +1,13 @@
-use crate::cairo_type::CairoWritable;
-use crate::types::{hex_bytes_padded, FromAnyStr};
+use crate::cairo_type::{write_felts, CairoType, CairoWritable, Layout};
+use crate::stdlib::prelude::*;
+use crate::types::chunked_bytes::{chunk_bytes, ChunkedBytes, Endian, LittleEndian};
+use crate::types::{check_whitespace, parse_bytes, FromAnyStr, ParseOptions};
 use cairo_vm::{
     types::relocatable::Relocatable,
     vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
     Felt252,
 };
+use num_traits::ToPrimitive;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct KeccakBytes(pub Vec<u8>);
@@ -12,17 +15,65 @@ pub struct KeccakBytes(pub Vec<u8>);
 // Le 64 bit chunks of a byte vec for efficient keccak hash computation in cairo
 impl KeccakBytes {
     pub fn to_limbs(&self) -> Vec<Felt252> {
-        let mut result: Vec<Felt252> = Vec::with_capacity(self.0.len().div_ceil(8));
-        for chunk in self.0.chunks(8) {
-            let mut buf = [0u8; 8];
-            // Copy chunk bytes as-is; interpret as little-endian u64
-            for (i, b) in chunk.iter().enumerate() {
-                buf[i] = *b;
-            }
-            let value = u64::from_le_bytes(buf);
-            result.push(Felt252::from(value));
-        }
-        result
+        le_u64_limbs(&self.0)
+    }
+
+    /// Writes the limbs alone to a new segment, for hints taking the length separately.
+    pub fn write_limbs(&self, vm: &mut VirtualMachine) -> Result<Relocatable, HintError> {
+        let limbs = vm.add_memory_segment();
+        write_felts(vm, limbs, self.to_limbs())?;
+        Ok(limbs)
+    }
+}
+
+// Shared with the other types written as keccak input, such as MPT nodes
+pub(crate) fn le_u64_limbs(bytes: &[u8]) -> Vec<Felt252> {
+    chunk_bytes::<8, LittleEndian>(bytes)
+}
+
+// Inverse of `le_u64_limbs`, for bytes read back from memory
+pub(crate) fn bytes_from_le_u64_limbs(
+    limbs: &[Felt252],
+    n_bytes: usize,
+) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::with_capacity(limbs.len() * 8);
+    for limb in limbs {
+        let word = limb
+            .to_u64()
+            .ok_or_else(|| format!("chunk {limb} does not fit in 64 bits"))?;
+        bytes.extend(word.to_le_bytes());
+    }
+    if n_bytes > bytes.len() {
+        return Err(format!(
+            "{n_bytes} bytes expected from {} chunks",
+            limbs.len()
+        ));
+    }
+    if bytes[n_bytes..].iter().any(|byte| *byte != 0) {
+        return Err(format!(
+            "chunks hold nonzero bytes past the first {n_bytes} bytes"
+        ));
+    }
+    bytes.truncate(n_bytes);
+    Ok(bytes)
+}
+
+// `(limbs, n_bytes)`: the limbs alone don't tell trailing zero bytes from padding
+impl CairoType for KeccakBytes {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        let limbs_ptr = vm.get_relocatable(address)?;
+        let n_bytes = vm
+            .get_integer((address + 1)?)?
+            .to_usize()
+            .ok_or_else(|| HintError::CustomHint("KeccakBytes length is not a usize".into()))?;
+        let limbs: Vec<Felt252> = vm
+            .get_integer_range(limbs_ptr, n_bytes.div_ceil(8))?
+            .into_iter()
+            .map(|limb| *limb)
+            .collect();
+        bytes_from_le_u64_limbs(&limbs, n_bytes)
+            .map(KeccakBytes)
+            .map_err(|e| HintError::CustomHint(e.into_boxed_str()))
     }
 }
 
@@ -32,49 +83,66 @@ impl CairoWritable for KeccakBytes {
         vm: &mut VirtualMachine,
         address: Relocatable,
     ) -> Result<Relocatable, HintError> {
-        let limbs_segment = vm.add_memory_segment();
+        let limbs = self.write_limbs(vm)?;
+        vm.insert_value(address, limbs)?;
+        vm.insert_value((address + 1)?, Felt252::from(self.0.len()))?;
+        Ok((address + 2)?)
+    }
 
-        // Write the 8 limbs to the new segment
-        let limbs = self.to_limbs();
-        for (i, limb) in limbs.iter().enumerate() {
-            vm.insert_value((limbs_segment + i)?, *limb)?;
-        }
+    fn n_fields() -> usize {
+        2
+    }
 
-        // Store a pointer to the new segment at the original address
-        vm.insert_value(address, limbs_segment)?;
+    fn layout() -> Layout {
+        Layout::new("KeccakBytes").pointer("limbs").felt("n_bytes")
+    }
+}
 
-        // Return the address after the pointer
-        Ok((address + 1)?)
+// The same bytes in another word layout, e.g. `Sha256Words` to hash them with sha256 too
+impl<const WORD: usize, E: Endian> From<KeccakBytes> for ChunkedBytes<WORD, E> {
+    fn from(bytes: KeccakBytes) -> Self {
+        ChunkedBytes::new(bytes.0)
     }
+}
 
-    fn n_fields() -> usize {
-        1
+impl From<Vec<u8>> for KeccakBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        KeccakBytes(bytes)
     }
 }
 
+// Hex, or base64 after the `base64:` prefix
 impl FromAnyStr for KeccakBytes {
     fn from_any_str(s: &str) -> Result<Self, String> {
-        let hex_decoded = hex_bytes_padded(s, None)?;
-        Ok(KeccakBytes(hex_decoded.clone()))
+        check_whitespace(s)?;
+        Ok(KeccakBytes(parse_bytes(s)?))
+    }
+
+    // Empty input is no bytes whatever the options, only the whitespace handling applies
+    fn from_any_str_with_options(s: &str, options: &ParseOptions) -> Result<Self, String> {
+        match options.trim_whitespace {
+            true => Self::from_any_str(s.trim()),
+            false => Self::from_any_str(s),
+        }
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for KeccakBytes {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?;
-        KeccakBytes::from_any_str(&s).map_err(serde::de::Error::custom)
+        crate::types::serde_utils::deserialize_bytes(deserializer)
     }
 }
 
+#[cfg(feature = "serde")]
 impl serde::Serialize for KeccakBytes {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let hex = hex::encode(self.0.clone());
-        serializer.serialize_str(&format!("0x{hex}"))
+        crate::types::serde_utils::serialize_hex(&self.0, serializer)
     }
 }
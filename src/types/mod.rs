@@ -1,21 +1,208 @@
+#[cfg(feature = "alloy")]
+pub mod alloy;
+#[cfg(feature = "ark")]
+pub mod ark;
+pub mod beacon;
+pub mod bls12_381;
+pub mod bounded_uint;
+pub mod byte_array;
+pub mod bytes;
+pub mod cairo_enum;
+pub mod chunked_bytes;
+pub mod dict;
+pub mod ec_point;
 pub mod felt;
+pub mod fixed_bytes;
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+pub mod fuzz;
+pub mod int;
 pub mod keccak_bytes;
+pub mod option;
+pub mod pointer;
+pub mod proof;
+pub mod rlp;
+#[cfg(feature = "schemars")]
+pub mod schema;
+pub mod sha256_bytes;
+pub mod sha512_bytes;
+pub mod short_string;
+#[cfg(feature = "ssz")]
+pub mod ssz;
+#[cfg(feature = "starknet")]
+pub mod starknet;
 pub mod uint256;
 pub mod uint256_32;
 pub mod uint384;
+pub mod uint_bits;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests;
 
+use crate::stdlib::prelude::*;
+use num_bigint::BigUint;
+
 // Shared string parsing trait and helper
+//
+// Numeric and fixed-size types reject empty input, a bare `0x` and surrounding whitespace.
+// Variable-length byte types read empty input as no bytes, and text types keep it as is.
 pub trait FromAnyStr: Sized {
     fn from_any_str(s: &str) -> Result<Self, String>;
+
+    /// Parses with the empty input and whitespace handling of `options`, e.g. to read `""` as
+    /// zero. Text and byte types, for which empty input is a valid value, override it.
+    fn from_any_str_with_options(s: &str, options: &ParseOptions) -> Result<Self, String> {
+        match options.prepare(s)? {
+            Some(s) => Self::from_any_str(s),
+            None => Self::from_any_str("0"),
+        }
+    }
+}
+
+/// How inputs without digits, `""`, `"0x"` or `"-"`, are read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmptyInput {
+    #[default]
+    Error,
+    Zero,
+}
+
+/// Input handling of `FromAnyStr::from_any_str_with_options`, the default matching
+/// `from_any_str`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParseOptions {
+    pub empty: EmptyInput,
+    // Strip leading and trailing whitespace instead of rejecting it
+    pub trim_whitespace: bool,
+}
+
+impl ParseOptions {
+    pub const LENIENT: Self = Self {
+        empty: EmptyInput::Zero,
+        trim_whitespace: true,
+    };
+
+    // The input left to parse, `None` for an empty input read as zero
+    pub fn prepare<'a>(&self, s: &'a str) -> Result<Option<&'a str>, String> {
+        let s = match self.trim_whitespace {
+            true => s.trim(),
+            false => s,
+        };
+        if self.empty == EmptyInput::Zero && has_no_digits(s) {
+            return Ok(None);
+        }
+        check_numeric_input(s)?;
+        Ok(Some(s))
+    }
+}
+
+fn has_no_digits(s: &str) -> bool {
+    let s = s.strip_prefix('-').unwrap_or(s);
+    s.strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s)
+        .is_empty()
+}
+
+pub(crate) fn check_whitespace(s: &str) -> Result<(), String> {
+    if s.trim() != s {
+        return Err(format!("unexpected whitespace around {s:?}"));
+    }
+    Ok(())
+}
+
+// Run by the `FromAnyStr` impls of numeric and fixed-size types before parsing
+pub(crate) fn check_numeric_input(s: &str) -> Result<(), String> {
+    check_whitespace(s)?;
+    if has_no_digits(s) {
+        return Err(format!("no digits in {s:?}"));
+    }
+    Ok(())
 }
 
 pub fn from_string<T: FromAnyStr>(s: &str) -> Result<T, String> {
     T::from_any_str(s)
 }
 
+/// What to do when a parsed value does not fit in the bit width of the target type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    #[default]
+    Error,
+    // Clamp to the maximum representable value
+    Saturate,
+    // Keep the low bits, i.e. reduce modulo 2^bits
+    Wrap,
+}
+
+impl OverflowPolicy {
+    pub fn apply(&self, value: BigUint, bits: usize) -> Result<BigUint, String> {
+        if value.bits() <= bits as u64 {
+            return Ok(value);
+        }
+        let max = (BigUint::from(1u32) << bits) - BigUint::from(1u32);
+        match self {
+            OverflowPolicy::Error => Err(format!("value does not fit in {bits} bits")),
+            OverflowPolicy::Saturate => Ok(max),
+            OverflowPolicy::Wrap => Ok(value & max),
+        }
+    }
+}
+
+// Parsing with an explicit overflow policy, `FromAnyStr` uses `OverflowPolicy::Error`
+pub trait FromAnyStrWithPolicy: FromAnyStr {
+    fn from_any_str_with_policy(s: &str, policy: OverflowPolicy) -> Result<Self, String>;
+}
+
+// Parsing in an explicit radix, without the prefix detection of `FromAnyStr`
+pub trait FromStrRadix: FromAnyStr {
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, String>;
+}
+
+// `BigUint::from_str_radix` panics outside of radix 2 to 36
+pub(crate) fn parse_biguint_radix(s: &str, radix: u32) -> Result<BigUint, String> {
+    if !(2..=36).contains(&radix) {
+        return Err(format!("invalid radix {radix}"));
+    }
+    <BigUint as num_traits::Num>::from_str_radix(s, radix)
+        .map_err(|e| format!("invalid base {radix} integer {s}: {e}"))
+}
+
+/// How strings are read: `Any` guesses the radix like `FromAnyStr` does, so `"FF"` is hex and
+/// `"123"` decimal, the strict modes only accept `0x` prefixed hex or plain decimal digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    #[default]
+    Any,
+    StrictHex,
+    StrictDec,
+}
+
+impl ParseMode {
+    // A leading '-' is left to the parser, only signed types accept it
+    pub fn parse<T: FromStrRadix>(&self, s: &str) -> Result<T, String> {
+        let (sign, unsigned) = match s.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", s),
+        };
+        match self {
+            ParseMode::Any => T::from_any_str(s),
+            ParseMode::StrictHex => {
+                let digits = unsigned
+                    .strip_prefix("0x")
+                    .or_else(|| unsigned.strip_prefix("0X"))
+                    .ok_or_else(|| format!("expected 0x prefixed hex, got {s}"))?;
+                T::from_str_radix(&format!("{sign}{digits}"), 16)
+            }
+            ParseMode::StrictDec => {
+                if unsigned.is_empty() || !unsigned.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(format!("expected decimal digits, got {s}"));
+                }
+                T::from_str_radix(s, 10)
+            }
+        }
+    }
+}
+
 pub fn hex_bytes_padded(input: &str, target_len: Option<usize>) -> Result<Vec<u8>, String> {
     let mut hex = input
         .strip_prefix("0x")
@@ -40,20 +227,72 @@ pub fn hex_bytes_padded(input: &str, target_len: Option<usize>) -> Result<Vec<u8
     Ok(bytes)
 }
 
+/// Prefix selecting base64 in `parse_bytes`, e.g. `base64:aGVsbG8=`.
+pub const BASE64_PREFIX: &str = "base64:";
+
+// Padding is optional so that unpadded gRPC and JWT style payloads decode too
+const BASE64_STANDARD: base64::engine::GeneralPurpose = base64::engine::GeneralPurpose::new(
+    &base64::alphabet::STANDARD,
+    base64::engine::GeneralPurposeConfig::new()
+        .with_decode_padding_mode(base64::engine::DecodePaddingMode::Indifferent),
+);
+const BASE64_URL_SAFE: base64::engine::GeneralPurpose = base64::engine::GeneralPurpose::new(
+    &base64::alphabet::URL_SAFE,
+    base64::engine::GeneralPurposeConfig::new()
+        .with_decode_padding_mode(base64::engine::DecodePaddingMode::Indifferent),
+);
+
+/// Decodes standard or URL-safe base64, the latter picked when the input contains `-` or `_`.
+pub fn decode_base64(input: &str) -> Result<Vec<u8>, String> {
+    use base64::Engine;
+    let engine = match input.contains(['-', '_']) {
+        true => &BASE64_URL_SAFE,
+        false => &BASE64_STANDARD,
+    };
+    engine
+        .decode(input)
+        .map_err(|e| format!("invalid base64 {input:?}: {e}"))
+}
+
+/// Bytes as hex, with an optional `0x` prefix, or as base64 after `BASE64_PREFIX`.
+pub fn parse_bytes(input: &str) -> Result<Vec<u8>, String> {
+    match input.strip_prefix(BASE64_PREFIX) {
+        Some(encoded) => decode_base64(encoded),
+        None => hex_bytes_padded(input, None),
+    }
+}
+
+#[cfg(feature = "serde")]
 pub mod serde_utils {
-    //! Serde helpers for deserializing types that implement `FromAnyStr`.
+    //! Serde helpers for deserializing types that implement `FromAnyStr`, and bincode
+    //! `encode`/`decode` helpers going through the same `Serialize`/`Deserialize` impls.
 
-    use super::FromAnyStr;
-    use serde::de::{self, Deserializer, Visitor};
-    use serde::Deserialize;
-    use std::fmt;
+    use super::{
+        decode_base64, FromAnyStr, FromAnyStrWithPolicy, FromStrRadix, OverflowPolicy, ParseMode,
+        ParseOptions, BASE64_PREFIX,
+    };
+    use crate::stdlib::prelude::*;
+    use core::fmt;
+    use serde::de::{self, DeserializeOwned, Deserializer, SeqAccess, Visitor};
+    use serde::{Deserialize, Serialize, Serializer};
 
-    struct AnyStrVisitor<T>(std::marker::PhantomData<T>);
+    struct AnyStrVisitor<T> {
+        parse: fn(&str) -> Result<T, String>,
+        // Negative JSON numbers are only forwarded to the parser of signed types
+        signed: bool,
+    }
 
-    impl<'de, T> Visitor<'de> for AnyStrVisitor<T>
-    where
-        T: FromAnyStr,
-    {
+    impl<T> AnyStrVisitor<T> {
+        fn parse_str<E: de::Error>(&self, value: &str) -> Result<T, E> {
+            (self.parse)(value).map_err(|e| {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(input = value, error = %e, "failed to parse value");
+                de::Error::custom(e)
+            })
+        }
+    }
+
+    impl<'de, T> Visitor<'de> for AnyStrVisitor<T> {
         type Value = T;
 
         fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -64,24 +303,113 @@ pub mod serde_utils {
         where
             E: de::Error,
         {
-            T::from_any_str(value).map_err(de::Error::custom)
+            self.parse_str(value)
         }
 
         fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
         where
             E: de::Error,
         {
-            T::from_any_str(&value.to_string()).map_err(de::Error::custom)
+            self.parse_str(&value.to_string())
         }
 
         fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
         where
             E: de::Error,
         {
-            if value < 0 {
+            if value < 0 && !self.signed {
                 return Err(de::Error::custom("negative values not supported"));
             }
-            T::from_any_str(&value.to_string()).map_err(de::Error::custom)
+            self.parse_str(&value.to_string())
+        }
+
+        // Raw big-endian bytes written by `serialize_hex`, parsed as their `0x` hex
+        fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            self.parse_str(&format!("0x{}", hex::encode(value)))
+        }
+    }
+
+    /// Serializes big-endian bytes as a `0x` hex string in human readable formats such as JSON,
+    /// and as a raw byte array in binary formats such as bincode, where the hex string would
+    /// more than double the size.
+    pub fn serialize_hex<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&format!("0x{}", hex::encode(bytes)))
+        } else {
+            serializer.serialize_bytes(bytes)
+        }
+    }
+
+    /// Integers that can be written as minimal hex, e.g. `0xff` or `0x0`, as starknet RPC does.
+    pub trait ToMinimalHex {
+        fn to_minimal_hex(&self) -> String;
+    }
+
+    /// Serializes an integer as minimal hex instead of the zero padded hex of its `Serialize`
+    /// impl, in human readable formats only. Use with
+    /// `#[serde(serialize_with = "serde_utils::serialize_minimal_hex")]`.
+    ///
+    /// Deserialization needs no helper, minimal and padded hex both parse.
+    pub fn serialize_minimal_hex<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: ToMinimalHex + Serialize,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&value.to_minimal_hex())
+        } else {
+            value.serialize(serializer)
+        }
+    }
+
+    /// Like `serialize_minimal_hex`, for a `Vec` or slice of integers.
+    pub fn serialize_minimal_hex_seq<S, T>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: ToMinimalHex + Serialize,
+    {
+        if !serializer.is_human_readable() {
+            return values.serialize(serializer);
+        }
+        serializer.collect_seq(values.iter().map(ToMinimalHex::to_minimal_hex))
+    }
+
+    // Binary formats such as bincode and postcard are not self-describing and can't drive
+    // `deserialize_any`: values written with `serialize_hex` are read back from their bytes,
+    // the others from their string
+    fn deserialize_with<'de, D, T>(
+        deserializer: D,
+        visitor: AnyStrVisitor<T>,
+        hex: bool,
+    ) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match (deserializer.is_human_readable(), hex) {
+            (true, _) => deserializer.deserialize_any(visitor),
+            (false, true) => deserializer.deserialize_bytes(visitor),
+            (false, false) => deserializer.deserialize_str(visitor),
+        }
+    }
+
+    /// Deserializes a type written with `serialize_hex`, from a string only in human readable
+    /// formats.
+    pub fn deserialize_hex<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromAnyStr,
+    {
+        let visitor = AnyStrVisitor {
+            parse: T::from_any_str,
+            signed: false,
+        };
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(visitor)
+        } else {
+            deserializer.deserialize_bytes(visitor)
         }
     }
 
@@ -91,7 +419,203 @@ pub mod serde_utils {
         D: Deserializer<'de>,
         T: FromAnyStr,
     {
-        deserializer.deserialize_any(AnyStrVisitor(std::marker::PhantomData))
+        deserialize_with(
+            deserializer,
+            AnyStrVisitor {
+                parse: T::from_any_str,
+                signed: false,
+            },
+            true,
+        )
+    }
+
+    /// Like `deserialize_from_any`, but also accepts negative JSON numbers, for signed types.
+    pub fn deserialize_signed<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromAnyStr,
+    {
+        deserialize_with(
+            deserializer,
+            AnyStrVisitor {
+                parse: T::from_any_str,
+                signed: true,
+            },
+            false,
+        )
+    }
+
+    /// Like `deserialize_from_any`, but clamps values that overflow the type to its maximum.
+    /// Use with `#[serde(deserialize_with = "serde_utils::deserialize_saturating")]`.
+    pub fn deserialize_saturating<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromAnyStrWithPolicy,
+    {
+        deserialize_with(
+            deserializer,
+            AnyStrVisitor {
+                parse: |s| T::from_any_str_with_policy(s, OverflowPolicy::Saturate),
+                signed: false,
+            },
+            true,
+        )
+    }
+
+    /// Like `deserialize_from_any`, but truncates values that overflow the type to its low bits.
+    /// Use with `#[serde(deserialize_with = "serde_utils::deserialize_wrapping")]`.
+    pub fn deserialize_wrapping<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromAnyStrWithPolicy,
+    {
+        deserialize_with(
+            deserializer,
+            AnyStrVisitor {
+                parse: |s| T::from_any_str_with_policy(s, OverflowPolicy::Wrap),
+                signed: false,
+            },
+            true,
+        )
+    }
+
+    // Binary formats carry the raw value, there is no radix to be strict about
+    fn deserialize_strict<'de, D, T>(deserializer: D, mode: ParseMode) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromStrRadix,
+    {
+        if !deserializer.is_human_readable() {
+            return deserialize_from_any(deserializer);
+        }
+        let visitor = AnyStrVisitor {
+            parse: match mode {
+                ParseMode::StrictHex => |s| ParseMode::StrictHex.parse(s),
+                ParseMode::StrictDec => |s| ParseMode::StrictDec.parse(s),
+                ParseMode::Any => T::from_any_str,
+            },
+            signed: true,
+        };
+        deserializer.deserialize_any(visitor)
+    }
+
+    /// Like `deserialize_from_any`, but only accepts `0x` prefixed hex strings.
+    /// Use with `#[serde(deserialize_with = "serde_utils::deserialize_strict_hex")]`.
+    pub fn deserialize_strict_hex<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromStrRadix,
+    {
+        deserialize_strict(deserializer, ParseMode::StrictHex)
+    }
+
+    /// Like `deserialize_from_any`, but only accepts decimal strings and numbers.
+    /// Use with `#[serde(deserialize_with = "serde_utils::deserialize_strict_dec")]`.
+    pub fn deserialize_strict_dec<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromStrRadix,
+    {
+        deserialize_strict(deserializer, ParseMode::StrictDec)
+    }
+
+    /// Like `deserialize_from_any`, but trims whitespace and reads empty strings and a bare
+    /// `0x` as zero, see `ParseOptions::LENIENT`.
+    /// Use with `#[serde(deserialize_with = "serde_utils::deserialize_lenient")]`.
+    pub fn deserialize_lenient<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromAnyStr,
+    {
+        deserialize_with(
+            deserializer,
+            AnyStrVisitor {
+                parse: |s| T::from_any_str_with_options(s, &ParseOptions::LENIENT),
+                signed: false,
+            },
+            true,
+        )
+    }
+
+    struct BytesVisitor<T> {
+        parse: fn(&str) -> Result<T, String>,
+    }
+
+    impl<'de, T: From<Vec<u8>>> Visitor<'de> for BytesVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a hex or base64 string, or an array of bytes")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            (self.parse)(value).map_err(de::Error::custom)
+        }
+
+        fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(T::from(value.to_vec()))
+        }
+
+        fn visit_byte_buf<E>(self, value: Vec<u8>) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(T::from(value))
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(byte) = seq.next_element::<u8>()? {
+                bytes.push(byte);
+            }
+            Ok(T::from(bytes))
+        }
+    }
+
+    /// Deserializes a byte type written with `serialize_hex`. Human readable formats also accept
+    /// base64 strings after `BASE64_PREFIX` and arrays of bytes, e.g. `[104, 105]`.
+    pub fn deserialize_bytes<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromAnyStr + From<Vec<u8>>,
+    {
+        let visitor = BytesVisitor {
+            parse: T::from_any_str,
+        };
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor)
+        } else {
+            deserializer.deserialize_bytes(visitor)
+        }
+    }
+
+    /// Like `deserialize_bytes`, but reads strings as base64, with or without `BASE64_PREFIX`.
+    /// Use with `#[serde(deserialize_with = "serde_utils::deserialize_base64")]`.
+    pub fn deserialize_base64<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: From<Vec<u8>>,
+    {
+        let visitor = BytesVisitor {
+            parse: |s| {
+                let encoded = s.strip_prefix(BASE64_PREFIX).unwrap_or(s);
+                decode_base64(encoded).map(T::from)
+            },
+        };
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor)
+        } else {
+            deserializer.deserialize_bytes(visitor)
+        }
     }
 
     /// Deserialize a vector of types that have custom Deserialize implementations
@@ -103,4 +627,19 @@ pub mod serde_utils {
     {
         Vec::<T>::deserialize(deserializer)
     }
+
+    /// Encodes a value with bincode's standard configuration, through its `Serialize` impl.
+    pub fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, String> {
+        bincode::serde::encode_to_vec(value, bincode::config::standard()).map_err(|e| e.to_string())
+    }
+
+    /// Decodes a value written by `encode`, failing on trailing bytes.
+    pub fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, String> {
+        let (value, len) = bincode::serde::decode_from_slice(bytes, bincode::config::standard())
+            .map_err(|e| e.to_string())?;
+        if len != bytes.len() {
+            return Err(format!("{} trailing bytes", bytes.len() - len));
+        }
+        Ok(value)
+    }
 }
@@ -0,0 +1,192 @@
+use crate::cairo_type::{CairoType, CairoWritable, Layout};
+use crate::stdlib::prelude::*;
+use cairo_vm::{
+    types::relocatable::{MaybeRelocatable, Relocatable},
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use core::marker::PhantomData;
+
+/// How a `CairoOption` is laid out in memory.
+pub trait OptionEncoding {
+    fn n_fields<T: CairoType>() -> usize;
+    fn layout<T: CairoType>() -> Layout {
+        Layout::felts("CairoOption", Self::n_fields::<T>())
+    }
+    fn read<T: CairoType>(
+        vm: &VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Option<T>, HintError>;
+    fn write<T: CairoType>(
+        value: Option<&T>,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError>;
+}
+
+/// `(is_some, value)` with the value inlined, zeroed when absent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Flagged;
+
+/// A single pointer to the value in a new segment, `0` when absent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Nullable;
+
+impl OptionEncoding for Flagged {
+    fn n_fields<T: CairoType>() -> usize {
+        1 + T::n_fields()
+    }
+
+    fn layout<T: CairoType>() -> Layout {
+        Layout::new("CairoOption")
+            .felt("is_some")
+            .nested("value", T::layout())
+    }
+
+    fn read<T: CairoType>(
+        vm: &VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Option<T>, HintError> {
+        let flag = vm.get_integer(address)?;
+        if *flag == Felt252::ZERO {
+            Ok(None)
+        } else if *flag == Felt252::ONE {
+            Ok(Some(T::from_memory(vm, (address + 1)?)?))
+        } else {
+            Err(HintError::CustomHint(
+                format!("invalid option flag {flag} at {address}").into_boxed_str(),
+            ))
+        }
+    }
+
+    fn write<T: CairoType>(
+        value: Option<&T>,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        match value {
+            Some(value) => {
+                vm.insert_value(address, Felt252::ONE)?;
+                value.to_memory(vm, (address + 1)?)
+            }
+            None => {
+                for i in 0..Self::n_fields::<T>() {
+                    vm.insert_value((address + i)?, Felt252::ZERO)?;
+                }
+                Ok((address + Self::n_fields::<T>())?)
+            }
+        }
+    }
+}
+
+impl OptionEncoding for Nullable {
+    fn n_fields<T: CairoType>() -> usize {
+        1
+    }
+
+    fn layout<T: CairoType>() -> Layout {
+        Layout::new("NullableOption").pointer("value")
+    }
+
+    fn read<T: CairoType>(
+        vm: &VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Option<T>, HintError> {
+        match vm.get_maybe(&address) {
+            Some(MaybeRelocatable::Int(value)) if value == Felt252::ZERO => Ok(None),
+            Some(MaybeRelocatable::RelocatableValue(ptr)) => Ok(Some(T::from_memory(vm, ptr)?)),
+            _ => Err(HintError::CustomHint(
+                format!("expected a pointer or 0 at {address}").into_boxed_str(),
+            )),
+        }
+    }
+
+    fn write<T: CairoType>(
+        value: Option<&T>,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        match value {
+            Some(value) => {
+                let segment = vm.add_memory_segment();
+                value.to_memory(vm, segment)?;
+                vm.insert_value(address, segment)?;
+            }
+            None => vm.insert_value(address, Felt252::ZERO)?,
+        }
+        Ok((address + 1)?)
+    }
+}
+
+/// Optional struct member, laid out as `(flag, value)` by default or as a nullable pointer
+/// with `NullableOption`. Deserializes from a value or `null`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CairoOption<T, E = Flagged> {
+    pub value: Option<T>,
+    encoding: PhantomData<E>,
+}
+
+pub type NullableOption<T> = CairoOption<T, Nullable>;
+
+impl<T, E> CairoOption<T, E> {
+    pub fn new(value: Option<T>) -> Self {
+        Self {
+            value,
+            encoding: PhantomData,
+        }
+    }
+
+    pub fn into_inner(self) -> Option<T> {
+        self.value
+    }
+}
+
+impl<T, E> From<Option<T>> for CairoOption<T, E> {
+    fn from(value: Option<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: CairoType, E: OptionEncoding> CairoType for CairoOption<T, E> {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        E::read(vm, address).map(Self::new)
+    }
+}
+
+impl<T: CairoType, E: OptionEncoding> CairoWritable for CairoOption<T, E> {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        E::write(self.value.as_ref(), vm, address)
+    }
+
+    fn n_fields() -> usize {
+        E::n_fields::<T>()
+    }
+
+    fn layout() -> Layout {
+        E::layout::<T>()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, E> serde::Deserialize<'de> for CairoOption<T, E> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Option::<T>::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, E> serde::Serialize for CairoOption<T, E> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
@@ -0,0 +1,113 @@
+//! Typed pointers, so that nested structures can be followed and built from hints without raw
+//! `MaybeRelocatable` arithmetic.
+
+use crate::cairo_type::{CairoType, CairoWritable, Layout};
+use crate::stdlib::prelude::*;
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+use core::marker::PhantomData;
+
+/// A `T*`: a relocatable to a `T`, written as a single cell. Arithmetic is in units of
+/// `T::n_fields()` as in Cairo, so `offset(n)` points to the `n`-th `T` of an array.
+pub struct CairoPointer<T> {
+    address: Relocatable,
+    _pointee: PhantomData<T>,
+}
+
+impl<T: CairoType> CairoPointer<T> {
+    pub fn new(address: Relocatable) -> Self {
+        Self {
+            address,
+            _pointee: PhantomData,
+        }
+    }
+
+    /// A pointer to the start of a new segment.
+    pub fn alloc(vm: &mut VirtualMachine) -> Self {
+        Self::new(vm.add_memory_segment())
+    }
+
+    pub fn address(&self) -> Relocatable {
+        self.address
+    }
+
+    /// Reads the `T` pointed to.
+    pub fn deref(&self, vm: &VirtualMachine) -> Result<T, HintError> {
+        T::from_memory(vm, self.address)
+    }
+
+    /// Pointer to the `n`-th `T` after this one.
+    pub fn offset(&self, n: usize) -> Result<Self, HintError> {
+        let cells = n.checked_mul(T::n_fields()).ok_or_else(|| {
+            HintError::CustomHint(
+                format!("pointer offset of {n} elements overflows").into_boxed_str(),
+            )
+        })?;
+        Ok(Self::new((self.address + cells)?))
+    }
+
+    /// Writes `value` at the address pointed to, returning the address past it.
+    pub fn write_pointee(
+        &self,
+        vm: &mut VirtualMachine,
+        value: &T,
+    ) -> Result<Relocatable, HintError> {
+        value.to_memory(vm, self.address)
+    }
+}
+
+// Manual impls, a pointer being copyable and comparable whatever it points to
+impl<T> Clone for CairoPointer<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for CairoPointer<T> {}
+
+impl<T> PartialEq for CairoPointer<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.address == other.address
+    }
+}
+
+impl<T> Eq for CairoPointer<T> {}
+
+impl<T> core::fmt::Debug for CairoPointer<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("CairoPointer").field(&self.address).finish()
+    }
+}
+
+impl<T> From<CairoPointer<T>> for Relocatable {
+    fn from(pointer: CairoPointer<T>) -> Self {
+        pointer.address
+    }
+}
+
+impl<T: CairoType> CairoType for CairoPointer<T> {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        Ok(Self::new(vm.get_relocatable(address)?))
+    }
+}
+
+impl<T: CairoType> CairoWritable for CairoPointer<T> {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        vm.insert_value(address, self.address)?;
+        Ok((address + 1)?)
+    }
+
+    fn n_fields() -> usize {
+        1
+    }
+
+    fn layout() -> Layout {
+        Layout::new(format!("{}*", T::layout().name)).pointer("ptr")
+    }
+}
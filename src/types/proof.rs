@@ -0,0 +1,274 @@
+//! Witness types of storage proofs: generic Merkle inclusion proofs and Ethereum Merkle Patricia
+//! Trie proofs, as returned by `eth_getProof`.
+
+use crate::cairo_type::{write_felts, CairoType, CairoWritable, Layout};
+use crate::stdlib::prelude::*;
+use crate::types::{
+    check_whitespace,
+    keccak_bytes::{bytes_from_le_u64_limbs, le_u64_limbs},
+    parse_bytes,
+    rlp::list_items,
+    FromAnyStr,
+};
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+/// Inclusion proof of `leaf` at `index`, with the sibling of each level from the leaf up to the
+/// root. Laid out as `(leaf, index, n_siblings, siblings)`, `siblings` pointing to a segment of
+/// `T`s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MerkleProof<T> {
+    pub leaf: T,
+    pub index: u64,
+    pub siblings: Vec<T>,
+}
+
+impl<T> MerkleProof<T> {
+    pub fn depth(&self) -> usize {
+        self.siblings.len()
+    }
+
+    /// Siblings from the leaf up, each with whether the node being proven is the right child at
+    /// that level, i.e. the bit of `index` for the level.
+    pub fn path(&self) -> impl Iterator<Item = (&T, bool)> {
+        self.siblings
+            .iter()
+            .enumerate()
+            .map(|(level, sibling)| (sibling, level < 64 && (self.index >> level) & 1 == 1))
+    }
+}
+
+impl<T: CairoType> CairoWritable for MerkleProof<T> {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let next = self.leaf.to_memory(vm, address)?;
+        let siblings = vm.add_memory_segment();
+        let mut ptr = siblings;
+        for sibling in &self.siblings {
+            ptr = sibling.to_memory(vm, ptr)?;
+        }
+        write_felts(
+            vm,
+            next,
+            [
+                Felt252::from(self.index),
+                Felt252::from(self.siblings.len()),
+            ],
+        )?;
+        vm.insert_value((next + 2)?, siblings)?;
+        Ok((next + 3)?)
+    }
+
+    fn n_fields() -> usize {
+        T::n_fields() + 3
+    }
+
+    fn layout() -> Layout {
+        Layout::new("MerkleProof")
+            .nested("leaf", T::layout())
+            .felt("index")
+            .felt("n_siblings")
+            .pointer("siblings")
+    }
+}
+
+/// Kind of an MPT node, decoded from its RLP list: 17 items for a branch, 2 for an extension or
+/// a leaf, told apart by the flag nibble of their hex prefix encoded path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MptNodeKind {
+    Branch,
+    Extension,
+    Leaf,
+}
+
+impl MptNodeKind {
+    // Value written by the `mpt_node_type` hint
+    pub fn to_felt(&self) -> Felt252 {
+        match self {
+            MptNodeKind::Branch => Felt252::ZERO,
+            MptNodeKind::Extension => Felt252::ONE,
+            MptNodeKind::Leaf => Felt252::TWO,
+        }
+    }
+}
+
+/// RLP encoded trie node, written as a pointer to its bytes in 64-bit little-endian chunks like
+/// `KeccakBytes`, ready to be hashed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MptNode(pub Vec<u8>);
+
+impl MptNode {
+    /// Reads back the node bytes from their 64-bit little-endian chunks.
+    pub fn from_limbs(limbs: &[Felt252], n_bytes: usize) -> Result<Self, String> {
+        bytes_from_le_u64_limbs(limbs, n_bytes).map(MptNode)
+    }
+
+    pub fn n_bytes(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn to_limbs(&self) -> Vec<Felt252> {
+        le_u64_limbs(&self.0)
+    }
+
+    pub fn kind(&self) -> Result<MptNodeKind, String> {
+        mpt_node_kind(&self.0)
+    }
+}
+
+impl From<Vec<u8>> for MptNode {
+    fn from(bytes: Vec<u8>) -> Self {
+        MptNode(bytes)
+    }
+}
+
+impl CairoWritable for MptNode {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let limbs = vm.add_memory_segment();
+        write_felts(vm, limbs, self.to_limbs())?;
+        vm.insert_value(address, limbs)?;
+        Ok((address + 1)?)
+    }
+
+    fn n_fields() -> usize {
+        1
+    }
+
+    fn layout() -> Layout {
+        Layout::new("MptNode").pointer("limbs")
+    }
+}
+
+// Hex, or base64 after the `base64:` prefix
+impl FromAnyStr for MptNode {
+    fn from_any_str(s: &str) -> Result<Self, String> {
+        check_whitespace(s)?;
+        Ok(MptNode(parse_bytes(s)?))
+    }
+}
+
+/// Nodes from the root down to the proven value, deserialized from the array of hex nodes of
+/// an `eth_getProof` `accountProof` or `storageProof`. Laid out as
+/// `(n_nodes, nodes_bytes_len, nodes)`, `nodes_bytes_len` pointing to the byte length of each
+/// node and `nodes` to a segment of `MptNode` pointers.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MptProof {
+    pub nodes: Vec<MptNode>,
+}
+
+impl MptProof {
+    pub fn new(nodes: Vec<MptNode>) -> Self {
+        Self { nodes }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &MptNode> {
+        self.nodes.iter()
+    }
+}
+
+impl CairoWritable for MptProof {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let bytes_len = vm.add_memory_segment();
+        write_felts(
+            vm,
+            bytes_len,
+            self.nodes.iter().map(|node| Felt252::from(node.n_bytes())),
+        )?;
+        let nodes = vm.add_memory_segment();
+        let mut ptr = nodes;
+        for node in &self.nodes {
+            ptr = node.to_memory(vm, ptr)?;
+        }
+        vm.insert_value(address, Felt252::from(self.nodes.len()))?;
+        vm.insert_value((address + 1)?, bytes_len)?;
+        vm.insert_value((address + 2)?, nodes)?;
+        Ok((address + 3)?)
+    }
+
+    fn n_fields() -> usize {
+        3
+    }
+
+    fn layout() -> Layout {
+        Layout::new("MptProof")
+            .felt("n_nodes")
+            .pointer("nodes_bytes_len")
+            .pointer("nodes")
+    }
+}
+
+fn mpt_node_kind(node: &[u8]) -> Result<MptNodeKind, String> {
+    let items = list_items(node).map_err(|e| format!("invalid MPT node: {e}"))?;
+    match items.len() {
+        17 => Ok(MptNodeKind::Branch),
+        2 => match items[0].first().map(|byte| byte >> 4) {
+            Some(0 | 1) => Ok(MptNodeKind::Extension),
+            Some(2 | 3) => Ok(MptNodeKind::Leaf),
+            _ => Err("invalid hex prefix in MPT node path".to_string()),
+        },
+        n => Err(format!("MPT node has {n} items, expected 17 or 2")),
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MptNode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::types::serde_utils::deserialize_bytes(deserializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for MptNode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::types::serde_utils::serialize_hex(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MptProof {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Vec::<MptNode>::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for MptProof {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.nodes.serialize(serializer)
+    }
+}
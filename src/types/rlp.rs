@@ -0,0 +1,296 @@
+//! Recursive Length Prefix encoding, as used by Ethereum for transactions, receipts and trie
+//! nodes.
+
+use crate::cairo_type::{write_felts, CairoWritable, Layout};
+use crate::stdlib::prelude::*;
+use crate::types::keccak_bytes::le_u64_limbs;
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+/// Prefix of an RLP item: whether it is a list, and where its payload starts and how long it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RlpHeader {
+    pub is_list: bool,
+    pub offset: usize,
+    pub len: usize,
+}
+
+impl RlpHeader {
+    /// Header of the item at the start of `bytes`, failing if its payload runs past the end.
+    pub fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let first = *bytes.first().ok_or("empty RLP item")?;
+        let (is_list, offset, len) = match first {
+            0x00..=0x7f => (false, 0, 1),
+            0x80..=0xb7 => (false, 1, (first - 0x80) as usize),
+            0xc0..=0xf7 => (true, 1, (first - 0xc0) as usize),
+            _ => {
+                let is_list = first >= 0xf8;
+                let len_of_len = (first - if is_list { 0xf7 } else { 0xb7 }) as usize;
+                let len_bytes = bytes.get(1..1 + len_of_len).ok_or("truncated RLP length")?;
+                let len = len_bytes
+                    .iter()
+                    .try_fold(0usize, |len, byte| {
+                        len.checked_mul(256)?.checked_add(*byte as usize)
+                    })
+                    .ok_or("RLP length overflows")?;
+                (is_list, 1 + len_of_len, len)
+            }
+        };
+        // The length is untrusted, up to 8 bytes, so the sum may not fit
+        let item_len = offset.checked_add(len).ok_or("RLP length overflows")?;
+        if item_len > bytes.len() {
+            return Err(format!(
+                "RLP item of {item_len} bytes exceeds the {} bytes left",
+                bytes.len()
+            ));
+        }
+        Ok(Self {
+            is_list,
+            offset,
+            len,
+        })
+    }
+
+    // Length of the whole item, prefix included, which fits for decoded headers
+    pub fn item_len(&self) -> usize {
+        self.offset.saturating_add(self.len)
+    }
+}
+
+/// Payloads of the items of the list making up all of `bytes`, e.g. the 17 items of a branch
+/// node.
+pub fn list_items(bytes: &[u8]) -> Result<Vec<&[u8]>, String> {
+    let header = RlpHeader::decode(bytes)?;
+    if !header.is_list || header.item_len() != bytes.len() {
+        return Err("not a single RLP list".to_string());
+    }
+    let mut payload = &bytes[header.offset..header.item_len()];
+    let mut items = Vec::new();
+    while !payload.is_empty() {
+        let item = RlpHeader::decode(payload)?;
+        items.push(&payload[item.offset..item.item_len()]);
+        payload = &payload[item.item_len()..];
+    }
+    Ok(items)
+}
+
+/// Nested byte structure to RLP encode. Deserializes from hex strings (or base64 after the
+/// `base64:` prefix) for byte strings, integers for their minimal big-endian bytes and arrays
+/// for lists, e.g. `["0x01", [42, "0xcafe"]]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RlpItem {
+    Bytes(Vec<u8>),
+    List(Vec<RlpItem>),
+}
+
+impl RlpItem {
+    /// Integers are encoded as their big-endian bytes without leading zeros, zero being empty.
+    pub fn uint(value: u64) -> Self {
+        let bytes = value.to_be_bytes();
+        let start = bytes.iter().position(|byte| *byte != 0).unwrap_or(8);
+        RlpItem::Bytes(bytes[start..].to_vec())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode_to(&mut out);
+        out
+    }
+
+    fn encode_to(&self, out: &mut Vec<u8>) {
+        match self {
+            RlpItem::Bytes(bytes) if bytes.len() == 1 && bytes[0] < 0x80 => out.push(bytes[0]),
+            RlpItem::Bytes(bytes) => {
+                encode_length(bytes.len(), 0x80, out);
+                out.extend_from_slice(bytes);
+            }
+            RlpItem::List(items) => {
+                let mut payload = Vec::new();
+                for item in items {
+                    item.encode_to(&mut payload);
+                }
+                encode_length(payload.len(), 0xc0, out);
+                out.extend(payload);
+            }
+        }
+    }
+
+    /// Decodes a single item spanning all of `bytes`.
+    pub fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let header = RlpHeader::decode(bytes)?;
+        if header.item_len() != bytes.len() {
+            return Err(format!(
+                "{} trailing bytes after the RLP item",
+                bytes.len() - header.item_len()
+            ));
+        }
+        if !header.is_list {
+            return Ok(RlpItem::Bytes(
+                bytes[header.offset..header.item_len()].to_vec(),
+            ));
+        }
+        let mut payload = &bytes[header.offset..];
+        let mut items = Vec::new();
+        while !payload.is_empty() {
+            let item_len = RlpHeader::decode(payload)?.item_len();
+            items.push(Self::decode(&payload[..item_len])?);
+            payload = &payload[item_len..];
+        }
+        Ok(RlpItem::List(items))
+    }
+}
+
+fn encode_length(len: usize, offset: u8, out: &mut Vec<u8>) {
+    if len <= 55 {
+        out.push(offset + len as u8);
+        return;
+    }
+    let len_bytes = len.to_be_bytes();
+    let start = len_bytes.iter().position(|byte| *byte != 0).unwrap_or(0);
+    out.push(offset + 55 + (len_bytes.len() - start) as u8);
+    out.extend_from_slice(&len_bytes[start..]);
+}
+
+impl From<Vec<u8>> for RlpItem {
+    fn from(bytes: Vec<u8>) -> Self {
+        RlpItem::Bytes(bytes)
+    }
+}
+
+impl From<Vec<RlpItem>> for RlpItem {
+    fn from(items: Vec<RlpItem>) -> Self {
+        RlpItem::List(items)
+    }
+}
+
+/// An `RlpItem` written RLP encoded, as a pointer to the encoding in 64-bit little-endian
+/// chunks like `KeccakBytes`, ready to be hashed or decoded with the `rlp_item_header` hint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RlpBytes(pub RlpItem);
+
+impl RlpBytes {
+    pub fn encoded(&self) -> Vec<u8> {
+        self.0.encode()
+    }
+
+    pub fn n_bytes(&self) -> usize {
+        self.encoded().len()
+    }
+
+    pub fn to_limbs(&self) -> Vec<Felt252> {
+        le_u64_limbs(&self.encoded())
+    }
+}
+
+impl From<RlpItem> for RlpBytes {
+    fn from(item: RlpItem) -> Self {
+        RlpBytes(item)
+    }
+}
+
+impl CairoWritable for RlpBytes {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let limbs = vm.add_memory_segment();
+        write_felts(vm, limbs, self.to_limbs())?;
+        vm.insert_value(address, limbs)?;
+        Ok((address + 1)?)
+    }
+
+    fn n_fields() -> usize {
+        1
+    }
+
+    fn layout() -> Layout {
+        Layout::new("RlpBytes").pointer("limbs")
+    }
+}
+
+// Human readable formats carry the nested structure, binary ones the encoding
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::*;
+    use crate::types::parse_bytes;
+    use core::fmt;
+    use serde::de::{self, Deserializer, SeqAccess, Visitor};
+    use serde::ser::{SerializeSeq, Serializer};
+    use serde::{Deserialize, Serialize};
+
+    struct RlpItemVisitor;
+
+    impl<'de> Visitor<'de> for RlpItemVisitor {
+        type Value = RlpItem;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a hex string, an integer or an array of RLP items")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            parse_bytes(value).map(RlpItem::Bytes).map_err(E::custom)
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            Ok(RlpItem::uint(value))
+        }
+
+        fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+            RlpItem::decode(value).map_err(E::custom)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(item) = seq.next_element::<RlpItem>()? {
+                items.push(item);
+            }
+            Ok(RlpItem::List(items))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for RlpItem {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_any(RlpItemVisitor)
+            } else {
+                deserializer.deserialize_bytes(RlpItemVisitor)
+            }
+        }
+    }
+
+    impl Serialize for RlpItem {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            if !serializer.is_human_readable() {
+                return serializer.serialize_bytes(&self.encode());
+            }
+            match self {
+                RlpItem::Bytes(bytes) => {
+                    crate::types::serde_utils::serialize_hex(bytes, serializer)
+                }
+                RlpItem::List(items) => {
+                    let mut seq = serializer.serialize_seq(Some(items.len()))?;
+                    for item in items {
+                        seq.serialize_element(item)?;
+                    }
+                    seq.end()
+                }
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for RlpBytes {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            RlpItem::deserialize(deserializer).map(RlpBytes)
+        }
+    }
+
+    impl Serialize for RlpBytes {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.0.serialize(serializer)
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! `JsonSchema` impls describing the inputs the `Deserialize` impls accept, so services taking
+//! program input JSON can publish them.
+
+use crate::stdlib::prelude::*;
+use crate::types::{
+    bytes::Bytes,
+    chunked_bytes::{ChunkedBytes, Endian},
+    felt::Felt,
+    fixed_bytes::FixedBytes,
+    keccak_bytes::KeccakBytes,
+    uint_bits::{LimbLayout, UintBits},
+};
+use alloc::borrow::Cow;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+// Hex with an optional `0x` prefix and `_` separators, a superset of decimal strings. JSON
+// numbers are only accepted up to `u64::MAX`.
+const HEX_OR_DECIMAL_PATTERN: &str = "^(0[xX])?[0-9a-fA-F_]+$";
+const HEX_PATTERN: &str = "^(0[xX])?[0-9a-fA-F_]*$";
+const BASE64_PATTERN: &str = "^base64:[A-Za-z0-9+/_-]*={0,2}$";
+
+fn integer_schema(description: &str) -> Schema {
+    json_schema!({
+        "description": description,
+        "anyOf": [
+            {
+                "type": "string",
+                "pattern": HEX_OR_DECIMAL_PATTERN,
+                "description": "decimal, or hex when prefixed with 0x or not a valid decimal",
+            },
+            { "type": "integer", "minimum": 0, "maximum": u64::MAX },
+        ],
+        "examples": ["0x1a2b3c", "123", 123],
+    })
+}
+
+impl JsonSchema for Felt {
+    fn schema_name() -> Cow<'static, str> {
+        "Felt".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        integer_schema("Cairo field element, below the STARK prime")
+    }
+}
+
+// The limb layout doesn't change the JSON format, `Uint256` and `Uint256Bits32` share a schema
+impl<const BITS: usize, const LIMB: usize, L: LimbLayout> JsonSchema for UintBits<BITS, LIMB, L> {
+    fn schema_name() -> Cow<'static, str> {
+        format!("Uint{BITS}").into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        integer_schema(&format!("{BITS}-bit unsigned integer"))
+    }
+}
+
+fn bytes_schema() -> Schema {
+    json_schema!({
+        "anyOf": [
+            {
+                "type": "string",
+                "pattern": HEX_PATTERN,
+                "description": "bytes as hex, with an optional 0x prefix",
+            },
+            {
+                "type": "string",
+                "pattern": BASE64_PATTERN,
+                "description": "bytes as standard or URL-safe base64, padding optional",
+            },
+            {
+                "type": "array",
+                "items": { "type": "integer", "minimum": 0, "maximum": 255 },
+            },
+        ],
+        "examples": ["0x68656c6c6f", "base64:aGVsbG8=", [104, 101, 108, 108, 111]],
+    })
+}
+
+impl JsonSchema for KeccakBytes {
+    fn schema_name() -> Cow<'static, str> {
+        "KeccakBytes".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        bytes_schema()
+    }
+}
+
+impl<const WORD: usize, E: Endian> JsonSchema for ChunkedBytes<WORD, E> {
+    fn schema_name() -> Cow<'static, str> {
+        format!("ChunkedBytes{WORD}{}", E::NAME).into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        bytes_schema()
+    }
+}
+
+impl JsonSchema for Bytes {
+    fn schema_name() -> Cow<'static, str> {
+        "Bytes".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        bytes_schema()
+    }
+}
+
+impl<const N: usize> JsonSchema for FixedBytes<N> {
+    fn schema_name() -> Cow<'static, str> {
+        format!("Bytes{N}").into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": format!("exactly {N} bytes as hex, with an optional 0x prefix"),
+            "type": "string",
+            "pattern": format!("^(0[xX])?[0-9a-fA-F]{{{}}}$", 2 * N),
+        })
+    }
+}
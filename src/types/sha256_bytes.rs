@@ -0,0 +1,104 @@
+use crate::cairo_type::{write_felts, CairoWritable, Layout};
+use crate::stdlib::prelude::*;
+use crate::types::{check_whitespace, hex_bytes_padded, FromAnyStr, ParseOptions};
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sha256Bytes(pub Vec<u8>);
+
+// Be 32 bit chunks of a byte vec, the input layout of cairo_sha256
+impl Sha256Bytes {
+    pub fn n_bytes(&self) -> usize {
+        self.0.len()
+    }
+
+    // A trailing partial chunk is the big-endian value of its bytes, e.g. [0x61, 0x62] is 0x6162
+    pub fn to_words(&self) -> Vec<Felt252> {
+        self.0
+            .chunks(4)
+            .map(|chunk| {
+                let value = chunk
+                    .iter()
+                    .fold(0u32, |word, byte| (word << 8) | *byte as u32);
+                Felt252::from(value)
+            })
+            .collect()
+    }
+
+    /// Words of the message after the standard SHA-256 padding (0x80, zeros, then the bit
+    /// length), a multiple of 16 words, i.e. of 512 bit blocks.
+    pub fn padded_words(&self) -> Vec<u32> {
+        let mut message = self.0.clone();
+        message.push(0x80);
+        while message.len() % 64 != 56 {
+            message.push(0);
+        }
+        message.extend((self.0.len() as u64 * 8).to_be_bytes());
+        message
+            .chunks(4)
+            .map(|chunk| u32::from_be_bytes(chunk.try_into().unwrap()))
+            .collect()
+    }
+}
+
+impl CairoWritable for Sha256Bytes {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let words_segment = vm.add_memory_segment();
+        write_felts(vm, words_segment, self.to_words())?;
+
+        // Store a pointer to the words at the original address
+        vm.insert_value(address, words_segment)?;
+        Ok((address + 1)?)
+    }
+
+    fn n_fields() -> usize {
+        1
+    }
+
+    fn layout() -> Layout {
+        Layout::new("Sha256Bytes").pointer("words")
+    }
+}
+
+impl FromAnyStr for Sha256Bytes {
+    fn from_any_str(s: &str) -> Result<Self, String> {
+        check_whitespace(s)?;
+        Ok(Sha256Bytes(hex_bytes_padded(s, None)?))
+    }
+
+    // Empty input is no bytes whatever the options, only the whitespace handling applies
+    fn from_any_str_with_options(s: &str, options: &ParseOptions) -> Result<Self, String> {
+        match options.trim_whitespace {
+            true => Self::from_any_str(s.trim()),
+            false => Self::from_any_str(s),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Sha256Bytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::types::serde_utils::deserialize_hex(deserializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Sha256Bytes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::types::serde_utils::serialize_hex(&self.0, serializer)
+    }
+}
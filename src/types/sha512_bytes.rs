@@ -0,0 +1,109 @@
+use crate::cairo_type::{write_felts, CairoWritable, Layout};
+use crate::stdlib::prelude::*;
+use crate::types::bounded_uint::Uint64;
+use crate::types::{check_whitespace, hex_bytes_padded, FromAnyStr, ParseOptions};
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sha512Bytes(pub Vec<u8>);
+
+// Be 64 bit chunks of a byte vec, the input layout of the sha512 hints
+impl Sha512Bytes {
+    pub fn n_bytes(&self) -> usize {
+        self.0.len()
+    }
+
+    // A trailing partial chunk is the big-endian value of its bytes, as for `Sha256Bytes`
+    pub fn to_words(&self) -> Vec<Uint64> {
+        self.0
+            .chunks(8)
+            .map(|chunk| {
+                Uint64(
+                    chunk
+                        .iter()
+                        .fold(0u64, |word, byte| (word << 8) | *byte as u64),
+                )
+            })
+            .collect()
+    }
+
+    /// Words of the message after the standard SHA-512 padding (0x80, zeros, then the 128 bit
+    /// length), a multiple of 16 words, i.e. of 1024 bit blocks.
+    pub fn padded_words(&self) -> Vec<u64> {
+        let mut message = self.0.clone();
+        message.push(0x80);
+        while message.len() % 128 != 112 {
+            message.push(0);
+        }
+        message.extend((self.0.len() as u128 * 8).to_be_bytes());
+        message
+            .chunks(8)
+            .map(|chunk| u64::from_be_bytes(chunk.try_into().unwrap()))
+            .collect()
+    }
+}
+
+impl CairoWritable for Sha512Bytes {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let words_segment = vm.add_memory_segment();
+        write_felts(
+            vm,
+            words_segment,
+            self.to_words().iter().map(Uint64::to_felt),
+        )?;
+
+        // Store a pointer to the words at the original address
+        vm.insert_value(address, words_segment)?;
+        Ok((address + 1)?)
+    }
+
+    fn n_fields() -> usize {
+        1
+    }
+
+    fn layout() -> Layout {
+        Layout::new("Sha512Bytes").pointer("words")
+    }
+}
+
+impl FromAnyStr for Sha512Bytes {
+    fn from_any_str(s: &str) -> Result<Self, String> {
+        check_whitespace(s)?;
+        Ok(Sha512Bytes(hex_bytes_padded(s, None)?))
+    }
+
+    // Empty input is no bytes whatever the options, only the whitespace handling applies
+    fn from_any_str_with_options(s: &str, options: &ParseOptions) -> Result<Self, String> {
+        match options.trim_whitespace {
+            true => Self::from_any_str(s.trim()),
+            false => Self::from_any_str(s),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Sha512Bytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::types::serde_utils::deserialize_hex(deserializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Sha512Bytes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::types::serde_utils::serialize_hex(&self.0, serializer)
+    }
+}
@@ -0,0 +1,105 @@
+use crate::cairo_type::{CairoType, CairoWritable, Layout};
+use crate::stdlib::prelude::*;
+use crate::types::{FromAnyStr, ParseOptions};
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use core::fmt;
+
+// Longest ASCII string that fits in a felt
+pub const MAX_SHORT_STRING_LEN: usize = 31;
+
+/// Cairo short string: up to 31 ASCII characters packed big-endian into a felt, as written
+/// with `'abc'` literals in Cairo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ShortString(pub Felt252);
+
+impl ShortString {
+    pub fn to_ascii(&self) -> Result<String, String> {
+        let bytes = self.0.to_bytes_be();
+        let start = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+        let text = &bytes[start..];
+        if text.len() > MAX_SHORT_STRING_LEN || !text.is_ascii() {
+            return Err(format!("felt {} is not a short string", self.0));
+        }
+        Ok(String::from_utf8_lossy(text).into_owned())
+    }
+}
+
+impl fmt::Display for ShortString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_ascii() {
+            Ok(text) => f.write_str(&text),
+            Err(_) => write!(f, "{}", self.0),
+        }
+    }
+}
+
+impl CairoType for ShortString {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        let value = vm.get_integer(address)?;
+        Ok(Self(*value))
+    }
+}
+
+impl CairoWritable for ShortString {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        vm.insert_value(address, self.0)?;
+        Ok((address + 1)?)
+    }
+
+    fn n_fields() -> usize {
+        1
+    }
+
+    fn layout() -> Layout {
+        Layout::new("ShortString").felt("value")
+    }
+}
+
+impl FromAnyStr for ShortString {
+    fn from_any_str(s: &str) -> Result<Self, String> {
+        if !s.is_ascii() {
+            return Err(format!("short string {s:?} is not ASCII"));
+        }
+        if s.len() > MAX_SHORT_STRING_LEN {
+            return Err(format!(
+                "short string {s:?} is longer than {MAX_SHORT_STRING_LEN} characters"
+            ));
+        }
+        Ok(Self(Felt252::from_bytes_be_slice(s.as_bytes())))
+    }
+
+    // Text, where empty input and whitespace are content
+    fn from_any_str_with_options(s: &str, _options: &ParseOptions) -> Result<Self, String> {
+        Self::from_any_str(s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ShortString {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        ShortString::from_any_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ShortString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let text = self.to_ascii().map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&text)
+    }
+}
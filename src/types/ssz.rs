@@ -0,0 +1,156 @@
+//! SSZ encoded consensus-layer values as streams of 32-byte chunks, and SSZ merkle roots, for
+//! beacon chain light-client programs.
+
+use crate::cairo_type::{BaseCairoType, CairoType, CairoWritable, Layout};
+use crate::types::uint256_32::Uint256Bits32;
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use sha2::{Digest, Sha256};
+use ssz::Encode;
+
+pub const BYTES_PER_CHUNK: usize = 32;
+
+pub type Chunk = [u8; BYTES_PER_CHUNK];
+
+/// 32-byte chunks of an SSZ serialization, the last one right padded with zeros. Laid out as
+/// `(n_chunks, chunks)`, `chunks` pointing to a segment of `Uint256Bits32`, i.e. of pointers to
+/// the eight big-endian 32-bit words of each chunk, the input layout of `cairo_sha256`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SszChunks {
+    chunks: Vec<Chunk>,
+}
+
+impl SszChunks {
+    pub fn from_ssz<T: Encode>(value: &T) -> Self {
+        Self::from_bytes(&value.as_ssz_bytes())
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let chunks = bytes
+            .chunks(BYTES_PER_CHUNK)
+            .map(|chunk| {
+                let mut padded = [0u8; BYTES_PER_CHUNK];
+                padded[..chunk.len()].copy_from_slice(chunk);
+                padded
+            })
+            .collect();
+        Self { chunks }
+    }
+
+    /// Chunks that are already roots, e.g. the field roots of a container.
+    pub fn from_chunks(chunks: Vec<Chunk>) -> Self {
+        Self { chunks }
+    }
+
+    pub fn chunks(&self) -> &[Chunk] {
+        &self.chunks
+    }
+
+    pub fn len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    pub fn to_words(&self) -> Vec<Uint256Bits32> {
+        self.chunks
+            .iter()
+            .map(|chunk| Uint256Bits32::from_bytes_be(chunk))
+            .collect()
+    }
+
+    /// Merkle root of the chunks padded to a power of two. This is the `hash_tree_root` of
+    /// packed basic values and of containers built with `from_chunks` from their field roots,
+    /// lists still needing `mix_in_length`.
+    pub fn merkle_root(&self) -> Chunk {
+        merkleize(&self.chunks)
+    }
+
+    pub fn root(&self) -> Uint256Bits32 {
+        root_to_uint(&self.merkle_root())
+    }
+}
+
+impl CairoWritable for SszChunks {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let chunks = vm.add_memory_segment();
+        let mut ptr = chunks;
+        for word in self.to_words() {
+            ptr = word.to_memory(vm, ptr)?;
+        }
+        vm.insert_value(address, Felt252::from(self.chunks.len()))?;
+        vm.insert_value((address + 1)?, chunks)?;
+        Ok((address + 2)?)
+    }
+
+    fn n_fields() -> usize {
+        2
+    }
+
+    fn layout() -> Layout {
+        Layout::new("SszChunks").felt("n_chunks").pointer("chunks")
+    }
+}
+
+pub fn root_to_uint(root: &Chunk) -> Uint256Bits32 {
+    Uint256Bits32::from_bytes_be(root)
+}
+
+pub fn hash_pair(left: &Chunk, right: &Chunk) -> Chunk {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Merkle root of `chunks` padded with zero chunks to the next power of two.
+pub fn merkleize(chunks: &[Chunk]) -> Chunk {
+    merkleize_layers(chunks, chunks.len().next_power_of_two())
+}
+
+/// Merkle root of `chunks` padded to the next power of two of `limit`, as SSZ lists and
+/// bitlists are. Fails if there are more chunks than the limit.
+pub fn merkleize_with_limit(chunks: &[Chunk], limit: usize) -> Result<Chunk, String> {
+    if chunks.len() > limit {
+        return Err(format!(
+            "{} chunks exceed the limit of {limit}",
+            chunks.len()
+        ));
+    }
+    Ok(merkleize_layers(chunks, limit.next_power_of_two()))
+}
+
+// Zero subtrees are hashed once per level instead of being materialized
+fn merkleize_layers(chunks: &[Chunk], width: usize) -> Chunk {
+    let mut layer = chunks.to_vec();
+    let mut zero = [0u8; BYTES_PER_CHUNK];
+    let mut remaining = width.max(1);
+    while remaining > 1 {
+        if layer.len() % 2 == 1 {
+            layer.push(zero);
+        }
+        layer = layer
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], &pair[1]))
+            .collect();
+        zero = hash_pair(&zero, &zero);
+        remaining /= 2;
+    }
+    layer.first().copied().unwrap_or(zero)
+}
+
+/// Root of a list: its chunks root mixed with its length, as a little-endian 256-bit integer.
+pub fn mix_in_length(root: &Chunk, len: usize) -> Chunk {
+    let mut length = [0u8; BYTES_PER_CHUNK];
+    length[..8].copy_from_slice(&(len as u64).to_le_bytes());
+    hash_pair(root, &length)
+}
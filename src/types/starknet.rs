@@ -0,0 +1,169 @@
+//! Conversions from the starknet-rs and starknet-types-core felts, and the Cairo 1 calldata
+//! serialization, to write data fetched over starknet RPC into VM memory.
+
+use crate::cairo_type::{write_felts, CairoWritable, Layout};
+use crate::stdlib::prelude::*;
+use crate::types::byte_array::ByteArray;
+use crate::types::felt::Felt;
+use crate::types::uint256::Uint256;
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use starknet_ff::FieldElement;
+
+// `Felt252` is starknet-types-core's `Felt`, re-exported by cairo-vm
+impl From<Felt252> for Felt {
+    fn from(value: Felt252) -> Self {
+        Felt(value)
+    }
+}
+
+impl From<Felt> for Felt252 {
+    fn from(value: Felt) -> Self {
+        value.0
+    }
+}
+
+// Both use the Stark prime, so the conversions can't fail
+impl From<FieldElement> for Felt {
+    fn from(value: FieldElement) -> Self {
+        Felt(Felt252::from_bytes_be(&value.to_bytes_be()))
+    }
+}
+
+impl From<Felt> for FieldElement {
+    fn from(value: Felt) -> Self {
+        FieldElement::from_bytes_be(&value.0.to_bytes_be()).expect("felt is below the prime")
+    }
+}
+
+/// Cairo 1 serialization of a value as a list of felts, as sent in transaction calldata.
+pub trait ToCalldata {
+    fn to_calldata(&self) -> Vec<Felt252>;
+}
+
+impl ToCalldata for Felt252 {
+    fn to_calldata(&self) -> Vec<Felt252> {
+        vec![*self]
+    }
+}
+
+impl ToCalldata for Felt {
+    fn to_calldata(&self) -> Vec<Felt252> {
+        vec![self.0]
+    }
+}
+
+impl ToCalldata for FieldElement {
+    fn to_calldata(&self) -> Vec<Felt252> {
+        vec![Felt::from(*self).0]
+    }
+}
+
+// `u256 { low, high }`
+impl ToCalldata for Uint256 {
+    fn to_calldata(&self) -> Vec<Felt252> {
+        self.to_limbs().collect()
+    }
+}
+
+impl ToCalldata for ByteArray {
+    fn to_calldata(&self) -> Vec<Felt252> {
+        ByteArray::to_calldata(self)
+    }
+}
+
+// `Array<T>` and `Span<T>`: the length followed by each element
+impl<T: ToCalldata> ToCalldata for [T] {
+    fn to_calldata(&self) -> Vec<Felt252> {
+        let mut calldata = vec![Felt252::from(self.len())];
+        calldata.extend(self.iter().flat_map(ToCalldata::to_calldata));
+        calldata
+    }
+}
+
+impl<T: ToCalldata> ToCalldata for Vec<T> {
+    fn to_calldata(&self) -> Vec<Felt252> {
+        self.as_slice().to_calldata()
+    }
+}
+
+/// Calldata written as `(calldata_len, calldata)`, the length followed by a pointer to a new
+/// segment holding the felts. Deserializes from a JSON array of felts, as returned by RPC.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Calldata(pub Vec<Felt252>);
+
+impl Calldata {
+    // Concatenates the serializations of `values`, e.g. the arguments of a call
+    pub fn from_values(values: &[&dyn ToCalldata]) -> Self {
+        Calldata(
+            values
+                .iter()
+                .flat_map(|value| value.to_calldata())
+                .collect(),
+        )
+    }
+}
+
+impl From<Vec<Felt252>> for Calldata {
+    fn from(felts: Vec<Felt252>) -> Self {
+        Calldata(felts)
+    }
+}
+
+impl From<Vec<FieldElement>> for Calldata {
+    fn from(felts: Vec<FieldElement>) -> Self {
+        felts.into_iter().map(|felt| Felt::from(felt).0).collect()
+    }
+}
+
+impl FromIterator<Felt252> for Calldata {
+    fn from_iter<I: IntoIterator<Item = Felt252>>(iter: I) -> Self {
+        Calldata(iter.into_iter().collect())
+    }
+}
+
+impl CairoWritable for Calldata {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let segment = vm.add_memory_segment();
+        write_felts(vm, segment, self.0.iter().copied())?;
+        vm.insert_value(address, Felt252::from(self.0.len()))?;
+        vm.insert_value((address + 1)?, segment)?;
+        Ok((address + 2)?)
+    }
+
+    fn n_fields() -> usize {
+        2
+    }
+
+    fn layout() -> Layout {
+        Layout::new("Calldata")
+            .felt("calldata_len")
+            .pointer("calldata")
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Calldata {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let felts = Vec::<Felt>::deserialize(deserializer)?;
+        Ok(felts.into_iter().map(|felt| felt.0).collect())
+    }
+}
+
+impl serde::Serialize for Calldata {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.0.iter().map(|felt| felt.to_hex_string()))
+    }
+}
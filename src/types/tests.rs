@@ -6,7 +6,7 @@
 // - Vector deserialization for arrays of values
 #[cfg(test)]
 mod serde_tests {
-    use crate::types::{felt, keccak_bytes, uint256, uint256_32, uint384};
+    use crate::types::{bls12_381, ec_point, felt, keccak_bytes, uint256, uint256_32, uint384};
     use serde::Deserialize;
 
     // Test structs - now clean without any serde attributes!
@@ -56,7 +56,7 @@ mod serde_tests {
 
         #[test]
         fn test_uint256_serialize_hex() {
-            let uint = uint256::Uint256(num_bigint::BigUint::from(255u32));
+            let uint = uint256::Uint256::new(num_bigint::BigUint::from(255u32));
             let json = serde_json::to_string(&uint).unwrap();
             assert_eq!(
                 json,
@@ -66,7 +66,7 @@ mod serde_tests {
 
         #[test]
         fn test_uint384_serialize_hex() {
-            let uint = uint384::UInt384(num_bigint::BigUint::from(255u32));
+            let uint = uint384::UInt384::new(num_bigint::BigUint::from(255u32));
             let json = serde_json::to_string(&uint).unwrap();
             // UInt384 = 48 bytes = 96 hex chars + "0x" prefix
             assert_eq!(json, "\"0x0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000ff\"");
@@ -74,7 +74,7 @@ mod serde_tests {
 
         #[test]
         fn test_uint256_bits32_serialize_hex() {
-            let uint = uint256_32::Uint256Bits32(num_bigint::BigUint::from(255u32));
+            let uint = uint256_32::Uint256Bits32::new(num_bigint::BigUint::from(255u32));
             let json = serde_json::to_string(&uint).unwrap();
             assert_eq!(
                 json,
@@ -85,7 +85,7 @@ mod serde_tests {
         #[test]
         fn test_zero_values_serialize() {
             let felt_zero = felt::Felt(cairo_vm::Felt252::from(0));
-            let uint256_zero = uint256::Uint256(num_bigint::BigUint::from(0u32));
+            let uint256_zero = uint256::Uint256::new(num_bigint::BigUint::from(0u32));
 
             assert_eq!(
                 serde_json::to_string(&felt_zero).unwrap(),
@@ -101,8 +101,8 @@ mod serde_tests {
         fn test_large_values_serialize() {
             let large_val =
                 num_bigint::BigUint::parse_bytes(b"123456789abcdef123456789abcdef", 16).unwrap();
-            let uint256 = uint256::Uint256(large_val.clone());
-            let uint384 = uint384::UInt384(large_val);
+            let uint256 = uint256::Uint256::new(large_val.clone());
+            let uint384 = uint384::UInt384::new(large_val);
 
             let json256 = serde_json::to_string(&uint256).unwrap();
             let json384 = serde_json::to_string(&uint384).unwrap();
@@ -116,11 +116,36 @@ mod serde_tests {
 
         #[test]
         fn test_round_trip_serialization() {
-            let original = uint256::Uint256(num_bigint::BigUint::from(12345u32));
+            let original = uint256::Uint256::new(num_bigint::BigUint::from(12345u32));
             let json = serde_json::to_string(&original).unwrap();
             let deserialized: uint256::Uint256 = serde_json::from_str(&json).unwrap();
             assert_eq!(original, deserialized);
         }
+
+        #[test]
+        fn test_minimal_hex_serialization() {
+            use crate::types::serde_utils::{serialize_minimal_hex, serialize_minimal_hex_seq};
+            use serde::Serialize;
+
+            #[derive(Debug, Serialize, Deserialize, PartialEq)]
+            struct Event {
+                #[serde(serialize_with = "serialize_minimal_hex")]
+                felt: felt::Felt,
+                #[serde(serialize_with = "serialize_minimal_hex")]
+                amount: uint256::Uint256,
+                #[serde(serialize_with = "serialize_minimal_hex_seq")]
+                keys: Vec<felt::Felt>,
+            }
+
+            let event = Event {
+                felt: felt::Felt(cairo_vm::Felt252::from(255)),
+                amount: uint256::Uint256::new(num_bigint::BigUint::from(0u32)),
+                keys: vec![felt::Felt(cairo_vm::Felt252::from(16))],
+            };
+            let json = serde_json::to_string(&event).unwrap();
+            assert_eq!(json, r#"{"felt":"0xff","amount":"0x0","keys":["0x10"]}"#);
+            assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+        }
     }
 
     mod felt_tests {
@@ -135,6 +160,67 @@ mod serde_tests {
             assert_eq!(wrapper.value, expected);
         }
 
+        #[test]
+        fn test_felt_signed_string() {
+            let minus_one = felt::Felt(-Felt252::ONE);
+            assert_eq!(minus_one.to_signed_string(), "-1");
+            assert_eq!(felt::Felt(Felt252::from(42)).to_signed_string(), "42");
+            assert_eq!(felt::Felt::from_signed_str("-1").unwrap(), minus_one);
+            assert_eq!(
+                felt::Felt::from_signed_str("-0xff").unwrap(),
+                felt::Felt(-Felt252::from(255))
+            );
+
+            // (P - 1) / 2 is the largest positive value, the next one reads as negative
+            let half =
+                "1809251394333065606848661391547535052811553607665798349986546028067936010240";
+            let max = felt::Felt::from_signed_str(half).unwrap();
+            assert_eq!(max.to_signed_string(), half);
+            let min = max.wrapping_add(&felt::Felt(Felt252::ONE));
+            assert_eq!(min.to_signed_string(), format!("-{half}"));
+            assert_eq!(
+                felt::Felt::from_signed_str(&format!("-{half}")).unwrap(),
+                min
+            );
+            assert!(felt::Felt::from_signed_str(&min.0.to_string()).is_err());
+        }
+
+        #[test]
+        fn test_felt_rejects_values_above_prime() {
+            use crate::types::{serde_utils, FromAnyStr, FromAnyStrWithPolicy, OverflowPolicy};
+
+            let prime = "0x800000000000011000000000000000000000000000000000000000000000001";
+            let prime_dec =
+                "3618502788666131213697322783095070105623107215331596699973092056135872020481";
+            for input in [prime, prime_dec] {
+                let json = format!(r#"{{"value": "{input}"}}"#);
+                let err = serde_json::from_str::<FeltWrapper>(&json).unwrap_err();
+                assert!(err.to_string().contains(input), "{err}");
+            }
+            assert_eq!(
+                felt::Felt::from_any_str_with_policy(prime, OverflowPolicy::Wrap).unwrap(),
+                felt::Felt(Felt252::ZERO)
+            );
+            assert_eq!(
+                felt::Felt::from_any_str_with_policy(prime, OverflowPolicy::Saturate).unwrap(),
+                felt::Felt(Felt252::MAX)
+            );
+            let max = "0x800000000000011000000000000000000000000000000000000000000000000";
+            assert_eq!(
+                felt::Felt::from_any_str(max).unwrap(),
+                felt::Felt(Felt252::MAX)
+            );
+
+            #[derive(Debug, Deserialize)]
+            struct Reduced {
+                #[serde(deserialize_with = "serde_utils::deserialize_wrapping")]
+                value: felt::Felt,
+            }
+            let json = format!(r#"{{"value": "{prime}"}}"#);
+            let reduced: Reduced = serde_json::from_str(&json).unwrap();
+            assert_eq!(reduced.value, felt::Felt(Felt252::ZERO));
+        }
+
         #[test]
         fn test_felt_deserialize_from_string_hex_uppercase() {
             let json = r#"{"value": "0X1A2B3C"}"#;
@@ -195,6 +281,30 @@ mod serde_tests {
             assert_eq!(wrapper.values, expected);
         }
 
+        #[test]
+        fn test_felt_arithmetic() {
+            let f = |x: u64| felt::Felt(Felt252::from(x));
+            let max = felt::Felt(Felt252::MAX);
+
+            assert_eq!(f(7) + f(5), f(12));
+            assert_eq!(f(7) - f(5), f(2));
+            assert_eq!(f(7) * f(5), f(35));
+            assert_eq!(f(7) % f(5), f(2));
+            assert_eq!(f(12) >> 2, f(3));
+            assert_eq!(f(3) << 2, f(12));
+            assert!(f(5) < f(7) && max > f(7));
+
+            // Operators wrap around the prime, the checked variants don't
+            assert_eq!(max.clone() + f(1), f(0));
+            assert_eq!(f(0) - f(1), max);
+            assert_eq!(max.checked_add(&f(1)), None);
+            assert_eq!(f(0).checked_sub(&f(1)), None);
+            assert_eq!(max.checked_mul(&f(2)), None);
+            assert_eq!(f(1).checked_shl(252), None);
+            assert_eq!(f(1).checked_shl(251), Some(f(1) << 251));
+            assert_eq!(f(1).checked_rem(&f(0)), None);
+        }
+
         #[test]
         fn test_felt_deserialize_invalid_string() {
             let json = r#"{"value": "invalid_hex"}"#;
@@ -211,7 +321,7 @@ mod serde_tests {
         fn test_uint256_deserialize_from_string_hex() {
             let json = r#"{"value": "0x1a2b3c4d5e6f"}"#;
             let wrapper: Uint256Wrapper = serde_json::from_str(json).unwrap();
-            let expected = uint256::Uint256(BigUint::from(0x1a2b3c4d5e6fu64));
+            let expected = uint256::Uint256::new(BigUint::from(0x1a2b3c4d5e6fu64));
             assert_eq!(wrapper.value, expected);
         }
 
@@ -219,7 +329,7 @@ mod serde_tests {
         fn test_uint256_deserialize_from_string_decimal() {
             let json = r#"{"value": "123456789"}"#;
             let wrapper: Uint256Wrapper = serde_json::from_str(json).unwrap();
-            let expected = uint256::Uint256(BigUint::from(123456789u64));
+            let expected = uint256::Uint256::new(BigUint::from(123456789u64));
             assert_eq!(wrapper.value, expected);
         }
 
@@ -227,7 +337,7 @@ mod serde_tests {
         fn test_uint256_deserialize_from_number() {
             let json = r#"{"value": 123456789}"#;
             let wrapper: Uint256Wrapper = serde_json::from_str(json).unwrap();
-            let expected = uint256::Uint256(BigUint::from(123456789u64));
+            let expected = uint256::Uint256::new(BigUint::from(123456789u64));
             assert_eq!(wrapper.value, expected);
         }
 
@@ -237,7 +347,7 @@ mod serde_tests {
             let wrapper: Uint256Wrapper = serde_json::from_str(json).unwrap();
             // This is 2^256 - 1
             let max_256 = BigUint::from(2u64).pow(256) - BigUint::from(1u64);
-            let expected = uint256::Uint256(max_256);
+            let expected = uint256::Uint256::new(max_256);
             assert_eq!(wrapper.value, expected);
         }
 
@@ -245,7 +355,7 @@ mod serde_tests {
         fn test_uint256_deserialize_zero() {
             let json = r#"{"value": "0"}"#;
             let wrapper: Uint256Wrapper = serde_json::from_str(json).unwrap();
-            let expected = uint256::Uint256(BigUint::from(0u64));
+            let expected = uint256::Uint256::new(BigUint::from(0u64));
             assert_eq!(wrapper.value, expected);
         }
 
@@ -257,6 +367,52 @@ mod serde_tests {
             assert!(result.is_err());
         }
 
+        #[test]
+        fn test_uint256_arithmetic() {
+            let u = |x: u64| uint256::Uint256::new(BigUint::from(x));
+            let max = uint256::Uint256::new((BigUint::from(1u32) << 256) - 1u32);
+
+            assert_eq!(u(7) + u(5), u(12));
+            assert_eq!(&u(7) - &u(5), u(2));
+            assert_eq!(u(7) * u(5), u(35));
+            assert_eq!(u(7) / u(5), u(1));
+            assert_eq!(u(7) % u(5), u(2));
+            assert!(u(5) < u(7) && max > u(7));
+
+            // Carries cross the 64-bit words and the 128-bit limbs
+            let low = uint256::Uint256::new(BigUint::from(u128::MAX));
+            assert_eq!((&low + &u(1)).to_biguint(), BigUint::from(1u32) << 128);
+            assert_eq!(
+                &low * &low,
+                uint256::Uint256::new(BigUint::from(u128::MAX).pow(2))
+            );
+
+            assert_eq!(max.checked_add(&u(1)), None);
+            assert_eq!(max.wrapping_add(&u(1)), u(0));
+            assert_eq!(u(0).checked_sub(&u(1)), None);
+            assert_eq!(u(0).wrapping_sub(&u(1)), max);
+            assert_eq!(max.checked_mul(&u(2)), None);
+            assert_eq!(max.wrapping_mul(&u(2)), &max - &u(1));
+            assert_eq!(u(1).checked_div(&u(0)), None);
+            assert_eq!(u(1).checked_rem(&u(0)), None);
+
+            assert_eq!(
+                u(1) << 255,
+                uint256::Uint256::new(BigUint::from(1u32) << 255)
+            );
+            assert_eq!(&max >> 192, u(u64::MAX));
+            assert_eq!(max.checked_shl(200).unwrap() >> 200, u(0xff_ffff_ffff_ffff));
+            assert_eq!(u(1).checked_shl(256), None);
+            assert_eq!(u(1).wrapping_shl(257), u(2));
+        }
+
+        #[test]
+        #[should_panic(expected = "attempt to add with overflow")]
+        fn test_uint256_add_overflow_panics() {
+            let max = uint256::Uint256::new((BigUint::from(1u32) << 256) - 1u32);
+            let _ = max + uint256::Uint256::new(BigUint::from(1u32));
+        }
+
         #[test]
         fn test_uint256_deserialize_invalid_hex() {
             let json = r#"{"value": "0xGGGG"}"#;
@@ -273,7 +429,7 @@ mod serde_tests {
         fn test_uint384_deserialize_from_string_hex() {
             let json = r#"{"value": "0x1a2b3c4d5e6f"}"#;
             let wrapper: UInt384Wrapper = serde_json::from_str(json).unwrap();
-            let expected = uint384::UInt384(BigUint::from(0x1a2b3c4d5e6fu64));
+            let expected = uint384::UInt384::new(BigUint::from(0x1a2b3c4d5e6fu64));
             assert_eq!(wrapper.value, expected);
         }
 
@@ -281,7 +437,7 @@ mod serde_tests {
         fn test_uint384_deserialize_from_string_decimal() {
             let json = r#"{"value": "123456789012345678901234567890"}"#;
             let wrapper: UInt384Wrapper = serde_json::from_str(json).unwrap();
-            let expected = uint384::UInt384(
+            let expected = uint384::UInt384::new(
                 BigUint::parse_bytes(b"123456789012345678901234567890", 10).unwrap(),
             );
             assert_eq!(wrapper.value, expected);
@@ -291,7 +447,7 @@ mod serde_tests {
         fn test_uint384_deserialize_from_number() {
             let json = r#"{"value": 123456789}"#;
             let wrapper: UInt384Wrapper = serde_json::from_str(json).unwrap();
-            let expected = uint384::UInt384(BigUint::from(123456789u64));
+            let expected = uint384::UInt384::new(BigUint::from(123456789u64));
             assert_eq!(wrapper.value, expected);
         }
 
@@ -301,7 +457,7 @@ mod serde_tests {
             let json = r#"{"value": "0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"}"#;
             let wrapper: UInt384Wrapper = serde_json::from_str(json).unwrap();
             let max_384 = BigUint::from(2u64).pow(384) - BigUint::from(1u64);
-            let expected = uint384::UInt384(max_384);
+            let expected = uint384::UInt384::new(max_384);
             assert_eq!(wrapper.value, expected);
         }
 
@@ -309,7 +465,7 @@ mod serde_tests {
         fn test_uint384_deserialize_zero() {
             let json = r#"{"value": "0"}"#;
             let wrapper: UInt384Wrapper = serde_json::from_str(json).unwrap();
-            let expected = uint384::UInt384(BigUint::from(0u64));
+            let expected = uint384::UInt384::new(BigUint::from(0u64));
             assert_eq!(wrapper.value, expected);
         }
 
@@ -321,6 +477,32 @@ mod serde_tests {
             assert!(result.is_err());
         }
 
+        #[test]
+        fn test_uint384_arithmetic() {
+            let u = |x: BigUint| uint384::UInt384::new(x);
+            let p = BigUint::parse_bytes(
+                b"1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab",
+                16,
+            )
+            .unwrap();
+            let x: BigUint = BigUint::from(3u32) << 300;
+
+            assert_eq!((u(x.clone()) % u(p.clone())).to_biguint(), &x % &p);
+            assert_eq!((u(p.clone()) - u(x.clone())).to_biguint(), &p - &x);
+            assert_eq!((u(x.clone()) >> 297).to_biguint(), BigUint::from(24u32));
+            // 3 << 383 loses its top bit
+            assert_eq!(
+                u(x.clone()).checked_shl(83),
+                Some(u(BigUint::from(1u32) << 383))
+            );
+            assert!(u(x.clone()).checked_mul(&u(x.clone())).is_none());
+            assert_eq!(
+                u(x.clone()).wrapping_mul(&u(x.clone())).to_biguint(),
+                (&x * &x) % (BigUint::from(1u32) << 384)
+            );
+            assert!(u(x) < u(p));
+        }
+
         #[test]
         fn test_uint384_deserialize_invalid_hex() {
             let json = r#"{"value": "0xZZZZ"}"#;
@@ -337,7 +519,7 @@ mod serde_tests {
         fn test_uint256_bits32_deserialize_from_string_hex() {
             let json = r#"{"value": "0x1a2b3c4d5e6f"}"#;
             let wrapper: Uint256Bits32Wrapper = serde_json::from_str(json).unwrap();
-            let expected = uint256_32::Uint256Bits32(BigUint::from(0x1a2b3c4d5e6fu64));
+            let expected = uint256_32::Uint256Bits32::new(BigUint::from(0x1a2b3c4d5e6fu64));
             assert_eq!(wrapper.value, expected);
         }
 
@@ -345,7 +527,7 @@ mod serde_tests {
         fn test_uint256_bits32_deserialize_from_string_decimal() {
             let json = r#"{"value": "123456789"}"#;
             let wrapper: Uint256Bits32Wrapper = serde_json::from_str(json).unwrap();
-            let expected = uint256_32::Uint256Bits32(BigUint::from(123456789u64));
+            let expected = uint256_32::Uint256Bits32::new(BigUint::from(123456789u64));
             assert_eq!(wrapper.value, expected);
         }
 
@@ -353,7 +535,7 @@ mod serde_tests {
         fn test_uint256_bits32_deserialize_from_number() {
             let json = r#"{"value": 123456789}"#;
             let wrapper: Uint256Bits32Wrapper = serde_json::from_str(json).unwrap();
-            let expected = uint256_32::Uint256Bits32(BigUint::from(123456789u64));
+            let expected = uint256_32::Uint256Bits32::new(BigUint::from(123456789u64));
             assert_eq!(wrapper.value, expected);
         }
 
@@ -362,7 +544,7 @@ mod serde_tests {
             let json = r#"{"value": "0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"}"#;
             let wrapper: Uint256Bits32Wrapper = serde_json::from_str(json).unwrap();
             let max_256 = BigUint::from(2u64).pow(256) - BigUint::from(1u64);
-            let expected = uint256_32::Uint256Bits32(max_256);
+            let expected = uint256_32::Uint256Bits32::new(max_256);
             assert_eq!(wrapper.value, expected);
         }
 
@@ -370,7 +552,7 @@ mod serde_tests {
         fn test_uint256_bits32_deserialize_zero() {
             let json = r#"{"value": "0"}"#;
             let wrapper: Uint256Bits32Wrapper = serde_json::from_str(json).unwrap();
-            let expected = uint256_32::Uint256Bits32(BigUint::from(0u64));
+            let expected = uint256_32::Uint256Bits32::new(BigUint::from(0u64));
             assert_eq!(wrapper.value, expected);
         }
 
@@ -433,137 +615,2196 @@ mod serde_tests {
         }
     }
 
-    mod edge_case_tests {
+    mod chunked_bytes_tests {
         use super::*;
+        use crate::cairo_type::CairoWritable;
+        use crate::types::chunked_bytes::{Blake2sWords, KeccakWords, LittleEndian, Sha256Words};
+        use crate::types::sha256_bytes::Sha256Bytes;
+        use cairo_vm::{vm::vm_core::VirtualMachine, Felt252};
 
         #[test]
-        fn test_hex_with_underscore_separators() {
-            let json = r#"{"value": "0x1a_2b_3c_4d"}"#;
-            let wrapper: FeltWrapper = serde_json::from_str(json).unwrap();
-            let expected = felt::Felt(cairo_vm::Felt252::from(0x1a2b3c4du64));
-            assert_eq!(wrapper.value, expected);
+        fn test_chunked_bytes_layouts() {
+            let message: Vec<u8> = (1..=11).collect();
+            let keccak = keccak_bytes::KeccakBytes(message.clone());
+            assert_eq!(
+                KeccakWords::from(keccak.clone()).to_chunks(),
+                keccak.to_limbs()
+            );
+            let sha256 = Sha256Words::new(message.clone());
+            assert_eq!(sha256.to_chunks(), Sha256Bytes(message.clone()).to_words());
+            assert_eq!(
+                Blake2sWords::new(message.clone()).to_chunks(),
+                [0x04030201u64, 0x08070605, 0x0b0a09].map(Felt252::from)
+            );
+            // The partial last word is the value of its bytes
+            assert_eq!(sha256.to_chunks()[2], Felt252::from(0x090a0bu64));
+
+            let words = sha256.to_chunks();
+            assert_eq!(Sha256Words::from_chunks(&words, 11).unwrap(), sha256);
+            let blake = sha256.clone().rechunk::<4, LittleEndian>();
+            assert_eq!(
+                Blake2sWords::from_chunks(&blake.to_chunks(), 11)
+                    .unwrap()
+                    .bytes,
+                message
+            );
+            assert_eq!(
+                Sha256Words::from_chunks(&words, 13).unwrap_err(),
+                "13 bytes take 4 words of 4 bytes, got 3"
+            );
+            assert!(Sha256Words::from_chunks(&words, 10)
+                .unwrap_err()
+                .ends_with("does not fit in 2 bytes"));
+
+            let mut vm = VirtualMachine::new(false, false);
+            let address = vm.add_memory_segment();
+            sha256.to_memory(&mut vm, address).unwrap();
+            let chunks = vm.get_relocatable(address).unwrap();
+            assert_eq!(
+                *vm.get_integer(chunks).unwrap(),
+                Felt252::from(0x01020304u64)
+            );
+        }
+    }
+
+    mod bytes_tests {
+        use super::*;
+        use crate::types::{bytes::Bytes, serde_utils, FromAnyStr};
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Base64Wrapper {
+            #[serde(deserialize_with = "serde_utils::deserialize_base64")]
+            value: Bytes,
         }
 
         #[test]
-        fn test_odd_length_hex() {
-            let json = r#"{"value": "0x1"}"#;
-            let wrapper: FeltWrapper = serde_json::from_str(json).unwrap();
-            let expected = felt::Felt(cairo_vm::Felt252::from(1u64));
-            assert_eq!(wrapper.value, expected);
+        fn test_keccak_bytes_from_base64_prefix() {
+            let json = r#"{"value": "base64:aGVsbG8="}"#;
+            let wrapper: KeccakBytesWrapper = serde_json::from_str(json).unwrap();
+            assert_eq!(wrapper.value.0, b"hello".to_vec());
+
+            // Unpadded and URL-safe alphabets decode too
+            let bytes = Bytes::from_any_str("base64:aGVsbG8").unwrap();
+            assert_eq!(bytes.0, b"hello".to_vec());
+            let bytes = Bytes::from_any_str("base64:-_8").unwrap();
+            assert_eq!(bytes.0, vec![0xfb, 0xff]);
+
+            assert!(Bytes::from_any_str("base64:a$==").is_err());
         }
 
         #[test]
-        fn test_empty_string_behavior() {
-            // Empty string should either fail or return zero - let's document the actual behavior
-            let json = r#"{"value": ""}"#;
-            let result: Result<FeltWrapper, _> = serde_json::from_str(json);
-            match result {
-                Ok(wrapper) => {
-                    // If it succeeds, it should be zero
-                    let expected = felt::Felt(cairo_vm::Felt252::ZERO);
-                    assert_eq!(wrapper.value, expected);
-                }
-                Err(_) => {
-                    // This is also acceptable behavior for empty string
-                }
-            }
+        fn test_bytes_from_json_array() {
+            let json = r#"{"value": [104, 105, 0, 255]}"#;
+            let wrapper: KeccakBytesWrapper = serde_json::from_str(json).unwrap();
+            assert_eq!(wrapper.value.0, vec![104, 105, 0, 255]);
+
+            let bytes: Bytes = serde_json::from_str("[]").unwrap();
+            assert!(bytes.is_empty());
+            assert!(serde_json::from_str::<Bytes>("[256]").is_err());
         }
 
         #[test]
-        fn test_null_value_fails() {
-            let json = r#"{"value": null}"#;
-            let result: Result<FeltWrapper, _> = serde_json::from_str(json);
-            assert!(result.is_err());
+        fn test_deserialize_base64_wrapper() {
+            let wrapper: Base64Wrapper = serde_json::from_str(r#"{"value": "aGk="}"#).unwrap();
+            assert_eq!(wrapper.value.0, b"hi".to_vec());
+            let wrapper: Base64Wrapper =
+                serde_json::from_str(r#"{"value": "base64:aGk="}"#).unwrap();
+            assert_eq!(wrapper.value.0, b"hi".to_vec());
+            // Strings are always base64 in this mode, `0x00` is not the hex byte
+            let wrapper: Base64Wrapper = serde_json::from_str(r#"{"value": "0x00"}"#).unwrap();
+            assert_eq!(wrapper.value.0, vec![0xd3, 0x1d, 0x34]);
         }
 
         #[test]
-        fn test_boolean_fails() {
-            let json = r#"{"value": true}"#;
-            let result: Result<FeltWrapper, _> = serde_json::from_str(json);
-            assert!(result.is_err());
+        fn test_bytes_roundtrip_and_conversions() {
+            let bytes = Bytes(vec![0x00, 0x01, 0xff]);
+            let json = serde_json::to_string(&bytes).unwrap();
+            assert_eq!(json, "\"0x0001ff\"");
+            assert_eq!(serde_json::from_str::<Bytes>(&json).unwrap(), bytes);
+
+            let encoded = serde_utils::encode(&bytes).unwrap();
+            assert_eq!(serde_utils::decode::<Bytes>(&encoded).unwrap(), bytes);
+
+            let keccak: keccak_bytes::KeccakBytes = bytes.clone().into();
+            assert_eq!(keccak.0, bytes.0);
+        }
+    }
+
+    mod fixed_bytes_tests {
+        use super::*;
+        use crate::cairo_type::{CairoType, CairoWritable};
+        use crate::types::fixed_bytes::{Bytes20, Bytes32, Bytes8};
+        use crate::types::{serde_utils, FromAnyStr};
+        use cairo_vm::{vm::vm_core::VirtualMachine, Felt252};
+        use num_bigint::BigUint;
+
+        #[test]
+        fn test_fixed_bytes_parse_exact_width() {
+            let address =
+                Bytes20::from_any_str("0x00000000219ab540356cbb839cbe05303d7705fa").unwrap();
+            assert_eq!(address.0[19], 0xfa);
+            // A hash where an address is expected, and a short value, are both rejected
+            let hash = format!("0x{}", "ab".repeat(32));
+            assert!(Bytes20::from_any_str(&hash).is_err());
+            assert!(Bytes20::from_any_str("0x1234").is_err());
+            assert!(Bytes8::from_any_str("").is_err());
+            assert_eq!(Bytes32::from_any_str(&hash).unwrap().0, [0xab; 32]);
+            assert_eq!(
+                Bytes8::from_any_str("base64:AQIDBAUGBwg=").unwrap().0,
+                [1, 2, 3, 4, 5, 6, 7, 8]
+            );
         }
 
         #[test]
-        fn test_array_fails() {
-            let json = r#"{"value": [1, 2, 3]}"#;
-            let result: Result<FeltWrapper, _> = serde_json::from_str(json);
-            assert!(result.is_err());
+        fn test_fixed_bytes_serde_roundtrip() {
+            let bytes = Bytes8::from([0, 1, 2, 3, 4, 5, 6, 0xff]);
+            let json = serde_json::to_string(&bytes).unwrap();
+            assert_eq!(json, "\"0x00010203040506ff\"");
+            assert_eq!(serde_json::from_str::<Bytes8>(&json).unwrap(), bytes);
+
+            let hash = Bytes32::from([0xab; 32]);
+            let encoded = serde_utils::encode(&hash).unwrap();
+            assert_eq!(serde_utils::decode::<Bytes32>(&encoded).unwrap(), hash);
         }
 
         #[test]
-        fn test_object_fails() {
-            let json = r#"{"value": {"nested": "object"}}"#;
-            let result: Result<FeltWrapper, _> = serde_json::from_str(json);
-            assert!(result.is_err());
+        fn test_fixed_bytes_memory_layout() {
+            let mut vm = VirtualMachine::new(false, false);
+            let base = vm.add_memory_segment();
+            let mut raw = [0u8; 32];
+            raw[0] = 0x01;
+            raw[31] = 0x02;
+            let hash = Bytes32::from(raw);
+            let address = Bytes20::from([0x11; 20]);
+
+            assert_eq!(Bytes32::n_fields(), 2);
+            assert_eq!(Bytes20::n_fields(), 1);
+            let next = hash.to_memory(&mut vm, base).unwrap();
+            let end = address.to_memory(&mut vm, next).unwrap();
+            assert_eq!(end, (base + 3usize).unwrap());
+
+            // Same limbs as the `Uint256` of the same value, low first
+            assert_eq!(*vm.get_integer(base).unwrap(), Felt252::from(2));
+            assert_eq!(
+                *vm.get_integer(next).unwrap(),
+                Felt252::from_bytes_be_slice(&[0x11; 20])
+            );
+            let uint = uint256::Uint256::from_memory(&vm, base).unwrap();
+            assert_eq!(uint, uint256::Uint256::from(hash));
+            assert_eq!(Bytes32::from_memory(&vm, base).unwrap(), hash);
+            assert_eq!(Bytes20::from_memory(&vm, next).unwrap(), address);
+
+            // A felt wider than 8 bytes is not a Bytes8
+            assert!(Bytes8::from_memory(&vm, next).is_err());
+        }
+
+        #[test]
+        fn test_fixed_bytes_conversions() {
+            let value = uint256::Uint256::new(BigUint::from(0xdeadbeefu32));
+            let address = Bytes20::try_from(value.clone()).unwrap();
+            assert_eq!(&address.0[16..], &[0xde, 0xad, 0xbe, 0xef]);
+            assert_eq!(uint256::Uint256::from(address), value);
+
+            let wide = uint256::Uint256::new(BigUint::from(1u32) << 200usize);
+            assert!(Bytes20::try_from(wide).is_err());
+
+            let felt = felt::Felt::from(Bytes8::from([0, 0, 0, 0, 0, 0, 1, 0]));
+            assert_eq!(felt.0, Felt252::from(256));
+            assert_eq!(Bytes8::try_from(felt).unwrap().0[6], 1);
+
+            assert!(felt::Felt::try_from(Bytes32::from([0xff; 32])).is_err());
+            let one = felt::Felt(Felt252::ONE);
+            assert_eq!(
+                felt::Felt::try_from(Bytes32::from(one.clone())).unwrap(),
+                one
+            );
         }
     }
 
-    mod comprehensive_format_tests {
+    mod rlp_tests {
+        use crate::types::rlp::{list_items, RlpBytes, RlpHeader, RlpItem};
+        use crate::types::serde_utils;
+
+        fn bytes(value: &[u8]) -> RlpItem {
+            RlpItem::Bytes(value.to_vec())
+        }
+
+        #[test]
+        fn test_rlp_encoding_vectors() {
+            assert_eq!(bytes(b"dog").encode(), hex::decode("83646f67").unwrap());
+            let list = RlpItem::List(vec![bytes(b"cat"), bytes(b"dog")]);
+            assert_eq!(list.encode(), hex::decode("c88363617483646f67").unwrap());
+            assert_eq!(bytes(b"").encode(), [0x80]);
+            assert_eq!(RlpItem::uint(0).encode(), [0x80]);
+            assert_eq!(RlpItem::uint(15).encode(), [0x0f]);
+            assert_eq!(RlpItem::uint(1024).encode(), [0x82, 0x04, 0x00]);
+            assert_eq!(RlpItem::List(vec![]).encode(), [0xc0]);
+
+            let long = bytes(&[0xaa; 56]);
+            let encoded = long.encode();
+            assert_eq!(&encoded[..2], &[0xb8, 56]);
+            assert_eq!(RlpItem::decode(&encoded).unwrap(), long);
+
+            // [ [], [[]], [ [], [[]] ] ]
+            let empty = || RlpItem::List(vec![]);
+            let nested = RlpItem::List(vec![
+                empty(),
+                RlpItem::List(vec![empty()]),
+                RlpItem::List(vec![empty(), RlpItem::List(vec![empty()])]),
+            ]);
+            let encoded = nested.encode();
+            assert_eq!(encoded, hex::decode("c7c0c1c0c3c0c1c0").unwrap());
+            assert_eq!(RlpItem::decode(&encoded).unwrap(), nested);
+            assert_eq!(list_items(&encoded).unwrap().len(), 3);
+        }
+
+        #[test]
+        fn test_rlp_decoding_errors() {
+            assert!(RlpItem::decode(&[]).is_err());
+            // Payload past the end, and trailing bytes
+            assert!(RlpItem::decode(&[0x83, 0x64, 0x6f]).is_err());
+            assert!(RlpItem::decode(&[0x01, 0x02]).is_err());
+            assert!(RlpHeader::decode(&[0xb8]).is_err());
+            assert!(list_items(&[0x83, 0x64, 0x6f, 0x67]).is_err());
+
+            // A length of `usize::MAX` must not overflow the item bounds
+            let huge = [0xbf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff];
+            assert_eq!(
+                RlpHeader::decode(&huge).unwrap_err(),
+                "RLP length overflows"
+            );
+            assert!(RlpItem::decode(&huge).is_err());
+            let mut list = huge;
+            list[0] = 0xff;
+            assert!(list_items(&list).is_err());
+        }
+
+        #[test]
+        fn test_rlp_bytes_serde() {
+            let json = r#"["0x01", [42, "0xcafe"], "base64:aGk="]"#;
+            let rlp: RlpBytes = serde_json::from_str(json).unwrap();
+            let expected = RlpItem::List(vec![
+                bytes(&[0x01]),
+                RlpItem::List(vec![RlpItem::uint(42), bytes(&[0xca, 0xfe])]),
+                bytes(b"hi"),
+            ]);
+            assert_eq!(rlp.0, expected);
+            assert_eq!(rlp.n_bytes(), rlp.encoded().len());
+
+            let json = serde_json::to_string(&rlp).unwrap();
+            assert_eq!(json, r#"["0x01",["0x2a","0xcafe"],"0x6869"]"#);
+            assert_eq!(serde_json::from_str::<RlpBytes>(&json).unwrap(), rlp);
+
+            // Binary formats carry the encoding
+            let encoded = serde_utils::encode(&rlp).unwrap();
+            assert_eq!(serde_utils::decode::<RlpBytes>(&encoded).unwrap(), rlp);
+        }
+    }
+
+    mod beacon_tests {
+        use crate::cairo_type::CairoWritable;
+        use crate::types::beacon::{BeaconBlockHeader, ExecutionPayloadHeader};
+        use cairo_vm::{vm::vm_core::VirtualMachine, Felt252};
+
+        const BEACON_HEADER: &str = r#"{
+            "slot": "8000000",
+            "proposer_index": "12345",
+            "parent_root": "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "state_root": "0x2222222222222222222222222222222222222222222222222222222222222222",
+            "body_root": "0x3333333333333333333333333333333333333333333333333333333333333333"
+        }"#;
+
+        const EXECUTION_HEADER: &str = r#"{
+            "parent_hash": "0x0101010101010101010101010101010101010101010101010101010101010101",
+            "fee_recipient": "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "state_root": "0x0202020202020202020202020202020202020202020202020202020202020202",
+            "receipts_root": "0x0303030303030303030303030303030303030303030303030303030303030303",
+            "logs_bloom": "0x@BLOOM@",
+            "prev_randao": "0x0404040404040404040404040404040404040404040404040404040404040404",
+            "block_number": "20000000",
+            "gas_limit": "30000000",
+            "gas_used": "15000000",
+            "timestamp": "1700000000",
+            "extra_data": "0x6265617665726275696c642e6f7267",
+            "base_fee_per_gas": "7000000000",
+            "block_hash": "0x0505050505050505050505050505050505050505050505050505050505050505",
+            "transactions_root": "0x0606060606060606060606060606060606060606060606060606060606060606",
+            "withdrawals_root": "0x0707070707070707070707070707070707070707070707070707070707070707",
+            "blob_gas_used": "131072",
+            "excess_blob_gas": "0"
+        }"#;
+
+        fn execution_header() -> ExecutionPayloadHeader {
+            serde_json::from_str(&EXECUTION_HEADER.replace("@BLOOM@", &"00".repeat(256))).unwrap()
+        }
+
+        #[test]
+        fn test_beacon_headers_serde() {
+            let header: BeaconBlockHeader = serde_json::from_str(BEACON_HEADER).unwrap();
+            assert_eq!(header.slot.0, 8000000);
+            assert_eq!(header.state_root.0, [0x22; 32]);
+            let json = serde_json::to_value(&header).unwrap();
+            assert_eq!(json["slot"], "8000000");
+            assert_eq!(
+                json,
+                serde_json::from_str::<serde_json::Value>(BEACON_HEADER).unwrap()
+            );
+
+            let header = execution_header();
+            assert_eq!(header.fee_recipient.0, [0xaa; 20]);
+            assert_eq!(header.extra_data.0, b"beaverbuild.org".to_vec());
+            let json = serde_json::to_value(&header).unwrap();
+            assert_eq!(json["base_fee_per_gas"], "7000000000");
+            assert_eq!(
+                serde_json::from_value::<ExecutionPayloadHeader>(json).unwrap(),
+                header
+            );
+
+            // The bloom is exactly 256 bytes
+            let short_bloom = EXECUTION_HEADER.replace("@BLOOM@", &"00".repeat(255));
+            assert!(serde_json::from_str::<ExecutionPayloadHeader>(&short_bloom).is_err());
+        }
+
+        #[test]
+        fn test_beacon_headers_memory() {
+            let mut vm = VirtualMachine::new(false, false);
+            let base = vm.add_memory_segment();
+            let header: BeaconBlockHeader = serde_json::from_str(BEACON_HEADER).unwrap();
+            let next = header.to_memory(&mut vm, base).unwrap();
+            assert_eq!(next, (base + BeaconBlockHeader::n_fields()).unwrap());
+            assert_eq!(*vm.get_integer(base).unwrap(), Felt252::from(8000000));
+            // Roots are `Uint256`s, low limb first
+            assert_eq!(
+                *vm.get_integer((base + 4usize).unwrap()).unwrap(),
+                Felt252::from(u128::from_be_bytes([0x22; 16]))
+            );
+
+            let base = vm.add_memory_segment();
+            let header = execution_header();
+            let next = header.to_memory(&mut vm, base).unwrap();
+            assert_eq!(next, (base + ExecutionPayloadHeader::n_fields()).unwrap());
+            assert_eq!(
+                *vm.get_integer((base + 2usize).unwrap()).unwrap(),
+                Felt252::from_bytes_be_slice(&[0xaa; 20])
+            );
+            assert_eq!(
+                *vm.get_integer((base + 10usize).unwrap()).unwrap(),
+                Felt252::from(20000000)
+            );
+            assert_eq!(
+                *vm.get_integer((base + 14usize).unwrap()).unwrap(),
+                Felt252::from(15)
+            );
+            let bloom = vm.get_relocatable((base + 7usize).unwrap()).unwrap();
+            assert_eq!(vm.get_integer_range(bloom, 64).unwrap().len(), 64);
+            assert_eq!(
+                *vm.get_integer((base + 16usize).unwrap()).unwrap(),
+                Felt252::from(7000000000u64)
+            );
+            assert_eq!(
+                *vm.get_integer((base + 25usize).unwrap()).unwrap(),
+                Felt252::ZERO
+            );
+
+            let mut too_long = execution_header();
+            too_long.extra_data.0 = vec![0; 33];
+            let base = vm.add_memory_segment();
+            assert!(too_long.to_memory(&mut vm, base).is_err());
+        }
+
+        #[cfg(feature = "ssz")]
+        #[test]
+        fn test_beacon_headers_hash_tree_root() {
+            let header: BeaconBlockHeader = serde_json::from_str(BEACON_HEADER).unwrap();
+            assert_eq!(
+                hex::encode(header.hash_tree_root()),
+                "e98fb17e54d32d6ce4c591d7b56a0554259c748ead0098384f41eb36938586e7"
+            );
+            assert_eq!(
+                hex::encode(execution_header().hash_tree_root().unwrap()),
+                "29f2ab36b1495698895f4d7d5bb5b83734df5557a7b6a6ea683914a49556f423"
+            );
+        }
+    }
+
+    #[cfg(feature = "alloy")]
+    mod alloy_tests {
         use super::*;
-        use cairo_vm::Felt252;
+        use crate::types::alloy::serialize_quantity;
+        use alloy_primitives::{address, b256, Address, Bytes, B256, U256};
+        use num_bigint::BigUint;
 
         #[test]
-        fn test_all_types_with_same_value() {
-            let value_str = "12345";
-            let value_num = 12345u64;
+        fn test_alloy_conversions() {
+            let value = U256::from(0x1234u64) << 200;
+            let uint = uint256::Uint256::from(value);
+            assert_eq!(uint.to_biguint(), BigUint::from(0x1234u64) << 200);
+            assert_eq!(uint.to_u256(), value);
+            assert_eq!(uint256::Uint256::from(B256::from(value)), uint);
+            assert_eq!(B256::from(uint.clone()), B256::from(value));
+
+            let address = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+            let uint = uint256::Uint256::from(address);
+            assert_eq!(
+                B256::from(uint.clone()),
+                b256!("000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045")
+            );
+            assert_eq!(Address::try_from(uint).unwrap(), address);
+            assert!(Address::try_from(uint256::Uint256::from(value)).is_err());
 
-            // Test Felt
-            let felt_json = format!(r#"{{"value": "{value_str}"}}"#);
-            let felt_wrapper: FeltWrapper = serde_json::from_str(&felt_json).unwrap();
-            assert_eq!(felt_wrapper.value, felt::Felt(Felt252::from(value_num)));
+            let bytes = Bytes::from_static(&[0xde, 0xad, 0xbe, 0xef]);
+            let keccak = keccak_bytes::KeccakBytes::from(bytes.clone());
+            assert_eq!(keccak.0, vec![0xde, 0xad, 0xbe, 0xef]);
+            assert_eq!(Bytes::from(keccak), bytes);
+        }
 
-            let felt_json_num = format!(r#"{{"value": {value_num}}}"#);
-            let felt_wrapper_num: FeltWrapper = serde_json::from_str(&felt_json_num).unwrap();
-            assert_eq!(felt_wrapper_num.value, felt::Felt(Felt252::from(value_num)));
+        #[test]
+        fn test_rpc_hex_formats() {
+            #[derive(Debug, serde::Serialize, Deserialize)]
+            struct Transaction {
+                #[serde(serialize_with = "serialize_quantity")]
+                value: uint256::Uint256,
+                hash: uint256::Uint256,
+                input: keccak_bytes::KeccakBytes,
+            }
 
-            // Test Uint256
-            let uint256_json = format!(r#"{{"value": "{value_str}"}}"#);
-            let uint256_wrapper: Uint256Wrapper = serde_json::from_str(&uint256_json).unwrap();
+            let json = r#"{"value": "0x1bc16d674ec80000", "hash": "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b", "input": "0x"}"#;
+            let tx: Transaction = serde_json::from_str(json).unwrap();
+            assert_eq!(tx.value.to_u256(), U256::from(2_000_000_000_000_000_000u64));
+            assert!(tx.input.0.is_empty());
+            let reserialized = serde_json::to_value(&tx).unwrap();
+            assert_eq!(reserialized["value"], "0x1bc16d674ec80000");
             assert_eq!(
-                uint256_wrapper.value,
-                uint256::Uint256(num_bigint::BigUint::from(value_num))
+                reserialized["hash"],
+                "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b"
             );
+            assert_eq!(reserialized["input"], "0x");
+
+            let zero = serde_json::to_value(Transaction {
+                value: uint256::Uint256::new(BigUint::from(0u32)),
+                ..tx
+            })
+            .unwrap();
+            assert_eq!(zero["value"], "0x0");
+        }
+    }
 
-            let uint256_json_num = format!(r#"{{"value": {value_num}}}"#);
-            let uint256_wrapper_num: Uint256Wrapper =
-                serde_json::from_str(&uint256_json_num).unwrap();
+    #[cfg(feature = "starknet")]
+    mod starknet_tests {
+        use super::*;
+        use crate::cairo_type::CairoWritable;
+        use crate::types::byte_array::ByteArray;
+        use crate::types::starknet::{Calldata, ToCalldata};
+        use cairo_vm::{vm::vm_core::VirtualMachine, Felt252};
+        use num_bigint::BigUint;
+        use starknet_ff::FieldElement;
+
+        #[test]
+        fn test_starknet_felt_conversions() {
+            let element = FieldElement::from_hex_be(
+                "0x49d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7",
+            )
+            .unwrap();
+            let felt = felt::Felt::from(element);
             assert_eq!(
-                uint256_wrapper_num.value,
-                uint256::Uint256(num_bigint::BigUint::from(value_num))
+                felt.0,
+                Felt252::from_hex(
+                    "0x49d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7"
+                )
+                .unwrap()
             );
+            assert_eq!(FieldElement::from(felt.clone()), element);
+            assert_eq!(Felt252::from(felt.clone()), felt.0);
+            assert_eq!(felt::Felt::from(Felt252::MAX), felt::Felt(Felt252::MAX));
+        }
 
-            // Test UInt384
-            let uint384_json = format!(r#"{{"value": "{value_str}"}}"#);
-            let uint384_wrapper: UInt384Wrapper = serde_json::from_str(&uint384_json).unwrap();
+        #[test]
+        fn test_calldata() {
+            let amount = uint256::Uint256::new((BigUint::from(1u32) << 128) + 5u32);
+            let recipients = vec![felt::Felt(Felt252::from(7)), felt::Felt(Felt252::from(8))];
+            let calldata = Calldata::from_values(&[&FieldElement::ONE, &amount, &recipients]);
+            let expected: Vec<Felt252> = [1u64, 5, 1, 2, 7, 8].map(Felt252::from).to_vec();
+            assert_eq!(calldata.0, expected);
+            assert_eq!(ToCalldata::to_calldata(&ByteArray(b"hi".to_vec())).len(), 3);
+
+            let json = r#"["0x1", "0x5", "0x1", "0x2", "0x7", "0x8"]"#;
+            assert_eq!(serde_json::from_str::<Calldata>(json).unwrap(), calldata);
+            let reserialized: Calldata =
+                serde_json::from_str(&serde_json::to_string(&calldata).unwrap()).unwrap();
+            assert_eq!(reserialized, calldata);
+
+            let mut vm = VirtualMachine::new(false, false);
+            let address = vm.add_memory_segment();
+            let end = calldata.to_memory(&mut vm, address).unwrap();
+            assert_eq!(end, (address + 2usize).unwrap());
+            assert_eq!(*vm.get_integer(address).unwrap(), Felt252::from(6));
+            let data = vm.get_relocatable((address + 1usize).unwrap()).unwrap();
             assert_eq!(
-                uint384_wrapper.value,
-                uint384::UInt384(num_bigint::BigUint::from(value_num))
+                *vm.get_integer((data + 5usize).unwrap()).unwrap(),
+                Felt252::from(8)
             );
+        }
+    }
 
-            let uint384_json_num = format!(r#"{{"value": {value_num}}}"#);
-            let uint384_wrapper_num: UInt384Wrapper =
-                serde_json::from_str(&uint384_json_num).unwrap();
+    #[cfg(feature = "schemars")]
+    mod schema_tests {
+        use super::*;
+        use schemars::{schema_for, JsonSchema};
+
+        #[test]
+        fn test_integer_schemas() {
+            let schema = schema_for!(felt::Felt).to_value();
+            let any_of = schema["anyOf"].as_array().unwrap();
+            assert_eq!(any_of[0]["type"], "string");
+            assert_eq!(any_of[1]["type"], "integer");
+            assert_eq!(any_of[1]["maximum"], u64::MAX);
+
+            assert_eq!(schema_for!(uint256::Uint256).to_value()["title"], "Uint256");
+            assert_eq!(schema_for!(uint384::UInt384).to_value()["title"], "Uint384");
             assert_eq!(
-                uint384_wrapper_num.value,
-                uint384::UInt384(num_bigint::BigUint::from(value_num))
+                schema_for!(uint256_32::Uint256Bits32).to_value()["anyOf"],
+                schema_for!(uint256::Uint256).to_value()["anyOf"]
             );
+        }
 
-            // Test Uint256Bits32
-            let uint256_32_json = format!(r#"{{"value": "{value_str}"}}"#);
-            let uint256_32_wrapper: Uint256Bits32Wrapper =
-                serde_json::from_str(&uint256_32_json).unwrap();
+        #[test]
+        fn test_input_struct_schema() {
+            #[derive(JsonSchema)]
+            #[allow(dead_code)]
+            struct Input {
+                block: uint256::Uint256,
+                data: keccak_bytes::KeccakBytes,
+                values: Vec<felt::Felt>,
+            }
+
+            let schema = schema_for!(Input).to_value();
+            assert_eq!(schema["properties"]["block"]["$ref"], "#/$defs/Uint256");
             assert_eq!(
-                uint256_32_wrapper.value,
-                uint256_32::Uint256Bits32(num_bigint::BigUint::from(value_num))
+                schema["properties"]["values"]["items"]["$ref"],
+                "#/$defs/Felt"
             );
+            let any_of = schema["$defs"]["KeccakBytes"]["anyOf"].as_array().unwrap();
+            assert_eq!(any_of[0]["type"], "string");
+            assert_eq!(any_of[2]["type"], "array");
+        }
+    }
 
-            let uint256_32_json_num = format!(r#"{{"value": {value_num}}}"#);
-            let uint256_32_wrapper_num: Uint256Bits32Wrapper =
-                serde_json::from_str(&uint256_32_json_num).unwrap();
+    #[cfg(feature = "arbitrary")]
+    mod arbitrary_tests {
+        use crate::types::{
+            bls12_381::{G1Point, G2Point},
+            ec_point::Secp256k1Point,
+            felt::Felt,
+            short_string::ShortString,
+            uint256::Uint256,
+            FromAnyStr,
+        };
+        use arbitrary::{Arbitrary, Unstructured};
+
+        // Deterministic pseudo-random fuzzer input
+        fn input(seed: u64) -> Vec<u8> {
+            let mut state = seed;
+            (0..1024)
+                .map(|_| {
+                    state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                    (state >> 56) as u8
+                })
+                .collect()
+        }
+
+        #[test]
+        fn test_arbitrary_values_are_valid() {
+            for seed in 0..16 {
+                let data = input(seed);
+                let mut u = Unstructured::new(&data);
+                let uint = Uint256::arbitrary(&mut u).unwrap();
+                assert_eq!(
+                    Uint256::from_any_str(&serde_json::to_string(&uint).unwrap().replace('"', ""))
+                        .unwrap(),
+                    uint
+                );
+                Felt::arbitrary(&mut u).unwrap();
+                assert!(ShortString::arbitrary(&mut u).unwrap().to_ascii().is_ok());
+                assert!(G1Point::arbitrary(&mut u).unwrap().is_on_curve());
+                assert!(G2Point::arbitrary(&mut u).unwrap().is_on_curve());
+                assert!(Secp256k1Point::arbitrary(&mut u).unwrap().is_on_curve());
+            }
+        }
+
+        #[test]
+        fn test_arbitrary_exhausted_input() {
+            let mut u = Unstructured::new(&[]);
             assert_eq!(
-                uint256_32_wrapper_num.value,
-                uint256_32::Uint256Bits32(num_bigint::BigUint::from(value_num))
+                Uint256::arbitrary(&mut u).unwrap(),
+                Uint256::from_any_str("0").unwrap()
             );
+            assert!(G1Point::arbitrary(&mut u).unwrap().is_on_curve());
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    mod proptest_tests {
+        use crate::types::{
+            bounded_uint::Uint8, ec_point::Secp256r1, felt::Felt, fuzz::strategy, uint256::Uint256,
+            FromAnyStr,
+        };
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn test_json_round_trip(felt in strategy::felt(), uint in strategy::uint256()) {
+                let json = serde_json::to_string(&felt).unwrap();
+                prop_assert_eq!(serde_json::from_str::<Felt>(&json).unwrap(), felt);
+                let json = serde_json::to_string(&uint).unwrap();
+                prop_assert_eq!(serde_json::from_str::<Uint256>(&json).unwrap(), uint);
+            }
+
+            #[test]
+            fn test_invalid_inputs_rejected(
+                wide in strategy::overflowing_hex(256),
+                narrow in strategy::overflowing_hex(8),
+                malformed in strategy::malformed_hex(),
+            ) {
+                prop_assert!(Uint256::from_any_str(&wide).is_err());
+                prop_assert!(Uint8::from_any_str(&narrow).is_err());
+                prop_assert!(Uint256::from_any_str(&malformed).is_err());
+            }
+        }
+
+        proptest! {
+            #![proptest_config(ProptestConfig::with_cases(16))]
+
+            #[test]
+            fn test_points_on_curve(
+                g1 in strategy::g1_point(),
+                g2 in strategy::g2_point(),
+                secp in strategy::ec_point::<Secp256r1>(),
+            ) {
+                prop_assert!(g1.is_on_curve());
+                prop_assert!(g2.is_on_curve());
+                prop_assert!(secp.is_on_curve());
+            }
+        }
+    }
+
+    mod sha512_bytes_tests {
+        use crate::types::bounded_uint::Uint64;
+        use crate::types::sha512_bytes::Sha512Bytes;
+
+        #[test]
+        fn test_sha512_bytes_words() {
+            let bytes: Sha512Bytes = serde_json::from_str(r#""0x0102030405060708090a""#).unwrap();
+            assert_eq!(bytes.n_bytes(), 10);
+            assert_eq!(
+                bytes.to_words(),
+                vec![Uint64(0x0102030405060708), Uint64(0x090a)]
+            );
+
+            let padded = bytes.padded_words();
+            assert_eq!(padded.len(), 16);
+            assert_eq!(padded[1], 0x090a800000000000);
+            assert_eq!(padded[15], 80);
+            assert_eq!(Sha512Bytes(vec![0; 112]).padded_words().len(), 32);
+        }
+    }
+
+    mod sha256_bytes_tests {
+        use crate::types::sha256_bytes::Sha256Bytes;
+        use cairo_vm::Felt252;
+
+        #[test]
+        fn test_sha256_bytes_words() {
+            let bytes = Sha256Bytes(b"abcde".to_vec());
+            assert_eq!(bytes.n_bytes(), 5);
+            assert_eq!(
+                bytes.to_words(),
+                vec![Felt252::from(0x61626364u32), Felt252::from(0x65)]
+            );
+
+            let padded = bytes.padded_words();
+            assert_eq!(padded.len(), 16);
+            assert_eq!(padded[1], 0x65800000);
+            assert_eq!(padded[15], 40);
+            assert_eq!(Sha256Bytes(vec![0; 56]).padded_words().len(), 32);
+        }
+
+        #[test]
+        fn test_sha256_bytes_serde() {
+            let bytes: Sha256Bytes = serde_json::from_str(r#""0x616263""#).unwrap();
+            assert_eq!(bytes.0, b"abc".to_vec());
+            assert_eq!(serde_json::to_string(&bytes).unwrap(), r#""0x616263""#);
+            assert!(serde_json::from_str::<Sha256Bytes>("123").is_err());
+        }
+    }
+
+    mod ec_point_tests {
+        use super::*;
+        use num_bigint::BigUint;
+
+        const SECP256K1_G_COMPRESSED: &str =
+            "0x0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        const SECP256K1_G_UNCOMPRESSED: &str = "0x0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+        const SECP256R1_G_UNCOMPRESSED: &str = "0x046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c2964fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5";
+
+        #[test]
+        fn test_secp256k1_compressed_matches_uncompressed() {
+            let compressed: ec_point::Secp256k1Point =
+                serde_json::from_str(&format!("\"{SECP256K1_G_COMPRESSED}\"")).unwrap();
+            let uncompressed: ec_point::Secp256k1Point =
+                serde_json::from_str(&format!("\"{SECP256K1_G_UNCOMPRESSED}\"")).unwrap();
+            assert_eq!(compressed, uncompressed);
+            assert!(compressed.is_on_curve());
+        }
+
+        #[test]
+        fn test_secp256r1_round_trip() {
+            let json = format!("\"{SECP256R1_G_UNCOMPRESSED}\"");
+            let point: ec_point::Secp256r1Point = serde_json::from_str(&json).unwrap();
+            assert_eq!(serde_json::to_string(&point).unwrap(), json);
+        }
+
+        #[test]
+        fn test_point_off_curve_fails() {
+            let point = ec_point::Secp256k1Point::new(
+                uint384::UInt384::new(BigUint::from(1u32)),
+                uint384::UInt384::new(BigUint::from(1u32)),
+            );
+            assert!(!point.is_on_curve());
+            let json = format!(
+                "\"0x04{}{}\"",
+                "00".repeat(31) + "01",
+                "00".repeat(31) + "01"
+            );
+            let result: Result<ec_point::Secp256k1Point, _> = serde_json::from_str(&json);
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn test_point_wrong_curve_fails() {
+            let json = format!("\"{SECP256K1_G_UNCOMPRESSED}\"");
+            let result: Result<ec_point::Secp256r1Point, _> = serde_json::from_str(&json);
+            assert!(result.is_err());
+        }
+    }
+
+    mod bls12_381_tests {
+        use super::*;
+        use crate::types::FromAnyStr;
+
+        const G1_COMPRESSED: &str = "0x97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+        const G1_Y: &str = "0x08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1";
+        const G2_COMPRESSED: &str = "0x93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+        const G2_Y0: &str = "0x0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801";
+        const G2_Y1: &str = "0x0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be";
+
+        #[test]
+        fn test_g1_decompress_generator() {
+            let point: bls12_381::G1Point =
+                serde_json::from_str(&format!("\"{G1_COMPRESSED}\"")).unwrap();
+            assert_eq!(point.y, bls12_381::Fq::from_any_str(G1_Y).unwrap());
+            assert!(point.is_on_curve());
+
+            let json = serde_json::to_string(&point).unwrap();
+            let restored: bls12_381::G1Point = serde_json::from_str(&json).unwrap();
+            assert_eq!(point, restored);
+        }
+
+        #[test]
+        fn test_g2_decompress_generator() {
+            let point: bls12_381::G2Point =
+                serde_json::from_str(&format!("\"{G2_COMPRESSED}\"")).unwrap();
+            assert_eq!(point.y.a0, bls12_381::Fq::from_any_str(G2_Y0).unwrap());
+            assert_eq!(point.y.a1, bls12_381::Fq::from_any_str(G2_Y1).unwrap());
+            assert!(point.is_on_curve());
+
+            let json = serde_json::to_string(&point).unwrap();
+            let restored: bls12_381::G2Point = serde_json::from_str(&json).unwrap();
+            assert_eq!(point, restored);
+        }
+
+        #[test]
+        fn test_g1_infinity() {
+            let json = format!("\"0xc0{}\"", "00".repeat(47));
+            let point: bls12_381::G1Point = serde_json::from_str(&json).unwrap();
+            assert!(point.is_infinity());
+        }
+
+        #[test]
+        fn test_fq_overflow_fails() {
+            let json = format!("\"0x{}\"", "ff".repeat(48));
+            let result: Result<bls12_381::Fq, _> = serde_json::from_str(&json);
+            assert!(result.is_err());
+        }
+    }
+
+    #[cfg(feature = "ark")]
+    mod ark_tests {
+        use super::*;
+        use crate::types::FromAnyStr;
+        use ark_bls12_381::{Fq, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+        use ark_ec::{AffineRepr, CurveGroup, PrimeGroup};
+        use ark_ff::{BigInteger384, PrimeField};
+
+        #[test]
+        fn test_ark_field_conversions() {
+            let limbs = BigInteger384::new([1, 2, 3, 4, 5, 6]);
+            let uint = uint384::UInt384::from(limbs);
+            assert_eq!(uint.words(), &[1, 2, 3, 4, 5, 6]);
+            assert_eq!(BigInteger384::from(uint), limbs);
+
+            let x = -Fq::from(5u64);
+            let fq = bls12_381::Fq::from(x);
+            assert_eq!(
+                fq.0.to_biguint(),
+                num_bigint::BigUint::from(Fq::MODULUS) - 5u32
+            );
+            assert_eq!(Fq::from(fq), x);
+        }
+
+        #[test]
+        fn test_ark_point_conversions() {
+            let g1 = bls12_381::G1Point::from_any_str(
+                "0x97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
+            )
+            .unwrap();
+            assert_eq!(bls12_381::G1Point::from(G1Affine::generator()), g1);
+            let g2 = bls12_381::G2Point::from_any_str(
+                "0x93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8",
+            )
+            .unwrap();
+            assert_eq!(bls12_381::G2Point::from(G2Affine::generator()), g2);
+
+            let p = (G1Projective::generator() * Fr::from(5u64)).into_affine();
+            let point = bls12_381::G1Point::from(p);
+            assert!(point.is_on_curve());
+            assert_eq!(G1Affine::from(point), p);
+            let q = (G2Projective::generator() * Fr::from(7u64)).into_affine();
+            assert_eq!(G2Affine::from(bls12_381::G2Point::from(q)), q);
+
+            assert!(bls12_381::G1Point::from(G1Affine::identity()).is_infinity());
+            assert!(G2Affine::from(bls12_381::G2Point::infinity()).is_zero());
+        }
+    }
+
+    #[cfg(feature = "ssz")]
+    mod ssz_tests {
+        use crate::cairo_type::{BaseCairoType, CairoType, CairoWritable};
+        use crate::types::ssz::{
+            hash_pair, merkleize, merkleize_with_limit, mix_in_length, SszChunks,
+        };
+        use crate::types::uint256_32::Uint256Bits32;
+        use cairo_vm::{vm::vm_core::VirtualMachine, Felt252};
+
+        fn chunk(first: u8) -> [u8; 32] {
+            let mut chunk = [0u8; 32];
+            chunk[0] = first;
+            chunk
+        }
+
+        #[test]
+        fn test_ssz_chunks() {
+            // Four packed uint64 fill exactly one chunk, a fifth starts a zero padded one
+            let chunks = SszChunks::from_ssz(&vec![1u64, 2, 3, 4, 5]);
+            assert_eq!(chunks.len(), 2);
+            assert_eq!(chunks.chunks()[0][..9], [1, 0, 0, 0, 0, 0, 0, 0, 2]);
+            assert_eq!(chunks.chunks()[1], chunk(5));
+            assert!(SszChunks::from_ssz(&Vec::<u8>::new()).is_empty());
+
+            assert_eq!(
+                chunks.merkle_root(),
+                hash_pair(&chunks.chunks()[0], &chunks.chunks()[1])
+            );
+            assert_eq!(
+                SszChunks::from_chunks(vec![chunk(7)]).merkle_root(),
+                chunk(7)
+            );
+        }
+
+        #[test]
+        fn test_ssz_merkleize() {
+            let leaves = [chunk(1), chunk(2), chunk(3)];
+            let root = merkleize(&leaves);
+            assert_eq!(
+                hex::encode(root),
+                "66c419026fee8793be7fd0011b9db46b98a79f9c9b640e25317865c358f442db"
+            );
+            assert_eq!(
+                hex::encode(mix_in_length(&root, 3)),
+                "48e0187123ec029d586ac948fc8081f1e6d11632e336b41983c90685040fe63d"
+            );
+            assert_eq!(merkleize_with_limit(&leaves, 3).unwrap(), root);
+
+            // Empty lists hash to the zero subtree of their limit
+            assert_eq!(
+                hex::encode(merkleize_with_limit(&[], 4).unwrap()),
+                "db56114e00fdd4c1f85c892bf35ac9a89289aaecb1ebd0a96cde606a748b5d71"
+            );
+            assert_eq!(merkleize(&[]), [0u8; 32]);
+            assert!(merkleize_with_limit(&leaves, 2).is_err());
+        }
+
+        #[test]
+        fn test_ssz_chunks_memory() {
+            let mut vm = VirtualMachine::new(false, false);
+            let base = vm.add_memory_segment();
+            let chunks = SszChunks::from_chunks(vec![chunk(1), chunk(2)]);
+            let next = chunks.to_memory(&mut vm, base).unwrap();
+            assert_eq!(next, (base + 2usize).unwrap());
+            assert_eq!(*vm.get_integer(base).unwrap(), Felt252::from(2));
+
+            let words = vm.get_relocatable((base + 1usize).unwrap()).unwrap();
+            let second = Uint256Bits32::from_memory(&vm, (words + 1usize).unwrap()).unwrap();
+            assert_eq!(second, chunks.to_words()[1]);
+            let limbs = vm.get_relocatable((words + 1usize).unwrap()).unwrap();
+            assert_eq!(
+                *vm.get_integer(limbs).unwrap(),
+                Felt252::from(0x02000000u32)
+            );
+            assert_eq!(
+                SszChunks::from_chunks(vec![chunk(1), chunk(2)]).root(),
+                Uint256Bits32::from_bytes_be(&hash_pair(&chunk(1), &chunk(2)))
+            );
+        }
+    }
+
+    mod bounded_uint_tests {
+        use crate::cairo_type::{CairoType, CairoWritable};
+        use crate::types::bounded_uint::{Uint128, Uint32, Uint8};
+        use crate::types::FromAnyStr;
+        use cairo_vm::{vm::vm_core::VirtualMachine, Felt252};
+        use serde::Deserialize;
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Header {
+            version: Uint8,
+            timestamp: Uint32,
+        }
+
+        #[test]
+        fn test_bounded_uint_checked_new() {
+            assert_eq!(Uint32::checked_new(7u64).unwrap(), Uint32(7));
+            assert_eq!(Uint32::checked_new(u32::MAX as u64).unwrap(), Uint32::MAX);
+            assert!(Uint32::checked_new(u32::MAX as u64 + 1).is_err());
+            assert!(Uint8::checked_new(-1i32).is_err());
+            assert_eq!(Uint128::checked_new(u128::MAX).unwrap(), Uint128::MAX);
+        }
+
+        #[test]
+        fn test_bounded_uint_parse() {
+            assert_eq!(Uint8::from_any_str("0xff").unwrap(), Uint8(255));
+            assert_eq!(Uint32::from_any_str("4294967295").unwrap(), Uint32::MAX);
+            assert!(Uint32::from_any_str("4294967296").is_err());
+            assert!(Uint8::from_any_str("0x100").is_err());
+            assert!(Uint8::from_any_str("-1").is_err());
+
+            let header: Header =
+                serde_json::from_str(r#"{"version": 1, "timestamp": "0x65000000"}"#).unwrap();
+            assert_eq!(
+                header,
+                Header {
+                    version: Uint8(1),
+                    timestamp: Uint32(0x65000000),
+                }
+            );
+            let result: Result<Header, _> =
+                serde_json::from_str(r#"{"version": 256, "timestamp": 0}"#);
+            assert!(result.is_err());
+            let result: Result<Header, _> =
+                serde_json::from_str(r#"{"version": 1, "timestamp": 5000000000}"#);
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn test_bounded_uint_memory() {
+            let mut vm = VirtualMachine::new(false, false);
+            let base = vm.add_memory_segment();
+            let next = Uint32(42).to_memory(&mut vm, base).unwrap();
+            assert_eq!(Uint32::from_memory(&vm, base).unwrap(), Uint32(42));
+
+            // Out of range cells are reported when read back
+            vm.insert_value(next, Felt252::from(1u64 << 32)).unwrap();
+            assert!(Uint32::from_memory(&vm, next).is_err());
+        }
+    }
+
+    mod byte_array_tests {
+        use crate::cairo_type::{CairoType, CairoWritable};
+        use crate::types::byte_array::ByteArray;
+        use crate::types::FromAnyStr;
+        use cairo_vm::{vm::vm_core::VirtualMachine, Felt252};
+
+        #[test]
+        fn test_byte_array_words() {
+            let short = ByteArray::from("hello");
+            let (words, pending_word, pending_len) = short.to_words();
+            assert!(words.is_empty());
+            assert_eq!(pending_word, Felt252::from_bytes_be_slice(b"hello"));
+            assert_eq!(pending_len, 5);
+
+            let bytes: Vec<u8> = (0..62).collect();
+            let calldata = ByteArray(bytes.clone()).to_calldata();
+            assert_eq!(
+                calldata,
+                vec![
+                    Felt252::from(2),
+                    Felt252::from_bytes_be_slice(&bytes[..31]),
+                    Felt252::from_bytes_be_slice(&bytes[31..]),
+                    Felt252::ZERO,
+                    Felt252::ZERO,
+                ]
+            );
+        }
+
+        #[test]
+        fn test_byte_array_memory_round_trip() {
+            let mut vm = VirtualMachine::new(false, false);
+            let base = vm.add_memory_segment();
+            let mut address = base;
+            let values = [
+                ByteArray::default(),
+                ByteArray::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit"),
+                ByteArray((0..93).collect()),
+            ];
+            for value in &values {
+                address = value.to_memory(&mut vm, address).unwrap();
+            }
+            for (i, value) in values.iter().enumerate() {
+                let address = (base + i * ByteArray::n_fields()).unwrap();
+                assert_eq!(&ByteArray::from_memory(&vm, address).unwrap(), value);
+            }
+        }
+
+        #[test]
+        fn test_byte_array_parse() {
+            assert_eq!(
+                ByteArray::from_any_str("0x0102").unwrap(),
+                ByteArray(vec![1, 2])
+            );
+            assert_eq!(
+                ByteArray::from_any_str("ab").unwrap(),
+                ByteArray::from("ab")
+            );
+            let json = serde_json::to_string(&ByteArray::from("ab")).unwrap();
+            assert_eq!(json, r#""0x6162""#);
+            assert_eq!(
+                serde_json::from_str::<ByteArray>(&json).unwrap(),
+                ByteArray::from("ab")
+            );
+        }
+    }
+
+    mod short_string_tests {
+        use crate::cairo_type::{CairoType, CairoWritable};
+        use crate::types::short_string::ShortString;
+        use crate::types::FromAnyStr;
+        use cairo_vm::{vm::vm_core::VirtualMachine, Felt252};
+
+        #[test]
+        fn test_short_string_parse() {
+            let hello = ShortString::from_any_str("hello").unwrap();
+            assert_eq!(hello.0, Felt252::from(0x68656c6c6fu64));
+            assert_eq!(hello.to_ascii().unwrap(), "hello");
+            assert_eq!(hello.to_string(), "hello");
+            assert_eq!(ShortString::from_any_str("").unwrap().0, Felt252::ZERO);
+
+            assert!(ShortString::from_any_str(&"a".repeat(31)).is_ok());
+            assert!(ShortString::from_any_str(&"a".repeat(32)).is_err());
+            assert!(ShortString::from_any_str("héllo").is_err());
+        }
+
+        #[test]
+        fn test_short_string_serde_round_trip() {
+            let value: ShortString = serde_json::from_str(r#""STARKNET""#).unwrap();
+            assert_eq!(serde_json::to_string(&value).unwrap(), r#""STARKNET""#);
+
+            // Felts that aren't ASCII can't be serialized back to a string
+            assert!(serde_json::to_string(&ShortString(Felt252::MAX)).is_err());
+        }
+
+        #[test]
+        fn test_short_string_memory() {
+            let mut vm = VirtualMachine::new(false, false);
+            let base = vm.add_memory_segment();
+            let value = ShortString::from_any_str("cairo").unwrap();
+            value.to_memory(&mut vm, base).unwrap();
+            assert_eq!(ShortString::from_memory(&vm, base).unwrap(), value);
+        }
+    }
+
+    mod option_tests {
+        use crate::cairo_type::{CairoType, CairoWritable};
+        use crate::types::felt::Felt;
+        use crate::types::option::{CairoOption, NullableOption};
+        use crate::types::uint256::Uint256;
+        use cairo_vm::{
+            types::relocatable::MaybeRelocatable, vm::vm_core::VirtualMachine, Felt252,
+        };
+        use num_bigint::BigUint;
+        use serde::Deserialize;
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Account {
+            balance: CairoOption<Uint256>,
+            delegate: NullableOption<Felt>,
+        }
+
+        #[test]
+        fn test_flagged_option_memory() {
+            assert_eq!(CairoOption::<Uint256>::n_fields(), 3);
+            let mut vm = VirtualMachine::new(false, false);
+            let base = vm.add_memory_segment();
+            let some = CairoOption::<Uint256>::new(Some(Uint256::new(BigUint::from(5u32))));
+            let next = some.to_memory(&mut vm, base).unwrap();
+            let end = CairoOption::<Uint256>::new(None)
+                .to_memory(&mut vm, next)
+                .unwrap();
+            assert_eq!(end, (base + 6usize).unwrap());
+
+            assert_eq!(CairoOption::from_memory(&vm, base).unwrap(), some);
+            assert_eq!(
+                CairoOption::<Uint256>::from_memory(&vm, next)
+                    .unwrap()
+                    .value,
+                None
+            );
+            assert_eq!(
+                *vm.get_integer((next + 1usize).unwrap()).unwrap(),
+                Felt252::ZERO
+            );
+
+            // Flags other than 0 and 1 are rejected
+            let bad = vm.add_memory_segment();
+            vm.insert_value(bad, Felt252::TWO).unwrap();
+            assert!(CairoOption::<Felt>::from_memory(&vm, bad).is_err());
+        }
+
+        #[test]
+        fn test_nullable_option_memory() {
+            assert_eq!(NullableOption::<Uint256>::n_fields(), 1);
+            let mut vm = VirtualMachine::new(false, false);
+            let base = vm.add_memory_segment();
+            let some = NullableOption::new(Some(Felt(Felt252::from(9))));
+            let next = some.to_memory(&mut vm, base).unwrap();
+            NullableOption::<Felt>::new(None)
+                .to_memory(&mut vm, next)
+                .unwrap();
+
+            assert!(matches!(
+                vm.get_maybe(&base),
+                Some(MaybeRelocatable::RelocatableValue(_))
+            ));
+            assert_eq!(NullableOption::from_memory(&vm, base).unwrap(), some);
+            assert_eq!(
+                NullableOption::<Felt>::from_memory(&vm, next)
+                    .unwrap()
+                    .value,
+                None
+            );
+        }
+
+        #[test]
+        fn test_option_deserialize() {
+            let account: Account =
+                serde_json::from_str(r#"{"balance": "0x10", "delegate": null}"#).unwrap();
+            assert_eq!(
+                account,
+                Account {
+                    balance: Some(Uint256::new(BigUint::from(16u32))).into(),
+                    delegate: None.into(),
+                }
+            );
+        }
+    }
+
+    mod cairo_enum_tests {
+        use crate::cairo_type::{CairoType, CairoWritable};
+        use crate::impl_cairo_enum;
+        use crate::types::cairo_enum::{Cairo0Enum, Cairo1Enum, CairoEnum};
+        use crate::types::felt::Felt;
+        use crate::types::uint256::Uint256;
+        use cairo_vm::{types::relocatable::Relocatable, vm::vm_core::VirtualMachine, Felt252};
+        use num_bigint::BigUint;
+
+        #[derive(Debug, Clone, PartialEq)]
+        enum Action {
+            Noop,
+            Transfer((Felt, Uint256)),
+            Burn(Uint256),
+        }
+
+        impl_cairo_enum!(Action: Cairo0Enum {
+            Noop,
+            Transfer((Felt, Uint256)),
+            Burn(Uint256),
+        });
+
+        #[derive(Debug, Clone, PartialEq)]
+        enum SierraAction {
+            Noop,
+            Transfer((Felt, Uint256)),
+            Burn(Uint256),
+        }
+
+        impl_cairo_enum!(SierraAction: Cairo1Enum {
+            Noop,
+            Transfer((Felt, Uint256)),
+            Burn(Uint256),
+        });
+
+        #[derive(Debug, Clone, PartialEq)]
+        enum Flag {
+            Off,
+            On(Felt),
+        }
+
+        impl_cairo_enum!(Flag: Cairo1Enum { Off, On(Felt) });
+
+        fn cells(vm: &VirtualMachine, address: Relocatable, n: usize) -> Vec<u64> {
+            (0..n)
+                .map(|i| {
+                    let value = vm.get_integer((address + i).unwrap()).unwrap();
+                    u64::try_from(*value).unwrap()
+                })
+                .collect()
+        }
+
+        // Writes `value`, checks its cells and reads it back
+        fn roundtrip<T: CairoType + PartialEq + std::fmt::Debug>(value: T, expected: &[u64]) {
+            let mut vm = VirtualMachine::new(false, false);
+            let base = vm.add_memory_segment();
+            let n_fields = T::n_fields();
+            let next = value.to_memory(&mut vm, base).unwrap();
+            assert_eq!(next, (base + n_fields).unwrap());
+            assert_eq!(cells(&vm, base, n_fields), expected);
+            assert_eq!(T::from_memory(&vm, base).unwrap(), value);
+        }
+
+        fn transfer() -> (Felt, Uint256) {
+            (Felt(Felt252::from(7)), Uint256::new(BigUint::from(5u32)))
+        }
+
+        #[test]
+        fn test_cairo0_enum() {
+            assert_eq!(Action::n_fields(), 4);
+            assert_eq!(Action::VARIANTS, ["Noop", "Transfer", "Burn"]);
+            roundtrip(Action::Noop, &[0, 0, 0, 0]);
+            roundtrip(Action::Transfer(transfer()), &[1, 7, 5, 0]);
+            // Payload first, then the padding
+            roundtrip(Action::Burn(transfer().1), &[2, 5, 0, 0]);
+
+            let names: Vec<_> = Action::layout()
+                .cells()
+                .into_iter()
+                .map(|cell| cell.name)
+                .collect();
+            assert_eq!(names, ["variant", "payload.0", "payload.1", "payload.2"]);
+        }
+
+        #[test]
+        fn test_cairo1_enum() {
+            // Jump table selectors above two variants, padding before the payload
+            roundtrip(SierraAction::Noop, &[5, 0, 0, 0]);
+            roundtrip(SierraAction::Transfer(transfer()), &[3, 7, 5, 0]);
+            roundtrip(SierraAction::Burn(transfer().1), &[1, 0, 5, 0]);
+
+            // The index up to two variants
+            assert_eq!(Flag::n_fields(), 2);
+            roundtrip(Flag::Off, &[0, 0]);
+            roundtrip(Flag::On(Felt(Felt252::from(9))), &[1, 9]);
+        }
+
+        #[test]
+        fn test_invalid_enum_discriminant() {
+            let mut vm = VirtualMachine::new(false, false);
+            let base = vm.add_memory_segment();
+            for (i, value) in [4u64, 0, 0, 0].into_iter().enumerate() {
+                vm.insert_value((base + i).unwrap(), Felt252::from(value))
+                    .unwrap();
+            }
+            let error = SierraAction::from_memory(&vm, base)
+                .unwrap_err()
+                .to_string();
+            assert!(
+                error.contains("invalid SierraAction discriminant 4"),
+                "{error}"
+            );
+            assert!(Action::from_memory(&vm, base).is_err());
+        }
+    }
+
+    mod dict_tests {
+        use crate::cairo_type::{CairoType, CairoWritable};
+        use crate::types::dict::CairoDict;
+        use crate::types::felt::Felt;
+        use crate::types::uint256::Uint256;
+        use cairo_vm::{vm::vm_core::VirtualMachine, Felt252};
+        use num_bigint::BigUint;
+
+        #[test]
+        fn test_dict_squashed_layout() {
+            let mut dict = CairoDict::<Felt, Felt>::new();
+            dict.insert(Felt(Felt252::from(9)), Felt(Felt252::from(90)));
+            dict.insert(Felt(Felt252::from(3)), Felt(Felt252::from(30)));
+            assert_eq!(
+                dict.insert(Felt(Felt252::from(9)), Felt(Felt252::from(91))),
+                Some(Felt(Felt252::from(90)))
+            );
+
+            let mut vm = VirtualMachine::new(false, false);
+            let base = vm.add_memory_segment();
+            let next = dict.to_memory(&mut vm, base).unwrap();
+            assert_eq!(next, (base + 2usize).unwrap());
+
+            let start = vm.get_relocatable(base).unwrap();
+            let end = vm.get_relocatable((base + 1usize).unwrap()).unwrap();
+            assert_eq!((end - start).unwrap(), 6);
+            let accesses: Vec<Felt252> = vm
+                .get_integer_range(start, 6)
+                .unwrap()
+                .into_iter()
+                .map(|value| value.into_owned())
+                .collect();
+            let expected: Vec<Felt252> = [3, 30, 30, 9, 91, 91]
+                .into_iter()
+                .map(Felt252::from)
+                .collect();
+            assert_eq!(accesses, expected);
+        }
+
+        #[test]
+        fn test_dict_struct_values() {
+            let json = r#"{"0x2": "0x5", "1": "7"}"#;
+            let dict: CairoDict<Felt, Uint256> = serde_json::from_str(json).unwrap();
+            assert_eq!(dict.len(), 2);
+            let keys: Vec<&Felt> = dict.iter().map(|(key, _)| key).collect();
+            assert_eq!(keys, vec![&Felt(Felt252::from(1)), &Felt(Felt252::from(2))]);
+
+            // Uint256 values take two felts and are stored by pointer
+            let mut vm = VirtualMachine::new(false, false);
+            let base = vm.add_memory_segment();
+            dict.to_memory(&mut vm, base).unwrap();
+            let start = vm.get_relocatable(base).unwrap();
+            let value_ptr = vm.get_relocatable((start + 1usize).unwrap()).unwrap();
+            assert_eq!(
+                vm.get_relocatable((start + 2usize).unwrap()).unwrap(),
+                value_ptr
+            );
+            assert_eq!(
+                Uint256::from_memory(&vm, value_ptr).unwrap(),
+                Uint256::new(BigUint::from(7u32))
+            );
+        }
+    }
+
+    mod int_tests {
+        use crate::cairo_type::{CairoType, CairoWritable};
+        use crate::types::int::{Int128, Int64};
+        use crate::types::FromAnyStr;
+        use cairo_vm::{vm::vm_core::VirtualMachine, Felt252};
+        use serde::Deserialize;
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Balances {
+            delta: Int64,
+            total: Int128,
+        }
+
+        #[test]
+        fn test_int_from_any_str() {
+            assert_eq!(Int64::from_any_str("-123").unwrap(), Int64(-123));
+            assert_eq!(Int64::from_any_str("-0xff").unwrap(), Int64(-255));
+            assert_eq!(Int128::from_any_str("0x10").unwrap(), Int128(16));
+            assert_eq!(
+                Int64::from_any_str("-9223372036854775808").unwrap(),
+                Int64(i64::MIN)
+            );
+            assert!(Int64::from_any_str("9223372036854775808").is_err());
+            assert!(Int64::from_any_str("-").is_err());
+            assert!(Int128::from_any_str("abc").is_err());
+        }
+
+        #[test]
+        fn test_int_deserialize() {
+            let json = r#"{"delta": -42, "total": "-0x100"}"#;
+            let balances: Balances = serde_json::from_str(json).unwrap();
+            assert_eq!(
+                balances,
+                Balances {
+                    delta: Int64(-42),
+                    total: Int128(-256),
+                }
+            );
+            assert_eq!(serde_json::to_string(&Int64(-42)).unwrap(), r#""-42""#);
+        }
+
+        #[test]
+        fn test_int_felt_encoding() {
+            // Negative values are encoded as P - |x|
+            assert_eq!(Int64(-1).to_felt(), Felt252::MAX);
+            assert_eq!(Int128::from_felt(&Felt252::MAX).unwrap(), Int128(-1));
+            assert_eq!(
+                Int128::from_felt(&Felt252::from(i128::MIN)).unwrap(),
+                Int128(i128::MIN)
+            );
+            // Felts outside of the signed range are rejected
+            assert!(Int64::from_felt(&Felt252::from(u64::MAX)).is_err());
+            assert!(Int128::from_felt(&Felt252::from(u128::MAX)).is_err());
+            assert!(Int128::from_felt(&(Felt252::from(i128::MIN) - Felt252::ONE)).is_err());
+            assert!(Int64::from_felt(&Felt252::from(i64::MIN)).is_ok());
+        }
+
+        #[test]
+        fn test_int_memory_round_trip() {
+            let mut vm = VirtualMachine::new(false, false);
+            let base = vm.add_memory_segment();
+            let next = Int64(-7).to_memory(&mut vm, base).unwrap();
+            Int128(i128::MAX).to_memory(&mut vm, next).unwrap();
+            assert_eq!(*vm.get_integer(base).unwrap(), -Felt252::from(7));
+            assert_eq!(Int64::from_memory(&vm, base).unwrap(), Int64(-7));
+            assert_eq!(Int128::from_memory(&vm, next).unwrap(), Int128(i128::MAX));
+        }
+    }
+
+    mod overflow_policy_tests {
+        use super::*;
+        use crate::types::serde_utils;
+        use num_bigint::BigUint;
+
+        #[derive(Debug, Deserialize)]
+        struct PolicyWrapper {
+            #[serde(deserialize_with = "serde_utils::deserialize_saturating")]
+            saturated: uint256::Uint256,
+            #[serde(deserialize_with = "serde_utils::deserialize_wrapping")]
+            wrapped: uint256::Uint256,
+        }
+
+        #[test]
+        fn test_overflow_policies() {
+            let overflow = format!("0x1{}", "00".repeat(31) + "05");
+            let json = format!(r#"{{"saturated": "{overflow}", "wrapped": "{overflow}"}}"#);
+            let wrapper: PolicyWrapper = serde_json::from_str(&json).unwrap();
+            let max_256 = BigUint::from(2u64).pow(256) - BigUint::from(1u64);
+            assert_eq!(wrapper.saturated, uint256::Uint256::new(max_256));
+            assert_eq!(wrapper.wrapped, uint256::Uint256::new(BigUint::from(5u64)));
+        }
+
+        #[test]
+        fn test_decimal_overflow_errors_by_default() {
+            let too_big = (BigUint::from(2u64).pow(256)).to_string();
+            let json = format!(r#"{{"value": "{too_big}"}}"#);
+            let result: Result<Uint256Wrapper, _> = serde_json::from_str(&json);
+            assert!(result.is_err());
+        }
+    }
+
+    mod parse_mode_tests {
+        use super::*;
+        use crate::types::bounded_uint::Uint8;
+        use crate::types::felt::Felt;
+        use crate::types::int::Int64;
+        use crate::types::{serde_utils, FromAnyStr, FromStrRadix, ParseMode};
+        use cairo_vm::Felt252;
+
+        #[derive(Debug, Deserialize)]
+        struct StrictWrapper {
+            #[serde(deserialize_with = "serde_utils::deserialize_strict_hex")]
+            hash: Felt,
+            #[serde(deserialize_with = "serde_utils::deserialize_strict_dec")]
+            amount: Uint8,
+        }
+
+        #[test]
+        fn test_from_str_radix() {
+            assert_eq!(
+                Felt::from_str_radix("ff", 16).unwrap(),
+                Felt(Felt252::from(255))
+            );
+            assert_eq!(
+                Felt::from_str_radix("777", 8).unwrap(),
+                Felt(Felt252::from(511))
+            );
+            assert_eq!(Uint8::from_str_radix("11111111", 2).unwrap(), Uint8(255));
+            assert!(Uint8::from_str_radix("100", 16).is_err());
+            assert_eq!(Int64::from_str_radix("-10", 16).unwrap(), Int64(-16));
+            assert_eq!(
+                uint256::Uint256::from_str_radix("10", 10).unwrap(),
+                uint256::Uint256::from_any_str("0xa").unwrap()
+            );
+            assert_eq!(
+                Felt::from_str_radix("1", 37).unwrap_err(),
+                "invalid radix 37"
+            );
+        }
+
+        #[test]
+        fn test_parse_modes() {
+            // The guesses strict modes rule out
+            assert_eq!(
+                ParseMode::Any.parse::<Felt>("FF").unwrap(),
+                Felt(Felt252::from(255))
+            );
+            assert!(ParseMode::StrictDec.parse::<Felt>("FF").is_err());
+            assert!(ParseMode::StrictHex.parse::<Felt>("FF").is_err());
+            assert!(ParseMode::StrictHex.parse::<Felt>("123").is_err());
+            assert_eq!(
+                ParseMode::StrictHex.parse::<Uint8>("0xFF").unwrap(),
+                Uint8(255)
+            );
+            assert_eq!(
+                ParseMode::StrictHex.parse::<Uint8>("0x10").unwrap(),
+                Uint8(16)
+            );
+            assert_eq!(
+                ParseMode::StrictDec.parse::<Uint8>("10").unwrap(),
+                Uint8(10)
+            );
+            assert!(ParseMode::StrictDec.parse::<Uint8>("0x10").is_err());
+            assert!(ParseMode::StrictDec.parse::<Uint8>("").is_err());
+            assert_eq!(
+                ParseMode::StrictHex.parse::<Int64>("-0x10").unwrap(),
+                Int64(-16)
+            );
+            assert_eq!(
+                ParseMode::StrictDec.parse::<Int64>("-10").unwrap(),
+                Int64(-10)
+            );
+            assert!(ParseMode::StrictDec.parse::<Uint8>("-10").is_err());
+        }
+
+        #[test]
+        fn test_strict_serde_fields() {
+            let wrapper: StrictWrapper =
+                serde_json::from_str(r#"{"hash": "0x123", "amount": "123"}"#).unwrap();
+            assert_eq!(wrapper.hash, Felt(Felt252::from(0x123)));
+            assert_eq!(wrapper.amount, Uint8(123));
+            let wrapper: StrictWrapper =
+                serde_json::from_str(r#"{"hash": "0x123", "amount": 7}"#).unwrap();
+            assert_eq!(wrapper.amount, Uint8(7));
+
+            for json in [
+                r#"{"hash": "123", "amount": "123"}"#,
+                r#"{"hash": 291, "amount": "123"}"#,
+                r#"{"hash": "0x123", "amount": "FF"}"#,
+                r#"{"hash": "0x123", "amount": "0x12"}"#,
+            ] {
+                assert!(
+                    serde_json::from_str::<StrictWrapper>(json).is_err(),
+                    "{json}"
+                );
+            }
+        }
+    }
+
+    mod edge_case_tests {
+        use super::*;
+        use crate::types::bounded_uint::Uint64;
+        use crate::types::byte_array::ByteArray;
+        use crate::types::int::Int64;
+        use crate::types::keccak_bytes::KeccakBytes;
+        use crate::types::short_string::ShortString;
+        use crate::types::{serde_utils, FromAnyStr, ParseOptions};
+
+        #[test]
+        fn test_hex_with_underscore_separators() {
+            let json = r#"{"value": "0x1a_2b_3c_4d"}"#;
+            let wrapper: FeltWrapper = serde_json::from_str(json).unwrap();
+            let expected = felt::Felt(cairo_vm::Felt252::from(0x1a2b3c4du64));
+            assert_eq!(wrapper.value, expected);
+        }
+
+        #[test]
+        fn test_odd_length_hex() {
+            let json = r#"{"value": "0x1"}"#;
+            let wrapper: FeltWrapper = serde_json::from_str(json).unwrap();
+            let expected = felt::Felt(cairo_vm::Felt252::from(1u64));
+            assert_eq!(wrapper.value, expected);
+        }
+
+        #[test]
+        fn test_empty_string_behavior() {
+            // Numeric types reject input without digits, unless told to read it as zero
+            for input in ["", "0x", " ", " 0x1", "1\n"] {
+                let json = format!(r#"{{"value": {input:?}}}"#);
+                assert!(
+                    serde_json::from_str::<FeltWrapper>(&json).is_err(),
+                    "{input:?}"
+                );
+                assert!(
+                    serde_json::from_str::<Uint256Wrapper>(&json).is_err(),
+                    "{input:?}"
+                );
+                assert!(Uint64::from_any_str(input).is_err(), "{input:?}");
+                assert!(Int64::from_any_str(input).is_err(), "{input:?}");
+            }
+            assert!(Int64::from_any_str("-").is_err());
+
+            let options = ParseOptions::LENIENT;
+            for input in ["", "0x", "  ", "-"] {
+                assert_eq!(
+                    felt::Felt::from_any_str_with_options(input, &options).unwrap(),
+                    felt::Felt(cairo_vm::Felt252::ZERO)
+                );
+                assert_eq!(
+                    Uint64::from_any_str_with_options(input, &options).unwrap(),
+                    Uint64(0)
+                );
+            }
+            assert_eq!(
+                Int64::from_any_str_with_options(" -5\t", &options).unwrap(),
+                Int64(-5)
+            );
+            let trim_only = ParseOptions {
+                trim_whitespace: true,
+                ..Default::default()
+            };
+            assert!(Uint64::from_any_str_with_options(" ", &trim_only).is_err());
+            assert_eq!(
+                Uint64::from_any_str_with_options(" 7 ", &trim_only).unwrap(),
+                Uint64(7)
+            );
+
+            #[derive(Debug, Deserialize)]
+            struct Lenient {
+                #[serde(deserialize_with = "serde_utils::deserialize_lenient")]
+                value: felt::Felt,
+            }
+            let lenient: Lenient = serde_json::from_str(r#"{"value": " 0x "}"#).unwrap();
+            assert_eq!(lenient.value, felt::Felt(cairo_vm::Felt252::ZERO));
+
+            // Byte types read empty input as no bytes, text types keep it as is
+            assert_eq!(KeccakBytes::from_any_str("").unwrap(), KeccakBytes(vec![]));
+            assert_eq!(
+                KeccakBytes::from_any_str("0x").unwrap(),
+                KeccakBytes(vec![])
+            );
+            assert!(KeccakBytes::from_any_str(" 0x12").is_err());
+            assert_eq!(
+                KeccakBytes::from_any_str_with_options(" 0x12", &options).unwrap(),
+                KeccakBytes(vec![0x12])
+            );
+            assert_eq!(
+                ByteArray::from_any_str_with_options(" a ", &options).unwrap(),
+                ByteArray::from(" a ")
+            );
+            assert_eq!(
+                ShortString::from_any_str("").unwrap(),
+                ShortString(cairo_vm::Felt252::ZERO)
+            );
+        }
+
+        #[test]
+        fn test_null_value_fails() {
+            let json = r#"{"value": null}"#;
+            let result: Result<FeltWrapper, _> = serde_json::from_str(json);
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn test_boolean_fails() {
+            let json = r#"{"value": true}"#;
+            let result: Result<FeltWrapper, _> = serde_json::from_str(json);
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn test_array_fails() {
+            let json = r#"{"value": [1, 2, 3]}"#;
+            let result: Result<FeltWrapper, _> = serde_json::from_str(json);
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn test_object_fails() {
+            let json = r#"{"value": {"nested": "object"}}"#;
+            let result: Result<FeltWrapper, _> = serde_json::from_str(json);
+            assert!(result.is_err());
+        }
+    }
+
+    mod comprehensive_format_tests {
+        use super::*;
+        use cairo_vm::Felt252;
+
+        #[test]
+        fn test_all_types_with_same_value() {
+            let value_str = "12345";
+            let value_num = 12345u64;
+
+            // Test Felt
+            let felt_json = format!(r#"{{"value": "{value_str}"}}"#);
+            let felt_wrapper: FeltWrapper = serde_json::from_str(&felt_json).unwrap();
+            assert_eq!(felt_wrapper.value, felt::Felt(Felt252::from(value_num)));
+
+            let felt_json_num = format!(r#"{{"value": {value_num}}}"#);
+            let felt_wrapper_num: FeltWrapper = serde_json::from_str(&felt_json_num).unwrap();
+            assert_eq!(felt_wrapper_num.value, felt::Felt(Felt252::from(value_num)));
+
+            // Test Uint256
+            let uint256_json = format!(r#"{{"value": "{value_str}"}}"#);
+            let uint256_wrapper: Uint256Wrapper = serde_json::from_str(&uint256_json).unwrap();
+            assert_eq!(
+                uint256_wrapper.value,
+                uint256::Uint256::new(num_bigint::BigUint::from(value_num))
+            );
+
+            let uint256_json_num = format!(r#"{{"value": {value_num}}}"#);
+            let uint256_wrapper_num: Uint256Wrapper =
+                serde_json::from_str(&uint256_json_num).unwrap();
+            assert_eq!(
+                uint256_wrapper_num.value,
+                uint256::Uint256::new(num_bigint::BigUint::from(value_num))
+            );
+
+            // Test UInt384
+            let uint384_json = format!(r#"{{"value": "{value_str}"}}"#);
+            let uint384_wrapper: UInt384Wrapper = serde_json::from_str(&uint384_json).unwrap();
+            assert_eq!(
+                uint384_wrapper.value,
+                uint384::UInt384::new(num_bigint::BigUint::from(value_num))
+            );
+
+            let uint384_json_num = format!(r#"{{"value": {value_num}}}"#);
+            let uint384_wrapper_num: UInt384Wrapper =
+                serde_json::from_str(&uint384_json_num).unwrap();
+            assert_eq!(
+                uint384_wrapper_num.value,
+                uint384::UInt384::new(num_bigint::BigUint::from(value_num))
+            );
+
+            // Test Uint256Bits32
+            let uint256_32_json = format!(r#"{{"value": "{value_str}"}}"#);
+            let uint256_32_wrapper: Uint256Bits32Wrapper =
+                serde_json::from_str(&uint256_32_json).unwrap();
+            assert_eq!(
+                uint256_32_wrapper.value,
+                uint256_32::Uint256Bits32::new(num_bigint::BigUint::from(value_num))
+            );
+
+            let uint256_32_json_num = format!(r#"{{"value": {value_num}}}"#);
+            let uint256_32_wrapper_num: Uint256Bits32Wrapper =
+                serde_json::from_str(&uint256_32_json_num).unwrap();
+            assert_eq!(
+                uint256_32_wrapper_num.value,
+                uint256_32::Uint256Bits32::new(num_bigint::BigUint::from(value_num))
+            );
+        }
+    }
+
+    mod binary_format_tests {
+        use crate::types::{
+            bls12_381::G1Point,
+            bounded_uint::Uint64,
+            felt::Felt,
+            int::Int64,
+            option::CairoOption,
+            serde_utils::{decode, encode},
+            short_string::ShortString,
+            uint256::Uint256,
+            uint384::UInt384,
+            FromAnyStr,
+        };
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Cache {
+            felt: Felt,
+            uint256: Uint256,
+            uint384: UInt384,
+            small: Uint64,
+            signed: Int64,
+            name: ShortString,
+            point: G1Point,
+            maybe: CairoOption<Uint256>,
+            values: Vec<Felt>,
+        }
+
+        fn cache() -> Cache {
+            Cache {
+                felt: Felt::from_any_str("0x1234").unwrap(),
+                uint256: Uint256::from_any_str("0xdeadbeef00000000000000000000000001").unwrap(),
+                uint384: UInt384::from_any_str("123456789").unwrap(),
+                small: Uint64::from_any_str("42").unwrap(),
+                signed: Int64::from_any_str("-7").unwrap(),
+                name: ShortString::from_any_str("hello").unwrap(),
+                point: G1Point::infinity(),
+                maybe: CairoOption::new(None),
+                values: vec![
+                    Felt::from_any_str("1").unwrap(),
+                    Felt::from_any_str("2").unwrap(),
+                ],
+            }
+        }
+
+        #[test]
+        fn test_bincode_round_trip() {
+            let bytes = encode(&cache()).unwrap();
+            assert_eq!(decode::<Cache>(&bytes).unwrap(), cache());
+
+            let mut trailing = bytes.clone();
+            trailing.push(0);
+            assert!(decode::<Cache>(&trailing).is_err());
+        }
+
+        #[test]
+        fn test_compact_encoding_uses_raw_bytes() {
+            let value = Uint256::from_any_str("0xff").unwrap();
+            // Length prefix and the 32 big-endian bytes, instead of a 66 character hex string
+            let bytes = encode(&value).unwrap();
+            assert_eq!(bytes.len(), 33);
+            assert_eq!(bytes[32], 0xff);
+            assert_eq!(
+                serde_json::to_string(&value).unwrap(),
+                format!("\"0x{:064x}\"", 0xff)
+            );
+
+            assert_eq!(
+                encode(&Uint64::from_any_str("1").unwrap()).unwrap().len(),
+                9
+            );
+            assert_eq!(
+                encode(&UInt384::from_any_str("1").unwrap()).unwrap().len(),
+                49
+            );
+            // Bytes that don't fit are still rejected when decoding
+            assert!(
+                decode::<Uint256>(&encode(&UInt384::from_any_str("0x1").unwrap()).unwrap()).is_ok()
+            );
+            assert!(
+                decode::<Uint64>(&encode(&Uint256::from_any_str("0x1").unwrap()).unwrap()).is_ok()
+            );
+            assert!(decode::<Uint64>(
+                &encode(&Uint256::from_any_str("0x10000000000000000").unwrap()).unwrap()
+            )
+            .is_err());
+        }
+
+        #[test]
+        fn test_postcard_round_trip() {
+            let bytes = postcard::to_allocvec(&cache()).unwrap();
+            assert_eq!(postcard::from_bytes::<Cache>(&bytes).unwrap(), cache());
+        }
+
+        #[test]
+        fn test_cbor_round_trip() {
+            let mut bytes = Vec::new();
+            ciborium::into_writer(&cache(), &mut bytes).unwrap();
+            assert_eq!(
+                ciborium::from_reader::<Cache, _>(bytes.as_slice()).unwrap(),
+                cache()
+            );
+        }
+    }
+
+    mod memory_tests {
+        use super::*;
+        use crate::cairo_type::{CairoType, CairoWritable};
+        use cairo_vm::{vm::vm_core::VirtualMachine, Felt252};
+        use num_bigint::BigUint;
+
+        #[test]
+        fn test_uint_bits_rejects_oversized_limbs() {
+            let limb_96 = Felt252::from(1u128 << 96);
+            let limbs = [limb_96, Felt252::ZERO, Felt252::ZERO, Felt252::ZERO];
+            assert!(uint384::UInt384::from_limbs(&limbs).is_err());
+            assert!(uint384::UInt384::from_limbs(&limbs[..3]).is_err());
+
+            // A high limb of 2^128 would otherwise set a word past the 256 bits
+            let mut vm = VirtualMachine::new(false, false);
+            let base = vm.add_memory_segment();
+            vm.insert_value(base, Felt252::ZERO).unwrap();
+            vm.insert_value(
+                (base + 1usize).unwrap(),
+                Felt252::from(1u128 << 127) * Felt252::TWO,
+            )
+            .unwrap();
+            assert!(uint256::Uint256::from_memory(&vm, base).is_err());
+            assert!(uint256::Uint256::from_memory_range(&vm, base, 1).is_err());
+        }
+
+        #[test]
+        fn test_uint_bits_memory_round_trip() {
+            let mut vm = VirtualMachine::new(false, false);
+            let base = vm.add_memory_segment();
+            let value = (BigUint::from(1u32) << 255usize) | BigUint::from(0x1234u32);
+
+            let uint256 = uint256::Uint256::new(value.clone());
+            let uint384 = uint384::UInt384::new(value.clone());
+            let uint256_32 = uint256_32::Uint256Bits32::new(value);
+
+            let next = uint256.to_memory(&mut vm, base).unwrap();
+            let next2 = uint384.to_memory(&mut vm, next).unwrap();
+            uint256_32.to_memory(&mut vm, next2).unwrap();
+
+            assert_eq!(uint256::Uint256::from_memory(&vm, base).unwrap(), uint256);
+            assert_eq!(uint384::UInt384::from_memory(&vm, next).unwrap(), uint384);
+            assert_eq!(
+                uint256_32::Uint256Bits32::from_memory(&vm, next2).unwrap(),
+                uint256_32
+            );
+
+            // Uint256Bits32 limbs are stored most significant first
+            let limbs_ptr = vm.get_relocatable(next2).unwrap();
+            assert_eq!(
+                *vm.get_integer(limbs_ptr).unwrap(),
+                Felt252::from(0x80000000u64)
+            );
+        }
+
+        #[test]
+        fn test_keccak_bytes_memory_round_trip() {
+            use crate::types::keccak_bytes::KeccakBytes;
+
+            let mut vm = VirtualMachine::new(false, false);
+            for bytes in [vec![], vec![0x01, 0x00, 0x00], vec![0; 8], vec![0xff; 9]] {
+                let address = vm.add_memory_segment();
+                let value = KeccakBytes(bytes);
+                let end = value.to_memory(&mut vm, address).unwrap();
+                assert_eq!(end, (address + 2usize).unwrap());
+                assert_eq!(KeccakBytes::from_memory(&vm, address).unwrap(), value);
+            }
+            assert_eq!(KeccakBytes::layout().cells()[1].name, "n_bytes");
+
+            // A length cutting nonzero bytes is rejected rather than truncated
+            let address = vm.add_memory_segment();
+            let limbs = KeccakBytes(vec![0x01, 0x02]).write_limbs(&mut vm).unwrap();
+            vm.insert_value(address, limbs).unwrap();
+            vm.insert_value((address + 1usize).unwrap(), Felt252::ONE)
+                .unwrap();
+            assert!(KeccakBytes::from_memory(&vm, address).is_err());
+        }
+
+        #[test]
+        fn test_from_memory_range() {
+            use crate::types::bounded_uint::Uint8;
+            use crate::types::fixed_bytes::Bytes32;
+
+            let mut vm = VirtualMachine::new(false, false);
+            let values: Vec<uint256::Uint256> = (0..5u32)
+                .map(|i| uint256::Uint256::new(BigUint::from(i) << 200usize))
+                .collect();
+            let base = vm.add_memory_segment();
+            let mut ptr = base;
+            for value in &values {
+                ptr = value.to_memory(&mut vm, ptr).unwrap();
+            }
+            assert_eq!(
+                uint256::Uint256::from_memory_range(&vm, base, 5).unwrap(),
+                values
+            );
+            // Read as the 10 felts, or as bytes with the same layout
+            let felts = felt::Felt::from_memory_range(&vm, base, 10).unwrap();
+            assert_eq!(felts[3].0, Felt252::from(1u128 << 72));
+            assert_eq!(Bytes32::from_memory_range(&vm, base, 5).unwrap().len(), 5);
+            assert!(felt::Felt::from_memory_range(&vm, base, 11).is_err());
+            assert!(Uint8::from_memory_range(&vm, base, 4).is_err());
+
+            // Indirect limbs are read one value at a time
+            let value = uint256_32::Uint256Bits32::new(BigUint::from(7u32));
+            let address = vm.add_memory_segment();
+            value.to_memory(&mut vm, address).unwrap();
+            assert_eq!(
+                uint256_32::Uint256Bits32::from_memory_range(&vm, address, 1).unwrap(),
+                vec![value]
+            );
+        }
+
+        #[test]
+        fn test_cairo_pointer() {
+            use crate::cairo_type::{FieldKind, Layout};
+            use crate::types::pointer::CairoPointer;
+            use uint256::Uint256;
+
+            let mut vm = VirtualMachine::new(false, false);
+            let array = CairoPointer::<Uint256>::alloc(&mut vm);
+            let values: Vec<Uint256> = (1u32..4).map(|i| Uint256::new(BigUint::from(i))).collect();
+            for (i, value) in values.iter().enumerate() {
+                array
+                    .offset(i)
+                    .unwrap()
+                    .write_pointee(&mut vm, value)
+                    .unwrap();
+            }
+            assert_eq!(
+                array.offset(2).unwrap().address(),
+                (array.address() + 4usize).unwrap()
+            );
+            assert_eq!(array.offset(1).unwrap().deref(&vm).unwrap(), values[1]);
+
+            // A struct holding a pointer to the array
+            let base = vm.add_memory_segment();
+            let next = array.offset(1).unwrap().to_memory(&mut vm, base).unwrap();
+            assert_eq!(next, (base + 1usize).unwrap());
+            let read = CairoPointer::<Uint256>::from_memory(&vm, base).unwrap();
+            assert_eq!(read.deref(&vm).unwrap(), values[1]);
+            assert_eq!(read.offset(1).unwrap().deref(&vm).unwrap(), values[2]);
+
+            // Pointers to pointers
+            let outer = CairoPointer::<CairoPointer<Uint256>>::new(base);
+            assert_eq!(outer.deref(&vm).unwrap(), read);
+
+            // Reading a felt as a pointer fails
+            let felt = vm.add_memory_segment();
+            vm.insert_value(felt, Felt252::from(7)).unwrap();
+            assert!(CairoPointer::<Uint256>::from_memory(&vm, felt).is_err());
+            assert!(array.offset(3).unwrap().deref(&vm).is_err());
+
+            let layout = CairoPointer::<Uint256>::layout();
+            assert_eq!(layout.fields[0].kind, FieldKind::Pointer);
+            assert_eq!(layout.size(), 1);
+            assert_eq!(layout, Layout::new(layout.name.clone()).pointer("ptr"));
+        }
+
+        #[test]
+        fn test_cairo_tuples() {
+            use crate::types::bounded_uint::Uint64;
+            use felt::Felt;
+            use uint256::Uint256;
+
+            let mut vm = VirtualMachine::new(false, false);
+            let base = vm.add_memory_segment();
+            let value = (
+                Felt(Felt252::from(7)),
+                Uint256::new(BigUint::from(1u128 << 100)),
+                Uint64::checked_new(42).unwrap(),
+            );
+            let next = value.to_memory(&mut vm, base).unwrap();
+            assert_eq!(next, (base + 4usize).unwrap());
+            assert_eq!(<(Felt, Uint256, Uint64)>::n_fields(), 4);
+            assert_eq!(
+                <(Felt, Uint256, Uint64)>::from_memory(&vm, base).unwrap(),
+                value
+            );
+
+            // Nested tuples are flattened in memory
+            let nested = <((Felt, Uint256), Uint64)>::from_memory(&vm, base).unwrap();
+            assert_eq!(nested, ((value.0.clone(), value.1.clone()), value.2));
+
+            let layout = <(Felt, Uint256, Uint64)>::layout();
+            assert_eq!(layout.name, "(Felt, UintBits<256, 128>, Uint64)");
+            let cells: Vec<_> = layout
+                .cells()
+                .into_iter()
+                .map(|cell| (cell.name, cell.offset))
+                .collect();
+            assert_eq!(
+                cells,
+                [
+                    ("0.value".to_string(), 0),
+                    ("1.low".to_string(), 1),
+                    ("1.high".to_string(), 2),
+                    ("2.value".to_string(), 3)
+                ]
+            );
+
+            // Reading past the written cells fails
+            assert!(<(Felt, Felt)>::from_memory(&vm, (base + 3usize).unwrap()).is_err());
+        }
+
+        #[test]
+        fn test_layouts() {
+            use crate::cairo_type::{FieldKind, Layout};
+            use crate::types::{
+                beacon::ExecutionPayloadHeader, bls12_381::G1Point, byte_array::ByteArray,
+                option::CairoOption, proof::MerkleProof,
+            };
+
+            let layout = uint256::Uint256::layout();
+            assert_eq!(layout.name, "UintBits<256, 128>");
+            let names: Vec<_> = layout.fields.iter().map(|f| f.name.as_str()).collect();
+            assert_eq!(names, ["low", "high"]);
+            assert_eq!(
+                uint256_32::Uint256Bits32::layout().fields[0].kind,
+                FieldKind::Pointer
+            );
+
+            let cells = G1Point::layout().cells();
+            assert_eq!(cells.len(), 8);
+            assert_eq!((cells[4].name.as_str(), cells[4].offset), ("y.d0", 4));
+
+            // Each described layout spans exactly `n_fields()` felts
+            fn check<T: CairoWritable>() -> Layout {
+                let layout = T::layout();
+                assert_eq!(layout.size(), T::n_fields(), "{}", layout.name);
+                layout
+            }
+            check::<uint384::UInt384>();
+            check::<ByteArray>();
+            check::<CairoOption<uint256::Uint256>>();
+            check::<MerkleProof<uint256::Uint256>>();
+            let header = check::<ExecutionPayloadHeader>();
+            let block_number = header
+                .cells()
+                .into_iter()
+                .find(|cell| cell.name == "block_number.value")
+                .unwrap();
+            assert_eq!(block_number.offset, 10);
+            assert_eq!(header.fields[12].name, "base_fee_per_gas");
+            assert_eq!(header.fields[12].offset, 16);
+            assert_eq!(header.fields[12].width(), 2);
+
+            // Types without member names get one felt per index
+            let felts = Layout::felts("Pair", 2);
+            assert_eq!(felts.fields[1].name, "1");
+            assert_eq!(felts.size(), 2);
+        }
+
+        #[test]
+        fn test_uint_checked_narrowing() {
+            let small = uint256::Uint256::new(BigUint::from(u64::MAX));
+            assert_eq!(small.to_u64_checked().unwrap(), u64::MAX);
+            assert_eq!(small.to_u128_checked().unwrap(), u64::MAX as u128);
+            assert_eq!(small.to_felt_checked().unwrap(), Felt252::from(u64::MAX));
+
+            let medium = uint384::UInt384::new(BigUint::from(u64::MAX) + BigUint::from(1u32));
+            let err = medium.to_u64_checked().unwrap_err();
+            assert_eq!(
+                err,
+                "384-bit value 0x10000000000000000 does not fit in a u64"
+            );
+            assert_eq!(medium.to_u128_checked().unwrap(), 1u128 << 64);
+
+            let prime = Felt252::MAX.to_biguint() + BigUint::from(1u32);
+            assert!(uint256::Uint256::new(prime).to_felt_checked().is_err());
+            let max_felt = uint256::Uint256::new(Felt252::MAX.to_biguint());
+            assert_eq!(max_felt.to_felt_checked().unwrap(), Felt252::MAX);
+        }
+
+        #[test]
+        fn test_uint_bits_custom_width() {
+            type Uint160 = crate::types::uint_bits::UintBits<160, 32>;
+            assert_eq!(Uint160::N_LIMBS, 5);
+            assert_eq!(Uint160::n_fields(), 5);
+
+            let json = r#""0xffffffffffffffffffffffffffffffffffffffff""#;
+            let value: Uint160 = serde_json::from_str(json).unwrap();
+            assert_eq!(serde_json::to_string(&value).unwrap(), json);
+            let overflow = r#""0x01ffffffffffffffffffffffffffffffffffffffff""#;
+            assert!(serde_json::from_str::<Uint160>(overflow).is_err());
+        }
+
+        #[test]
+        fn test_uint_bits_limbs_match_biguint() {
+            // Limbs straddling word boundaries, checked against a BigUint computation
+            let value = BigUint::parse_bytes(
+                b"f1e2d3c4b5a69788796a5b4c3d2e1f00112233445566778899aabbccddeeff0123456789abcdeffedcba98765432",
+                16,
+            )
+            .unwrap();
+            let uint384 = uint384::UInt384::new(value.clone());
+            let mask = (BigUint::from(1u32) << 96usize) - BigUint::from(1u32);
+            let expected: Vec<Felt252> = (0..4)
+                .map(|i| Felt252::from(&((&value >> (96 * i)) & &mask)))
+                .collect();
+            let limbs: Vec<Felt252> = uint384.to_limbs().collect();
+            assert_eq!(limbs, expected);
+            assert_eq!(uint384::UInt384::from_limbs(&limbs).unwrap(), uint384);
+            assert_eq!(uint384.to_biguint(), value);
+            assert_eq!(uint384.words().len(), 6);
+
+            let uint256_32 = uint256_32::Uint256Bits32::new(BigUint::from(0x1_0000_0002u64));
+            let limbs: Vec<Felt252> = uint256_32.to_limbs().collect();
+            assert_eq!(limbs[6], Felt252::from(1));
+            assert_eq!(limbs[7], Felt252::from(2));
         }
     }
 }
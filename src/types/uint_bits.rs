@@ -0,0 +1,639 @@
+use crate::cairo_type::{
+    read_felt_chunks, write_felts, BaseCairoType, CairoType, CairoWritable, Layout,
+};
+use crate::stdlib::prelude::*;
+use crate::types::{
+    check_numeric_input, hex_bytes_padded, parse_biguint_radix, FromAnyStr, FromAnyStrWithPolicy,
+    FromStrRadix, OverflowPolicy,
+};
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use core::marker::PhantomData;
+use num_bigint::BigUint;
+
+// How the limbs of a `UintBits` are laid out in VM memory
+pub trait LimbLayout {
+    // Limbs are stored most significant first
+    const BIG_ENDIAN: bool;
+    // Limbs are stored in a new segment, and a pointer to it is stored at the address
+    const INDIRECT: bool;
+}
+
+// Limbs stored in place, least significant first, e.g. `Uint256 { low, high }`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inline;
+
+impl LimbLayout for Inline {
+    const BIG_ENDIAN: bool = false;
+    const INDIRECT: bool = false;
+}
+
+// Pointer to a segment of limbs, most significant first, e.g. `felt*` of u32 words
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SegmentBigEndian;
+
+impl LimbLayout for SegmentBigEndian {
+    const BIG_ENDIAN: bool = true;
+    const INDIRECT: bool = true;
+}
+
+// Capacity of the fixed word storage, wider integers are rejected at compile time
+const MAX_WORDS: usize = 8;
+
+/// Unsigned integer of `BITS` bits split into `LIMB`-bit limbs.
+///
+/// The value is kept as little-endian `u64` words so limb conversions don't allocate.
+/// New widths only need an alias, e.g. `type Uint160 = UintBits<160, 32>;`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UintBits<const BITS: usize, const LIMB: usize, L: LimbLayout = Inline> {
+    words: [u64; MAX_WORDS],
+    layout: PhantomData<L>,
+}
+
+// Bits `[start, start + len)` of little-endian `words`, with `len` at most 256
+fn read_bits(words: &[u64], start: usize, len: usize) -> [u64; 4] {
+    let mut digits = [0u64; 4];
+    for (j, digit) in digits.iter_mut().enumerate().take(len.div_ceil(64)) {
+        let bit = start + j * 64;
+        let (word, shift) = (bit / 64, bit % 64);
+        *digit = words.get(word).copied().unwrap_or(0) >> shift;
+        if shift > 0 {
+            *digit |= words.get(word + 1).copied().unwrap_or(0) << (64 - shift);
+        }
+    }
+    if len % 64 != 0 {
+        digits[len / 64] &= (1u64 << (len % 64)) - 1;
+    }
+    digits
+}
+
+// ORs the little-endian `digits` into `words` at bit `start`, dropping bits past the capacity
+fn write_bits(words: &mut [u64; MAX_WORDS], start: usize, digits: &[u64]) {
+    for (j, digit) in digits.iter().enumerate().filter(|(_, digit)| **digit != 0) {
+        let bit = start + j * 64;
+        let (word, shift) = (bit / 64, bit % 64);
+        if word < MAX_WORDS {
+            words[word] |= digit << shift;
+        }
+        if shift > 0 && word + 1 < MAX_WORDS {
+            words[word + 1] |= digit >> (64 - shift);
+        }
+    }
+}
+
+// Felt from at most four little-endian digits, which must be below the field prime
+fn digits_to_felt(digits: &[u64]) -> Felt252 {
+    if digits.iter().skip(1).all(|digit| *digit == 0) {
+        return Felt252::from(digits.first().copied().unwrap_or(0));
+    }
+    let mut bytes = [0u8; 32];
+    for (chunk, digit) in bytes.chunks_exact_mut(8).zip(digits) {
+        chunk.copy_from_slice(&digit.to_le_bytes());
+    }
+    Felt252::from_bytes_le(&bytes)
+}
+
+impl<const BITS: usize, const LIMB: usize, L: LimbLayout> UintBits<BITS, LIMB, L> {
+    pub const N_LIMBS: usize = BITS.div_ceil(LIMB);
+    pub const BYTES: usize = BITS.div_ceil(8);
+    const WORDS: usize = BITS.div_ceil(64);
+    const VALID: () = assert!(
+        BITS <= MAX_WORDS * 64 && LIMB > 0 && LIMB < 252,
+        "UintBits supports up to 512 bits with limbs that fit in a felt"
+    );
+
+    fn from_words(words: [u64; MAX_WORDS]) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+        Self {
+            words,
+            layout: PhantomData,
+        }
+    }
+
+    // Panics if `value` does not fit in `BITS` bits
+    pub fn new(value: BigUint) -> Self {
+        assert!(
+            value.bits() <= BITS as u64,
+            "value does not fit in {BITS} bits"
+        );
+        let mut words = [0u64; MAX_WORDS];
+        for (word, digit) in words.iter_mut().zip(value.iter_u64_digits()) {
+            *word = digit;
+        }
+        Self::from_words(words)
+    }
+
+    pub fn to_biguint(&self) -> BigUint {
+        let digits = self
+            .words()
+            .iter()
+            .flat_map(|w| [*w as u32, (w >> 32) as u32]);
+        BigUint::new(digits.collect())
+    }
+
+    // Little-endian u64 words of the value
+    pub fn words(&self) -> &[u64] {
+        &self.words[..Self::WORDS]
+    }
+
+    // Inverse of `words`, panics if the value does not fit in `BITS` bits
+    pub fn from_words_le(digits: &[u64]) -> Self {
+        let mut words = [0u64; MAX_WORDS];
+        for (word, digit) in words.iter_mut().zip(digits) {
+            *word = *digit;
+        }
+        let (value, overflow) = Self::truncate(words);
+        assert!(
+            !overflow && digits.iter().skip(MAX_WORDS).all(|digit| *digit == 0),
+            "value does not fit in {BITS} bits"
+        );
+        value
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    fn limb(&self, i: usize) -> Felt252 {
+        digits_to_felt(&read_bits(&self.words, i * LIMB, LIMB))
+    }
+
+    // Limbs in memory order, as defined by the layout
+    pub fn to_limbs(&self) -> impl ExactSizeIterator<Item = Felt252> + '_ {
+        (0..Self::N_LIMBS).map(move |i| {
+            if L::BIG_ENDIAN {
+                self.limb(Self::N_LIMBS - 1 - i)
+            } else {
+                self.limb(i)
+            }
+        })
+    }
+
+    // Inverse of `to_limbs`, rejecting limbs above `LIMB` bits
+    pub fn from_limbs(limbs: &[Felt252]) -> Result<Self, String> {
+        if limbs.len() != Self::N_LIMBS {
+            return Err(format!(
+                "expected {} limbs, got {}",
+                Self::N_LIMBS,
+                limbs.len()
+            ));
+        }
+        let mut words = [0u64; MAX_WORDS];
+        for (i, limb) in limbs.iter().enumerate() {
+            let digits = limb.to_le_digits();
+            let bits = digits
+                .iter()
+                .rposition(|digit| *digit != 0)
+                .map_or(0, |j| 64 * (j + 1) - digits[j].leading_zeros() as usize);
+            if bits > LIMB {
+                return Err(format!(
+                    "limb {i} ({}) does not fit in {LIMB} bits",
+                    limb.to_hex_string()
+                ));
+            }
+            let position = if L::BIG_ENDIAN {
+                limbs.len() - 1 - i
+            } else {
+                i
+            };
+            write_bits(&mut words, position * LIMB, &digits);
+        }
+        match Self::truncate(words) {
+            (value, false) => Ok(value),
+            (_, true) => Err(format!("limbs do not fit in {BITS} bits")),
+        }
+    }
+
+    fn fits_in_words(&self, n: usize) -> bool {
+        self.words[n..].iter().all(|word| *word == 0)
+    }
+
+    pub fn to_u64_checked(&self) -> Result<u64, String> {
+        if !self.fits_in_words(1) {
+            return Err(self.narrowing_error("u64"));
+        }
+        Ok(self.words[0])
+    }
+
+    pub fn to_u128_checked(&self) -> Result<u128, String> {
+        if !self.fits_in_words(2) {
+            return Err(self.narrowing_error("u128"));
+        }
+        Ok(((self.words[1] as u128) << 64) | self.words[0] as u128)
+    }
+
+    pub fn to_usize_checked(&self) -> Result<usize, String> {
+        self.to_u64_checked()
+            .ok()
+            .and_then(|value| usize::try_from(value).ok())
+            .ok_or_else(|| self.narrowing_error("usize"))
+    }
+
+    // Fails if the value is not below the field prime instead of reducing it
+    pub fn to_felt_checked(&self) -> Result<Felt252, String> {
+        let max = Felt252::MAX.to_le_digits();
+        let above_max = (0..4)
+            .rev()
+            .map(|i| self.words[i].cmp(&max[i]))
+            .find(|ordering| ordering.is_ne())
+            .is_some_and(|ordering| ordering.is_gt());
+        if !self.fits_in_words(4) || above_max {
+            return Err(self.narrowing_error("felt252"));
+        }
+        Ok(digits_to_felt(&self.words[..4]))
+    }
+
+    fn narrowing_error(&self, target: &str) -> String {
+        format!(
+            "{BITS}-bit value 0x{} does not fit in a {target}",
+            self.to_biguint().to_str_radix(16)
+        )
+    }
+
+    #[cfg(feature = "serde")]
+    fn to_bytes_be(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = self.words().iter().flat_map(|w| w.to_le_bytes()).collect();
+        bytes.truncate(Self::BYTES);
+        bytes.reverse();
+        bytes
+    }
+}
+
+impl<const BITS: usize, const LIMB: usize, L: LimbLayout> From<BigUint>
+    for UintBits<BITS, LIMB, L>
+{
+    fn from(value: BigUint) -> Self {
+        Self::new(value)
+    }
+}
+
+// Arithmetic modulo 2^BITS. The operators panic on overflow like Rust's integers do in debug
+// builds, the `checked_` and `wrapping_` variants return `None` or wrap around instead.
+impl<const BITS: usize, const LIMB: usize, L: LimbLayout> UintBits<BITS, LIMB, L> {
+    // Drops the bits past `BITS`, flagging whether any was set
+    fn truncate(mut words: [u64; MAX_WORDS]) -> (Self, bool) {
+        let mut overflow = false;
+        for (i, word) in words.iter_mut().enumerate() {
+            let kept = BITS.saturating_sub(i * 64);
+            let mask = match kept {
+                0 => 0,
+                1..=63 => (1u64 << kept) - 1,
+                _ => u64::MAX,
+            };
+            overflow |= *word & !mask != 0;
+            *word &= mask;
+        }
+        (Self::from_words(words), overflow)
+    }
+
+    pub fn overflowing_add(&self, rhs: &Self) -> (Self, bool) {
+        let mut words = [0u64; MAX_WORDS];
+        let mut carry = false;
+        for (i, word) in words.iter_mut().enumerate() {
+            let (sum, c1) = self.words[i].overflowing_add(rhs.words[i]);
+            let (sum, c2) = sum.overflowing_add(carry as u64);
+            *word = sum;
+            carry = c1 || c2;
+        }
+        let (result, overflow) = Self::truncate(words);
+        (result, overflow || carry)
+    }
+
+    pub fn overflowing_sub(&self, rhs: &Self) -> (Self, bool) {
+        let mut words = [0u64; MAX_WORDS];
+        let mut borrow = false;
+        for (i, word) in words.iter_mut().enumerate() {
+            let (difference, b1) = self.words[i].overflowing_sub(rhs.words[i]);
+            let (difference, b2) = difference.overflowing_sub(borrow as u64);
+            *word = difference;
+            borrow = b1 || b2;
+        }
+        (Self::truncate(words).0, borrow)
+    }
+
+    pub fn overflowing_mul(&self, rhs: &Self) -> (Self, bool) {
+        let mut product = [0u64; 2 * MAX_WORDS];
+        for (i, a) in self.words().iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, b) in rhs.words().iter().enumerate() {
+                let t = *a as u128 * *b as u128 + product[i + j] as u128 + carry;
+                product[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            product[i + Self::WORDS] = carry as u64;
+        }
+        let mut words = [0u64; MAX_WORDS];
+        words.copy_from_slice(&product[..MAX_WORDS]);
+        let (result, overflow) = Self::truncate(words);
+        (
+            result,
+            overflow || product[MAX_WORDS..].iter().any(|word| *word != 0),
+        )
+    }
+
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_add(rhs);
+        (!overflow).then_some(result)
+    }
+
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_sub(rhs);
+        (!overflow).then_some(result)
+    }
+
+    pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_mul(rhs);
+        (!overflow).then_some(result)
+    }
+
+    pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        (!rhs.is_zero()).then(|| Self::new(self.to_biguint() / rhs.to_biguint()))
+    }
+
+    pub fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+        (!rhs.is_zero()).then(|| Self::new(self.to_biguint() % rhs.to_biguint()))
+    }
+
+    // Bits shifted past `BITS` are dropped, only shifts of `BITS` or more fail
+    pub fn checked_shl(&self, shift: u32) -> Option<Self> {
+        ((shift as usize) < BITS).then(|| {
+            let mut words = [0u64; MAX_WORDS];
+            write_bits(&mut words, shift as usize, &self.words);
+            Self::truncate(words).0
+        })
+    }
+
+    pub fn checked_shr(&self, shift: u32) -> Option<Self> {
+        ((shift as usize) < BITS).then(|| {
+            let mut words = [0u64; MAX_WORDS];
+            words[..4].copy_from_slice(&read_bits(&self.words, shift as usize, 256));
+            words[4..].copy_from_slice(&read_bits(&self.words, shift as usize + 256, 256));
+            Self::from_words(words)
+        })
+    }
+
+    pub fn wrapping_add(&self, rhs: &Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    pub fn wrapping_sub(&self, rhs: &Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    pub fn wrapping_mul(&self, rhs: &Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+
+    // The shift is taken modulo `BITS`, as for Rust's integers
+    pub fn wrapping_shl(&self, shift: u32) -> Self {
+        self.checked_shl(shift % BITS as u32)
+            .expect("shift is below BITS")
+    }
+
+    pub fn wrapping_shr(&self, shift: u32) -> Self {
+        self.checked_shr(shift % BITS as u32)
+            .expect("shift is below BITS")
+    }
+}
+
+macro_rules! uint_bits_binary_op {
+    ($op:ident, $method:ident, $checked:ident, $message:literal) => {
+        impl<const BITS: usize, const LIMB: usize, L: LimbLayout> core::ops::$op
+            for UintBits<BITS, LIMB, L>
+        {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                self.$checked(&rhs).expect($message)
+            }
+        }
+
+        impl<const BITS: usize, const LIMB: usize, L: LimbLayout> core::ops::$op
+            for &UintBits<BITS, LIMB, L>
+        {
+            type Output = UintBits<BITS, LIMB, L>;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.$checked(rhs).expect($message)
+            }
+        }
+    };
+}
+
+uint_bits_binary_op!(Add, add, checked_add, "attempt to add with overflow");
+uint_bits_binary_op!(Sub, sub, checked_sub, "attempt to subtract with overflow");
+uint_bits_binary_op!(Mul, mul, checked_mul, "attempt to multiply with overflow");
+uint_bits_binary_op!(Div, div, checked_div, "attempt to divide by zero");
+uint_bits_binary_op!(
+    Rem,
+    rem,
+    checked_rem,
+    "attempt to calculate the remainder with a divisor of zero"
+);
+
+macro_rules! uint_bits_shift_op {
+    ($op:ident, $method:ident, $checked:ident, $message:literal) => {
+        impl<const BITS: usize, const LIMB: usize, L: LimbLayout> core::ops::$op<u32>
+            for UintBits<BITS, LIMB, L>
+        {
+            type Output = Self;
+
+            fn $method(self, shift: u32) -> Self {
+                self.$checked(shift).expect($message)
+            }
+        }
+
+        impl<const BITS: usize, const LIMB: usize, L: LimbLayout> core::ops::$op<u32>
+            for &UintBits<BITS, LIMB, L>
+        {
+            type Output = UintBits<BITS, LIMB, L>;
+
+            fn $method(self, shift: u32) -> Self::Output {
+                self.$checked(shift).expect($message)
+            }
+        }
+    };
+}
+
+uint_bits_shift_op!(Shl, shl, checked_shl, "attempt to shift left with overflow");
+uint_bits_shift_op!(
+    Shr,
+    shr,
+    checked_shr,
+    "attempt to shift right with overflow"
+);
+
+impl<const BITS: usize, const LIMB: usize, L: LimbLayout + Eq> Ord for UintBits<BITS, LIMB, L> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.words.iter().rev().cmp(other.words.iter().rev())
+    }
+}
+
+impl<const BITS: usize, const LIMB: usize, L: LimbLayout + Eq> PartialOrd
+    for UintBits<BITS, LIMB, L>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const BITS: usize, const LIMB: usize, L: LimbLayout> BaseCairoType
+    for UintBits<BITS, LIMB, L>
+{
+    fn from_bytes_be(bytes: &[u8]) -> Self {
+        if bytes.len() > Self::BYTES {
+            panic!(
+                "Invalid bytes length for {BITS}-bit integer. Expected {} bytes, got {}",
+                Self::BYTES,
+                bytes.len()
+            );
+        }
+        let mut words = [0u64; MAX_WORDS];
+        for (i, byte) in bytes.iter().rev().enumerate() {
+            words[i / 8] |= (*byte as u64) << (8 * (i % 8));
+        }
+        Self::from_words(words)
+    }
+
+    fn bytes_len() -> usize {
+        Self::BYTES
+    }
+}
+
+impl<const BITS: usize, const LIMB: usize, L: LimbLayout> CairoType for UintBits<BITS, LIMB, L> {
+    fn from_memory(vm: &VirtualMachine, address: Relocatable) -> Result<Self, HintError> {
+        let limbs_address = if L::INDIRECT {
+            vm.get_relocatable(address)?
+        } else {
+            address
+        };
+        let limbs = (0..Self::N_LIMBS)
+            .map(|i| Ok(*vm.get_integer((limbs_address + i)?)?))
+            .collect::<Result<Vec<Felt252>, HintError>>()?;
+        Self::from_limbs(&limbs).map_err(|e| {
+            HintError::CustomHint(format!("invalid limbs at {limbs_address}: {e}").into_boxed_str())
+        })
+    }
+
+    fn from_memory_range(
+        vm: &VirtualMachine,
+        address: Relocatable,
+        count: usize,
+    ) -> Result<Vec<Self>, HintError> {
+        if L::INDIRECT {
+            return (0..count)
+                .map(|i| Self::from_memory(vm, (address + i)?))
+                .collect();
+        }
+        read_felt_chunks(vm, address, count, Self::N_LIMBS, Self::from_limbs)
+    }
+}
+
+impl<const BITS: usize, const LIMB: usize, L: LimbLayout> CairoWritable
+    for UintBits<BITS, LIMB, L>
+{
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        let limbs_address = if L::INDIRECT {
+            vm.add_memory_segment()
+        } else {
+            address
+        };
+        write_felts(vm, limbs_address, self.to_limbs())?;
+        if L::INDIRECT {
+            vm.insert_value(address, limbs_address)?;
+        }
+        Ok((address + Self::n_fields())?)
+    }
+
+    fn n_fields() -> usize {
+        if L::INDIRECT {
+            1
+        } else {
+            Self::N_LIMBS
+        }
+    }
+
+    // Two limbs are Cairo's `(low, high)`, more the `d0`, `d1`, ... of `Uint384`
+    fn layout() -> Layout {
+        let name = format!("UintBits<{BITS}, {LIMB}>");
+        if L::INDIRECT {
+            return Layout::new(name).pointer("limbs");
+        }
+        match Self::N_LIMBS {
+            2 => Layout::new(name).felt("low").felt("high"),
+            n => (0..n).fold(Layout::new(name), |layout, i| layout.felt(format!("d{i}"))),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMB: usize, L: LimbLayout> FromAnyStr for UintBits<BITS, LIMB, L> {
+    fn from_any_str(s: &str) -> Result<Self, String> {
+        Self::from_any_str_with_policy(s, OverflowPolicy::Error)
+    }
+}
+
+impl<const BITS: usize, const LIMB: usize, L: LimbLayout> FromAnyStrWithPolicy
+    for UintBits<BITS, LIMB, L>
+{
+    fn from_any_str_with_policy(s: &str, policy: OverflowPolicy) -> Result<Self, String> {
+        check_numeric_input(s)?;
+        if !s.starts_with("0x") && !s.starts_with("0X") {
+            if let Some(value) = BigUint::parse_bytes(s.as_bytes(), 10) {
+                return Ok(Self::new(policy.apply(value, BITS)?));
+            }
+        }
+        // If it has a prefix or decimal parsing fails, treat as hex.
+        let bytes = hex_bytes_padded(s, None)?;
+        Ok(Self::new(
+            policy.apply(BigUint::from_bytes_be(&bytes), BITS)?,
+        ))
+    }
+}
+
+impl<const BITS: usize, const LIMB: usize, L: LimbLayout> FromStrRadix for UintBits<BITS, LIMB, L> {
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, String> {
+        let value = parse_biguint_radix(s, radix)?;
+        Ok(Self::new(OverflowPolicy::Error.apply(value, BITS)?))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const BITS: usize, const LIMB: usize, L: LimbLayout> serde::Deserialize<'de>
+    for UintBits<BITS, LIMB, L>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::types::serde_utils::deserialize_from_any(deserializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const BITS: usize, const LIMB: usize, L: LimbLayout> serde::Serialize
+    for UintBits<BITS, LIMB, L>
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::types::serde_utils::serialize_hex(&self.to_bytes_be(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const BITS: usize, const LIMB: usize, L: LimbLayout> crate::types::serde_utils::ToMinimalHex
+    for UintBits<BITS, LIMB, L>
+{
+    fn to_minimal_hex(&self) -> String {
+        format!("0x{}", self.to_biguint().to_str_radix(16))
+    }
+}
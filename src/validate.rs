@@ -0,0 +1,478 @@
+//! Declarative constraints on deserialized inputs, checked right after parsing so that an input
+//! out of bounds fails with the path of the field rather than with a range check error in the
+//! middle of the run.
+//!
+//! ```ignore
+//! impl_validate!(TransferInput {
+//!     amount: max_bits(128), nonzero();
+//!     fee: range(..=1_000_000);
+//!     proof: len(1..=64), each(nested());
+//! });
+//! let input: Validated<TransferInput> = program_input.parse()?;
+//! ```
+
+use crate::cairo_type::{CairoWritable, Layout};
+use crate::stdlib::prelude::*;
+use crate::types::{
+    bounded_uint::{Uint128, Uint16, Uint32, Uint64, Uint8},
+    byte_array::ByteArray,
+    bytes::Bytes,
+    felt::Felt,
+    fixed_bytes::FixedBytes,
+    keccak_bytes::KeccakBytes,
+    uint_bits::{LimbLayout, UintBits},
+};
+use cairo_vm::{
+    types::relocatable::Relocatable,
+    vm::{errors::hint_errors::HintError, vm_core::VirtualMachine},
+    Felt252,
+};
+use core::ops::{Bound, RangeBounds};
+use num_bigint::BigUint;
+
+/// A failed constraint and the path of the field it is on, e.g. `proof[2].value`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    pub path: String,
+    pub message: String,
+}
+
+impl ValidationError {
+    pub fn new(path: &str, message: impl Into<String>) -> Self {
+        Self {
+            path: path.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl core::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.path.is_empty() {
+            true => write!(f, "{}", self.message),
+            false => write!(f, "{}: {}", self.path, self.message),
+        }
+    }
+}
+
+/// Input types with constraints on their fields, usually implemented with `impl_validate!`.
+pub trait Validate {
+    /// Checks the value found at `path`, prefixing the path of failed fields with it.
+    fn validate_at(&self, path: &str) -> Result<(), ValidationError>;
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_at("")
+    }
+}
+
+/// `path.field`, or `field` at the root.
+pub fn field_path(path: &str, field: &str) -> String {
+    match path.is_empty() {
+        true => field.to_string(),
+        false => format!("{path}.{field}"),
+    }
+}
+
+/// A check on values of type `T`.
+pub trait Constraint<T: ?Sized> {
+    fn check(&self, value: &T, path: &str) -> Result<(), ValidationError>;
+}
+
+/// Integers the numeric constraints apply to.
+pub trait NumericValue {
+    fn to_biguint(&self) -> BigUint;
+}
+
+impl NumericValue for Felt252 {
+    fn to_biguint(&self) -> BigUint {
+        Felt252::to_biguint(self)
+    }
+}
+
+impl NumericValue for Felt {
+    fn to_biguint(&self) -> BigUint {
+        self.0.to_biguint()
+    }
+}
+
+impl<const BITS: usize, const LIMB: usize, L: LimbLayout> NumericValue for UintBits<BITS, LIMB, L> {
+    fn to_biguint(&self) -> BigUint {
+        UintBits::to_biguint(self)
+    }
+}
+
+// Big-endian, as a hash or an address compares
+impl<const N: usize> NumericValue for FixedBytes<N> {
+    fn to_biguint(&self) -> BigUint {
+        BigUint::from_bytes_be(&self.0)
+    }
+}
+
+macro_rules! numeric_value {
+    ($($ty:ty),*; $($wrapper:ty),*) => {
+        $(
+            impl NumericValue for $ty {
+                fn to_biguint(&self) -> BigUint {
+                    BigUint::from(*self)
+                }
+            }
+        )*
+        $(
+            impl NumericValue for $wrapper {
+                fn to_biguint(&self) -> BigUint {
+                    BigUint::from(self.0)
+                }
+            }
+        )*
+    };
+}
+
+numeric_value!(u8, u16, u32, u64, u128, usize; Uint8, Uint16, Uint32, Uint64, Uint128);
+
+/// Collections the length constraint applies to.
+pub trait Length {
+    fn length(&self) -> usize;
+}
+
+impl<T> Length for [T] {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Length for Vec<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Length for str {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Length for String {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+macro_rules! byte_length {
+    ($($ty:ty),*) => {
+        $(
+            impl Length for $ty {
+                fn length(&self) -> usize {
+                    self.0.len()
+                }
+            }
+        )*
+    };
+}
+
+byte_length!(Bytes, KeccakBytes, ByteArray);
+
+fn describe_bounds<T: core::fmt::Display>(bounds: &(Bound<T>, Bound<T>)) -> String {
+    let start = match &bounds.0 {
+        Bound::Included(start) => format!("[{start}"),
+        Bound::Excluded(start) => format!("({start}"),
+        Bound::Unbounded => "[0".to_string(),
+    };
+    let end = match &bounds.1 {
+        Bound::Included(end) => format!("{end}]"),
+        Bound::Excluded(end) => format!("{end})"),
+        Bound::Unbounded => "inf)".to_string(),
+    };
+    format!("{start}, {end}")
+}
+
+fn owned_bounds<T: Clone>(range: impl RangeBounds<T>) -> (Bound<T>, Bound<T>) {
+    (range.start_bound().cloned(), range.end_bound().cloned())
+}
+
+/// At most `bits` bits, e.g. 128 for a felt holding a `u128`.
+pub struct MaxBits(pub u64);
+
+pub fn max_bits(bits: u64) -> MaxBits {
+    MaxBits(bits)
+}
+
+impl<T: NumericValue + ?Sized> Constraint<T> for MaxBits {
+    fn check(&self, value: &T, path: &str) -> Result<(), ValidationError> {
+        let bits = value.to_biguint().bits();
+        match bits <= self.0 {
+            true => Ok(()),
+            false => Err(ValidationError::new(
+                path,
+                format!("has {bits} bits, more than the {} allowed", self.0),
+            )),
+        }
+    }
+}
+
+pub struct NonZero;
+
+pub fn nonzero() -> NonZero {
+    NonZero
+}
+
+impl<T: NumericValue + ?Sized> Constraint<T> for NonZero {
+    fn check(&self, value: &T, path: &str) -> Result<(), ValidationError> {
+        match value.to_biguint().bits() {
+            0 => Err(ValidationError::new(path, "must not be zero")),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A value within `range`, e.g. `range(1..=1000)`.
+pub struct InRange(pub (Bound<u128>, Bound<u128>));
+
+pub fn range(range: impl RangeBounds<u128>) -> InRange {
+    InRange(owned_bounds(range))
+}
+
+impl<T: NumericValue + ?Sized> Constraint<T> for InRange {
+    fn check(&self, value: &T, path: &str) -> Result<(), ValidationError> {
+        let value = value.to_biguint();
+        let above_start = match self.0 .0 {
+            Bound::Included(start) => value >= BigUint::from(start),
+            Bound::Excluded(start) => value > BigUint::from(start),
+            Bound::Unbounded => true,
+        };
+        let below_end = match self.0 .1 {
+            Bound::Included(end) => value <= BigUint::from(end),
+            Bound::Excluded(end) => value < BigUint::from(end),
+            Bound::Unbounded => true,
+        };
+        match above_start && below_end {
+            true => Ok(()),
+            false => Err(ValidationError::new(
+                path,
+                format!("{value} is not in {}", describe_bounds(&self.0)),
+            )),
+        }
+    }
+}
+
+/// A length within `range`, e.g. `len(1..=64)` for a non-empty proof of at most 64 nodes.
+pub struct Len(pub (Bound<usize>, Bound<usize>));
+
+pub fn len(range: impl RangeBounds<usize>) -> Len {
+    Len(owned_bounds(range))
+}
+
+impl<T: Length + ?Sized> Constraint<T> for Len {
+    fn check(&self, value: &T, path: &str) -> Result<(), ValidationError> {
+        let length = value.length();
+        match self.0.contains(&length) {
+            true => Ok(()),
+            false => Err(ValidationError::new(
+                path,
+                format!("length {length} is not in {}", describe_bounds(&self.0)),
+            )),
+        }
+    }
+}
+
+/// The constraints of a field that implements `Validate` itself.
+pub struct Nested;
+
+pub fn nested() -> Nested {
+    Nested
+}
+
+impl<T: Validate + ?Sized> Constraint<T> for Nested {
+    fn check(&self, value: &T, path: &str) -> Result<(), ValidationError> {
+        value.validate_at(path)
+    }
+}
+
+/// A constraint on each element of an array, reported at `path[i]`.
+pub struct Each<C>(pub C);
+
+pub fn each<C>(constraint: C) -> Each<C> {
+    Each(constraint)
+}
+
+impl<T, C: Constraint<T>> Constraint<[T]> for Each<C> {
+    fn check(&self, values: &[T], path: &str) -> Result<(), ValidationError> {
+        values
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, value)| self.0.check(value, &format!("{path}[{i}]")))
+    }
+}
+
+impl<T, C: Constraint<T>> Constraint<Vec<T>> for Each<C> {
+    fn check(&self, values: &Vec<T>, path: &str) -> Result<(), ValidationError> {
+        Constraint::<[T]>::check(self, values, path)
+    }
+}
+
+/// Implements `Validate` for a struct from a list of constraints per field, in place of a
+/// derive. Fields are checked in order, each against its constraints in order, and fields
+/// without constraints are left out.
+///
+/// ```ignore
+/// impl_validate!(Input {
+///     balance: max_bits(128);
+///     proof: len(..=64), each(nested());
+/// });
+/// ```
+#[macro_export]
+macro_rules! impl_validate {
+    ($ty:ty { $($field:ident : $($constraint:expr),+);* $(;)? }) => {
+        impl $crate::validate::Validate for $ty {
+            fn validate_at(
+                &self,
+                path: &str,
+            ) -> ::core::result::Result<(), $crate::validate::ValidationError> {
+                #[allow(unused_imports)]
+                use $crate::validate::{each, len, max_bits, nested, nonzero, range};
+                $(
+                    let field_path = $crate::validate::field_path(path, stringify!($field));
+                    $(
+                        $crate::validate::Constraint::check(&$constraint, &self.$field, &field_path)?;
+                    )+
+                )*
+                Ok(())
+            }
+        }
+    };
+}
+
+/// A `T` validated as it is deserialized, so parse errors and constraint violations both come
+/// out of `from_value` / `ProgramInput::parse`. Written and laid out as `T`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Validated<T>(pub T);
+
+impl<T> Validated<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> core::ops::Deref for Validated<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: CairoWritable> CairoWritable for Validated<T> {
+    fn to_memory(
+        &self,
+        vm: &mut VirtualMachine,
+        address: Relocatable,
+    ) -> Result<Relocatable, HintError> {
+        self.0.to_memory(vm, address)
+    }
+
+    fn n_fields() -> usize {
+        T::n_fields()
+    }
+
+    fn layout() -> Layout {
+        T::layout()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de> + Validate> serde::Deserialize<'de> for Validated<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = T::deserialize(deserializer)?;
+        value.validate().map_err(serde::de::Error::custom)?;
+        Ok(Self(value))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Validated<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::impl_validate;
+    use crate::types::uint256;
+    use crate::types::{bounded_uint::Uint64, felt::Felt};
+    use crate::validate::{Validate, Validated, ValidationError};
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct Node {
+        value: Felt,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Transfer {
+        amount: uint256::Uint256,
+        fee: Uint64,
+        recipient: Felt,
+        nodes: Vec<Node>,
+    }
+
+    impl_validate!(Node {
+        value: max_bits(64);
+    });
+
+    impl_validate!(Transfer {
+        amount: max_bits(128), nonzero();
+        fee: range(..=1000);
+        recipient: nonzero();
+        nodes: len(1..=2), each(nested());
+    });
+
+    #[test]
+    fn test_validate() {
+        let parse = |json: &str| serde_json::from_str::<Transfer>(json).unwrap().validate();
+        let valid = r#"{"amount": "0x10", "fee": 5, "recipient": "0x1", "nodes": [{"value": 3}]}"#;
+        assert_eq!(parse(valid), Ok(()));
+        assert_eq!(
+            parse(r#"{"amount": "0x0", "fee": 5, "recipient": "0x1", "nodes": [{"value": 3}]}"#)
+                .unwrap_err()
+                .to_string(),
+            "amount: must not be zero"
+        );
+        assert_eq!(
+            parse(r#"{"amount": "0x1", "fee": 1001, "recipient": "0x1", "nodes": [{"value": 3}]}"#)
+                .unwrap_err()
+                .to_string(),
+            "fee: 1001 is not in [0, 1000]"
+        );
+        assert_eq!(
+            parse(r#"{"amount": "0x1", "fee": 1, "recipient": "0x1", "nodes": []}"#)
+                .unwrap_err()
+                .to_string(),
+            "nodes: length 0 is not in [1, 2]"
+        );
+        let error = parse(&format!(
+            r#"{{"amount": "0x1", "fee": 1, "recipient": "0x1", "nodes": [{{"value": 1}}, {{"value": "{}"}}]}}"#,
+            "0x1".to_string() + &"0".repeat(16)
+        ))
+        .unwrap_err();
+        assert_eq!(
+            error,
+            ValidationError::new("nodes[1].value", "has 65 bits, more than the 64 allowed")
+        );
+
+        // Checked as part of deserialization
+        let validated: Validated<Transfer> = serde_json::from_str(valid).unwrap();
+        assert_eq!(validated.fee.0, 5);
+        let error = serde_json::from_str::<Validated<Transfer>>(
+            r#"{"amount": "0x1", "fee": 1, "recipient": "0x0", "nodes": [{"value": 3}]}"#,
+        )
+        .unwrap_err();
+        assert!(error.to_string().starts_with("recipient: must not be zero"));
+    }
+}
@@ -0,0 +1,11 @@
+%builtins output
+
+// Writes the two felts of the program input, e.g. a `Uint256`, to the output
+func main(output_ptr: felt*) -> (output_ptr: felt*) {
+    alloc_locals;
+    local input: felt*;
+    %{ ids.input = segments.gen_arg(program_input) %}
+    assert output_ptr[0] = input[0];
+    assert output_ptr[1] = input[1];
+    return (output_ptr=output_ptr + 2);
+}